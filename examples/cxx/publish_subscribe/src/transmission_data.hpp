@@ -17,6 +17,8 @@
 #include <iostream>
 
 struct TransmissionData {
+    // identifies the type independent of the language, see the Rust counterpart
+    static constexpr const char* IOX2_TYPE_NAME = "TransmissionData";
     std::int32_t x;
     std::int32_t y;
     double funky;
@@ -17,6 +17,8 @@
 #include <iostream>
 
 struct CustomHeader {
+    // identifies the type independent of the language, see the Rust counterpart
+    static constexpr const char* IOX2_TYPE_NAME = "CustomHeader";
     int32_t version;
     uint64_t timestamp;
 };
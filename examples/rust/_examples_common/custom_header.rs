@@ -10,7 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-#[derive(Default, Debug)]
+use iceoryx2::prelude::*;
+
+#[derive(Default, Debug, ZeroCopySend)]
+// identifies the type independent of the language, see the C++ counterpart
+#[type_name("CustomHeader")]
 #[repr(C)]
 pub struct CustomHeader {
     pub version: i32,
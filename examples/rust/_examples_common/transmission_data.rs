@@ -10,7 +10,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-#[derive(Debug)]
+use iceoryx2::prelude::*;
+
+#[derive(Debug, ZeroCopySend)]
+// identifies the type independent of the language, see the C++ counterpart
+#[type_name("TransmissionData")]
 #[repr(C)]
 pub struct TransmissionData {
     pub x: i32,
@@ -14,12 +14,15 @@ use std::time::Duration;
 
 use iceoryx2::prelude::*;
 use iceoryx2_bb_container::{
-    byte_string::FixedSizeByteString, queue::FixedSizeQueue, vec::FixedSizeVec,
+    byte_string::FixedSizeByteString, hash_map::FixedSizeHashMap, list::FixedSizeList,
+    priority_queue::FixedSizePriorityQueue, queue::FixedSizeQueue,
+    ring_buffer::FixedSizeRingBuffer, vec::FixedSizeVec,
 };
 
 // For both data types we derive from PlacementDefault to allow in memory initialization
 // without any copy. Avoids stack overflows when data type is larger than the available stack.
-#[derive(Debug, Default, PlacementDefault)]
+// ZeroCopySend ensures at compile time that the types can be shared between processes.
+#[derive(Debug, Default, PlacementDefault, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexData {
     name: FixedSizeByteString<4>,
@@ -28,7 +31,8 @@ pub struct ComplexData {
 
 // For both data types we derive from PlacementDefault to allow in memory initialization
 // without any copy. Avoids stack overflows when data type is larger than the available stack.
-#[derive(Debug, Default, PlacementDefault)]
+// ZeroCopySend ensures at compile time that the types can be shared between processes.
+#[derive(Debug, Default, PlacementDefault, ZeroCopySend)]
 #[repr(C)]
 pub struct ComplexDataType {
     plain_old_data: u64,
@@ -36,6 +40,10 @@ pub struct ComplexDataType {
     vec_of_data: FixedSizeVec<u64, 4>,
     vec_of_complex_data: FixedSizeVec<ComplexData, 404857>,
     a_queue_of_things: FixedSizeQueue<FixedSizeByteString<4>, 2>,
+    a_map_of_readings: FixedSizeHashMap<u32, u64, 8>,
+    a_list_of_tasks: FixedSizeList<u64, 4>,
+    a_priority_queue_of_deadlines: FixedSizePriorityQueue<u64, 4>,
+    a_ring_buffer_of_measurements: FixedSizeRingBuffer<u64, 4>,
 }
 
 const CYCLE_TIME: Duration = Duration::from_secs(1);
@@ -78,6 +86,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         payload
             .a_queue_of_things
             .push(FixedSizeByteString::from_bytes(b"buh")?);
+        payload.a_map_of_readings.insert(7, counter)?;
+        payload.a_list_of_tasks.push_back(counter)?;
+        payload.a_priority_queue_of_deadlines.push(counter);
+        payload.a_ring_buffer_of_measurements.push(counter);
 
         sample.send()?;
         println!("{} :: send", counter);
@@ -37,8 +37,9 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use iceoryx2_bb_derive_macros::PlacementDefault;
+use iceoryx2_bb_derive_macros::{PlacementDefault, ZeroCopySend};
 use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic};
 use serde::{de::Visitor, Deserialize, Deserializer, Serialize, Serializer};
 
@@ -76,7 +77,7 @@ impl std::fmt::Display for FixedSizeByteStringModificationError {
 impl std::error::Error for FixedSizeByteStringModificationError {}
 
 /// Relocatable string with compile time fixed size capacity.
-#[derive(Clone, Copy, PlacementDefault)]
+#[derive(Clone, Copy, PlacementDefault, ZeroCopySend)]
 #[repr(C)]
 pub struct FixedSizeByteString<const CAPACITY: usize> {
     len: usize,
@@ -0,0 +1,774 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three hash map variations that are similar to [`std::collections::HashMap`].
+//!
+//!  * [`FixedSizeHashMap`](crate::hash_map::FixedSizeHashMap), compile-time fixed size hash map
+//!     that is self-contained.
+//!  * [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap), run-time fixed size hash map
+//!     that acquires the required memory from a custom user-provided allocator.
+//!  * [`HashMap`](crate::hash_map::HashMap), run-time fixed size hash map that uses by default
+//!     heap memory.
+//!
+//! In contrast to [`std::collections::HashMap`] the keys are hashed with a deterministic hash
+//! function so that the same key is always stored at the same position, independent of the
+//! process that accesses the map.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizeHashMap`](crate::hash_map::FixedSizeHashMap)
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::FixedSizeHashMap;
+//!
+//! const MAP_CAPACITY: usize = 8;
+//! let mut map = FixedSizeHashMap::<u32, f64, MAP_CAPACITY>::new();
+//!
+//! // sensor id -> reading
+//! map.insert(12, 0.25).expect("map has enough capacity");
+//! map.insert(34, 4.75).expect("map has enough capacity");
+//!
+//! println!("reading of sensor 12 {}", map.get(&12).unwrap());
+//! ```
+//!
+//! ## Use the [`HashMap`](crate::hash_map::HashMap)
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::HashMap;
+//!
+//! let map_capacity = 1234;
+//! let mut map = HashMap::<u32, f64>::new(map_capacity);
+//!
+//! map.insert(12, 0.25).expect("map has enough capacity");
+//!
+//! println!("reading of sensor 12 {}", map.get(&12).unwrap());
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap) inside constructs which provides memory
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::{details::Slot, RelocatableHashMap};
+//! use iceoryx2_bb_elementary::math::align_to;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//! use core::mem::MaybeUninit;
+//!
+//! const MAP_CAPACITY:usize = 12;
+//! struct MyConstruct {
+//!     map: RelocatableHashMap<u32, u64>,
+//!     map_memory: [MaybeUninit<Slot<u32, u64>>; MAP_CAPACITY],
+//! }
+//!
+//! impl MyConstruct {
+//!     pub fn new() -> Self {
+//!         Self {
+//!             map: unsafe { RelocatableHashMap::new(MAP_CAPACITY,
+//!                             align_to::<MaybeUninit<Slot<u32, u64>>>(
+//!                                 std::mem::size_of::<RelocatableHashMap<u32, u64>>()) as isize) },
+//!             map_memory: core::array::from_fn(|_| MaybeUninit::uninit()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Create [`RelocatableHashMap`](crate::hash_map::RelocatableHashMap) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_map::RelocatableHashMap;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const MAP_CAPACITY:usize = 12;
+//! const MEM_SIZE: usize = RelocatableHashMap::<u32, u64>::const_memory_size(MAP_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let map = unsafe { RelocatableHashMap::<u32, u64>::new_uninit(MAP_CAPACITY) };
+//! unsafe { map.init(&bump_allocator).expect("map init failed") };
+//! ```
+//!
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+/// Hash map with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatableHashMap`] it is movable but is not shared memory compatible.
+pub type HashMap<K, V> = details::HashMap<K, V, OwningPointer<details::Slot<K, V>>>;
+/// **Non-movable** relocatable hash map with runtime fixed size capacity.
+pub type RelocatableHashMap<K, V> = details::HashMap<K, V, RelocatablePointer<details::Slot<K, V>>>;
+
+/// Failures caused by [`FixedSizeHashMap::insert()`], [`HashMap::insert()`] or
+/// [`RelocatableHashMap::insert()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HashMapInsertError {
+    /// The key is not yet contained and the map has no free slot left.
+    ExceedsCapacity,
+}
+
+impl std::fmt::Display for HashMapInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "HashMapInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for HashMapInsertError {}
+
+// FNV-1a, the std hashers are randomly seeded per process and cannot be used for shared memory
+struct DeterministicHasher(u64);
+
+impl Default for DeterministicHasher {
+    fn default() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for DeterministicHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(0x100000001b3);
+        }
+    }
+}
+
+/// Iterator over all key-value pairs of a hash map. The order of the elements is unspecified.
+pub struct Iter<'a, K, V> {
+    slots: *const details::Slot<K, V>,
+    capacity: usize,
+    index: usize,
+    _lifetime: PhantomData<&'a details::Slot<K, V>>,
+}
+
+impl<'a, K: 'a, V: 'a> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.capacity {
+            let slot = unsafe { &*self.slots.add(self.index) };
+            self.index += 1;
+
+            if slot.is_occupied {
+                return Some(unsafe { (slot.key.assume_init_ref(), slot.value.assume_init_ref()) });
+            }
+        }
+
+        None
+    }
+}
+
+#[doc(hidden)]
+pub mod details {
+    use super::*;
+
+    /// Storage of a single key-value pair of the hash map.
+    #[repr(C)]
+    pub struct Slot<K, V> {
+        pub(super) key: MaybeUninit<K>,
+        pub(super) value: MaybeUninit<V>,
+        pub(super) is_occupied: bool,
+    }
+
+    /// **Non-movable** relocatable hash map with runtime fixed size capacity.
+    #[repr(C)]
+    pub struct HashMap<K, V, PointerType: PointerTrait<Slot<K, V>>> {
+        data_ptr: PointerType,
+        capacity: usize,
+        len: usize,
+        // the slot memory is provided uninitialized and the occupancy flags are set up
+        // with the first insertion
+        are_slots_initialized: bool,
+        is_initialized: IoxAtomicBool,
+        _phantom_data: PhantomData<(K, V)>,
+    }
+
+    unsafe impl<K: Send, V: Send, PointerType: PointerTrait<Slot<K, V>>> Send
+        for HashMap<K, V, PointerType>
+    {
+    }
+
+    unsafe impl<K: Sync, V: Sync, PointerType: PointerTrait<Slot<K, V>>> Sync
+        for HashMap<K, V, PointerType>
+    {
+    }
+
+    impl<K: Hash + Eq, V> HashMap<K, V, OwningPointer<Slot<K, V>>> {
+        /// Creates a new [`HashMap`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                data_ptr: OwningPointer::<Slot<K, V>>::new_with_alloc(capacity),
+                capacity,
+                len: 0,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        /// Inserts a key-value pair into the map. If the key was already contained, the value is
+        /// updated and the old value is returned. If the key is not contained and the map is full
+        /// it fails with [`HashMapInsertError::ExceedsCapacity`].
+        pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, HashMapInsertError> {
+            unsafe { self.insert_impl(key, value) }
+        }
+
+        /// Removes the key from the map and returns its value. If the key is not contained it
+        /// returns [`None`].
+        pub fn remove(&mut self, key: &K) -> Option<V> {
+            unsafe { self.remove_impl(key) }
+        }
+
+        /// Removes all elements from the map
+        pub fn clear(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+
+    impl<K, V> RelocatableContainer for HashMap<K, V, RelocatablePointer<Slot<K, V>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                capacity,
+                len: 0,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                capacity,
+                len: 0,
+                are_slots_initialized: false,
+                is_initialized: IoxAtomicBool::new(false),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            if self
+                .is_initialized
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                fatal_panic!(
+                    from "HashMap::init()",
+                    "Memory already initialized. Initializing it twice may lead to undefined behavior."
+                );
+            }
+
+            self.data_ptr.init(fail!(from "HashMap::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(
+                     std::mem::size_of::<Slot<K, V>>() * self.capacity,
+                     std::mem::align_of::<Slot<K, V>>(),
+                 )), "Failed to initialize hash map since the allocation of the data memory failed."
+            ));
+            self.is_initialized
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<K: Hash + Eq, V> HashMap<K, V, RelocatablePointer<Slot<K, V>>> {
+        /// Inserts a key-value pair into the map. If the key was already contained, the value is
+        /// updated and the old value is returned. If the key is not contained and the map is full
+        /// it fails with [`HashMapInsertError::ExceedsCapacity`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn insert(&mut self, key: K, value: V) -> Result<Option<V>, HashMapInsertError> {
+            self.insert_impl(key, value)
+        }
+
+        /// Removes the key from the map and returns its value. If the key is not contained it
+        /// returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn remove(&mut self, key: &K) -> Option<V> {
+            self.remove_impl(key)
+        }
+
+        /// Removes all elements from the map
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashMap::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.clear_impl()
+        }
+    }
+
+    impl<K: Hash + Eq, V, PointerType: PointerTrait<Slot<K, V>>> HashMap<K, V, PointerType> {
+        /// Returns a reference to the value of the key. If the key is not contained it returns
+        /// [`None`].
+        pub fn get(&self, key: &K) -> Option<&V> {
+            self.verify_init(&format!("HashMap<{}>::get()", std::any::type_name::<K>()));
+
+            self.find(key)
+                .map(|index| unsafe { (*self.slot(index)).value.assume_init_ref() })
+        }
+
+        /// Returns a mutable reference to the value of the key. If the key is not contained it
+        /// returns [`None`].
+        pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+            self.verify_init(&format!(
+                "HashMap<{}>::get_mut()",
+                std::any::type_name::<K>()
+            ));
+
+            self.find(key)
+                .map(|index| unsafe { (*self.slot_mut(index)).value.assume_init_mut() })
+        }
+
+        /// Returns true if the key is contained in the map, otherwise false
+        pub fn contains_key(&self, key: &K) -> bool {
+            self.verify_init(&format!(
+                "HashMap<{}>::contains_key()",
+                std::any::type_name::<K>()
+            ));
+
+            self.find(key).is_some()
+        }
+
+        fn home_index(&self, key: &K) -> usize {
+            let mut hasher = DeterministicHasher::default();
+            key.hash(&mut hasher);
+            (hasher.finish() % self.capacity as u64) as usize
+        }
+
+        fn find(&self, key: &K) -> Option<usize> {
+            if self.is_empty() {
+                return None;
+            }
+
+            let home_index = self.home_index(key);
+            for n in 0..self.capacity {
+                let index = (home_index + n) % self.capacity;
+                let slot = unsafe { &*self.slot(index) };
+                if !slot.is_occupied {
+                    return None;
+                }
+
+                if unsafe { slot.key.assume_init_ref() } == key {
+                    return Some(index);
+                }
+            }
+
+            None
+        }
+
+        pub(crate) unsafe fn insert_impl(
+            &mut self,
+            key: K,
+            value: V,
+        ) -> Result<Option<V>, HashMapInsertError> {
+            self.verify_init(&format!(
+                "HashMap<{}>::insert()",
+                std::any::type_name::<K>()
+            ));
+
+            if let Some(index) = self.find(&key) {
+                let slot = &mut *self.slot_mut(index);
+                return Ok(Some(
+                    std::mem::replace(&mut slot.value, MaybeUninit::new(value)).assume_init(),
+                ));
+            }
+
+            if self.is_full() {
+                fail!(from "HashMap::insert()", with HashMapInsertError::ExceedsCapacity,
+                    "Unable to insert key since the hash map with a capacity of {} is full.",
+                    self.capacity);
+            }
+
+            self.initialize_slots();
+
+            let home_index = self.home_index(&key);
+            for n in 0..self.capacity {
+                let slot = &mut *self.slot_mut((home_index + n) % self.capacity);
+                if !slot.is_occupied {
+                    slot.key.write(key);
+                    slot.value.write(value);
+                    slot.is_occupied = true;
+                    self.len += 1;
+                    return Ok(None);
+                }
+            }
+
+            fatal_panic!(from "HashMap::insert()",
+                "This should never happen! The hash map is not full but contains no free slot.");
+        }
+
+        pub(crate) unsafe fn remove_impl(&mut self, key: &K) -> Option<V> {
+            self.verify_init(&format!(
+                "HashMap<{}>::remove()",
+                std::any::type_name::<K>()
+            ));
+
+            let mut free_index = self.find(key)?;
+            let value = self.take_slot(free_index).1;
+
+            // shift the following elements of the probe sequence backwards so that every
+            // element stays reachable from its home index without the need of tombstones
+            let mut index = free_index;
+            loop {
+                index = (index + 1) % self.capacity;
+                let slot = &*self.slot(index);
+                if !slot.is_occupied {
+                    break;
+                }
+
+                let home_index = self.home_index(slot.key.assume_init_ref());
+                let distance_to_home = (index + self.capacity - home_index) % self.capacity;
+                let distance_to_free = (index + self.capacity - free_index) % self.capacity;
+                if distance_to_free <= distance_to_home {
+                    let (key, value) = self.take_slot(index);
+                    let free_slot = &mut *self.slot_mut(free_index);
+                    free_slot.key.write(key);
+                    free_slot.value.write(value);
+                    free_slot.is_occupied = true;
+                    free_index = index;
+                }
+            }
+
+            self.len -= 1;
+            Some(value)
+        }
+    }
+
+    impl<K, V, PointerType: PointerTrait<Slot<K, V>>> HashMap<K, V, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_initialized
+                    .load(std::sync::atomic::Ordering::Relaxed),
+                "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+                source
+            );
+        }
+
+        /// Returns the required memory size for a hash map with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<Slot<K, V>>(capacity)
+        }
+
+        /// Returns true if the map is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns the capacity of the map
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns the number of elements inside the map
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns true if the map is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity()
+        }
+
+        /// Returns an iterator over all key-value pairs. The order of the elements is
+        /// unspecified.
+        pub fn iter(&self) -> Iter<'_, K, V> {
+            self.verify_init(&format!("HashMap<{}>::iter()", std::any::type_name::<K>()));
+
+            Iter {
+                slots: if self.are_slots_initialized {
+                    unsafe { self.data_ptr.as_ptr() }
+                } else {
+                    core::ptr::null()
+                },
+                capacity: if self.are_slots_initialized {
+                    self.capacity
+                } else {
+                    0
+                },
+                index: 0,
+                _lifetime: PhantomData,
+            }
+        }
+
+        /// Returns an iterator over all keys. The order of the keys is unspecified.
+        pub fn keys(&self) -> impl Iterator<Item = &K> {
+            self.iter().map(|(key, _)| key)
+        }
+
+        /// Returns an iterator over all values. The order of the values is unspecified.
+        pub fn values(&self) -> impl Iterator<Item = &V> {
+            self.iter().map(|(_, value)| value)
+        }
+
+        unsafe fn slot(&self, index: usize) -> *const Slot<K, V> {
+            self.data_ptr.as_ptr().add(index)
+        }
+
+        unsafe fn slot_mut(&mut self, index: usize) -> *mut Slot<K, V> {
+            self.data_ptr.as_mut_ptr().add(index)
+        }
+
+        unsafe fn initialize_slots(&mut self) {
+            if self.are_slots_initialized {
+                return;
+            }
+
+            for index in 0..self.capacity {
+                core::ptr::addr_of_mut!((*self.slot_mut(index)).is_occupied).write(false);
+            }
+            self.are_slots_initialized = true;
+        }
+
+        unsafe fn take_slot(&mut self, index: usize) -> (K, V) {
+            let slot = &mut *self.slot_mut(index);
+            slot.is_occupied = false;
+            (
+                std::mem::replace(&mut slot.key, MaybeUninit::uninit()).assume_init(),
+                std::mem::replace(&mut slot.value, MaybeUninit::uninit()).assume_init(),
+            )
+        }
+
+        pub(crate) unsafe fn clear_impl(&mut self) {
+            if self.is_empty() {
+                return;
+            }
+
+            for index in 0..self.capacity {
+                if (*self.slot(index)).is_occupied {
+                    self.take_slot(index);
+                }
+            }
+            self.len = 0;
+        }
+    }
+
+    impl<K: Debug, V: Debug, PointerType: PointerTrait<Slot<K, V>>> Debug
+        for HashMap<K, V, PointerType>
+    {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_map().entries(self.iter()).finish()
+        }
+    }
+
+    impl<K: Hash + Eq, V: PartialEq, PointerType: PointerTrait<Slot<K, V>>> PartialEq
+        for HashMap<K, V, PointerType>
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.len() == other.len()
+                && self
+                    .iter()
+                    .all(|(key, value)| other.get(key) == Some(value))
+        }
+    }
+
+    impl<K: Hash + Eq, V: Eq, PointerType: PointerTrait<Slot<K, V>>> Eq for HashMap<K, V, PointerType> {}
+
+    impl<K, V, PointerType: PointerTrait<Slot<K, V>>> Drop for HashMap<K, V, PointerType> {
+        fn drop(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+/// Relocatable hash map with compile time fixed size capacity. In contrast to its counterpart the
+/// [`HashMap`] it is movable.
+#[repr(C)]
+pub struct FixedSizeHashMap<K, V, const CAPACITY: usize> {
+    state: RelocatableHashMap<K, V>,
+    _data: [MaybeUninit<details::Slot<K, V>>; CAPACITY],
+}
+
+// the hash map stores its elements inline and uses a relative pointer to access them
+unsafe impl<K: ZeroCopySend, V: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            K::__type_identity(),
+            V::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+impl<K, V, const CAPACITY: usize> PlacementDefault for FixedSizeHashMap<K, V, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<K, V, const CAPACITY: usize> Default for FixedSizeHashMap<K, V, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, const CAPACITY: usize> Debug for FixedSizeHashMap<K, V, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<K: Hash + Eq, V: PartialEq, const CAPACITY: usize> PartialEq
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.state.eq(&other.state)
+    }
+}
+
+impl<K: Hash + Eq, V: Eq, const CAPACITY: usize> Eq for FixedSizeHashMap<K, V, CAPACITY> {}
+
+impl<K: Hash + Eq + Clone, V: Clone, const CAPACITY: usize> Clone
+    for FixedSizeHashMap<K, V, CAPACITY>
+{
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for (key, value) in self.iter() {
+            // cannot fail since both maps have the same capacity
+            let _ = new_self.insert(key.clone(), value.clone());
+        }
+        new_self
+    }
+}
+
+unsafe impl<K: Send, V: Send, const CAPACITY: usize> Send for FixedSizeHashMap<K, V, CAPACITY> {}
+unsafe impl<K: Sync, V: Sync, const CAPACITY: usize> Sync for FixedSizeHashMap<K, V, CAPACITY> {}
+
+impl<K, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    fn initialize_state() -> RelocatableHashMap<K, V> {
+        unsafe {
+            RelocatableHashMap::new(
+                CAPACITY,
+                align_to::<MaybeUninit<details::Slot<K, V>>>(std::mem::size_of::<
+                    RelocatableHashMap<K, V>,
+                >()) as isize,
+            )
+        }
+    }
+
+    /// Creates a new hash map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the map is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the capacity of the map
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns the number of elements inside the map
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if the map is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Removes all elements from the map
+    pub fn clear(&mut self) {
+        unsafe { self.state.clear_impl() }
+    }
+
+    /// Returns an iterator over all key-value pairs. The order of the elements is
+    /// unspecified.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.state.iter()
+    }
+
+    /// Returns an iterator over all keys. The order of the keys is unspecified.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.state.keys()
+    }
+
+    /// Returns an iterator over all values. The order of the values is unspecified.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.state.values()
+    }
+}
+
+impl<K: Hash + Eq, V, const CAPACITY: usize> FixedSizeHashMap<K, V, CAPACITY> {
+    /// Inserts a key-value pair into the map. If the key was already contained, the value is
+    /// updated and the old value is returned. If the key is not contained and the map is full
+    /// it fails with [`HashMapInsertError::ExceedsCapacity`].
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, HashMapInsertError> {
+        unsafe { self.state.insert_impl(key, value) }
+    }
+
+    /// Removes the key from the map and returns its value. If the key is not contained it
+    /// returns [`None`].
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        unsafe { self.state.remove_impl(key) }
+    }
+
+    /// Returns a reference to the value of the key. If the key is not contained it returns
+    /// [`None`].
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.state.get(key)
+    }
+
+    /// Returns a mutable reference to the value of the key. If the key is not contained it
+    /// returns [`None`].
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.state.get_mut(key)
+    }
+
+    /// Returns true if the key is contained in the map, otherwise false
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.state.contains_key(key)
+    }
+}
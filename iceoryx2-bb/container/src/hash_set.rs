@@ -0,0 +1,370 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three hash set variations that are similar to [`std::collections::HashSet`].
+//!
+//!  * [`FixedSizeHashSet`](crate::hash_set::FixedSizeHashSet), compile-time fixed size hash set
+//!     that is self-contained.
+//!  * [`RelocatableHashSet`](crate::hash_set::RelocatableHashSet), run-time fixed size hash set
+//!     that acquires the required memory from a custom user-provided allocator.
+//!  * [`HashSet`](crate::hash_set::HashSet), run-time fixed size hash set that uses by default
+//!     heap memory.
+//!
+//! The sets are built on top of the [`hash_map`](crate::hash_map) and share its deterministic
+//! hash function.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizeHashSet`](crate::hash_set::FixedSizeHashSet)
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_set::FixedSizeHashSet;
+//!
+//! const SET_CAPACITY: usize = 8;
+//! let mut set = FixedSizeHashSet::<u32, SET_CAPACITY>::new();
+//!
+//! set.insert(12).expect("set has enough capacity");
+//!
+//! println!("contains 12: {}", set.contains(&12));
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatableHashSet`](crate::hash_set::RelocatableHashSet) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::hash_set::RelocatableHashSet;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const SET_CAPACITY:usize = 12;
+//! const MEM_SIZE: usize = RelocatableHashSet::<u32>::const_memory_size(SET_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let set = unsafe { RelocatableHashSet::<u32>::new_uninit(SET_CAPACITY) };
+//! unsafe { set.init(&bump_allocator).expect("set init failed") };
+//! ```
+//!
+use crate::hash_map::{self, details::Slot, HashMapInsertError};
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+/// Hash set with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatableHashSet`] it is movable but is not shared memory compatible.
+pub type HashSet<T> = details::HashSet<T, OwningPointer<Slot<T, ()>>>;
+/// **Non-movable** relocatable hash set with runtime fixed size capacity.
+pub type RelocatableHashSet<T> = details::HashSet<T, RelocatablePointer<Slot<T, ()>>>;
+
+/// Failures caused by [`FixedSizeHashSet::insert()`], [`HashSet::insert()`] or
+/// [`RelocatableHashSet::insert()`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum HashSetInsertError {
+    /// The value is not yet contained and the set has no free slot left.
+    ExceedsCapacity,
+}
+
+impl std::fmt::Display for HashSetInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "HashSetInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for HashSetInsertError {}
+
+impl From<HashMapInsertError> for HashSetInsertError {
+    fn from(value: HashMapInsertError) -> Self {
+        match value {
+            HashMapInsertError::ExceedsCapacity => HashSetInsertError::ExceedsCapacity,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod details {
+    use super::*;
+
+    /// **Non-movable** relocatable hash set with runtime fixed size capacity.
+    #[repr(C)]
+    pub struct HashSet<T, PointerType: PointerTrait<Slot<T, ()>>> {
+        map: hash_map::details::HashMap<T, (), PointerType>,
+    }
+
+    impl<T: Hash + Eq> HashSet<T, OwningPointer<Slot<T, ()>>> {
+        /// Creates a new [`HashSet`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                map: hash_map::HashMap::new(capacity),
+            }
+        }
+
+        /// Adds a value to the set. Returns true when the value was newly inserted and false
+        /// when it was already contained. If the set is full it fails with
+        /// [`HashSetInsertError::ExceedsCapacity`].
+        pub fn insert(&mut self, value: T) -> Result<bool, HashSetInsertError> {
+            unsafe { self.insert_impl(value) }
+        }
+
+        /// Removes the value from the set. Returns true when the value was contained, otherwise
+        /// false.
+        pub fn remove(&mut self, value: &T) -> bool {
+            unsafe { self.remove_impl(value) }
+        }
+
+        /// Removes all elements from the set
+        pub fn clear(&mut self) {
+            unsafe { self.map.clear_impl() }
+        }
+    }
+
+    impl<T> RelocatableContainer for HashSet<T, RelocatablePointer<Slot<T, ()>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                map: hash_map::RelocatableHashMap::new(capacity, distance_to_data),
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                map: hash_map::RelocatableHashMap::new_uninit(capacity),
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            self.map.init(allocator)
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T: Hash + Eq> HashSet<T, RelocatablePointer<Slot<T, ()>>> {
+        /// Adds a value to the set. Returns true when the value was newly inserted and false
+        /// when it was already contained. If the set is full it fails with
+        /// [`HashSetInsertError::ExceedsCapacity`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashSet::init()`] must have been called once before
+        ///
+        pub unsafe fn insert(&mut self, value: T) -> Result<bool, HashSetInsertError> {
+            self.insert_impl(value)
+        }
+
+        /// Removes the value from the set. Returns true when the value was contained, otherwise
+        /// false.
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashSet::init()`] must have been called once before
+        ///
+        pub unsafe fn remove(&mut self, value: &T) -> bool {
+            self.remove_impl(value)
+        }
+
+        /// Removes all elements from the set
+        ///
+        /// # Safety
+        ///
+        ///  * [`HashSet::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.map.clear_impl()
+        }
+    }
+
+    impl<T: Hash + Eq, PointerType: PointerTrait<Slot<T, ()>>> HashSet<T, PointerType> {
+        /// Returns true if the value is contained in the set, otherwise false
+        pub fn contains(&self, value: &T) -> bool {
+            self.map.contains_key(value)
+        }
+
+        pub(crate) unsafe fn insert_impl(&mut self, value: T) -> Result<bool, HashSetInsertError> {
+            Ok(self.map.insert_impl(value, ())?.is_none())
+        }
+
+        pub(crate) unsafe fn remove_impl(&mut self, value: &T) -> bool {
+            self.map.remove_impl(value).is_some()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<Slot<T, ()>>> HashSet<T, PointerType> {
+        /// Returns the required memory size for a hash set with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            hash_map::details::HashMap::<T, (), PointerType>::const_memory_size(capacity)
+        }
+
+        /// Returns true if the set is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.map.is_empty()
+        }
+
+        /// Returns the capacity of the set
+        pub fn capacity(&self) -> usize {
+            self.map.capacity()
+        }
+
+        /// Returns the number of elements inside the set
+        pub fn len(&self) -> usize {
+            self.map.len()
+        }
+
+        /// Returns true if the set is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.map.is_full()
+        }
+
+        /// Returns an iterator over all values. The order of the values is unspecified.
+        pub fn iter(&self) -> impl Iterator<Item = &T> {
+            self.map.keys()
+        }
+    }
+
+    impl<T: Debug, PointerType: PointerTrait<Slot<T, ()>>> Debug for HashSet<T, PointerType> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_set().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T: Hash + Eq, PointerType: PointerTrait<Slot<T, ()>>> PartialEq for HashSet<T, PointerType> {
+        fn eq(&self, other: &Self) -> bool {
+            self.map.eq(&other.map)
+        }
+    }
+
+    impl<T: Hash + Eq, PointerType: PointerTrait<Slot<T, ()>>> Eq for HashSet<T, PointerType> {}
+}
+
+/// Relocatable hash set with compile time fixed size capacity. In contrast to its counterpart the
+/// [`HashSet`] it is movable.
+#[repr(C)]
+pub struct FixedSizeHashSet<T, const CAPACITY: usize> {
+    map: hash_map::FixedSizeHashMap<T, (), CAPACITY>,
+}
+
+// the hash set stores its elements inline and uses a relative pointer to access them
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeHashSet<T, CAPACITY> {
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeHashSet<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let map_ptr = core::ptr::addr_of_mut!((*ptr).map);
+        hash_map::FixedSizeHashMap::placement_default(map_ptr);
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeHashSet<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            map: hash_map::FixedSizeHashMap::default(),
+        }
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeHashSet<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> PartialEq for FixedSizeHashSet<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.map.eq(&other.map)
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> Eq for FixedSizeHashSet<T, CAPACITY> {}
+
+impl<T: Hash + Eq + Clone, const CAPACITY: usize> Clone for FixedSizeHashSet<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        Self {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, const CAPACITY: usize> FixedSizeHashSet<T, CAPACITY> {
+    /// Creates a new hash set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the set is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the capacity of the set
+    pub fn capacity(&self) -> usize {
+        self.map.capacity()
+    }
+
+    /// Returns the number of elements inside the set
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if the set is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.map.is_full()
+    }
+
+    /// Removes all elements from the set
+    pub fn clear(&mut self) {
+        self.map.clear()
+    }
+
+    /// Returns an iterator over all values. The order of the values is unspecified.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.map.keys()
+    }
+}
+
+impl<T: Hash + Eq, const CAPACITY: usize> FixedSizeHashSet<T, CAPACITY> {
+    /// Adds a value to the set. Returns true when the value was newly inserted and false
+    /// when it was already contained. If the set is full it fails with
+    /// [`HashSetInsertError::ExceedsCapacity`].
+    pub fn insert(&mut self, value: T) -> Result<bool, HashSetInsertError> {
+        Ok(self.map.insert(value, ())?.is_none())
+    }
+
+    /// Removes the value from the set. Returns true when the value was contained, otherwise
+    /// false.
+    pub fn remove(&mut self, value: &T) -> bool {
+        self.map.remove(value).is_some()
+    }
+
+    /// Returns true if the value is contained in the set, otherwise false
+    pub fn contains(&self, value: &T) -> bool {
+        self.map.contains_key(value)
+    }
+}
@@ -36,6 +36,7 @@
 //!
 //! ```
 //! use iceoryx2_bb_container::byte_string::*;
+//! use iceoryx2_bb_container::hash_map::*;
 //! use iceoryx2_bb_container::vec::*;
 //!
 //! const TEXT_CAPACITY: usize = 123;
@@ -45,12 +46,14 @@
 //! struct MyMessageType {
 //!     some_text: FixedSizeByteString<TEXT_CAPACITY>,
 //!     some_data: FixedSizeVec<u64, DATA_CAPACITY>,
+//!     some_readings: FixedSizeHashMap<u32, f64, DATA_CAPACITY>,
 //! }
 //!
 //! # fn main() -> Result<(), Box<dyn std::error::Error>> {
 //! let my_message = MyMessageType {
 //!     some_text: FixedSizeByteString::from_bytes(b"Hello World")?,
 //!     some_data: FixedSizeVec::new(),
+//!     some_readings: FixedSizeHashMap::new(),
 //! };
 //! # Ok(())
 //! # }
@@ -87,11 +90,34 @@
 
 /// A byte string similar to [`std::string::String`] but it does not support UTF-8
 pub mod byte_string;
+/// A hash map similar to [`std::collections::HashMap`]
+pub mod hash_map;
+/// A hash set similar to [`std::collections::HashSet`]
+pub mod hash_set;
+/// A doubly linked list similar to [`std::collections::LinkedList`] whose elements are linked
+/// by their index
+pub mod list;
+/// A priority queue similar to [`std::collections::BinaryHeap`]
+pub mod priority_queue;
 /// A queue similar to [`std::collections::VecDeque`]
 pub mod queue;
+/// A ring buffer that overwrites the oldest element when it is full
+pub mod ring_buffer;
 /// Extends the [ByteString](crate::byte_string) so that custom string types with a semantic
 /// ruleset on their content can be realized.
 #[macro_use]
 pub mod semantic_string;
+/// A relocatable heap from which the dynamically sized shared memory containers acquire their
+/// memory
+pub mod shm_heap;
+/// A doubly linked list similar to [`std::collections::LinkedList`] that acquires its memory
+/// from a [`ShmHeap`](crate::shm_heap::ShmHeap)
+pub mod shm_list;
+/// A UTF-8 string similar to [`std::string::String`] that acquires its memory from a
+/// [`ShmHeap`](crate::shm_heap::ShmHeap)
+pub mod shm_string;
+/// A vector similar to [`std::vec::Vec`] that acquires its memory from a
+/// [`ShmHeap`](crate::shm_heap::ShmHeap)
+pub mod shm_vec;
 /// A vector similar to [`std::vec::Vec`]
 pub mod vec;
@@ -0,0 +1,909 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three doubly linked list variations that are similar to [`std::collections::LinkedList`].
+//! The elements are linked by their index instead of a pointer so that the list stays valid
+//! when it is shared between processes.
+//!
+//!  * [`FixedSizeList`](crate::list::FixedSizeList), compile-time fixed size list that is
+//!     self-contained.
+//!  * [`RelocatableList`](crate::list::RelocatableList), run-time fixed size list that acquires
+//!     the required memory from a custom user-provided allocator.
+//!  * [`List`](crate::list::List), run-time fixed size list that uses by default heap memory.
+//!
+//! Every inserted element is identified by a [`ListHandle`](crate::list::ListHandle) that can
+//! be used to access or remove it in constant time. The handle of a removed element stays
+//! invalid, even when its storage is reused by another element.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizeList`](crate::list::FixedSizeList)
+//!
+//! ```
+//! use iceoryx2_bb_container::list::FixedSizeList;
+//!
+//! const LIST_CAPACITY: usize = 8;
+//! let mut list = FixedSizeList::<u64, LIST_CAPACITY>::new();
+//!
+//! list.push_back(2).expect("list has enough capacity");
+//! let handle = list.push_back(3).expect("list has enough capacity");
+//! list.push_front(1).expect("list has enough capacity");
+//!
+//! assert_eq!(list.remove(handle), Some(3));
+//! for value in list.iter() {
+//!     println!("value {}", value);
+//! }
+//! ```
+//!
+//! ## Use the [`List`](crate::list::List)
+//!
+//! ```
+//! use iceoryx2_bb_container::list::List;
+//!
+//! let list_capacity = 1234;
+//! let mut list = List::<u64>::new(list_capacity);
+//!
+//! list.push_back(123).expect("list has enough capacity");
+//!
+//! println!("pop from list {}", list.pop_front().unwrap());
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatableList`](crate::list::RelocatableList) inside constructs which provides memory
+//!
+//! ```
+//! use iceoryx2_bb_container::list::{details::Node, RelocatableList};
+//! use iceoryx2_bb_elementary::math::align_to;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//! use core::mem::MaybeUninit;
+//!
+//! const LIST_CAPACITY:usize = 12;
+//! struct MyConstruct {
+//!     list: RelocatableList<u128>,
+//!     list_memory: [MaybeUninit<Node<u128>>; LIST_CAPACITY],
+//! }
+//!
+//! impl MyConstruct {
+//!     pub fn new() -> Self {
+//!         Self {
+//!             list: unsafe { RelocatableList::new(LIST_CAPACITY,
+//!                             align_to::<MaybeUninit<Node<u128>>>(
+//!                                 std::mem::size_of::<RelocatableList<u128>>()) as isize) },
+//!             list_memory: core::array::from_fn(|_| MaybeUninit::uninit()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Create [`RelocatableList`](crate::list::RelocatableList) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::list::RelocatableList;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const LIST_CAPACITY:usize = 12;
+//! const MEM_SIZE: usize = RelocatableList::<u128>::const_memory_size(LIST_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let list = unsafe { RelocatableList::<u128>::new_uninit(LIST_CAPACITY) };
+//! unsafe { list.init(&bump_allocator).expect("list init failed") };
+//! ```
+//!
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use std::marker::PhantomData;
+use std::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+const INVALID_INDEX: usize = usize::MAX;
+
+/// List with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatableList`] it is movable but is not shared memory compatible.
+pub type List<T> = details::List<T, OwningPointer<details::Node<T>>>;
+/// **Non-movable** relocatable list with runtime fixed size capacity.
+pub type RelocatableList<T> = details::List<T, RelocatablePointer<details::Node<T>>>;
+
+/// Failures caused by [`FixedSizeList::push_front()`], [`FixedSizeList::push_back()`] and
+/// their counterparts of [`List`] and [`RelocatableList`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ListInsertError {
+    /// The list has no free node left.
+    ExceedsCapacity,
+}
+
+impl std::fmt::Display for ListInsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ListInsertError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ListInsertError {}
+
+/// Identifies an element of a list. It becomes invalid as soon as the element is removed.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ListHandle {
+    index: usize,
+    generation: usize,
+}
+
+unsafe impl ZeroCopySend for ListHandle {}
+
+/// Iterator over all elements of a list, starting with the front element.
+pub struct Iter<'a, T> {
+    nodes: *const details::Node<T>,
+    front: usize,
+    back: usize,
+    remaining: usize,
+    _lifetime: PhantomData<&'a details::Node<T>>,
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.nodes.add(self.front) };
+        self.front = node.next;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let node = unsafe { &*self.nodes.add(self.back) };
+        self.back = node.prev;
+        self.remaining -= 1;
+        Some(unsafe { node.value.assume_init_ref() })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
+
+#[doc(hidden)]
+pub mod details {
+    use super::*;
+
+    /// Storage of a single element of the list.
+    #[repr(C)]
+    pub struct Node<T> {
+        pub(super) value: MaybeUninit<T>,
+        pub(super) prev: usize,
+        pub(super) next: usize,
+        pub(super) generation: usize,
+        pub(super) is_occupied: bool,
+    }
+
+    /// **Non-movable** relocatable list with runtime fixed size capacity.
+    #[repr(C)]
+    pub struct List<T, PointerType: PointerTrait<Node<T>>> {
+        data_ptr: PointerType,
+        capacity: usize,
+        len: usize,
+        head: usize,
+        tail: usize,
+        // removed nodes are linked by their next index, nodes starting from
+        // untouched_index were never used and are still uninitialized
+        free_head: usize,
+        untouched_index: usize,
+        is_initialized: IoxAtomicBool,
+        _phantom_data: PhantomData<T>,
+    }
+
+    unsafe impl<T: Send, PointerType: PointerTrait<Node<T>>> Send for List<T, PointerType> {}
+    unsafe impl<T: Sync, PointerType: PointerTrait<Node<T>>> Sync for List<T, PointerType> {}
+
+    impl<T> List<T, OwningPointer<Node<T>>> {
+        /// Creates a new [`List`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                data_ptr: OwningPointer::<Node<T>>::new_with_alloc(capacity),
+                capacity,
+                len: 0,
+                head: INVALID_INDEX,
+                tail: INVALID_INDEX,
+                free_head: INVALID_INDEX,
+                untouched_index: 0,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        /// Adds an element at the front of the list and returns its [`ListHandle`]. If the list
+        /// is full it fails with [`ListInsertError::ExceedsCapacity`].
+        pub fn push_front(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+            unsafe { self.push_front_impl(value) }
+        }
+
+        /// Adds an element at the back of the list and returns its [`ListHandle`]. If the list
+        /// is full it fails with [`ListInsertError::ExceedsCapacity`].
+        pub fn push_back(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+            unsafe { self.push_back_impl(value) }
+        }
+
+        /// Removes the front element of the list. If the list is empty it returns [`None`].
+        pub fn pop_front(&mut self) -> Option<T> {
+            unsafe { self.pop_front_impl() }
+        }
+
+        /// Removes the back element of the list. If the list is empty it returns [`None`].
+        pub fn pop_back(&mut self) -> Option<T> {
+            unsafe { self.pop_back_impl() }
+        }
+
+        /// Removes the element of the [`ListHandle`]. If the handle is invalid it returns
+        /// [`None`].
+        pub fn remove(&mut self, handle: ListHandle) -> Option<T> {
+            unsafe { self.remove_impl(handle) }
+        }
+
+        /// Removes all elements from the list
+        pub fn clear(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+
+    impl<T> RelocatableContainer for List<T, RelocatablePointer<Node<T>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                capacity,
+                len: 0,
+                head: INVALID_INDEX,
+                tail: INVALID_INDEX,
+                free_head: INVALID_INDEX,
+                untouched_index: 0,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                capacity,
+                len: 0,
+                head: INVALID_INDEX,
+                tail: INVALID_INDEX,
+                free_head: INVALID_INDEX,
+                untouched_index: 0,
+                is_initialized: IoxAtomicBool::new(false),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            if self
+                .is_initialized
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                fatal_panic!(
+                    from "List::init()",
+                    "Memory already initialized. Initializing it twice may lead to undefined behavior."
+                );
+            }
+
+            self.data_ptr.init(fail!(from "List::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(
+                     std::mem::size_of::<Node<T>>() * self.capacity,
+                     std::mem::align_of::<Node<T>>(),
+                 )), "Failed to initialize list since the allocation of the data memory failed."
+            ));
+            self.is_initialized
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T> List<T, RelocatablePointer<Node<T>>> {
+        /// Adds an element at the front of the list and returns its [`ListHandle`]. If the list
+        /// is full it fails with [`ListInsertError::ExceedsCapacity`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn push_front(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+            self.push_front_impl(value)
+        }
+
+        /// Adds an element at the back of the list and returns its [`ListHandle`]. If the list
+        /// is full it fails with [`ListInsertError::ExceedsCapacity`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn push_back(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+            self.push_back_impl(value)
+        }
+
+        /// Removes the front element of the list. If the list is empty it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn pop_front(&mut self) -> Option<T> {
+            self.pop_front_impl()
+        }
+
+        /// Removes the back element of the list. If the list is empty it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn pop_back(&mut self) -> Option<T> {
+            self.pop_back_impl()
+        }
+
+        /// Removes the element of the [`ListHandle`]. If the handle is invalid it returns
+        /// [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn remove(&mut self, handle: ListHandle) -> Option<T> {
+            self.remove_impl(handle)
+        }
+
+        /// Removes all elements from the list
+        ///
+        /// # Safety
+        ///
+        ///  * [`List::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.clear_impl()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<Node<T>>> List<T, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_initialized
+                    .load(std::sync::atomic::Ordering::Relaxed),
+                "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+                source
+            );
+        }
+
+        /// Returns the required memory size for a list with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<Node<T>>(capacity)
+        }
+
+        /// Returns true if the list is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns the capacity of the list
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns the number of elements inside the list
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns true if the list is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity()
+        }
+
+        /// Returns true if the [`ListHandle`] refers to an element of the list, otherwise false
+        pub fn contains(&self, handle: ListHandle) -> bool {
+            self.verify_init(&format!("List<{}>::contains()", std::any::type_name::<T>()));
+
+            if self.untouched_index <= handle.index {
+                return false;
+            }
+
+            let node = unsafe { &*self.node(handle.index) };
+            node.is_occupied && node.generation == handle.generation
+        }
+
+        /// Returns a reference to the element of the [`ListHandle`]. If the handle is invalid
+        /// it returns [`None`].
+        pub fn get(&self, handle: ListHandle) -> Option<&T> {
+            if !self.contains(handle) {
+                return None;
+            }
+
+            Some(unsafe { (*self.node(handle.index)).value.assume_init_ref() })
+        }
+
+        /// Returns a mutable reference to the element of the [`ListHandle`]. If the handle is
+        /// invalid it returns [`None`].
+        pub fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+            if !self.contains(handle) {
+                return None;
+            }
+
+            Some(unsafe { (*self.node_mut(handle.index)).value.assume_init_mut() })
+        }
+
+        /// Returns a reference to the front element. If the list is empty it returns [`None`].
+        pub fn front(&self) -> Option<&T> {
+            self.value(self.head)
+        }
+
+        /// Returns a mutable reference to the front element. If the list is empty it returns
+        /// [`None`].
+        pub fn front_mut(&mut self) -> Option<&mut T> {
+            self.value_mut(self.head)
+        }
+
+        /// Returns a reference to the back element. If the list is empty it returns [`None`].
+        pub fn back(&self) -> Option<&T> {
+            self.value(self.tail)
+        }
+
+        /// Returns a mutable reference to the back element. If the list is empty it returns
+        /// [`None`].
+        pub fn back_mut(&mut self) -> Option<&mut T> {
+            self.value_mut(self.tail)
+        }
+
+        /// Returns an iterator over all elements, starting with the front element
+        pub fn iter(&self) -> Iter<'_, T> {
+            self.verify_init(&format!("List<{}>::iter()", std::any::type_name::<T>()));
+
+            Iter {
+                nodes: unsafe { self.data_ptr.as_ptr() },
+                front: self.head,
+                back: self.tail,
+                remaining: self.len,
+                _lifetime: PhantomData,
+            }
+        }
+
+        fn value(&self, index: usize) -> Option<&T> {
+            self.verify_init(&format!("List<{}>::value()", std::any::type_name::<T>()));
+
+            if index == INVALID_INDEX {
+                return None;
+            }
+
+            Some(unsafe { (*self.node(index)).value.assume_init_ref() })
+        }
+
+        fn value_mut(&mut self, index: usize) -> Option<&mut T> {
+            self.verify_init(&format!(
+                "List<{}>::value_mut()",
+                std::any::type_name::<T>()
+            ));
+
+            if index == INVALID_INDEX {
+                return None;
+            }
+
+            Some(unsafe { (*self.node_mut(index)).value.assume_init_mut() })
+        }
+
+        unsafe fn node(&self, index: usize) -> *const Node<T> {
+            self.data_ptr.as_ptr().add(index)
+        }
+
+        unsafe fn node_mut(&mut self, index: usize) -> *mut Node<T> {
+            self.data_ptr.as_mut_ptr().add(index)
+        }
+
+        unsafe fn acquire_node(&mut self, value: T) -> Result<usize, ListInsertError> {
+            let index = if self.free_head != INVALID_INDEX {
+                let index = self.free_head;
+                let node = &mut *self.node_mut(index);
+                self.free_head = node.next;
+                node.value.write(value);
+                node.is_occupied = true;
+                index
+            } else if self.untouched_index < self.capacity {
+                let index = self.untouched_index;
+                self.untouched_index += 1;
+                self.node_mut(index).write(Node {
+                    value: MaybeUninit::new(value),
+                    prev: INVALID_INDEX,
+                    next: INVALID_INDEX,
+                    generation: 0,
+                    is_occupied: true,
+                });
+                index
+            } else {
+                fail!(from "List::push()", with ListInsertError::ExceedsCapacity,
+                    "Unable to add element since the list with a capacity of {} is full.",
+                    self.capacity);
+            };
+
+            self.len += 1;
+            Ok(index)
+        }
+
+        unsafe fn handle(&self, index: usize) -> ListHandle {
+            ListHandle {
+                index,
+                generation: (*self.node(index)).generation,
+            }
+        }
+
+        pub(crate) unsafe fn push_front_impl(
+            &mut self,
+            value: T,
+        ) -> Result<ListHandle, ListInsertError> {
+            self.verify_init(&format!(
+                "List<{}>::push_front()",
+                std::any::type_name::<T>()
+            ));
+
+            let index = self.acquire_node(value)?;
+            let old_head = self.head;
+            {
+                let node = &mut *self.node_mut(index);
+                node.prev = INVALID_INDEX;
+                node.next = old_head;
+            }
+
+            if old_head == INVALID_INDEX {
+                self.tail = index;
+            } else {
+                (*self.node_mut(old_head)).prev = index;
+            }
+            self.head = index;
+
+            Ok(self.handle(index))
+        }
+
+        pub(crate) unsafe fn push_back_impl(
+            &mut self,
+            value: T,
+        ) -> Result<ListHandle, ListInsertError> {
+            self.verify_init(&format!(
+                "List<{}>::push_back()",
+                std::any::type_name::<T>()
+            ));
+
+            let index = self.acquire_node(value)?;
+            let old_tail = self.tail;
+            {
+                let node = &mut *self.node_mut(index);
+                node.prev = old_tail;
+                node.next = INVALID_INDEX;
+            }
+
+            if old_tail == INVALID_INDEX {
+                self.head = index;
+            } else {
+                (*self.node_mut(old_tail)).next = index;
+            }
+            self.tail = index;
+
+            Ok(self.handle(index))
+        }
+
+        pub(crate) unsafe fn pop_front_impl(&mut self) -> Option<T> {
+            self.verify_init(&format!(
+                "List<{}>::pop_front()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.head == INVALID_INDEX {
+                return None;
+            }
+
+            Some(self.unlink(self.head))
+        }
+
+        pub(crate) unsafe fn pop_back_impl(&mut self) -> Option<T> {
+            self.verify_init(&format!("List<{}>::pop_back()", std::any::type_name::<T>()));
+
+            if self.tail == INVALID_INDEX {
+                return None;
+            }
+
+            Some(self.unlink(self.tail))
+        }
+
+        pub(crate) unsafe fn remove_impl(&mut self, handle: ListHandle) -> Option<T> {
+            if !self.contains(handle) {
+                return None;
+            }
+
+            Some(self.unlink(handle.index))
+        }
+
+        pub(crate) unsafe fn clear_impl(&mut self) {
+            while self.pop_front_impl().is_some() {}
+        }
+
+        unsafe fn unlink(&mut self, index: usize) -> T {
+            let (prev, next) = {
+                let node = &*self.node(index);
+                (node.prev, node.next)
+            };
+
+            if prev == INVALID_INDEX {
+                self.head = next;
+            } else {
+                (*self.node_mut(prev)).next = next;
+            }
+
+            if next == INVALID_INDEX {
+                self.tail = prev;
+            } else {
+                (*self.node_mut(next)).prev = prev;
+            }
+
+            let free_head = self.free_head;
+            let node = &mut *self.node_mut(index);
+            node.is_occupied = false;
+            // invalidates all handles to the element
+            node.generation = node.generation.wrapping_add(1);
+            node.prev = INVALID_INDEX;
+            node.next = free_head;
+            self.free_head = index;
+            self.len -= 1;
+
+            std::mem::replace(&mut node.value, MaybeUninit::uninit()).assume_init()
+        }
+    }
+
+    impl<T: Debug, PointerType: PointerTrait<Node<T>>> Debug for List<T, PointerType> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T: PartialEq, PointerType: PointerTrait<Node<T>>> PartialEq for List<T, PointerType> {
+        fn eq(&self, other: &Self) -> bool {
+            self.len() == other.len() && self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: Eq, PointerType: PointerTrait<Node<T>>> Eq for List<T, PointerType> {}
+
+    impl<'a, T, PointerType: PointerTrait<Node<T>>> IntoIterator for &'a List<T, PointerType> {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<Node<T>>> Drop for List<T, PointerType> {
+        fn drop(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+/// Relocatable list with compile time fixed size capacity. In contrast to its counterpart the
+/// [`List`] it is movable.
+#[repr(C)]
+pub struct FixedSizeList<T, const CAPACITY: usize> {
+    state: RelocatableList<T>,
+    _data: [MaybeUninit<details::Node<T>>; CAPACITY],
+}
+
+// the list stores its elements inline and links them by their index
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeList<T, CAPACITY> {
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeList<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeList<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeList<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for FixedSizeList<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state.eq(&other.state)
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for FixedSizeList<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeList<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for value in self.iter() {
+            // cannot fail since both lists have the same capacity
+            let _ = new_self.push_back(value.clone());
+        }
+        new_self
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a FixedSizeList<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeList<T, CAPACITY> {}
+unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeList<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FixedSizeList<T, CAPACITY> {
+    fn initialize_state() -> RelocatableList<T> {
+        unsafe {
+            RelocatableList::new(
+                CAPACITY,
+                align_to::<MaybeUninit<details::Node<T>>>(std::mem::size_of::<RelocatableList<T>>())
+                    as isize,
+            )
+        }
+    }
+
+    /// Creates a new list.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the list is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the capacity of the list
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns the number of elements inside the list
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if the list is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Adds an element at the front of the list and returns its [`ListHandle`]. If the list is
+    /// full it fails with [`ListInsertError::ExceedsCapacity`].
+    pub fn push_front(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+        unsafe { self.state.push_front_impl(value) }
+    }
+
+    /// Adds an element at the back of the list and returns its [`ListHandle`]. If the list is
+    /// full it fails with [`ListInsertError::ExceedsCapacity`].
+    pub fn push_back(&mut self, value: T) -> Result<ListHandle, ListInsertError> {
+        unsafe { self.state.push_back_impl(value) }
+    }
+
+    /// Removes the front element of the list. If the list is empty it returns [`None`].
+    pub fn pop_front(&mut self) -> Option<T> {
+        unsafe { self.state.pop_front_impl() }
+    }
+
+    /// Removes the back element of the list. If the list is empty it returns [`None`].
+    pub fn pop_back(&mut self) -> Option<T> {
+        unsafe { self.state.pop_back_impl() }
+    }
+
+    /// Removes the element of the [`ListHandle`]. If the handle is invalid it returns [`None`].
+    pub fn remove(&mut self, handle: ListHandle) -> Option<T> {
+        unsafe { self.state.remove_impl(handle) }
+    }
+
+    /// Removes all elements from the list
+    pub fn clear(&mut self) {
+        unsafe { self.state.clear_impl() }
+    }
+
+    /// Returns true if the [`ListHandle`] refers to an element of the list, otherwise false
+    pub fn contains(&self, handle: ListHandle) -> bool {
+        self.state.contains(handle)
+    }
+
+    /// Returns a reference to the element of the [`ListHandle`]. If the handle is invalid it
+    /// returns [`None`].
+    pub fn get(&self, handle: ListHandle) -> Option<&T> {
+        self.state.get(handle)
+    }
+
+    /// Returns a mutable reference to the element of the [`ListHandle`]. If the handle is
+    /// invalid it returns [`None`].
+    pub fn get_mut(&mut self, handle: ListHandle) -> Option<&mut T> {
+        self.state.get_mut(handle)
+    }
+
+    /// Returns a reference to the front element. If the list is empty it returns [`None`].
+    pub fn front(&self) -> Option<&T> {
+        self.state.front()
+    }
+
+    /// Returns a mutable reference to the front element. If the list is empty it returns
+    /// [`None`].
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.state.front_mut()
+    }
+
+    /// Returns a reference to the back element. If the list is empty it returns [`None`].
+    pub fn back(&self) -> Option<&T> {
+        self.state.back()
+    }
+
+    /// Returns a mutable reference to the back element. If the list is empty it returns
+    /// [`None`].
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.state.back_mut()
+    }
+
+    /// Returns an iterator over all elements, starting with the front element
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.state.iter()
+    }
+}
@@ -0,0 +1,546 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three priority queue variations that are similar to [`std::collections::BinaryHeap`].
+//! The greatest element is always removed first.
+//!
+//!  * [`FixedSizePriorityQueue`](crate::priority_queue::FixedSizePriorityQueue), compile-time
+//!     fixed size priority queue that is self-contained.
+//!  * [`RelocatablePriorityQueue`](crate::priority_queue::RelocatablePriorityQueue), run-time
+//!     fixed size priority queue that acquires the required memory from a custom user-provided
+//!     allocator.
+//!  * [`PriorityQueue`](crate::priority_queue::PriorityQueue), run-time fixed size priority
+//!     queue that uses by default heap memory.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizePriorityQueue`](crate::priority_queue::FixedSizePriorityQueue)
+//!
+//! ```
+//! use iceoryx2_bb_container::priority_queue::FixedSizePriorityQueue;
+//!
+//! const QUEUE_CAPACITY: usize = 8;
+//! let mut queue = FixedSizePriorityQueue::<u64, QUEUE_CAPACITY>::new();
+//!
+//! queue.push(3);
+//! queue.push(17);
+//! queue.push(5);
+//!
+//! // the greatest element is removed first
+//! assert_eq!(queue.pop(), Some(17));
+//! ```
+//!
+//! ## Use the [`PriorityQueue`](crate::priority_queue::PriorityQueue)
+//!
+//! ```
+//! use iceoryx2_bb_container::priority_queue::PriorityQueue;
+//!
+//! let queue_capacity = 1234;
+//! let mut queue = PriorityQueue::<u64>::new(queue_capacity);
+//!
+//! queue.push(123);
+//!
+//! println!("pop from priority queue {}", queue.pop().unwrap());
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatablePriorityQueue`](crate::priority_queue::RelocatablePriorityQueue) inside constructs which provides memory
+//!
+//! ```
+//! use iceoryx2_bb_container::priority_queue::RelocatablePriorityQueue;
+//! use iceoryx2_bb_elementary::math::align_to;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//! use core::mem::MaybeUninit;
+//!
+//! const QUEUE_CAPACITY:usize = 12;
+//! struct MyConstruct {
+//!     queue: RelocatablePriorityQueue<u128>,
+//!     queue_memory: [MaybeUninit<u128>; QUEUE_CAPACITY],
+//! }
+//!
+//! impl MyConstruct {
+//!     pub fn new() -> Self {
+//!         Self {
+//!             queue: unsafe { RelocatablePriorityQueue::new(QUEUE_CAPACITY,
+//!                             align_to::<MaybeUninit<u128>>(std::mem::size_of::<RelocatablePriorityQueue<u128>>()) as isize) },
+//!             queue_memory: core::array::from_fn(|_| MaybeUninit::uninit()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Create [`RelocatablePriorityQueue`](crate::priority_queue::RelocatablePriorityQueue) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::priority_queue::RelocatablePriorityQueue;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const QUEUE_CAPACITY:usize = 12;
+//! const MEM_SIZE: usize = RelocatablePriorityQueue::<u128>::const_memory_size(QUEUE_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let queue = unsafe { RelocatablePriorityQueue::<u128>::new_uninit(QUEUE_CAPACITY) };
+//! unsafe { queue.init(&bump_allocator).expect("priority queue init failed") };
+//! ```
+//!
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use std::marker::PhantomData;
+use std::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+/// Priority queue with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatablePriorityQueue`] it is movable but is not shared memory compatible.
+pub type PriorityQueue<T> = details::PriorityQueue<T, OwningPointer<MaybeUninit<T>>>;
+/// **Non-movable** relocatable priority queue with runtime fixed size capacity.
+pub type RelocatablePriorityQueue<T> =
+    details::PriorityQueue<T, RelocatablePointer<MaybeUninit<T>>>;
+
+#[doc(hidden)]
+pub mod details {
+    use super::*;
+
+    /// **Non-movable** relocatable priority queue with runtime fixed size capacity.
+    #[repr(C)]
+    pub struct PriorityQueue<T, PointerType: PointerTrait<MaybeUninit<T>>> {
+        data_ptr: PointerType,
+        len: usize,
+        capacity: usize,
+        is_initialized: IoxAtomicBool,
+        _phantom_data: PhantomData<T>,
+    }
+
+    unsafe impl<T: Send, PointerType: PointerTrait<MaybeUninit<T>>> Send
+        for PriorityQueue<T, PointerType>
+    {
+    }
+
+    unsafe impl<T: Sync, PointerType: PointerTrait<MaybeUninit<T>>> Sync
+        for PriorityQueue<T, PointerType>
+    {
+    }
+
+    impl<T: Ord> PriorityQueue<T, OwningPointer<MaybeUninit<T>>> {
+        /// Creates a new [`PriorityQueue`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                data_ptr: OwningPointer::<MaybeUninit<T>>::new_with_alloc(capacity),
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        /// Adds an element to the priority queue. If the queue is full it returns false,
+        /// otherwise true.
+        pub fn push(&mut self, value: T) -> bool {
+            unsafe { self.push_impl(value) }
+        }
+
+        /// Removes the greatest element from the priority queue. If the queue is empty it
+        /// returns [`None`].
+        pub fn pop(&mut self) -> Option<T> {
+            unsafe { self.pop_impl() }
+        }
+
+        /// Removes all elements from the priority queue
+        pub fn clear(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+
+    impl<T> RelocatableContainer for PriorityQueue<T, RelocatablePointer<MaybeUninit<T>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(false),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            if self
+                .is_initialized
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                fatal_panic!(
+                    from "PriorityQueue::init()",
+                    "Memory already initialized. Initializing it twice may lead to undefined behavior."
+                );
+            }
+
+            self.data_ptr.init(fail!(from "PriorityQueue::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(
+                     std::mem::size_of::<T>() * self.capacity,
+                     std::mem::align_of::<T>(),
+                 )), "Failed to initialize priority queue since the allocation of the data memory failed."
+            ));
+            self.is_initialized
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T: Ord> PriorityQueue<T, RelocatablePointer<MaybeUninit<T>>> {
+        /// Adds an element to the priority queue. If the queue is full it returns false,
+        /// otherwise true.
+        ///
+        /// # Safety
+        ///
+        ///  * [`PriorityQueue::init()`] must have been called once before
+        ///
+        pub unsafe fn push(&mut self, value: T) -> bool {
+            self.push_impl(value)
+        }
+
+        /// Removes the greatest element from the priority queue. If the queue is empty it
+        /// returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`PriorityQueue::init()`] must have been called once before
+        ///
+        pub unsafe fn pop(&mut self) -> Option<T> {
+            self.pop_impl()
+        }
+
+        /// Removes all elements from the priority queue
+        ///
+        /// # Safety
+        ///
+        ///  * [`PriorityQueue::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.clear_impl()
+        }
+    }
+
+    impl<T: Ord, PointerType: PointerTrait<MaybeUninit<T>>> PriorityQueue<T, PointerType> {
+        pub(crate) unsafe fn push_impl(&mut self, value: T) -> bool {
+            self.verify_init(&format!(
+                "PriorityQueue<{}>::push()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.is_full() {
+                return false;
+            }
+
+            self.data_ptr
+                .as_mut_ptr()
+                .add(self.len)
+                .write(MaybeUninit::new(value));
+            self.len += 1;
+            self.sift_up(self.len - 1);
+            true
+        }
+
+        pub(crate) unsafe fn pop_impl(&mut self) -> Option<T> {
+            self.verify_init(&format!(
+                "PriorityQueue<{}>::pop()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.is_empty() {
+                return None;
+            }
+
+            self.len -= 1;
+            let data = self.data_ptr.as_mut_ptr();
+            core::ptr::swap(data, data.add(self.len));
+            let value = data.add(self.len).read().assume_init();
+            self.sift_down(0);
+            Some(value)
+        }
+
+        unsafe fn element(&self, index: usize) -> &T {
+            (*self.data_ptr.as_ptr().add(index)).assume_init_ref()
+        }
+
+        unsafe fn sift_up(&mut self, mut index: usize) {
+            while index > 0 {
+                let parent = (index - 1) / 2;
+                if self.element(index) <= self.element(parent) {
+                    break;
+                }
+
+                let data = self.data_ptr.as_mut_ptr();
+                core::ptr::swap(data.add(index), data.add(parent));
+                index = parent;
+            }
+        }
+
+        unsafe fn sift_down(&mut self, mut index: usize) {
+            loop {
+                let left = 2 * index + 1;
+                let right = left + 1;
+                let mut greatest = index;
+
+                if left < self.len && self.element(left) > self.element(greatest) {
+                    greatest = left;
+                }
+
+                if right < self.len && self.element(right) > self.element(greatest) {
+                    greatest = right;
+                }
+
+                if greatest == index {
+                    break;
+                }
+
+                let data = self.data_ptr.as_mut_ptr();
+                core::ptr::swap(data.add(index), data.add(greatest));
+                index = greatest;
+            }
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<MaybeUninit<T>>> PriorityQueue<T, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_initialized
+                    .load(std::sync::atomic::Ordering::Relaxed),
+                "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+                source
+            );
+        }
+
+        /// Returns the required memory size for a priority queue with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<T>(capacity)
+        }
+
+        /// Returns true if the priority queue is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns the capacity of the priority queue
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns the number of elements inside the priority queue
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns true if the priority queue is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity()
+        }
+
+        /// Returns a reference to the greatest element without removing it. If the queue is
+        /// empty it returns [`None`].
+        pub fn peek(&self) -> Option<&T> {
+            self.as_slice().first()
+        }
+
+        /// Returns an iterator over all elements. The order of the elements is unspecified.
+        pub fn iter(&self) -> core::slice::Iter<'_, T> {
+            self.as_slice().iter()
+        }
+
+        fn as_slice(&self) -> &[T] {
+            self.verify_init(&format!(
+                "PriorityQueue<{}>::as_slice()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.is_empty() {
+                return &[];
+            }
+
+            unsafe { core::slice::from_raw_parts(self.data_ptr.as_ptr() as *const T, self.len) }
+        }
+
+        pub(crate) unsafe fn clear_impl(&mut self) {
+            let len = self.len;
+            // set the length first so that a panicking drop cannot cause a double drop
+            self.len = 0;
+            for index in 0..len {
+                core::ptr::drop_in_place((*self.data_ptr.as_mut_ptr().add(index)).as_mut_ptr());
+            }
+        }
+    }
+
+    impl<T: Debug, PointerType: PointerTrait<MaybeUninit<T>>> Debug for PriorityQueue<T, PointerType> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<MaybeUninit<T>>> Drop for PriorityQueue<T, PointerType> {
+        fn drop(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+/// Relocatable priority queue with compile time fixed size capacity. In contrast to its
+/// counterpart the [`PriorityQueue`] it is movable.
+#[repr(C)]
+pub struct FixedSizePriorityQueue<T, const CAPACITY: usize> {
+    state: RelocatablePriorityQueue<T>,
+    _data: [MaybeUninit<T>; CAPACITY],
+}
+
+// the priority queue stores its elements inline and uses a relative pointer to access them
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizePriorityQueue<T, CAPACITY>
+{
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizePriorityQueue<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizePriorityQueue<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizePriorityQueue<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: Ord + Clone, const CAPACITY: usize> Clone for FixedSizePriorityQueue<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for value in self.iter() {
+            // cannot fail since both queues have the same capacity
+            new_self.push(value.clone());
+        }
+        new_self
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizePriorityQueue<T, CAPACITY> {}
+unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizePriorityQueue<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FixedSizePriorityQueue<T, CAPACITY> {
+    fn initialize_state() -> RelocatablePriorityQueue<T> {
+        unsafe {
+            RelocatablePriorityQueue::new(
+                CAPACITY,
+                align_to::<MaybeUninit<T>>(std::mem::size_of::<RelocatablePriorityQueue<T>>())
+                    as isize,
+            )
+        }
+    }
+
+    /// Creates a new priority queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the priority queue is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the capacity of the priority queue
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns the number of elements inside the priority queue
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if the priority queue is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Removes all elements from the priority queue
+    pub fn clear(&mut self) {
+        unsafe { self.state.clear_impl() }
+    }
+
+    /// Returns a reference to the greatest element without removing it. If the queue is empty
+    /// it returns [`None`].
+    pub fn peek(&self) -> Option<&T> {
+        self.state.peek()
+    }
+
+    /// Returns an iterator over all elements. The order of the elements is unspecified.
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.state.iter()
+    }
+}
+
+impl<T: Ord, const CAPACITY: usize> FixedSizePriorityQueue<T, CAPACITY> {
+    /// Adds an element to the priority queue. If the queue is full it returns false, otherwise
+    /// true.
+    pub fn push(&mut self, value: T) -> bool {
+        unsafe { self.state.push_impl(value) }
+    }
+
+    /// Removes the greatest element from the priority queue. If the queue is empty it returns
+    /// [`None`].
+    pub fn pop(&mut self) -> Option<T> {
+        unsafe { self.state.pop_impl() }
+    }
+}
@@ -0,0 +1,721 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Three ring buffer variations that overwrite the oldest element when they are full.
+//!
+//!  * [`FixedSizeRingBuffer`](crate::ring_buffer::FixedSizeRingBuffer), compile-time fixed size
+//!     ring buffer that is self-contained.
+//!  * [`RelocatableRingBuffer`](crate::ring_buffer::RelocatableRingBuffer), run-time fixed size
+//!     ring buffer that acquires the required memory from a custom user-provided allocator.
+//!  * [`RingBuffer`](crate::ring_buffer::RingBuffer), run-time fixed size ring buffer that uses
+//!     by default heap memory.
+//!
+//! # Basic Examples
+//!
+//! ## Use the [`FixedSizeRingBuffer`](crate::ring_buffer::FixedSizeRingBuffer)
+//!
+//! ```
+//! use iceoryx2_bb_container::ring_buffer::FixedSizeRingBuffer;
+//!
+//! const BUFFER_CAPACITY: usize = 3;
+//! let mut buffer = FixedSizeRingBuffer::<u64, BUFFER_CAPACITY>::new();
+//!
+//! for reading in 1..=3 {
+//!     buffer.push(reading);
+//! }
+//!
+//! // the buffer is full, the oldest element (1) is overwritten
+//! assert_eq!(buffer.push(4), Some(1));
+//!
+//! for reading in buffer.iter() {
+//!     println!("reading {}", reading);
+//! }
+//! ```
+//!
+//! ## Use the [`RingBuffer`](crate::ring_buffer::RingBuffer)
+//!
+//! ```
+//! use iceoryx2_bb_container::ring_buffer::RingBuffer;
+//!
+//! let buffer_capacity = 1234;
+//! let mut buffer = RingBuffer::<u64>::new(buffer_capacity);
+//!
+//! buffer.push(123);
+//!
+//! println!("pop from ring buffer {}", buffer.pop().unwrap());
+//! ```
+//!
+//! # Advanced Examples
+//!
+//! ## Create [`RelocatableRingBuffer`](crate::ring_buffer::RelocatableRingBuffer) inside constructs which provides memory
+//!
+//! ```
+//! use iceoryx2_bb_container::ring_buffer::RelocatableRingBuffer;
+//! use iceoryx2_bb_elementary::math::align_to;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//! use core::mem::MaybeUninit;
+//!
+//! const BUFFER_CAPACITY:usize = 12;
+//! struct MyConstruct {
+//!     buffer: RelocatableRingBuffer<u128>,
+//!     buffer_memory: [MaybeUninit<u128>; BUFFER_CAPACITY],
+//! }
+//!
+//! impl MyConstruct {
+//!     pub fn new() -> Self {
+//!         Self {
+//!             buffer: unsafe { RelocatableRingBuffer::new(BUFFER_CAPACITY,
+//!                             align_to::<MaybeUninit<u128>>(std::mem::size_of::<RelocatableRingBuffer<u128>>()) as isize) },
+//!             buffer_memory: core::array::from_fn(|_| MaybeUninit::uninit()),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! ## Create [`RelocatableRingBuffer`](crate::ring_buffer::RelocatableRingBuffer) with allocator
+//!
+//! ```
+//! use iceoryx2_bb_container::ring_buffer::RelocatableRingBuffer;
+//! use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+//! use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+//!
+//! const BUFFER_CAPACITY:usize = 12;
+//! const MEM_SIZE: usize = RelocatableRingBuffer::<u128>::const_memory_size(BUFFER_CAPACITY);
+//! let mut memory = [0u8; MEM_SIZE];
+//!
+//! let bump_allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+//!
+//! let buffer = unsafe { RelocatableRingBuffer::<u128>::new_uninit(BUFFER_CAPACITY) };
+//! unsafe { buffer.init(&bump_allocator).expect("ring buffer init failed") };
+//! ```
+//!
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::math::unaligned_mem_size;
+use iceoryx2_bb_elementary::owning_pointer::OwningPointer;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+use std::marker::PhantomData;
+use std::{alloc::Layout, fmt::Debug, mem::MaybeUninit};
+
+/// Ring buffer with run-time fixed size capacity. In contrast to its counterpart the
+/// [`RelocatableRingBuffer`] it is movable but is not shared memory compatible.
+pub type RingBuffer<T> = details::RingBuffer<T, OwningPointer<MaybeUninit<T>>>;
+/// **Non-movable** relocatable ring buffer with runtime fixed size capacity.
+pub type RelocatableRingBuffer<T> = details::RingBuffer<T, RelocatablePointer<MaybeUninit<T>>>;
+
+/// Iterator over all elements of a ring buffer, starting with the oldest element.
+pub struct Iter<'a, T> {
+    data: *const MaybeUninit<T>,
+    start: usize,
+    capacity: usize,
+    front: usize,
+    back: usize,
+    _lifetime: PhantomData<&'a T>,
+}
+
+impl<'a, T: 'a> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let index = (self.start + self.front) % self.capacity;
+        self.front += 1;
+        Some(unsafe { (*self.data.add(index)).assume_init_ref() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        let index = (self.start + self.back) % self.capacity;
+        Some(unsafe { (*self.data.add(index)).assume_init_ref() })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Iter<'a, T> {}
+
+/// Iterator over mutable references to all elements of a ring buffer, starting with the oldest
+/// element.
+pub struct IterMut<'a, T> {
+    data: *mut MaybeUninit<T>,
+    start: usize,
+    capacity: usize,
+    front: usize,
+    back: usize,
+    _lifetime: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: 'a> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        let index = (self.start + self.front) % self.capacity;
+        self.front += 1;
+        Some(unsafe { (*self.data.add(index)).assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        let index = (self.start + self.back) % self.capacity;
+        Some(unsafe { (*self.data.add(index)).assume_init_mut() })
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for IterMut<'a, T> {}
+
+#[doc(hidden)]
+pub mod details {
+    use super::*;
+
+    /// **Non-movable** relocatable ring buffer with runtime fixed size capacity.
+    #[repr(C)]
+    pub struct RingBuffer<T, PointerType: PointerTrait<MaybeUninit<T>>> {
+        data_ptr: PointerType,
+        start: usize,
+        len: usize,
+        capacity: usize,
+        is_initialized: IoxAtomicBool,
+        _phantom_data: PhantomData<T>,
+    }
+
+    unsafe impl<T: Send, PointerType: PointerTrait<MaybeUninit<T>>> Send
+        for RingBuffer<T, PointerType>
+    {
+    }
+
+    unsafe impl<T: Sync, PointerType: PointerTrait<MaybeUninit<T>>> Sync
+        for RingBuffer<T, PointerType>
+    {
+    }
+
+    impl<T> RingBuffer<T, OwningPointer<MaybeUninit<T>>> {
+        /// Creates a new [`RingBuffer`] with the provided capacity
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                data_ptr: OwningPointer::<MaybeUninit<T>>::new_with_alloc(capacity),
+                start: 0,
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        /// Adds an element at the end of the ring buffer. If the ring buffer is full, the oldest
+        /// element is overwritten and returned, otherwise it returns [`None`].
+        pub fn push(&mut self, value: T) -> Option<T> {
+            unsafe { self.push_impl(value) }
+        }
+
+        /// Removes the oldest element of the ring buffer. If the ring buffer is empty it
+        /// returns [`None`].
+        pub fn pop(&mut self) -> Option<T> {
+            unsafe { self.pop_impl() }
+        }
+
+        /// Removes all elements from the ring buffer
+        pub fn clear(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+
+    impl<T> RelocatableContainer for RingBuffer<T, RelocatablePointer<MaybeUninit<T>>> {
+        unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new(distance_to_data),
+                start: 0,
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(true),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn new_uninit(capacity: usize) -> Self {
+            Self {
+                data_ptr: RelocatablePointer::new_uninit(),
+                start: 0,
+                len: 0,
+                capacity,
+                is_initialized: IoxAtomicBool::new(false),
+                _phantom_data: PhantomData,
+            }
+        }
+
+        unsafe fn init<Allocator: BaseAllocator>(
+            &self,
+            allocator: &Allocator,
+        ) -> Result<(), AllocationError> {
+            if self
+                .is_initialized
+                .load(std::sync::atomic::Ordering::Relaxed)
+            {
+                fatal_panic!(
+                    from "RingBuffer::init()",
+                    "Memory already initialized. Initializing it twice may lead to undefined behavior."
+                );
+            }
+
+            self.data_ptr.init(fail!(from "RingBuffer::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(
+                     std::mem::size_of::<T>() * self.capacity,
+                     std::mem::align_of::<T>(),
+                 )), "Failed to initialize ring buffer since the allocation of the data memory failed."
+            ));
+            self.is_initialized
+                .store(true, std::sync::atomic::Ordering::Relaxed);
+
+            Ok(())
+        }
+
+        fn memory_size(capacity: usize) -> usize {
+            Self::const_memory_size(capacity)
+        }
+    }
+
+    impl<T> RingBuffer<T, RelocatablePointer<MaybeUninit<T>>> {
+        /// Adds an element at the end of the ring buffer. If the ring buffer is full, the oldest
+        /// element is overwritten and returned, otherwise it returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`RingBuffer::init()`] must have been called once before
+        ///
+        pub unsafe fn push(&mut self, value: T) -> Option<T> {
+            self.push_impl(value)
+        }
+
+        /// Removes the oldest element of the ring buffer. If the ring buffer is empty it
+        /// returns [`None`].
+        ///
+        /// # Safety
+        ///
+        ///  * [`RingBuffer::init()`] must have been called once before
+        ///
+        pub unsafe fn pop(&mut self) -> Option<T> {
+            self.pop_impl()
+        }
+
+        /// Removes all elements from the ring buffer
+        ///
+        /// # Safety
+        ///
+        ///  * [`RingBuffer::init()`] must have been called once before
+        ///
+        pub unsafe fn clear(&mut self) {
+            self.clear_impl()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<MaybeUninit<T>>> RingBuffer<T, PointerType> {
+        #[inline(always)]
+        fn verify_init(&self, source: &str) {
+            debug_assert!(
+                self.is_initialized
+                    .load(std::sync::atomic::Ordering::Relaxed),
+                "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+                source
+            );
+        }
+
+        /// Returns the required memory size for a ring buffer with a specified capacity
+        pub const fn const_memory_size(capacity: usize) -> usize {
+            unaligned_mem_size::<T>(capacity)
+        }
+
+        /// Returns true if the ring buffer is empty, otherwise false
+        pub fn is_empty(&self) -> bool {
+            self.len == 0
+        }
+
+        /// Returns the capacity of the ring buffer
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+
+        /// Returns the number of elements inside the ring buffer
+        pub fn len(&self) -> usize {
+            self.len
+        }
+
+        /// Returns true if the ring buffer is full, otherwise false
+        pub fn is_full(&self) -> bool {
+            self.len() == self.capacity()
+        }
+
+        /// Returns a reference to the element at the provided position, starting with 0 for the
+        /// oldest element. If the index is out of range it returns [`None`].
+        pub fn get(&self, index: usize) -> Option<&T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::get()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.len <= index {
+                return None;
+            }
+
+            Some(unsafe { (*self.data_ptr.as_ptr().add(self.position(index))).assume_init_ref() })
+        }
+
+        /// Returns a mutable reference to the element at the provided position, starting with 0
+        /// for the oldest element. If the index is out of range it returns [`None`].
+        pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::get_mut()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.len <= index {
+                return None;
+            }
+
+            let position = self.position(index);
+            Some(unsafe { (*self.data_ptr.as_mut_ptr().add(position)).assume_init_mut() })
+        }
+
+        /// Returns a reference to the oldest element. If the ring buffer is empty it returns
+        /// [`None`].
+        pub fn front(&self) -> Option<&T> {
+            self.get(0)
+        }
+
+        /// Returns a reference to the most recently added element. If the ring buffer is empty
+        /// it returns [`None`].
+        pub fn back(&self) -> Option<&T> {
+            self.get(self.len.checked_sub(1)?)
+        }
+
+        /// Returns an iterator over all elements, starting with the oldest element
+        pub fn iter(&self) -> Iter<'_, T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::iter()",
+                std::any::type_name::<T>()
+            ));
+
+            Iter {
+                data: unsafe { self.data_ptr.as_ptr() },
+                start: self.start,
+                capacity: self.capacity,
+                front: 0,
+                back: self.len,
+                _lifetime: PhantomData,
+            }
+        }
+
+        /// Returns an iterator over mutable references to all elements, starting with the oldest
+        /// element
+        pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::iter_mut()",
+                std::any::type_name::<T>()
+            ));
+
+            IterMut {
+                data: unsafe { self.data_ptr.as_mut_ptr() },
+                start: self.start,
+                capacity: self.capacity,
+                front: 0,
+                back: self.len,
+                _lifetime: PhantomData,
+            }
+        }
+
+        fn position(&self, index: usize) -> usize {
+            (self.start + index) % self.capacity
+        }
+
+        pub(crate) unsafe fn push_impl(&mut self, value: T) -> Option<T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::push()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.capacity == 0 {
+                return Some(value);
+            }
+
+            if self.is_full() {
+                let position = self.start;
+                self.start = self.position(1);
+                return Some(
+                    std::mem::replace(
+                        &mut *self.data_ptr.as_mut_ptr().add(position),
+                        MaybeUninit::new(value),
+                    )
+                    .assume_init(),
+                );
+            }
+
+            let position = self.position(self.len);
+            self.data_ptr
+                .as_mut_ptr()
+                .add(position)
+                .write(MaybeUninit::new(value));
+            self.len += 1;
+            None
+        }
+
+        pub(crate) unsafe fn pop_impl(&mut self) -> Option<T> {
+            self.verify_init(&format!(
+                "RingBuffer<{}>::pop()",
+                std::any::type_name::<T>()
+            ));
+
+            if self.is_empty() {
+                return None;
+            }
+
+            let position = self.start;
+            self.start = self.position(1);
+            self.len -= 1;
+            let value = std::mem::replace(
+                &mut *self.data_ptr.as_mut_ptr().add(position),
+                MaybeUninit::uninit(),
+            );
+            Some(value.assume_init())
+        }
+
+        pub(crate) unsafe fn clear_impl(&mut self) {
+            while self.pop_impl().is_some() {}
+        }
+    }
+
+    impl<T: Debug, PointerType: PointerTrait<MaybeUninit<T>>> Debug for RingBuffer<T, PointerType> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_list().entries(self.iter()).finish()
+        }
+    }
+
+    impl<T: PartialEq, PointerType: PointerTrait<MaybeUninit<T>>> PartialEq
+        for RingBuffer<T, PointerType>
+    {
+        fn eq(&self, other: &Self) -> bool {
+            self.len() == other.len() && self.iter().eq(other.iter())
+        }
+    }
+
+    impl<T: Eq, PointerType: PointerTrait<MaybeUninit<T>>> Eq for RingBuffer<T, PointerType> {}
+
+    impl<'a, T, PointerType: PointerTrait<MaybeUninit<T>>> IntoIterator
+        for &'a RingBuffer<T, PointerType>
+    {
+        type Item = &'a T;
+        type IntoIter = Iter<'a, T>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.iter()
+        }
+    }
+
+    impl<T, PointerType: PointerTrait<MaybeUninit<T>>> Drop for RingBuffer<T, PointerType> {
+        fn drop(&mut self) {
+            unsafe { self.clear_impl() }
+        }
+    }
+}
+
+/// Relocatable ring buffer with compile time fixed size capacity. In contrast to its counterpart
+/// the [`RingBuffer`] it is movable.
+#[repr(C)]
+pub struct FixedSizeRingBuffer<T, const CAPACITY: usize> {
+    state: RelocatableRingBuffer<T>,
+    _data: [MaybeUninit<T>; CAPACITY],
+}
+
+// the ring buffer stores its elements inline and uses a relative pointer to access them
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend
+    for FixedSizeRingBuffer<T, CAPACITY>
+{
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeRingBuffer<T, CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<T, const CAPACITY: usize> Default for FixedSizeRingBuffer<T, CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: core::array::from_fn(|_| MaybeUninit::uninit()),
+        }
+    }
+}
+
+impl<T: Debug, const CAPACITY: usize> Debug for FixedSizeRingBuffer<T, CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<T: PartialEq, const CAPACITY: usize> PartialEq for FixedSizeRingBuffer<T, CAPACITY> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state.eq(&other.state)
+    }
+}
+
+impl<T: Eq, const CAPACITY: usize> Eq for FixedSizeRingBuffer<T, CAPACITY> {}
+
+impl<T: Clone, const CAPACITY: usize> Clone for FixedSizeRingBuffer<T, CAPACITY> {
+    fn clone(&self) -> Self {
+        let mut new_self = Self::new();
+        for value in self.iter() {
+            new_self.push(value.clone());
+        }
+        new_self
+    }
+}
+
+impl<'a, T, const CAPACITY: usize> IntoIterator for &'a FixedSizeRingBuffer<T, CAPACITY> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+unsafe impl<T: Send, const CAPACITY: usize> Send for FixedSizeRingBuffer<T, CAPACITY> {}
+unsafe impl<T: Sync, const CAPACITY: usize> Sync for FixedSizeRingBuffer<T, CAPACITY> {}
+
+impl<T, const CAPACITY: usize> FixedSizeRingBuffer<T, CAPACITY> {
+    fn initialize_state() -> RelocatableRingBuffer<T> {
+        unsafe {
+            RelocatableRingBuffer::new(
+                CAPACITY,
+                align_to::<MaybeUninit<T>>(std::mem::size_of::<RelocatableRingBuffer<T>>())
+                    as isize,
+            )
+        }
+    }
+
+    /// Creates a new ring buffer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if the ring buffer is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.state.is_empty()
+    }
+
+    /// Returns the capacity of the ring buffer
+    pub fn capacity(&self) -> usize {
+        self.state.capacity()
+    }
+
+    /// Returns the number of elements inside the ring buffer
+    pub fn len(&self) -> usize {
+        self.state.len()
+    }
+
+    /// Returns true if the ring buffer is full, otherwise false
+    pub fn is_full(&self) -> bool {
+        self.state.is_full()
+    }
+
+    /// Adds an element at the end of the ring buffer. If the ring buffer is full, the oldest
+    /// element is overwritten and returned, otherwise it returns [`None`].
+    pub fn push(&mut self, value: T) -> Option<T> {
+        unsafe { self.state.push_impl(value) }
+    }
+
+    /// Removes the oldest element of the ring buffer. If the ring buffer is empty it returns
+    /// [`None`].
+    pub fn pop(&mut self) -> Option<T> {
+        unsafe { self.state.pop_impl() }
+    }
+
+    /// Removes all elements from the ring buffer
+    pub fn clear(&mut self) {
+        unsafe { self.state.clear_impl() }
+    }
+
+    /// Returns a reference to the element at the provided position, starting with 0 for the
+    /// oldest element. If the index is out of range it returns [`None`].
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.state.get(index)
+    }
+
+    /// Returns a mutable reference to the element at the provided position, starting with 0
+    /// for the oldest element. If the index is out of range it returns [`None`].
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.state.get_mut(index)
+    }
+
+    /// Returns a reference to the oldest element. If the ring buffer is empty it returns
+    /// [`None`].
+    pub fn front(&self) -> Option<&T> {
+        self.state.front()
+    }
+
+    /// Returns a reference to the most recently added element. If the ring buffer is empty it
+    /// returns [`None`].
+    pub fn back(&self) -> Option<&T> {
+        self.state.back()
+    }
+
+    /// Returns an iterator over all elements, starting with the oldest element
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.state.iter()
+    }
+
+    /// Returns an iterator over mutable references to all elements, starting with the oldest
+    /// element
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.state.iter_mut()
+    }
+}
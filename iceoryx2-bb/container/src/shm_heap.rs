@@ -0,0 +1,537 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A relocatable heap from which the dynamically sized shared memory containers
+//! [`ShmVec`](crate::shm_vec::ShmVec), [`ShmString`](crate::shm_string::ShmString) and
+//! [`ShmList`](crate::shm_list::ShmList) acquire their memory.
+//!
+//!  * [`ShmHeap`](crate::shm_heap::ShmHeap), **non-movable** heap with a run-time fixed size
+//!     capacity that acquires its memory from a custom user-provided allocator.
+//!  * [`FixedSizeShmHeap`](crate::shm_heap::FixedSizeShmHeap), heap with a compile-time fixed
+//!     size capacity that is self-contained.
+//!  * [`ShmPayload`](crate::shm_heap::ShmPayload), a value together with the heap its
+//!     containers allocate from. It is self-contained and can be used as publish-subscribe
+//!     payload so that the sample owns the memory of all containers. The containers implement
+//!     [`ShmSend`](crate::shm_heap::ShmSend) instead of [`ZeroCopySend`] so that they can only
+//!     be sent as part of a [`ShmPayload`](crate::shm_heap::ShmPayload).
+//!
+//! The heap is a bump allocator. Released memory is only reused when it was the most recent
+//! allocation, it is intended to be filled once and released as a whole, for instance when
+//! the sample that contains it is released. Concurrent allocations from multiple threads are
+//! synchronized with a compare-and-swap on the used memory.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::shm_heap::{ShmPayload, ShmSend};
+//! use iceoryx2_bb_container::shm_string::ShmString;
+//! use iceoryx2_bb_container::shm_vec::ShmVec;
+//!
+//! #[derive(Debug, Default)]
+//! #[repr(C)]
+//! struct Measurement {
+//!     sensor: ShmString,
+//!     readings: ShmVec<f64>,
+//! }
+//!
+//! // all fields implement ShmSend
+//! unsafe impl ShmSend for Measurement {}
+//!
+//! const HEAP_SIZE: usize = 1024;
+//! let mut payload = ShmPayload::<Measurement, HEAP_SIZE>::default();
+//!
+//! let (measurement, heap) = payload.split_mut();
+//! // the containers are only moved together with the heap as part of the payload
+//! unsafe {
+//!     measurement.sensor.push_str(heap, "front-left").expect("heap has enough memory");
+//!     for i in 0..32 {
+//!         measurement.readings.push(heap, i as f64 * 0.5).expect("heap has enough memory");
+//!     }
+//!
+//!     println!("{} :: {:?}", payload.sensor.as_str(), payload.readings.as_slice());
+//! }
+//! ```
+
+use iceoryx2_bb_elementary::allocator::{
+    AllocationError, AllocationGrowError, AllocationShrinkError, Allocator, BaseAllocator,
+};
+use iceoryx2_bb_elementary::math::align_to;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+pub use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::{layout_hash, ZeroCopySend};
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::mem::MaybeUninit;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+use std::sync::atomic::Ordering;
+
+/// **Non-movable** relocatable heap with runtime fixed size capacity.
+#[repr(C)]
+#[derive(Debug)]
+pub struct ShmHeap {
+    data_ptr: RelocatablePointer<u8>,
+    capacity: usize,
+    used: IoxAtomicUsize,
+    is_initialized: IoxAtomicBool,
+}
+
+impl RelocatableContainer for ShmHeap {
+    unsafe fn new(capacity: usize, distance_to_data: isize) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new(distance_to_data),
+            capacity,
+            used: IoxAtomicUsize::new(0),
+            is_initialized: IoxAtomicBool::new(true),
+        }
+    }
+
+    unsafe fn new_uninit(capacity: usize) -> Self {
+        Self {
+            data_ptr: RelocatablePointer::new_uninit(),
+            capacity,
+            used: IoxAtomicUsize::new(0),
+            is_initialized: IoxAtomicBool::new(false),
+        }
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        allocator: &Allocator,
+    ) -> Result<(), AllocationError> {
+        if self.is_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(
+                from "ShmHeap::init()",
+                "Memory already initialized. Initializing it twice may lead to undefined behavior."
+            );
+        }
+
+        self.data_ptr
+            .init(fail!(from "ShmHeap::init", when allocator
+                 .allocate(Layout::from_size_align_unchecked(self.capacity, 1)),
+                 "Failed to initialize shm heap since the allocation of the data memory failed."
+            ));
+        self.is_initialized.store(true, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    fn memory_size(capacity: usize) -> usize {
+        Self::const_memory_size(capacity)
+    }
+}
+
+impl ShmHeap {
+    #[inline(always)]
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_initialized.load(Ordering::Relaxed),
+            "From: {}, Undefined behavior - the object was not initialized with 'init' before.",
+            source
+        );
+    }
+
+    /// Returns the required memory size for a heap with a specified capacity
+    pub const fn const_memory_size(capacity: usize) -> usize {
+        capacity
+    }
+
+    /// Returns the capacity of the heap in bytes
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of bytes that are in use, including the padding that was required to
+    /// align the allocations
+    pub fn used(&self) -> usize {
+        self.used.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the memory pointed to by `ptr` belongs to the heap, otherwise false
+    pub fn contains(&self, ptr: *const u8) -> bool {
+        let start = self.start();
+        start <= ptr as usize && (ptr as usize) < start + self.capacity
+    }
+
+    fn start(&self) -> usize {
+        unsafe { self.data_ptr.as_ptr() as usize }
+    }
+
+    fn offset_of(&self, ptr: NonNull<u8>) -> usize {
+        ptr.as_ptr() as usize - self.start()
+    }
+
+    // only the most recent allocation, the one that ends at `used`, can be resized in place,
+    // fails when the allocation is not or no longer the most recent one
+    fn resize_last_allocation(&self, old_end: usize, new_end: usize) -> bool {
+        self.used
+            .compare_exchange(old_end, new_end, Ordering::Relaxed, Ordering::Relaxed)
+            .is_ok()
+    }
+
+    fn verify_ownership(&self, ptr: NonNull<u8>, source: &str) {
+        if !self.contains(ptr.as_ptr()) {
+            fatal_panic!(from self,
+                "{} since the memory {:?} was not allocated from this heap.", source, ptr);
+        }
+    }
+}
+
+impl BaseAllocator for ShmHeap {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocationError> {
+        let msg = "Unable to allocate memory";
+        self.verify_init("ShmHeap::allocate()");
+
+        if layout.size() == 0 {
+            fail!(from self, with AllocationError::SizeIsZero,
+                "{} since the requested size is zero.", msg);
+        }
+
+        let start = self.start();
+        let mut used = self.used.load(Ordering::Relaxed);
+        let offset = loop {
+            // the offsets are computed with checked arithmetic so that a huge size or alignment
+            // can never wrap around and pass the capacity check
+            let remainder = (start + used) % layout.align();
+            let padding = if remainder == 0 {
+                0
+            } else {
+                layout.align() - remainder
+            };
+            let (offset, new_used) = match used
+                .checked_add(padding)
+                .and_then(|offset| Some((offset, offset.checked_add(layout.size())?)))
+            {
+                Some((offset, new_used)) if new_used <= self.capacity => (offset, new_used),
+                _ => {
+                    fail!(from self, with AllocationError::OutOfMemory,
+                        "{} since the heap has not enough memory left for {:?}.", msg, layout);
+                }
+            };
+
+            match self.used.compare_exchange_weak(
+                used,
+                new_used,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => break offset,
+                Err(v) => used = v,
+            }
+        };
+
+        Ok(unsafe {
+            NonNull::new_unchecked(core::ptr::slice_from_raw_parts_mut(
+                (start + offset) as *mut u8,
+                layout.size(),
+            ))
+        })
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        self.verify_ownership(ptr, "Unable to deallocate memory");
+
+        // only the most recent allocation can be released, all other memory is released
+        // together with the heap
+        let offset = self.offset_of(ptr);
+        self.resize_last_allocation(offset + layout.size(), offset);
+    }
+}
+
+impl Allocator for ShmHeap {
+    unsafe fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocationGrowError> {
+        let msg = "Unable to grow memory";
+        self.verify_ownership(ptr, msg);
+
+        if new_layout.size() < old_layout.size() {
+            fail!(from self, with AllocationGrowError::GrowWouldShrink,
+                "{} since the new size {} is smaller than the old size {}.",
+                msg, new_layout.size(), old_layout.size());
+        }
+
+        let is_aligned = ptr.as_ptr() as usize % new_layout.align() == 0;
+        let offset = self.offset_of(ptr);
+        if is_aligned
+            && new_layout.size() <= self.capacity - offset
+            && self.resize_last_allocation(offset + old_layout.size(), offset + new_layout.size())
+        {
+            return Ok(NonNull::new_unchecked(core::ptr::slice_from_raw_parts_mut(
+                ptr.as_ptr(),
+                new_layout.size(),
+            )));
+        }
+
+        let memory = match self.allocate(new_layout) {
+            Ok(memory) => memory,
+            Err(AllocationError::SizeIsZero) => {
+                fail!(from self, with AllocationGrowError::SizeIsZero,
+                    "{} since the requested size is zero.", msg);
+            }
+            Err(_) => {
+                fail!(from self, with AllocationGrowError::OutOfMemory,
+                    "{} since the heap has not enough memory left for {:?}.", msg, new_layout);
+            }
+        };
+
+        core::ptr::copy_nonoverlapping(ptr.as_ptr(), memory.as_ptr() as *mut u8, old_layout.size());
+
+        Ok(memory)
+    }
+
+    unsafe fn shrink(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: Layout,
+        new_layout: Layout,
+    ) -> Result<NonNull<[u8]>, AllocationShrinkError> {
+        let msg = "Unable to shrink memory";
+        self.verify_ownership(ptr, msg);
+
+        if old_layout.size() < new_layout.size() {
+            fail!(from self, with AllocationShrinkError::ShrinkWouldGrow,
+                "{} since the new size {} is greater than the old size {}.",
+                msg, new_layout.size(), old_layout.size());
+        }
+
+        if new_layout.size() == 0 {
+            fail!(from self, with AllocationShrinkError::SizeIsZero,
+                "{} since the requested size is zero.", msg);
+        }
+
+        if ptr.as_ptr() as usize % new_layout.align() != 0 {
+            fail!(from self, with AllocationShrinkError::AlignmentFailure,
+                "{} since the memory is not aligned to {}.", msg, new_layout.align());
+        }
+
+        let offset = self.offset_of(ptr);
+        self.resize_last_allocation(offset + old_layout.size(), offset + new_layout.size());
+
+        Ok(NonNull::new_unchecked(core::ptr::slice_from_raw_parts_mut(
+            ptr.as_ptr(),
+            new_layout.size(),
+        )))
+    }
+}
+
+/// Relocatable heap with compile time fixed size capacity. In contrast to its counterpart the
+/// [`ShmHeap`] it is movable as long as no container that uses it is stored outside of it.
+#[repr(C)]
+pub struct FixedSizeShmHeap<const CAPACITY: usize> {
+    state: ShmHeap,
+    _data: [MaybeUninit<u8>; CAPACITY],
+}
+
+impl<const CAPACITY: usize> Debug for FixedSizeShmHeap<CAPACITY> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.state.fmt(f)
+    }
+}
+
+impl<const CAPACITY: usize> PlacementDefault for FixedSizeShmHeap<CAPACITY> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
+        state_ptr.write(Self::initialize_state());
+    }
+}
+
+impl<const CAPACITY: usize> Default for FixedSizeShmHeap<CAPACITY> {
+    fn default() -> Self {
+        Self {
+            state: Self::initialize_state(),
+            _data: [MaybeUninit::uninit(); CAPACITY],
+        }
+    }
+}
+
+impl<const CAPACITY: usize> Deref for FixedSizeShmHeap<CAPACITY> {
+    type Target = ShmHeap;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+impl<const CAPACITY: usize> FixedSizeShmHeap<CAPACITY> {
+    fn initialize_state() -> ShmHeap {
+        unsafe {
+            ShmHeap::new(
+                CAPACITY,
+                align_to::<MaybeUninit<u8>>(std::mem::size_of::<ShmHeap>()) as isize,
+            )
+        }
+    }
+
+    /// Creates a new heap.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+/// Marks a type as safe to be transferred via zero-copy as value of a [`ShmPayload`]. In
+/// contrast to [`ZeroCopySend`] the type may contain containers, like
+/// [`ShmVec`](crate::shm_vec::ShmVec), that refer to memory in the heap of the [`ShmPayload`].
+/// The containers themselves implement only [`ShmSend`] so that they cannot be sent without
+/// their heap.
+///
+/// It is implemented for all [`ZeroCopySend`] types and the containers of this crate. Types
+/// that contain a container implement it manually.
+///
+/// ```
+/// use iceoryx2_bb_container::shm_heap::ShmSend;
+/// use iceoryx2_bb_container::shm_vec::ShmVec;
+///
+/// #[repr(C)]
+/// struct Trajectory {
+///     id: u64,
+///     positions: ShmVec<[f64; 3]>,
+/// }
+///
+/// // all fields implement ShmSend
+/// unsafe impl ShmSend for Trajectory {}
+/// ```
+///
+/// A container without its heap cannot be sent.
+///
+/// ```compile_fail
+/// use iceoryx2_bb_container::shm_vec::ShmVec;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// fn send<T: ZeroCopySend>() {}
+///
+/// send::<ShmVec<u64>>();
+/// ```
+///
+/// # Safety
+///
+///  * all fields of the type must implement [`ShmSend`]
+///  * the type must satisfy the safety requirements of [`ZeroCopySend`] apart from the
+///    containers
+///  * when [`ShmSend::layout_hash()`] is provided, it must change whenever the memory layout of
+///    the type changes
+pub unsafe trait ShmSend {
+    /// Returns a hash of the structural memory layout of the type, or [`None`] when the layout
+    /// is unknown and the type is identified by its name, see [`ZeroCopySend::layout_hash()`].
+    fn layout_hash() -> Option<u64> {
+        None
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ShmSend for T {
+    fn layout_hash() -> Option<u64> {
+        Some(T::__type_identity())
+    }
+}
+
+/// A value together with the [`ShmHeap`] from which the containers of the value, like
+/// [`ShmVec`](crate::shm_vec::ShmVec), acquire their memory. Since the containers refer to
+/// their memory only with relative pointers, the [`ShmPayload`] is self-contained and can be
+/// sent as a whole to other processes.
+///
+/// **Important:** The containers of the value must only use the heap of the [`ShmPayload`]
+/// they are stored in and must not be moved out of the value, for instance with
+/// [`core::mem::swap()`], after they acquired memory.
+#[repr(C)]
+pub struct ShmPayload<T, const HEAP_SIZE: usize> {
+    value: T,
+    heap: FixedSizeShmHeap<HEAP_SIZE>,
+}
+
+// the containers of the value refer to their memory in the heap with relative pointers, when
+// the value has no layout hash the payload is identified by its type name
+unsafe impl<T: ShmSend, const HEAP_SIZE: usize> ZeroCopySend for ShmPayload<T, HEAP_SIZE> {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::layout_hash()?,
+            HEAP_SIZE as u64,
+        ]))
+    }
+}
+
+impl<T: PlacementDefault, const HEAP_SIZE: usize> PlacementDefault for ShmPayload<T, HEAP_SIZE> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        T::placement_default(core::ptr::addr_of_mut!((*ptr).value));
+        FixedSizeShmHeap::placement_default(core::ptr::addr_of_mut!((*ptr).heap));
+    }
+}
+
+impl<T: Default, const HEAP_SIZE: usize> Default for ShmPayload<T, HEAP_SIZE> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: Debug, const HEAP_SIZE: usize> Debug for ShmPayload<T, HEAP_SIZE> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShmPayload")
+            .field("value", &self.value)
+            .field("heap", &self.heap)
+            .finish()
+    }
+}
+
+impl<T, const HEAP_SIZE: usize> Deref for ShmPayload<T, HEAP_SIZE> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, const HEAP_SIZE: usize> DerefMut for ShmPayload<T, HEAP_SIZE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+impl<T, const HEAP_SIZE: usize> ShmPayload<T, HEAP_SIZE> {
+    /// Creates a new [`ShmPayload`] with an empty heap.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            heap: FixedSizeShmHeap::new(),
+        }
+    }
+
+    /// Writes the value and an empty heap into the provided memory without copying the
+    /// heap memory.
+    ///
+    /// # Safety
+    ///
+    ///  * `ptr` must be valid for writes and properly aligned
+    ///
+    pub unsafe fn placement_new(ptr: *mut Self, value: T) {
+        core::ptr::addr_of_mut!((*ptr).value).write(value);
+        FixedSizeShmHeap::placement_default(core::ptr::addr_of_mut!((*ptr).heap));
+    }
+
+    /// Returns a reference to the heap
+    pub fn heap(&self) -> &ShmHeap {
+        &self.heap
+    }
+
+    /// Returns a mutable reference to the value and a reference to the heap so that the
+    /// containers of the value can allocate memory.
+    pub fn split_mut(&mut self) -> (&mut T, &ShmHeap) {
+        (&mut self.value, &self.heap)
+    }
+}
@@ -0,0 +1,361 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A dynamically sized doubly linked list whose nodes are acquired from a [`ShmHeap`] and are
+//! linked with relative pointers. It is shared memory compatible as long as it is stored in the
+//! same shared memory segment as the heap, for instance as part of a
+//! [`ShmPayload`](crate::shm_heap::ShmPayload).
+//!
+//! **Important:** The [`ShmList`] is movable while it is empty, afterwards it must only be
+//! moved together with the heap. Therefore, all methods that acquire or access the nodes are
+//! `unsafe` and the list provides no safe access to its elements. The elements are not dropped
+//! together with the list, they have to be removed explicitly, for instance with
+//! [`ShmList::clear()`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::shm_heap::FixedSizeShmHeap;
+//! use iceoryx2_bb_container::shm_list::ShmList;
+//!
+//! let heap = FixedSizeShmHeap::<1024>::new();
+//! let mut list = ShmList::<u64>::new();
+//!
+//! // list and heap are not moved until they go out of scope
+//! unsafe {
+//!     list.push_back(&heap, 2).expect("heap has enough memory");
+//!     list.push_front(&heap, 1).expect("heap has enough memory");
+//!
+//!     assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+//!     assert_eq!(list.pop_back(&heap), Some(2));
+//! }
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::ptr::NonNull;
+
+use iceoryx2_bb_elementary::allocator::{AllocationError, BaseAllocator};
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::layout_hash;
+use iceoryx2_bb_log::fail;
+
+use crate::shm_heap::{ShmHeap, ShmSend};
+
+#[repr(C)]
+struct Node<T> {
+    prev: RelocatablePointer<Node<T>>,
+    next: RelocatablePointer<Node<T>>,
+    value: T,
+}
+
+// a link that points to itself is interpreted as no link since it can never point to a node
+fn get<T>(link: &RelocatablePointer<Node<T>>) -> Option<NonNull<Node<T>>> {
+    let ptr = unsafe { link.as_ptr() };
+    if core::ptr::eq(ptr as *const u8, link as *const _ as *const u8) {
+        None
+    } else {
+        Some(unsafe { NonNull::new_unchecked(ptr as *mut Node<T>) })
+    }
+}
+
+fn set<T>(link: &mut RelocatablePointer<Node<T>>, target: Option<NonNull<Node<T>>>) {
+    *link = unsafe { RelocatablePointer::new_uninit() };
+    if let Some(target) = target {
+        unsafe {
+            link.init(NonNull::new_unchecked(core::ptr::slice_from_raw_parts_mut(
+                target.as_ptr() as *mut u8,
+                core::mem::size_of::<Node<T>>(),
+            )))
+        };
+    }
+}
+
+/// Iterator over the elements of a [`ShmList`] starting from the front.
+pub struct Iter<'a, T> {
+    node: Option<NonNull<Node<T>>>,
+    remaining: usize,
+    _list: &'a ShmList<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = unsafe { &*self.node?.as_ptr() };
+        self.node = get(&node.next);
+        self.remaining -= 1;
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Doubly linked list whose nodes are acquired from a [`ShmHeap`].
+#[repr(C)]
+pub struct ShmList<T> {
+    head: RelocatablePointer<Node<T>>,
+    tail: RelocatablePointer<Node<T>>,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for ShmList<T> {}
+unsafe impl<T: Sync> Sync for ShmList<T> {}
+
+// the nodes are linked with relative pointers into the heap of the payload
+unsafe impl<T: ShmSend> ShmSend for ShmList<T> {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            core::mem::size_of::<Node<T>>() as u64,
+            T::layout_hash()?,
+        ]))
+    }
+}
+
+// the nodes are not accessed since the list could have been moved without its heap
+impl<T> Debug for ShmList<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShmList<{}> {{ len: {} }}",
+            core::any::type_name::<T>(),
+            self.len
+        )
+    }
+}
+
+impl<T> Default for ShmList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PlacementDefault for ShmList<T> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        ptr.write(Self::new())
+    }
+}
+
+impl<T> ShmList<T> {
+    /// Creates a new empty [`ShmList`].
+    pub fn new() -> Self {
+        Self {
+            head: unsafe { RelocatablePointer::new_uninit() },
+            tail: unsafe { RelocatablePointer::new_uninit() },
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements stored inside the list
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn node_layout() -> Layout {
+        Layout::new::<Node<T>>()
+    }
+
+    fn allocate_node(
+        &self,
+        heap: &ShmHeap,
+        value: T,
+        source: &str,
+    ) -> Result<NonNull<Node<T>>, AllocationError> {
+        let memory = fail!(from source, when heap.allocate(Self::node_layout()),
+            "Unable to add element since the heap could not provide the memory for a new node.");
+        let node = memory.as_ptr() as *mut Node<T>;
+
+        unsafe {
+            node.write(Node {
+                prev: RelocatablePointer::new_uninit(),
+                next: RelocatablePointer::new_uninit(),
+                value,
+            })
+        };
+
+        Ok(unsafe { NonNull::new_unchecked(node) })
+    }
+
+    fn release_node(&mut self, heap: &ShmHeap, node: NonNull<Node<T>>) -> T {
+        self.len -= 1;
+        unsafe {
+            let value = core::ptr::addr_of!((*node.as_ptr()).value).read();
+            heap.deallocate(node.cast(), Self::node_layout());
+            value
+        }
+    }
+
+    /// Adds an element at the front of the list. The node is acquired from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the list acquired its nodes before
+    ///  * the list must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn push_front(&mut self, heap: &ShmHeap, value: T) -> Result<(), AllocationError> {
+        let node = self.allocate_node(heap, value, "ShmList::push_front()")?;
+        let old_head = get(&self.head);
+
+        unsafe { set(&mut (*node.as_ptr()).next, old_head) };
+        match old_head {
+            Some(old_head) => unsafe { set(&mut (*old_head.as_ptr()).prev, Some(node)) },
+            None => set(&mut self.tail, Some(node)),
+        }
+        set(&mut self.head, Some(node));
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Adds an element at the back of the list. The node is acquired from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the list acquired its nodes before
+    ///  * the list must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn push_back(&mut self, heap: &ShmHeap, value: T) -> Result<(), AllocationError> {
+        let node = self.allocate_node(heap, value, "ShmList::push_back()")?;
+        let old_tail = get(&self.tail);
+
+        unsafe { set(&mut (*node.as_ptr()).prev, old_tail) };
+        match old_tail {
+            Some(old_tail) => unsafe { set(&mut (*old_tail.as_ptr()).next, Some(node)) },
+            None => set(&mut self.head, Some(node)),
+        }
+        set(&mut self.tail, Some(node));
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Removes the first element of the list and returns it. If the list is empty it returns
+    /// [`None`].
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the list acquired its nodes
+    ///  * the list must not have been moved independently of `heap`
+    ///
+    pub unsafe fn pop_front(&mut self, heap: &ShmHeap) -> Option<T> {
+        let node = get(&self.head)?;
+        let new_head = get(unsafe { &(*node.as_ptr()).next });
+
+        match new_head {
+            Some(new_head) => unsafe { set(&mut (*new_head.as_ptr()).prev, None) },
+            None => set(&mut self.tail, None),
+        }
+        set(&mut self.head, new_head);
+
+        Some(self.release_node(heap, node))
+    }
+
+    /// Removes the last element of the list and returns it. If the list is empty it returns
+    /// [`None`].
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the list acquired its nodes
+    ///  * the list must not have been moved independently of `heap`
+    ///
+    pub unsafe fn pop_back(&mut self, heap: &ShmHeap) -> Option<T> {
+        let node = get(&self.tail)?;
+        let new_tail = get(unsafe { &(*node.as_ptr()).prev });
+
+        match new_tail {
+            Some(new_tail) => unsafe { set(&mut (*new_tail.as_ptr()).next, None) },
+            None => set(&mut self.head, None),
+        }
+        set(&mut self.tail, new_tail);
+
+        Some(self.release_node(heap, node))
+    }
+
+    /// Removes all elements from the list.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the list acquired its nodes
+    ///  * the list must not have been moved independently of `heap`
+    ///
+    pub unsafe fn clear(&mut self, heap: &ShmHeap) {
+        // releasing from the back returns the most recent allocations first
+        while self.pop_back(heap).is_some() {}
+    }
+
+    /// Returns a reference to the first element or [`None`] if the list is empty
+    ///
+    /// # Safety
+    ///
+    ///  * the list must not have been moved independently of its heap
+    ///
+    pub unsafe fn front(&self) -> Option<&T> {
+        get(&self.head).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a mutable reference to the first element or [`None`] if the list is empty
+    ///
+    /// # Safety
+    ///
+    ///  * the list must not have been moved independently of its heap
+    ///
+    pub unsafe fn front_mut(&mut self) -> Option<&mut T> {
+        get(&self.head).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Returns a reference to the last element or [`None`] if the list is empty
+    ///
+    /// # Safety
+    ///
+    ///  * the list must not have been moved independently of its heap
+    ///
+    pub unsafe fn back(&self) -> Option<&T> {
+        get(&self.tail).map(|node| unsafe { &(*node.as_ptr()).value })
+    }
+
+    /// Returns a mutable reference to the last element or [`None`] if the list is empty
+    ///
+    /// # Safety
+    ///
+    ///  * the list must not have been moved independently of its heap
+    ///
+    pub unsafe fn back_mut(&mut self) -> Option<&mut T> {
+        get(&self.tail).map(|node| unsafe { &mut (*node.as_ptr()).value })
+    }
+
+    /// Returns an iterator over all elements starting from the front
+    ///
+    /// # Safety
+    ///
+    ///  * the list must not have been moved independently of its heap
+    ///
+    pub unsafe fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            node: get(&self.head),
+            remaining: self.len,
+            _list: self,
+        }
+    }
+}
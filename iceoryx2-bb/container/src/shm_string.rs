@@ -0,0 +1,200 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A dynamically sized UTF-8 string that acquires its memory from a [`ShmHeap`] and refers to
+//! it with a relative pointer. It is shared memory compatible as long as it is stored in the
+//! same shared memory segment as the heap, for instance as part of a
+//! [`ShmPayload`](crate::shm_heap::ShmPayload).
+//!
+//! **Important:** The [`ShmString`] is movable until it acquired memory from the heap,
+//! afterwards it must only be moved together with the heap. Therefore, all methods that acquire
+//! or access the memory are `unsafe` and the string provides no safe access to its content,
+//! like [`core::ops::Deref`] or [`core::fmt::Display`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::shm_heap::FixedSizeShmHeap;
+//! use iceoryx2_bb_container::shm_string::ShmString;
+//!
+//! let heap = FixedSizeShmHeap::<1024>::new();
+//! let mut text = ShmString::new();
+//!
+//! // text and heap are not moved until they go out of scope
+//! unsafe {
+//!     text.push_str(&heap, "hello").expect("heap has enough memory");
+//!     text.push(&heap, '!').expect("heap has enough memory");
+//!
+//!     assert_eq!(text.as_str(), "hello!");
+//! }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::zero_copy_send::layout_hash;
+use iceoryx2_bb_log::fail;
+
+use crate::shm_heap::{ShmHeap, ShmSend};
+use crate::shm_vec::ShmVec;
+
+/// UTF-8 string with a dynamic capacity whose memory is acquired from a [`ShmHeap`].
+#[repr(C)]
+#[derive(Default)]
+pub struct ShmString {
+    data: ShmVec<u8>,
+}
+
+unsafe impl ShmSend for ShmString {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+        ]))
+    }
+}
+
+impl PlacementDefault for ShmString {
+    unsafe fn placement_default(ptr: *mut Self) {
+        ptr.write(Self::new())
+    }
+}
+
+// the content is not accessed since the string could have been moved without its heap
+impl Debug for ShmString {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShmString {{ len: {}, capacity: {} }}",
+            self.len(),
+            self.capacity()
+        )
+    }
+}
+
+impl ShmString {
+    /// Creates a new empty [`ShmString`] that has not acquired any memory yet.
+    pub fn new() -> Self {
+        Self {
+            data: ShmVec::new(),
+        }
+    }
+
+    /// Returns the number of bytes the string can hold without acquiring more memory
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Returns the length of the string in bytes
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns true if the string is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Ensures that at least `additional` further bytes can be added without acquiring more
+    /// memory from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the string acquired its memory before
+    ///  * the string must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn reserve(
+        &mut self,
+        heap: &ShmHeap,
+        additional: usize,
+    ) -> Result<(), AllocationError> {
+        self.data.reserve(heap, additional)
+    }
+
+    /// Appends a character at the end of the string. If the string has not enough capacity
+    /// it acquires more memory from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the string acquired its memory before
+    ///  * the string must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn push(&mut self, heap: &ShmHeap, value: char) -> Result<(), AllocationError> {
+        let mut buffer = [0u8; 4];
+        fail!(from "ShmString::push()",
+            when self.data.extend_from_slice(heap, value.encode_utf8(&mut buffer).as_bytes()),
+            "Unable to push character since the string could not acquire more memory.");
+        Ok(())
+    }
+
+    /// Appends a string slice at the end of the string. If the string has not enough capacity
+    /// it acquires more memory from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the string acquired its memory before
+    ///  * the string must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn push_str(&mut self, heap: &ShmHeap, value: &str) -> Result<(), AllocationError> {
+        fail!(from "ShmString::push_str()",
+            when self.data.extend_from_slice(heap, value.as_bytes()),
+            "Unable to push string since the string could not acquire more memory.");
+        Ok(())
+    }
+
+    /// Removes the last character of the string and returns it. If the string is empty it
+    /// returns [`None`].
+    ///
+    /// # Safety
+    ///
+    ///  * the string must not have been moved independently of its heap
+    ///
+    pub unsafe fn pop(&mut self) -> Option<char> {
+        let value = self.as_str().chars().next_back()?;
+        for _ in 0..value.len_utf8() {
+            self.data.pop();
+        }
+        Some(value)
+    }
+
+    /// Removes all characters from the string. The memory is kept and can be reused.
+    ///
+    /// # Safety
+    ///
+    ///  * the string must not have been moved independently of its heap
+    ///
+    pub unsafe fn clear(&mut self) {
+        self.data.clear()
+    }
+
+    /// Returns the contents as string slice
+    ///
+    /// # Safety
+    ///
+    ///  * the string must not have been moved independently of its heap
+    ///
+    pub unsafe fn as_str(&self) -> &str {
+        // only valid UTF-8 is added to the underlying vector
+        core::str::from_utf8_unchecked(self.data.as_slice())
+    }
+
+    /// Returns the contents as byte slice
+    ///
+    /// # Safety
+    ///
+    ///  * the string must not have been moved independently of its heap
+    ///
+    pub unsafe fn as_bytes(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+}
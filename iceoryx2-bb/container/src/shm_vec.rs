@@ -0,0 +1,309 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A dynamically sized vector that acquires its memory from a [`ShmHeap`] and refers to it with
+//! a relative pointer. It is shared memory compatible as long as it is stored in the same shared
+//! memory segment as the heap, for instance as part of a
+//! [`ShmPayload`](crate::shm_heap::ShmPayload).
+//!
+//! **Important:** The [`ShmVec`] is movable until it acquired memory from the heap, afterwards
+//! it must only be moved together with the heap. Therefore, all methods that acquire or access
+//! the memory are `unsafe` and the vector provides no safe access to its elements, like
+//! [`core::ops::Deref`]. The elements are not dropped together with the vector, they have to
+//! be removed explicitly, for instance with [`ShmVec::clear()`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_container::shm_heap::FixedSizeShmHeap;
+//! use iceoryx2_bb_container::shm_vec::ShmVec;
+//!
+//! let heap = FixedSizeShmHeap::<1024>::new();
+//! let mut vec = ShmVec::<u64>::new();
+//!
+//! // vec and heap are not moved until they go out of scope
+//! unsafe {
+//!     vec.push(&heap, 123).expect("heap has enough memory");
+//!     vec.extend_from_slice(&heap, &[4, 5, 6]).expect("heap has enough memory");
+//!
+//!     assert_eq!(vec.as_slice(), &[123, 4, 5, 6]);
+//!     assert_eq!(vec.pop(), Some(6));
+//! }
+//! ```
+
+use std::alloc::Layout;
+use std::fmt::Debug;
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+
+use iceoryx2_bb_elementary::allocator::{
+    AllocationError, AllocationGrowError, Allocator, BaseAllocator,
+};
+use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+use iceoryx2_bb_elementary::pointer_trait::PointerTrait;
+use iceoryx2_bb_elementary::relocatable_ptr::RelocatablePointer;
+use iceoryx2_bb_elementary::zero_copy_send::layout_hash;
+use iceoryx2_bb_log::fail;
+
+use crate::shm_heap::{ShmHeap, ShmSend};
+
+/// Vector with a dynamic capacity whose memory is acquired from a [`ShmHeap`].
+#[repr(C)]
+pub struct ShmVec<T> {
+    data_ptr: RelocatablePointer<MaybeUninit<T>>,
+    capacity: usize,
+    len: usize,
+}
+
+unsafe impl<T: Send> Send for ShmVec<T> {}
+unsafe impl<T: Sync> Sync for ShmVec<T> {}
+
+// the elements are referred to with a relative pointer into the heap of the payload
+unsafe impl<T: ShmSend> ShmSend for ShmVec<T> {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::layout_hash()?,
+        ]))
+    }
+}
+
+// the elements are not accessed since the vector could have been moved without its heap
+impl<T> Debug for ShmVec<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ShmVec<{}> {{ len: {}, capacity: {} }}",
+            core::any::type_name::<T>(),
+            self.len,
+            self.capacity
+        )
+    }
+}
+
+impl<T> Default for ShmVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PlacementDefault for ShmVec<T> {
+    unsafe fn placement_default(ptr: *mut Self) {
+        ptr.write(Self::new())
+    }
+}
+
+impl<T> ShmVec<T> {
+    /// Creates a new empty [`ShmVec`] that has not acquired any memory yet.
+    pub fn new() -> Self {
+        Self {
+            data_ptr: unsafe { RelocatablePointer::new_uninit() },
+            capacity: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements the vector can hold without acquiring more memory
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of elements stored inside the vector
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the vector is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn data(&self) -> *mut MaybeUninit<T> {
+        if self.capacity == 0 || core::mem::size_of::<T>() == 0 {
+            NonNull::dangling().as_ptr()
+        } else {
+            unsafe { self.data_ptr.as_ptr() as *mut MaybeUninit<T> }
+        }
+    }
+
+    /// Ensures that at least `additional` further elements can be added without acquiring
+    /// more memory from the heap. When the vector has to grow, the heap must be the same
+    /// heap from which the current memory was acquired.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the vector acquired its memory before
+    ///  * the vector must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn reserve(
+        &mut self,
+        heap: &ShmHeap,
+        additional: usize,
+    ) -> Result<(), AllocationError> {
+        let msg = "Unable to reserve memory";
+        let required_capacity = match self.len.checked_add(additional) {
+            Some(v) => v,
+            None => {
+                fail!(from "ShmVec::reserve()", with AllocationError::SizeTooLarge,
+                    "{} since the required capacity exceeds the maximum supported capacity.", msg);
+            }
+        };
+
+        if required_capacity <= self.capacity {
+            return Ok(());
+        }
+
+        if core::mem::size_of::<T>() == 0 {
+            self.capacity = usize::MAX;
+            return Ok(());
+        }
+
+        let new_capacity = required_capacity.max(self.capacity * 2);
+        let new_layout = match Layout::array::<T>(new_capacity) {
+            Ok(layout) => layout,
+            Err(_) => {
+                fail!(from "ShmVec::reserve()", with AllocationError::SizeTooLarge,
+                    "{} since the required capacity of {} elements exceeds the maximum supported size.",
+                    msg, new_capacity);
+            }
+        };
+
+        let memory = if self.capacity == 0 {
+            fail!(from "ShmVec::reserve()", when heap.allocate(new_layout),
+                "{} since the heap could not provide memory for {} elements.", msg, new_capacity)
+        } else {
+            let old_layout = unsafe {
+                Layout::from_size_align_unchecked(
+                    core::mem::size_of::<T>() * self.capacity,
+                    core::mem::align_of::<T>(),
+                )
+            };
+            let old_memory = unsafe { NonNull::new_unchecked(self.data() as *mut u8) };
+            match unsafe { heap.grow(old_memory, old_layout, new_layout) } {
+                Ok(memory) => memory,
+                Err(e) => {
+                    let reason = match e {
+                        AllocationGrowError::OutOfMemory => AllocationError::OutOfMemory,
+                        AllocationGrowError::AlignmentFailure => AllocationError::AlignmentFailure,
+                        _ => AllocationError::InternalError,
+                    };
+                    fail!(from "ShmVec::reserve()", with reason,
+                        "{} since the heap could not grow the memory to {} elements ({:?}).",
+                        msg, new_capacity, e);
+                }
+            }
+        };
+
+        self.data_ptr = unsafe { RelocatablePointer::new_uninit() };
+        unsafe { self.data_ptr.init(memory) };
+        self.capacity = new_capacity;
+
+        Ok(())
+    }
+
+    /// Adds an element at the end of the vector. If the vector has not enough capacity
+    /// it acquires more memory from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the vector acquired its memory before
+    ///  * the vector must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn push(&mut self, heap: &ShmHeap, value: T) -> Result<(), AllocationError> {
+        if self.len == self.capacity {
+            fail!(from "ShmVec::push()", when self.reserve(heap, 1),
+                "Unable to push value since the vector could not acquire more memory.");
+        }
+
+        unsafe { self.data().add(self.len).write(MaybeUninit::new(value)) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends all elements of the slice at the end of the vector. If the vector has not
+    /// enough capacity it acquires more memory from the heap.
+    ///
+    /// # Safety
+    ///
+    ///  * `heap` must be the heap from which the vector acquired its memory before
+    ///  * the vector must not be moved independently of `heap` afterwards until it is dropped
+    ///
+    pub unsafe fn extend_from_slice(
+        &mut self,
+        heap: &ShmHeap,
+        other: &[T],
+    ) -> Result<(), AllocationError>
+    where
+        T: Clone,
+    {
+        fail!(from "ShmVec::extend_from_slice()", when self.reserve(heap, other.len()),
+            "Unable to extend vector since it could not acquire more memory.");
+
+        for value in other {
+            unsafe {
+                self.data()
+                    .add(self.len)
+                    .write(MaybeUninit::new(value.clone()))
+            };
+            self.len += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Removes the last element of the vector and returns it. If the vector is empty it
+    /// returns [`None`].
+    ///
+    /// # Safety
+    ///
+    ///  * the vector must not have been moved independently of its heap
+    ///
+    pub unsafe fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { self.data().add(self.len).read().assume_init() })
+    }
+
+    /// Removes all elements from the vector. The memory is kept and can be reused.
+    ///
+    /// # Safety
+    ///
+    ///  * the vector must not have been moved independently of its heap
+    ///
+    pub unsafe fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Returns a slice to the contents of the vector
+    ///
+    /// # Safety
+    ///
+    ///  * the vector must not have been moved independently of its heap
+    ///
+    pub unsafe fn as_slice(&self) -> &[T] {
+        core::slice::from_raw_parts(self.data() as *const T, self.len)
+    }
+
+    /// Returns a mutable slice to the contents of the vector
+    ///
+    /// # Safety
+    ///
+    ///  * the vector must not have been moved independently of its heap
+    ///
+    pub unsafe fn as_mut_slice(&mut self) -> &mut [T] {
+        core::slice::from_raw_parts_mut(self.data() as *mut T, self.len)
+    }
+}
@@ -89,6 +89,7 @@ use iceoryx2_bb_elementary::{
     pointer_trait::PointerTrait,
     relocatable_container::RelocatableContainer,
     relocatable_ptr::RelocatablePointer,
+    zero_copy_send::ZeroCopySend,
 };
 use iceoryx2_bb_log::{fail, fatal_panic};
 use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
@@ -419,6 +420,18 @@ impl<'de, T: Deserialize<'de>, const CAPACITY: usize> Deserialize<'de>
     }
 }
 
+// the vector stores its elements inline and uses a relative pointer to access them
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for FixedSizeVec<T, CAPACITY> {
+    fn layout_hash() -> Option<u64> {
+        Some(Self::__hash_layout(&[
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
 impl<T, const CAPACITY: usize> PlacementDefault for FixedSizeVec<T, CAPACITY> {
     unsafe fn placement_default(ptr: *mut Self) {
         let state_ptr = core::ptr::addr_of_mut!((*ptr).state);
@@ -0,0 +1,305 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod hash_map {
+    use std::hash::{Hash, Hasher};
+
+    use iceoryx2_bb_container::hash_map::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeHashMap<usize, usize, SUT_CAPACITY>;
+
+    // all keys share the same hash so that every insert and remove operates on one probe sequence
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct CollidingKey(usize);
+
+    impl Hash for CollidingKey {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            0usize.hash(state)
+        }
+    }
+
+    #[test]
+    fn relocatable_insert_get_remove_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 8192];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableHashMap::<usize, usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.is_full(), eq false);
+            assert_that!(unsafe { sut.insert(i, i * 2 + 3) }, eq Ok(None));
+            assert_that!(sut, is_not_empty);
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.get(&i), eq Some(&(i * 2 + 3)));
+        }
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut, len sut.capacity() - i);
+            assert_that!(unsafe { sut.remove(&i) }, eq Some(i * 2 + 3));
+            assert_that!(sut.is_full(), eq false);
+        }
+
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn relocatable_clear_empties_map() {
+        let mut memory = [0xffu8; 8192];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableHashMap::<usize, usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.insert(i, i) }, is_ok);
+        }
+
+        unsafe { sut.clear() };
+        assert_that!(sut, is_empty);
+        assert_that!(sut.contains_key(&0), eq false);
+    }
+
+    #[test]
+    fn capacity_is_correct() {
+        let sut = Sut::new();
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+    }
+
+    #[test]
+    fn newly_created_map_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut, len 0);
+        assert_that!(sut.get(&0), eq None);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn insert_and_get_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i * 7, i), eq Ok(None));
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.contains_key(&(i * 7)), eq true);
+            assert_that!(sut.get(&(i * 7)), eq Some(&i));
+        }
+        assert_that!(sut.contains_key(&1), eq false);
+    }
+
+    #[test]
+    fn insert_existing_key_replaces_value() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(12, 34), eq Ok(None));
+        assert_that!(sut.insert(12, 56), eq Ok(Some(34)));
+
+        assert_that!(sut, len 1);
+        assert_that!(sut.get(&12), eq Some(&56));
+    }
+
+    #[test]
+    fn insert_into_full_map_fails() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, i), is_ok);
+        }
+
+        assert_that!(sut.insert(SUT_CAPACITY, 0), eq Err(HashMapInsertError::ExceedsCapacity));
+        assert_that!(sut.insert(0, 1), eq Ok(Some(0)));
+    }
+
+    #[test]
+    fn get_mut_modifies_value() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(1, 2), is_ok);
+        *sut.get_mut(&1).unwrap() = 3;
+
+        assert_that!(sut.get(&1), eq Some(&3));
+        assert_that!(sut.get_mut(&2), eq None);
+    }
+
+    #[test]
+    fn remove_non_existing_key_returns_none() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.remove(&1), eq None);
+        assert_that!(sut.insert(1, 2), is_ok);
+        assert_that!(sut.remove(&3), eq None);
+        assert_that!(sut, len 1);
+    }
+
+    #[test]
+    fn remove_keeps_colliding_keys_reachable() {
+        let mut sut = FixedSizeHashMap::<CollidingKey, usize, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(CollidingKey(i), i), is_ok);
+        }
+
+        for i in (0..SUT_CAPACITY).step_by(2) {
+            assert_that!(sut.remove(&CollidingKey(i)), eq Some(i));
+        }
+
+        for i in 0..SUT_CAPACITY {
+            if i % 2 == 0 {
+                assert_that!(sut.get(&CollidingKey(i)), eq None);
+            } else {
+                assert_that!(sut.get(&CollidingKey(i)), eq Some(&i));
+            }
+        }
+    }
+
+    #[test]
+    fn removed_slots_can_be_reused() {
+        let mut sut = Sut::new();
+
+        for n in 0..3 {
+            for i in 0..SUT_CAPACITY {
+                assert_that!(sut.insert(i + n, i), is_ok);
+            }
+            assert_that!(sut.is_full(), eq true);
+
+            for i in 0..SUT_CAPACITY {
+                assert_that!(sut.remove(&(i + n)), eq Some(i));
+            }
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn iterate_over_all_elements_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.insert(i, i * 2), is_ok);
+        }
+
+        let expected_elements: Vec<(usize, usize)> =
+            (0..SUT_CAPACITY / 2).map(|i| (i, i * 2)).collect();
+        let mut elements: Vec<(usize, usize)> = sut.iter().map(|(k, v)| (*k, *v)).collect();
+        elements.sort();
+        assert_that!(elements, eq expected_elements);
+
+        let expected_keys: Vec<usize> = (0..SUT_CAPACITY / 2).collect();
+        let mut keys: Vec<usize> = sut.keys().copied().collect();
+        keys.sort();
+        assert_that!(keys, eq expected_keys);
+
+        let expected_sum: usize = (0..SUT_CAPACITY / 2).map(|i| i * 2).sum();
+        assert_that!(sut.values().sum::<usize>(), eq expected_sum);
+    }
+
+    #[test]
+    fn valid_after_move() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, i + 1), is_ok);
+        }
+
+        let sut2 = sut;
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut2.get(&i), eq Some(&(i + 1)));
+        }
+    }
+
+    #[test]
+    fn clone_and_equality_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.insert(i, i + 1), is_ok);
+        }
+
+        let mut sut2 = sut.clone();
+        assert_that!(sut2, eq sut);
+
+        assert_that!(sut2.insert(0, 0), is_ok);
+        assert_that!(sut2, ne sut);
+    }
+
+    #[test]
+    fn drops_all_objects_when_out_of_scope() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn hash_map_clear_drops_all_objects() {
+        LifetimeTracker::start_tracking();
+        let mut sut = HashMap::<usize, LifetimeTracker>::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn remove_releases_object() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeHashMap::<usize, LifetimeTracker, SUT_CAPACITY>::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i, LifetimeTracker::new()), is_ok);
+        }
+
+        for i in (0..SUT_CAPACITY).rev() {
+            let result = sut.remove(&i);
+            assert_that!(result, is_some);
+            drop(result);
+            assert_that!(LifetimeTracker::number_of_living_instances(), eq i);
+        }
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe {sut.assume_init()}.get(&123), eq None);
+        assert_that!(unsafe {sut.assume_init_mut()}.insert(123, 456), eq Ok(None));
+        assert_that!(unsafe {sut.assume_init_mut()}.insert(789, 12), eq Ok(None));
+
+        assert_that!(unsafe {sut.assume_init_mut()}.remove(&123), eq Some(456));
+        assert_that!(unsafe {sut.assume_init()}.get(&789), eq Some(&12));
+    }
+}
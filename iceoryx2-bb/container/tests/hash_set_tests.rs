@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod hash_set {
+    use iceoryx2_bb_container::hash_set::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeHashSet<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn relocatable_insert_remove_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 8192];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableHashSet::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.insert(i) }, eq Ok(true));
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.contains(&i), eq true);
+            assert_that!(unsafe { sut.remove(&i) }, eq true);
+            assert_that!(sut.contains(&i), eq false);
+        }
+
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn newly_created_set_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.contains(&0), eq false);
+    }
+
+    #[test]
+    fn insert_existing_value_returns_false() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.insert(5), eq Ok(true));
+        assert_that!(sut.insert(5), eq Ok(false));
+        assert_that!(sut, len 1);
+    }
+
+    #[test]
+    fn insert_into_full_set_fails() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i), eq Ok(true));
+        }
+
+        assert_that!(sut.insert(SUT_CAPACITY), eq Err(HashSetInsertError::ExceedsCapacity));
+        assert_that!(sut.insert(0), eq Ok(false));
+    }
+
+    #[test]
+    fn remove_non_existing_value_returns_false() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.remove(&1), eq false);
+        assert_that!(sut.insert(1), is_ok);
+        assert_that!(sut.remove(&1), eq true);
+        assert_that!(sut.remove(&1), eq false);
+    }
+
+    #[test]
+    fn iterate_over_all_values_works() {
+        let mut sut = HashSet::<usize>::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.insert(i * 3), is_ok);
+        }
+
+        let expected_values: Vec<usize> = (0..SUT_CAPACITY).map(|i| i * 3).collect();
+        let mut values: Vec<usize> = sut.iter().copied().collect();
+        values.sort();
+        assert_that!(values, eq expected_values);
+
+        sut.clear();
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn clone_and_equality_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY / 2 {
+            assert_that!(sut.insert(i), is_ok);
+        }
+
+        let mut sut2 = sut.clone();
+        assert_that!(sut2, eq sut);
+
+        assert_that!(sut2.remove(&0), eq true);
+        assert_that!(sut2, ne sut);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe {sut.assume_init_mut()}.insert(123), eq Ok(true));
+        assert_that!(unsafe {sut.assume_init_mut()}.insert(456), eq Ok(true));
+
+        assert_that!(unsafe {sut.assume_init()}.contains(&123), eq true);
+        assert_that!(unsafe {sut.assume_init_mut()}.remove(&456), eq true);
+    }
+}
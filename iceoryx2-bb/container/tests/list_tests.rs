@@ -0,0 +1,200 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod list {
+    use iceoryx2_bb_container::list::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeList<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn relocatable_push_pop_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 16384];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableList::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.push_back(i) }, is_ok);
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.pop_front() }, eq Some(i));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn newly_created_list_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.front(), eq None);
+        assert_that!(sut.back(), eq None);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn push_front_and_push_back_order_elements() {
+        let mut sut = Sut::new();
+
+        assert_that!(sut.push_back(2), is_ok);
+        assert_that!(sut.push_front(1), is_ok);
+        assert_that!(sut.push_back(3), is_ok);
+        assert_that!(sut.push_front(0), is_ok);
+
+        let elements: Vec<usize> = sut.iter().copied().collect();
+        assert_that!(elements, eq vec![0, 1, 2, 3]);
+        let elements: Vec<usize> = sut.iter().rev().copied().collect();
+        assert_that!(elements, eq vec![3, 2, 1, 0]);
+
+        assert_that!(sut.pop_back(), eq Some(3));
+        assert_that!(sut.pop_front(), eq Some(0));
+        assert_that!(sut.front(), eq Some(&1));
+        assert_that!(sut.back(), eq Some(&2));
+    }
+
+    #[test]
+    fn push_into_full_list_fails() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.push_back(i), is_ok);
+        }
+
+        assert_that!(sut.push_back(0), eq Err(ListInsertError::ExceedsCapacity));
+        assert_that!(sut.push_front(0), eq Err(ListInsertError::ExceedsCapacity));
+    }
+
+    #[test]
+    fn remove_by_handle_works() {
+        let mut sut = Sut::new();
+
+        let handles: Vec<ListHandle> = (0..SUT_CAPACITY)
+            .map(|i| sut.push_back(i).unwrap())
+            .collect();
+
+        for (i, handle) in handles.iter().enumerate().step_by(2) {
+            assert_that!(sut.get(*handle), eq Some(&i));
+            assert_that!(sut.remove(*handle), eq Some(i));
+            assert_that!(sut.contains(*handle), eq false);
+        }
+
+        let expected_elements: Vec<usize> = (0..SUT_CAPACITY).filter(|i| i % 2 == 1).collect();
+        let elements: Vec<usize> = sut.iter().copied().collect();
+        assert_that!(elements, eq expected_elements);
+    }
+
+    #[test]
+    fn handle_of_removed_element_stays_invalid_when_node_is_reused() {
+        let mut sut = Sut::new();
+
+        let handle = sut.push_back(1).unwrap();
+        assert_that!(sut.pop_front(), eq Some(1));
+
+        let new_handle = sut.push_back(2).unwrap();
+        assert_that!(new_handle, ne handle);
+        assert_that!(sut.get(handle), eq None);
+        assert_that!(sut.remove(handle), eq None);
+        assert_that!(sut.get(new_handle), eq Some(&2));
+    }
+
+    #[test]
+    fn get_mut_front_mut_and_back_mut_modify_elements() {
+        let mut sut = List::<usize>::new(SUT_CAPACITY);
+
+        let handle = sut.push_back(1).unwrap();
+        assert_that!(sut.push_back(2), is_ok);
+        assert_that!(sut.push_back(3), is_ok);
+
+        *sut.get_mut(handle).unwrap() = 10;
+        *sut.back_mut().unwrap() = 30;
+        assert_that!(sut.front(), eq Some(&10));
+        *sut.front_mut().unwrap() = 11;
+
+        let elements: Vec<usize> = sut.iter().copied().collect();
+        assert_that!(elements, eq vec![11, 2, 30]);
+    }
+
+    #[test]
+    fn removed_nodes_can_be_reused() {
+        let mut sut = Sut::new();
+
+        for n in 0..3 {
+            for i in 0..SUT_CAPACITY {
+                assert_that!(sut.push_front(i + n), is_ok);
+            }
+            assert_that!(sut.is_full(), eq true);
+
+            for i in 0..SUT_CAPACITY {
+                assert_that!(sut.pop_back(), eq Some(i + n));
+            }
+            assert_that!(sut, is_empty);
+        }
+    }
+
+    #[test]
+    fn valid_after_move_and_clone() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.push_back(i), is_ok);
+        }
+
+        let sut2 = sut;
+        let sut3 = sut2.clone();
+        assert_that!(sut3, eq sut2);
+
+        let expected_elements: Vec<usize> = (0..SUT_CAPACITY).collect();
+        let elements: Vec<usize> = (&sut3).into_iter().copied().collect();
+        assert_that!(elements, eq expected_elements);
+    }
+
+    #[test]
+    fn clear_and_drop_release_all_objects() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeList::<LifetimeTracker, SUT_CAPACITY>::new();
+
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.push_back(LifetimeTracker::new()), is_ok);
+        }
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+
+        for _ in 0..SUT_CAPACITY {
+            assert_that!(sut.push_front(LifetimeTracker::new()), is_ok);
+        }
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe { sut.assume_init_mut() }.push_back(123), is_ok);
+        assert_that!(unsafe { sut.assume_init_mut() }.push_front(456), is_ok);
+
+        assert_that!(unsafe {sut.assume_init_mut()}.pop_back(), eq Some(123));
+        assert_that!(unsafe {sut.assume_init()}.front(), eq Some(&456));
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod priority_queue {
+    use iceoryx2_bb_container::priority_queue::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizePriorityQueue<usize, SUT_CAPACITY>;
+
+    // deterministic sequence that visits every value below SUT_CAPACITY exactly once
+    fn shuffled_values() -> Vec<usize> {
+        (0..SUT_CAPACITY)
+            .map(|i| (i * 37 + 11) % SUT_CAPACITY)
+            .collect()
+    }
+
+    #[test]
+    fn relocatable_push_pop_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 8192];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatablePriorityQueue::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for value in shuffled_values() {
+            assert_that!(unsafe { sut.push(value) }, eq true);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in (0..SUT_CAPACITY).rev() {
+            assert_that!(unsafe { sut.pop() }, eq Some(i));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn newly_created_priority_queue_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.peek(), eq None);
+    }
+
+    #[test]
+    fn pop_returns_greatest_element_first() {
+        let mut sut = Sut::new();
+
+        for value in shuffled_values() {
+            assert_that!(sut.push(value), eq true);
+            assert_that!(sut.peek(), is_some);
+        }
+
+        assert_that!(sut.peek(), eq Some(&(SUT_CAPACITY - 1)));
+        for i in (0..SUT_CAPACITY).rev() {
+            assert_that!(sut.pop(), eq Some(i));
+        }
+        assert_that!(sut.pop(), eq None);
+    }
+
+    #[test]
+    fn push_into_full_priority_queue_fails() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.push(i), eq true);
+        }
+
+        assert_that!(sut.push(SUT_CAPACITY), eq false);
+        assert_that!(sut.peek(), eq Some(&(SUT_CAPACITY - 1)));
+    }
+
+    #[test]
+    fn duplicated_elements_are_all_returned() {
+        let mut sut = FixedSizePriorityQueue::<u32, 8>::new();
+
+        for value in [3, 1, 3, 2, 1, 3] {
+            assert_that!(sut.push(value), eq true);
+        }
+
+        let mut elements = vec![];
+        while let Some(value) = sut.pop() {
+            elements.push(value);
+        }
+        assert_that!(elements, eq vec![3, 3, 3, 2, 1, 1]);
+    }
+
+    #[test]
+    fn interleaved_push_and_pop_keeps_order() {
+        let mut sut = PriorityQueue::<usize>::new(SUT_CAPACITY);
+
+        for value in shuffled_values() {
+            assert_that!(sut.push(value), eq true);
+            if value % 3 == 0 {
+                let greatest = *sut.iter().max().unwrap();
+                assert_that!(sut.pop(), eq Some(greatest));
+            }
+        }
+
+        let mut previous = usize::MAX;
+        while let Some(value) = sut.pop() {
+            assert_that!(value, lt previous);
+            previous = value;
+        }
+    }
+
+    #[test]
+    fn iter_contains_all_elements() {
+        let mut sut = Sut::new();
+
+        for value in shuffled_values() {
+            assert_that!(sut.push(value), eq true);
+        }
+
+        let expected_elements: Vec<usize> = (0..SUT_CAPACITY).collect();
+        let mut elements: Vec<usize> = sut.iter().copied().collect();
+        elements.sort();
+        assert_that!(elements, eq expected_elements);
+
+        let sut2 = sut.clone();
+        assert_that!(sut2.iter().len(), eq SUT_CAPACITY);
+        assert_that!(sut2.peek(), eq sut.peek());
+    }
+
+    #[derive(Debug)]
+    struct TrackedValue {
+        value: usize,
+        _tracker: LifetimeTracker,
+    }
+
+    impl TrackedValue {
+        fn new(value: usize) -> Self {
+            Self {
+                value,
+                _tracker: LifetimeTracker::new(),
+            }
+        }
+    }
+
+    impl PartialEq for TrackedValue {
+        fn eq(&self, other: &Self) -> bool {
+            self.value == other.value
+        }
+    }
+
+    impl Eq for TrackedValue {}
+
+    impl PartialOrd for TrackedValue {
+        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for TrackedValue {
+        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+            self.value.cmp(&other.value)
+        }
+    }
+
+    #[test]
+    fn clear_and_drop_release_all_objects() {
+        LifetimeTracker::start_tracking();
+        let mut sut = PriorityQueue::<TrackedValue>::new(SUT_CAPACITY);
+
+        for value in shuffled_values() {
+            assert_that!(sut.push(TrackedValue::new(value)), eq true);
+        }
+        sut.clear();
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+
+        for value in shuffled_values() {
+            assert_that!(sut.push(TrackedValue::new(value)), eq true);
+        }
+        assert_that!(sut.pop().map(|v| v.value), eq Some(SUT_CAPACITY - 1));
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe {sut.assume_init_mut()}.push(123), eq true);
+        assert_that!(unsafe {sut.assume_init_mut()}.push(456), eq true);
+
+        assert_that!(unsafe {sut.assume_init_mut()}.pop(), eq Some(456));
+        assert_that!(unsafe {sut.assume_init()}.peek(), eq Some(&123));
+    }
+}
@@ -0,0 +1,162 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod ring_buffer {
+    use iceoryx2_bb_container::ring_buffer::*;
+    use iceoryx2_bb_elementary::{
+        bump_allocator::BumpAllocator, placement_default::PlacementDefault,
+    };
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const SUT_CAPACITY: usize = 128;
+    type Sut = FixedSizeRingBuffer<usize, SUT_CAPACITY>;
+
+    #[test]
+    fn relocatable_push_pop_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 8192];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let mut sut = unsafe { RelocatableRingBuffer::<usize>::new_uninit(SUT_CAPACITY) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.push(i * 2) }, eq None);
+            assert_that!(sut, len i + 1);
+        }
+        assert_that!(sut.is_full(), eq true);
+
+        for i in 0..sut.capacity() {
+            assert_that!(unsafe { sut.pop() }, eq Some(i * 2));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn newly_created_ring_buffer_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.is_full(), eq false);
+        assert_that!(sut.capacity(), eq SUT_CAPACITY);
+        assert_that!(sut.front(), eq None);
+        assert_that!(sut.back(), eq None);
+        assert_that!(sut.iter().count(), eq 0);
+    }
+
+    #[test]
+    fn push_into_full_ring_buffer_overwrites_oldest_element() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY {
+            assert_that!(sut.push(i), eq None);
+        }
+
+        for i in 0..SUT_CAPACITY * 2 {
+            assert_that!(sut.push(SUT_CAPACITY + i), eq Some(i));
+            assert_that!(sut, len SUT_CAPACITY);
+            assert_that!(sut.front(), eq Some(&(i + 1)));
+            assert_that!(sut.back(), eq Some(&(SUT_CAPACITY + i)));
+        }
+    }
+
+    #[test]
+    fn get_returns_elements_starting_with_oldest() {
+        let mut sut = FixedSizeRingBuffer::<usize, 4>::new();
+
+        for i in 0..6 {
+            sut.push(i);
+        }
+
+        assert_that!(sut.get(0), eq Some(&2));
+        assert_that!(sut.get(3), eq Some(&5));
+        assert_that!(sut.get(4), eq None);
+
+        *sut.get_mut(1).unwrap() = 9;
+        assert_that!(sut.get(1), eq Some(&9));
+    }
+
+    #[test]
+    fn iterators_traverse_from_oldest_to_newest() {
+        let mut sut = FixedSizeRingBuffer::<usize, 4>::new();
+
+        for i in 0..7 {
+            sut.push(i);
+        }
+
+        let elements: Vec<usize> = sut.iter().copied().collect();
+        assert_that!(elements, eq vec![3, 4, 5, 6]);
+        let elements: Vec<usize> = sut.iter().rev().copied().collect();
+        assert_that!(elements, eq vec![6, 5, 4, 3]);
+        assert_that!(sut.iter().len(), eq 4);
+
+        for value in sut.iter_mut() {
+            *value *= 2;
+        }
+        let elements: Vec<usize> = (&sut).into_iter().copied().collect();
+        assert_that!(elements, eq vec![6, 8, 10, 12]);
+    }
+
+    #[test]
+    fn ring_buffer_with_heap_memory_works() {
+        let mut sut = RingBuffer::<usize>::new(SUT_CAPACITY);
+
+        for i in 0..SUT_CAPACITY + 3 {
+            sut.push(i);
+        }
+
+        assert_that!(sut.pop(), eq Some(3));
+        sut.clear();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.pop(), eq None);
+    }
+
+    #[test]
+    fn clone_and_equality_works() {
+        let mut sut = Sut::new();
+
+        for i in 0..SUT_CAPACITY + 10 {
+            sut.push(i);
+        }
+
+        let mut sut2 = sut.clone();
+        assert_that!(sut2, eq sut);
+
+        sut2.push(0);
+        assert_that!(sut2, ne sut);
+    }
+
+    #[test]
+    fn drops_all_objects_when_overwritten_or_out_of_scope() {
+        LifetimeTracker::start_tracking();
+        let mut sut = FixedSizeRingBuffer::<LifetimeTracker, SUT_CAPACITY>::new();
+
+        for _ in 0..SUT_CAPACITY * 2 {
+            drop(sut.push(LifetimeTracker::new()));
+        }
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq SUT_CAPACITY);
+
+        drop(sut);
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let mut sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe {sut.assume_init()}, len 0);
+        assert_that!(unsafe {sut.assume_init_mut()}.push(123), eq None);
+        assert_that!(unsafe {sut.assume_init_mut()}.push(456), eq None);
+
+        assert_that!(unsafe {sut.assume_init_mut()}.pop(), eq Some(123));
+        assert_that!(unsafe {sut.assume_init()}.front(), eq Some(&456));
+    }
+}
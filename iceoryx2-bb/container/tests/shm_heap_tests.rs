@@ -0,0 +1,215 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_heap {
+    use std::alloc::Layout;
+    use std::ptr::NonNull;
+    use std::sync::Barrier;
+
+    use iceoryx2_bb_container::shm_heap::*;
+    use iceoryx2_bb_container::shm_vec::ShmVec;
+    use iceoryx2_bb_elementary::allocator::{
+        AllocationError, AllocationGrowError, AllocationShrinkError, Allocator, BaseAllocator,
+    };
+    use iceoryx2_bb_elementary::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::{assert_that, memory::RawMemory};
+
+    const HEAP_SIZE: usize = 1024;
+    type Sut = FixedSizeShmHeap<HEAP_SIZE>;
+
+    #[test]
+    fn relocatable_heap_works_with_uninitialized_memory() {
+        let mut memory = [0xffu8; 2048];
+        let allocator = BumpAllocator::new(memory.as_mut_ptr() as usize);
+
+        let sut = unsafe { ShmHeap::new_uninit(HEAP_SIZE) };
+        unsafe { assert_that!(sut.init(&allocator), is_ok) };
+
+        let memory = sut.allocate(Layout::new::<u8>());
+        assert_that!(memory, is_ok);
+        assert_that!(sut.contains(memory.unwrap().as_ptr() as *const u8), eq true);
+        assert_that!(sut.used(), eq 1);
+    }
+
+    #[test]
+    fn newly_created_heap_is_empty() {
+        let sut = Sut::new();
+        assert_that!(sut.capacity(), eq HEAP_SIZE);
+        assert_that!(sut.used(), eq 0);
+    }
+
+    #[test]
+    fn allocate_respects_alignment() {
+        let sut = Sut::new();
+
+        assert_that!(sut.allocate(Layout::new::<u8>()), is_ok);
+        let memory = sut.allocate(Layout::new::<u64>()).unwrap();
+
+        assert_that!(memory.as_ptr() as *const u8 as usize % 8, eq 0);
+        assert_that!(memory.len(), eq 8);
+    }
+
+    #[test]
+    fn allocate_with_zero_size_fails() {
+        let sut = Sut::new();
+        let result = sut.allocate(Layout::from_size_align(0, 1).unwrap());
+        assert_that!(result, eq Err(AllocationError::SizeIsZero));
+    }
+
+    #[test]
+    fn allocate_more_than_capacity_fails() {
+        let sut = Sut::new();
+
+        assert_that!(
+            sut.allocate(Layout::from_size_align(HEAP_SIZE, 1).unwrap()),
+            is_ok
+        );
+        let result = sut.allocate(Layout::new::<u8>());
+        assert_that!(result, eq Err(AllocationError::OutOfMemory));
+    }
+
+    #[test]
+    fn allocate_that_exceeds_the_address_space_fails() {
+        let sut = Sut::new();
+        assert_that!(sut.allocate(Layout::new::<u8>()), is_ok);
+
+        let result = sut.allocate(Layout::from_size_align(isize::MAX as usize, 1).unwrap());
+        assert_that!(result, eq Err(AllocationError::OutOfMemory));
+
+        let result = sut.allocate(Layout::from_size_align(1, 1 << 62).unwrap());
+        assert_that!(result, eq Err(AllocationError::OutOfMemory));
+        assert_that!(sut.used(), eq 1);
+    }
+
+    #[test]
+    fn deallocate_of_most_recent_allocation_releases_memory() {
+        let sut = Sut::new();
+        let layout = Layout::from_size_align(128, 8).unwrap();
+
+        let first = sut.allocate(layout).unwrap();
+        let second = sut.allocate(layout).unwrap();
+        unsafe { sut.deallocate(NonNull::new_unchecked(first.as_ptr() as *mut u8), layout) };
+        assert_that!(sut.used(), eq 256);
+
+        unsafe { sut.deallocate(NonNull::new_unchecked(second.as_ptr() as *mut u8), layout) };
+        assert_that!(sut.used(), eq 128);
+    }
+
+    #[test]
+    fn grow_of_most_recent_allocation_is_performed_in_place() {
+        let sut = Sut::new();
+        let old_layout = Layout::from_size_align(16, 8).unwrap();
+        let new_layout = Layout::from_size_align(64, 8).unwrap();
+
+        let memory = sut.allocate(old_layout).unwrap();
+        let ptr = unsafe { NonNull::new_unchecked(memory.as_ptr() as *mut u8) };
+        unsafe { ptr.as_ptr().write(123) };
+
+        let grown = unsafe { sut.grow(ptr, old_layout, new_layout) }.unwrap();
+        assert_that!(grown.as_ptr() as *mut u8, eq ptr.as_ptr());
+        assert_that!(grown.len(), eq 64);
+        assert_that!(sut.used(), eq 64);
+        assert_that!(unsafe { *ptr.as_ptr() }, eq 123);
+    }
+
+    #[test]
+    fn grow_of_older_allocation_copies_content() {
+        let sut = Sut::new();
+        let old_layout = Layout::from_size_align(16, 8).unwrap();
+        let new_layout = Layout::from_size_align(64, 8).unwrap();
+
+        let memory = sut.allocate(old_layout).unwrap();
+        let ptr = unsafe { NonNull::new_unchecked(memory.as_ptr() as *mut u8) };
+        unsafe { ptr.as_ptr().write(89) };
+        assert_that!(sut.allocate(old_layout), is_ok);
+
+        let grown = unsafe { sut.grow(ptr, old_layout, new_layout) }.unwrap();
+        assert_that!(grown.as_ptr() as *mut u8, ne ptr.as_ptr());
+        assert_that!(unsafe { *(grown.as_ptr() as *mut u8) }, eq 89);
+    }
+
+    #[test]
+    fn grow_and_shrink_in_wrong_direction_fails() {
+        let sut = Sut::new();
+        let small_layout = Layout::from_size_align(16, 8).unwrap();
+        let large_layout = Layout::from_size_align(64, 8).unwrap();
+
+        let memory = sut.allocate(small_layout).unwrap();
+        let ptr = unsafe { NonNull::new_unchecked(memory.as_ptr() as *mut u8) };
+
+        let result = unsafe { sut.grow(ptr, large_layout, small_layout) };
+        assert_that!(result, eq Err(AllocationGrowError::GrowWouldShrink));
+        let result = unsafe { sut.shrink(ptr, small_layout, large_layout) };
+        assert_that!(result, eq Err(AllocationShrinkError::ShrinkWouldGrow));
+    }
+
+    #[test]
+    fn concurrent_allocations_do_not_overlap() {
+        const NUMBER_OF_THREADS: usize = 4;
+        const ALLOCATIONS_PER_THREAD: usize = 32;
+        let sut = FixedSizeShmHeap::<{ NUMBER_OF_THREADS * ALLOCATIONS_PER_THREAD * 8 }>::new();
+        let barrier = Barrier::new(NUMBER_OF_THREADS);
+
+        let mut addresses: Vec<usize> = std::thread::scope(|s| {
+            let threads: Vec<_> = (0..NUMBER_OF_THREADS)
+                .map(|_| {
+                    s.spawn(|| {
+                        barrier.wait();
+                        (0..ALLOCATIONS_PER_THREAD)
+                            .map(|_| {
+                                sut.allocate(Layout::new::<u64>()).unwrap().as_ptr() as *const u8
+                                    as usize
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            threads
+                .into_iter()
+                .flat_map(|t| t.join().unwrap())
+                .collect()
+        });
+
+        addresses.sort();
+        addresses.dedup();
+        assert_that!(addresses, len NUMBER_OF_THREADS * ALLOCATIONS_PER_THREAD);
+        assert_that!(sut.used(), eq sut.capacity());
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let sut = RawMemory::<Sut>::new_filled(0xff);
+        unsafe { Sut::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }.used(), eq 0);
+        let memory = unsafe { sut.assume_init() }.allocate(Layout::new::<u32>());
+        assert_that!(memory, is_ok);
+        assert_that!(unsafe { sut.assume_init() }.contains(memory.unwrap().as_ptr() as *const u8), eq true);
+    }
+
+    #[test]
+    fn payload_is_valid_after_move() {
+        let mut sut = ShmPayload::<ShmVec<usize>, HEAP_SIZE>::default();
+
+        let (vec, heap) = sut.split_mut();
+        for i in 0..64 {
+            assert_that!(unsafe { vec.push(heap, i) }, is_ok);
+        }
+
+        let sut2 = Box::new(sut);
+        let expected_content: Vec<usize> = (0..64).collect();
+        assert_that!(unsafe { sut2.as_slice() }, eq expected_content.as_slice());
+        assert_that!(sut2.heap().contains(unsafe { sut2.as_slice() }.as_ptr() as *const u8), eq true);
+    }
+}
@@ -0,0 +1,145 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_list {
+    use iceoryx2_bb_container::shm_heap::{FixedSizeShmHeap, ShmPayload};
+    use iceoryx2_bb_container::shm_list::*;
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker};
+
+    const HEAP_SIZE: usize = 8192;
+    type Heap = FixedSizeShmHeap<HEAP_SIZE>;
+
+    #[test]
+    fn newly_created_list_is_empty() {
+        let sut = ShmList::<u64>::new();
+        assert_that!(sut, is_empty);
+        assert_that!(unsafe { sut.front() }, eq None);
+        assert_that!(unsafe { sut.back() }, eq None);
+        assert_that!(unsafe { sut.iter().count() }, eq 0);
+    }
+
+    #[test]
+    fn push_back_and_pop_front_is_fifo() {
+        let heap = Heap::new();
+        let mut sut = ShmList::<usize>::new();
+
+        for i in 0..64 {
+            assert_that!(unsafe { sut.push_back(&heap, i) }, is_ok);
+            assert_that!(sut, len i + 1);
+            assert_that!(unsafe { sut.back() }, eq Some(&i));
+        }
+
+        for i in 0..64 {
+            assert_that!(unsafe { sut.front() }, eq Some(&i));
+            assert_that!(unsafe { sut.pop_front(&heap) }, eq Some(i));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn push_front_and_pop_front_is_lifo() {
+        let heap = Heap::new();
+        let mut sut = ShmList::<usize>::new();
+
+        for i in 0..64 {
+            assert_that!(unsafe { sut.push_front(&heap, i) }, is_ok);
+        }
+
+        for i in (0..64).rev() {
+            assert_that!(unsafe { sut.pop_front(&heap) }, eq Some(i));
+        }
+        assert_that!(unsafe { sut.pop_front(&heap) }, eq None);
+        assert_that!(unsafe { sut.pop_back(&heap) }, eq None);
+    }
+
+    #[test]
+    fn iterate_over_mixed_insertions_works() {
+        let heap = Heap::new();
+        let mut sut = ShmList::<usize>::new();
+
+        assert_that!(unsafe { sut.push_back(&heap, 3) }, is_ok);
+        assert_that!(unsafe { sut.push_front(&heap, 2) }, is_ok);
+        assert_that!(unsafe { sut.push_back(&heap, 4) }, is_ok);
+        assert_that!(unsafe { sut.push_front(&heap, 1) }, is_ok);
+        unsafe { *sut.back_mut().unwrap() = 5 };
+        unsafe { *sut.front_mut().unwrap() = 0 };
+
+        let content: Vec<usize> = unsafe { sut.iter() }.copied().collect();
+        assert_that!(content, eq vec![0, 2, 3, 5]);
+        assert_that!(unsafe { sut.pop_back(&heap) }, eq Some(5));
+        assert_that!(unsafe { sut.pop_front(&heap) }, eq Some(0));
+
+        let content: Vec<usize> = unsafe { sut.iter() }.copied().collect();
+        assert_that!(content, eq vec![2, 3]);
+    }
+
+    #[test]
+    fn clear_releases_memory_of_all_nodes() {
+        let heap = Heap::new();
+        let mut sut = ShmList::<u64>::new();
+
+        for i in 0..32 {
+            assert_that!(unsafe { sut.push_back(&heap, i) }, is_ok);
+        }
+
+        unsafe { sut.clear(&heap) };
+        assert_that!(sut, is_empty);
+        assert_that!(heap.used(), eq 0);
+    }
+
+    #[test]
+    fn push_into_exhausted_heap_fails() {
+        let heap = FixedSizeShmHeap::<64>::new();
+        let mut sut = ShmList::<u64>::new();
+
+        while unsafe { sut.push_back(&heap, 0) }.is_ok() {}
+
+        assert_that!(unsafe { sut.push_front(&heap, 0) }, eq Err(AllocationError::OutOfMemory));
+        assert_that!(sut.is_empty(), eq false);
+    }
+
+    #[test]
+    fn clear_releases_all_objects() {
+        LifetimeTracker::start_tracking();
+        // the list must not be moved independently of its heap therefore both are dropped as payload
+        let mut sut = ShmPayload::<ShmList<LifetimeTracker>, HEAP_SIZE>::default();
+
+        let (list, heap) = sut.split_mut();
+        for _ in 0..10 {
+            assert_that!(
+                unsafe { list.push_back(heap, LifetimeTracker::new()) },
+                is_ok
+            );
+        }
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 10);
+
+        unsafe { list.clear(heap) };
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+        assert_that!(list.is_empty(), eq true);
+    }
+
+    #[test]
+    fn list_in_payload_is_valid_after_move() {
+        let mut sut = ShmPayload::<ShmList<usize>, HEAP_SIZE>::default();
+
+        let (list, heap) = sut.split_mut();
+        for i in 0..32 {
+            assert_that!(unsafe { list.push_back(heap, i) }, is_ok);
+        }
+
+        let sut2 = Box::new(sut);
+        let expected_content: Vec<usize> = (0..32).collect();
+        let content: Vec<usize> = unsafe { sut2.iter() }.copied().collect();
+        assert_that!(content, eq expected_content);
+    }
+}
@@ -0,0 +1,77 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_string {
+    use iceoryx2_bb_container::shm_heap::FixedSizeShmHeap;
+    use iceoryx2_bb_container::shm_string::*;
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_testing::assert_that;
+
+    const HEAP_SIZE: usize = 1024;
+    type Heap = FixedSizeShmHeap<HEAP_SIZE>;
+
+    #[test]
+    fn newly_created_string_is_empty() {
+        let sut = ShmString::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.capacity(), eq 0);
+        assert_that!(unsafe { sut.as_str() }, eq "");
+    }
+
+    #[test]
+    fn push_str_and_push_works() {
+        let heap = Heap::new();
+        let mut sut = ShmString::new();
+
+        assert_that!(unsafe { sut.push_str(&heap, "all glory to the ") }, is_ok);
+        assert_that!(unsafe { sut.push_str(&heap, "hypnotoad") }, is_ok);
+        assert_that!(unsafe { sut.push(&heap, '!') }, is_ok);
+
+        assert_that!(unsafe { sut.as_str() }, eq "all glory to the hypnotoad!");
+        assert_that!(unsafe { sut.as_bytes() }, eq b"all glory to the hypnotoad!");
+        assert_that!(sut, len 27);
+    }
+
+    #[test]
+    fn pop_removes_multi_byte_characters() {
+        let heap = Heap::new();
+        let mut sut = ShmString::new();
+
+        assert_that!(unsafe { sut.push_str(&heap, "aä€") }, is_ok);
+
+        assert_that!(unsafe { sut.pop() }, eq Some('€'));
+        assert_that!(unsafe { sut.pop() }, eq Some('ä'));
+        assert_that!(unsafe { sut.as_str() }, eq "a");
+        assert_that!(unsafe { sut.pop() }, eq Some('a'));
+        assert_that!(unsafe { sut.pop() }, eq None);
+    }
+
+    #[test]
+    fn push_into_exhausted_heap_fails() {
+        let heap = FixedSizeShmHeap::<8>::new();
+        let mut sut = ShmString::new();
+
+        assert_that!(unsafe { sut.push_str(&heap, "12345678") }, is_ok);
+        assert_that!(unsafe { sut.push(&heap, '9') }, eq Err(AllocationError::OutOfMemory));
+        assert_that!(unsafe { sut.as_str() }, eq "12345678");
+    }
+
+    #[test]
+    fn debug_does_not_access_the_content() {
+        let heap = Heap::new();
+        let mut sut = ShmString::new();
+
+        assert_that!(unsafe { sut.push_str(&heap, "fuu") }, is_ok);
+
+        assert_that!(format!("{:?}", sut), eq "ShmString { len: 3, capacity: 3 }");
+    }
+}
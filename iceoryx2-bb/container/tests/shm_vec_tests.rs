@@ -0,0 +1,153 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_vec {
+    use iceoryx2_bb_container::shm_heap::{FixedSizeShmHeap, ShmPayload};
+    use iceoryx2_bb_container::shm_vec::*;
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_elementary::placement_default::PlacementDefault;
+    use iceoryx2_bb_testing::{assert_that, lifetime_tracker::LifetimeTracker, memory::RawMemory};
+
+    const HEAP_SIZE: usize = 4096;
+    type Heap = FixedSizeShmHeap<HEAP_SIZE>;
+
+    #[test]
+    fn newly_created_vec_is_empty_and_has_no_memory() {
+        let mut sut = ShmVec::<u64>::new();
+        assert_that!(sut, is_empty);
+        assert_that!(sut.capacity(), eq 0);
+        assert_that!(unsafe { sut.pop() }, eq None);
+    }
+
+    #[test]
+    fn push_and_pop_works() {
+        let heap = Heap::new();
+        let mut sut = ShmVec::<usize>::new();
+
+        for i in 0..128 {
+            assert_that!(unsafe { sut.push(&heap, i * 2) }, is_ok);
+            assert_that!(sut, len i + 1);
+        }
+
+        for i in (0..128).rev() {
+            assert_that!(unsafe { sut.pop() }, eq Some(i * 2));
+        }
+        assert_that!(sut, is_empty);
+    }
+
+    #[test]
+    fn growing_vec_reuses_heap_memory_in_place() {
+        let heap = Heap::new();
+        let mut sut = ShmVec::<u64>::new();
+
+        for i in 0..256 {
+            assert_that!(unsafe { sut.push(&heap, i) }, is_ok);
+        }
+
+        assert_that!(heap.used(), eq sut.capacity() * 8);
+    }
+
+    #[test]
+    fn vecs_sharing_one_heap_keep_their_content() {
+        let heap = Heap::new();
+        let mut sut_1 = ShmVec::<u32>::new();
+        let mut sut_2 = ShmVec::<u32>::new();
+
+        for i in 0..100 {
+            assert_that!(unsafe { sut_1.push(&heap, i) }, is_ok);
+            assert_that!(unsafe { sut_2.push(&heap, i + 1000) }, is_ok);
+        }
+
+        let expected_1: Vec<u32> = (0..100).collect();
+        let expected_2: Vec<u32> = (1000..1100).collect();
+        assert_that!(unsafe { sut_1.as_slice() }, eq expected_1.as_slice());
+        assert_that!(unsafe { sut_2.as_slice() }, eq expected_2.as_slice());
+    }
+
+    #[test]
+    fn push_into_exhausted_heap_fails() {
+        let heap = FixedSizeShmHeap::<64>::new();
+        let mut sut = ShmVec::<u64>::new();
+
+        assert_that!(unsafe { sut.reserve(&heap, 8) }, is_ok);
+        for i in 0..8 {
+            assert_that!(unsafe { sut.push(&heap, i) }, is_ok);
+        }
+
+        assert_that!(unsafe { sut.push(&heap, 8) }, eq Err(AllocationError::OutOfMemory));
+        assert_that!(sut, len 8);
+    }
+
+    #[test]
+    fn extend_from_slice_works() {
+        let heap = Heap::new();
+        let mut sut = ShmVec::<u8>::new();
+
+        assert_that!(unsafe { sut.extend_from_slice(&heap, b"hello") }, is_ok);
+        assert_that!(unsafe { sut.extend_from_slice(&heap, b" world") }, is_ok);
+
+        assert_that!(unsafe { sut.as_slice() }, eq b"hello world");
+    }
+
+    #[test]
+    fn as_mut_slice_modifies_content() {
+        let heap = Heap::new();
+        let mut sut = ShmVec::<u8>::new();
+
+        assert_that!(unsafe { sut.extend_from_slice(&heap, &[1, 2, 3]) }, is_ok);
+        unsafe { sut.as_mut_slice()[1] = 5 };
+        unsafe { sut.as_mut_slice()[2] = 7 };
+
+        assert_that!(unsafe { sut.as_slice() }, eq [1, 5, 7]);
+    }
+
+    #[test]
+    fn clear_releases_all_objects() {
+        LifetimeTracker::start_tracking();
+        // the vec must not be moved independently of its heap therefore both are dropped as payload
+        let mut sut = ShmPayload::<ShmVec<LifetimeTracker>, HEAP_SIZE>::default();
+
+        let (vec, heap) = sut.split_mut();
+        for _ in 0..10 {
+            assert_that!(unsafe { vec.push(heap, LifetimeTracker::new()) }, is_ok);
+        }
+        unsafe { vec.clear() };
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+
+        for _ in 0..10 {
+            assert_that!(unsafe { vec.push(heap, LifetimeTracker::new()) }, is_ok);
+        }
+        unsafe { vec.clear() };
+        assert_that!(LifetimeTracker::number_of_living_instances(), eq 0);
+    }
+
+    #[test]
+    fn debug_does_not_access_the_elements() {
+        let heap = Heap::new();
+        let mut sut = ShmVec::<u8>::new();
+        assert_that!(unsafe { sut.extend_from_slice(&heap, &[1, 2, 3]) }, is_ok);
+
+        assert_that!(format!("{:?}", sut), eq "ShmVec<u8> { len: 3, capacity: 3 }");
+    }
+
+    #[test]
+    fn placement_default_works() {
+        let heap = Heap::new();
+        let expected_content: &[u64] = &[42];
+        let mut sut = RawMemory::<ShmVec<u64>>::new_filled(0xff);
+        unsafe { ShmVec::placement_default(sut.as_mut_ptr()) };
+
+        assert_that!(unsafe { sut.assume_init() }, len 0);
+        assert_that!(unsafe { sut.assume_init_mut().push(&heap, 42) }, is_ok);
+        assert_that!(unsafe { sut.assume_init().as_slice() }, eq expected_content);
+    }
+}
@@ -99,3 +99,192 @@ pub fn placement_default_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Implements the [`iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend`] trait when all
+/// fields of the struct or enum implement it. Types that own heap memory or contain pointers,
+/// like [`String`], [`Vec`] or references, are rejected at compile time. The type must be
+/// `#[repr(C)]` or `#[repr(transparent)]` since otherwise its layout may differ between
+/// compilations.
+///
+/// The generated `layout_hash()` covers the size and alignment of the type as well as the
+/// offset and the layout of every field, but not the field names. With the
+/// `#[type_name("...")]` attribute the type name can be set so that the type can be identified
+/// independent of its module path, for instance, to interoperate with other languages.
+///
+/// ```
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[type_name("MyPayload")]
+/// #[repr(C)]
+/// struct MyPayload {
+///     value_1: u64,
+///     value_2: Option<usize>,
+///     value_3: [u8; 1024],
+/// }
+///
+/// assert_eq!(MyPayload::type_name(), "MyPayload");
+/// assert!(MyPayload::layout_hash().is_some());
+/// ```
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// #[repr(C)]
+/// struct HeapOwningPayload {
+///     value: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use iceoryx2_bb_derive_macros::ZeroCopySend;
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[derive(ZeroCopySend)]
+/// struct PayloadWithoutStableLayout {
+///     value: u64,
+/// }
+/// ```
+#[proc_macro_derive(ZeroCopySend, attributes(type_name))]
+pub fn zero_copy_send_derive(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+
+    let mut type_name = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("type_name"))
+    {
+        match attr.parse_args::<syn::LitStr>() {
+            Ok(value) => type_name = Some(value),
+            Err(e) => return TokenStream::from(e.to_compile_error()),
+        }
+    }
+
+    // the layout, and therefore the layout hash, is only stable across compilations for
+    // types with a defined representation
+    let mut has_stable_layout = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                has_stable_layout = true;
+            }
+            // skip the arguments of other representations like `align(8)`
+            if meta.input.peek(syn::token::Paren) {
+                let _ = meta.input.parse::<proc_macro2::TokenStream>();
+            }
+            Ok(())
+        });
+
+        if let Err(e) = result {
+            return TokenStream::from(e.to_compile_error());
+        }
+    }
+
+    if !has_stable_layout && !matches!(input.data, Data::Union(_)) {
+        return TokenStream::from(quote! {
+            compile_error!("ZeroCopySend can only be derived for types with #[repr(C)] or #[repr(transparent)].");
+        });
+    }
+
+    for param in input.generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(ZeroCopySend));
+    }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let (field_types, layout_values) = match input.data {
+        Data::Struct(ref data_struct) => {
+            let field_types: Vec<&syn::Type> = data_struct.fields.iter().map(|f| &f.ty).collect();
+            let layout_values: Vec<proc_macro2::TokenStream> = data_struct
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let ty = &f.ty;
+                    let member = match f.ident {
+                        Some(ref ident) => quote! { #ident },
+                        None => {
+                            let index = syn::Index::from(i);
+                            quote! { #index }
+                        }
+                    };
+                    quote! {
+                        (unsafe { core::ptr::addr_of!((*value).#member) } as usize - base) as u64,
+                        <#ty as ZeroCopySend>::__type_identity(),
+                    }
+                })
+                .collect();
+            (field_types, layout_values)
+        }
+        Data::Enum(ref data_enum) => {
+            // the offsets of enum fields are not accessible, therefore only the order of
+            // the variants and their fields is considered
+            let field_types: Vec<&syn::Type> = data_enum
+                .variants
+                .iter()
+                .flat_map(|v| v.fields.iter().map(|f| &f.ty))
+                .collect();
+            let layout_values = data_enum
+                .variants
+                .iter()
+                .map(|v| {
+                    let number_of_fields = v.fields.len();
+                    let field_identities = v.fields.iter().map(|f| {
+                        let ty = &f.ty;
+                        quote! { <#ty as ZeroCopySend>::__type_identity(), }
+                    });
+                    quote! {
+                        #number_of_fields as u64,
+                        #(#field_identities)*
+                    }
+                })
+                .collect();
+            (field_types, layout_values)
+        }
+        Data::Union(_) => {
+            return TokenStream::from(quote! {
+                compile_error!("ZeroCopySend cannot be derived for unions.");
+            })
+        }
+    };
+
+    let type_name_impl = type_name.map(|type_name| {
+        quote! {
+            fn type_name() -> &'static str {
+                #type_name
+            }
+        }
+    });
+
+    let expanded = quote! {
+        unsafe impl #impl_generics ZeroCopySend for #name #ty_generics #where_clause {
+            #type_name_impl
+
+            #[allow(unused_variables)]
+            fn layout_hash() -> Option<u64> {
+                <Self as ZeroCopySend>::__with_uninit(|value: *const Self| {
+                    let base = value as usize;
+                    Some(<Self as ZeroCopySend>::__hash_layout(&[
+                        core::mem::size_of::<Self>() as u64,
+                        core::mem::align_of::<Self>() as u64,
+                        #(#layout_values)*
+                    ]))
+                })
+            }
+        }
+
+        const _: () = {
+            #[allow(dead_code)]
+            fn assert_fields_are_zero_copy_send #impl_generics () #where_clause {
+                fn assert_zero_copy_send<T: ZeroCopySend + ?Sized>() {}
+                #(assert_zero_copy_send::<#field_types>();)*
+            }
+        };
+    };
+
+    TokenStream::from(expanded)
+}
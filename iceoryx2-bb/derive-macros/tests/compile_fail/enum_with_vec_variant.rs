@@ -0,0 +1,24 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+#[derive(ZeroCopySend)]
+#[repr(C)]
+enum EnumWithVecVariant {
+    Empty,
+    Values(Vec<u8>),
+}
+
+fn main() {}
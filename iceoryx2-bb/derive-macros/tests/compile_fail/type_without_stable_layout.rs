@@ -0,0 +1,28 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+
+use iceoryx2_bb_derive_macros::ZeroCopySend;
+
+#[derive(ZeroCopySend)]
+struct StructWithoutRepr {
+    value: u64,
+}
+
+#[derive(ZeroCopySend)]
+#[repr(u8)]
+enum EnumWithPrimitiveRepr {
+    A(u32),
+    B(u64),
+}
+
+fn main() {}
@@ -0,0 +1,291 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(test)]
+mod zero_copy_send {
+    use std::marker::PhantomData;
+
+    use iceoryx2_bb_derive_macros::ZeroCopySend;
+    use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn zero_copy_send_type_name<T: ZeroCopySend + ?Sized>() -> &'static str {
+        core::any::type_name::<T>()
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct UnitStruct;
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct UnnamedTestStruct(u64, [u8; 16], Option<i32>);
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct NamedTestStruct {
+        value1: UnitStruct,
+        value2: UnnamedTestStruct,
+        value3: (bool, char, f64),
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct GenericTestStruct<T, const CAPACITY: usize> {
+        value: [T; CAPACITY],
+        _data: PhantomData<T>,
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    enum TestEnum {
+        Empty,
+        Unnamed(u32, u64),
+        Named { value: NamedTestStruct },
+    }
+
+    #[test]
+    fn zero_copy_send_derive_for_structs_works() {
+        assert_that!(zero_copy_send_type_name::<UnitStruct>(), is_not_empty);
+        assert_that!(
+            zero_copy_send_type_name::<UnnamedTestStruct>(),
+            is_not_empty
+        );
+        assert_that!(zero_copy_send_type_name::<NamedTestStruct>(), is_not_empty);
+        assert_that!(
+            zero_copy_send_type_name::<[NamedTestStruct]>(),
+            is_not_empty
+        );
+    }
+
+    #[test]
+    fn zero_copy_send_derive_for_generic_structs_works() {
+        assert_that!(
+            zero_copy_send_type_name::<GenericTestStruct<u8, 12>>(),
+            is_not_empty
+        );
+        assert_that!(
+            zero_copy_send_type_name::<GenericTestStruct<NamedTestStruct, 3>>(),
+            is_not_empty
+        );
+    }
+
+    #[test]
+    fn zero_copy_send_derive_for_enums_works() {
+        assert_that!(zero_copy_send_type_name::<TestEnum>(), is_not_empty);
+        assert_that!(zero_copy_send_type_name::<Option<TestEnum>>(), is_not_empty);
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[type_name("MyCustomTypeName")]
+    #[allow(dead_code)]
+    struct CustomTypeNameStruct {
+        value: u64,
+    }
+
+    mod other_module {
+        use iceoryx2_bb_derive_macros::ZeroCopySend;
+        use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct NamedTestStruct {
+            pub other_value1: super::UnitStruct,
+            pub other_value2: super::UnnamedTestStruct,
+            pub other_value3: (bool, char, f64),
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct ReorderedTestStruct {
+            pub value2: super::UnnamedTestStruct,
+            pub value1: super::UnitStruct,
+            pub value3: (bool, char, f64),
+        }
+    }
+
+    #[test]
+    fn zero_copy_send_derive_uses_type_name_attribute() {
+        assert_that!(CustomTypeNameStruct::type_name(), eq "MyCustomTypeName");
+        assert_that!(NamedTestStruct::type_name(), eq core::any::type_name::<NamedTestStruct>());
+    }
+
+    #[test]
+    fn zero_copy_send_derive_layout_hash_is_independent_of_names() {
+        assert_that!(NamedTestStruct::layout_hash(), is_some);
+        assert_that!(
+            NamedTestStruct::layout_hash(),
+            eq other_module::NamedTestStruct::layout_hash()
+        );
+    }
+
+    #[test]
+    fn zero_copy_send_derive_layout_hash_depends_on_layout() {
+        assert_that!(
+            NamedTestStruct::layout_hash(),
+            ne other_module::ReorderedTestStruct::layout_hash()
+        );
+        assert_that!(
+            GenericTestStruct::<u8, 12>::layout_hash(),
+            ne GenericTestStruct::<u8, 13>::layout_hash()
+        );
+        assert_that!(
+            GenericTestStruct::<u8, 12>::layout_hash(),
+            ne GenericTestStruct::<i8, 12>::layout_hash()
+        );
+        assert_that!(
+            CustomTypeNameStruct::layout_hash(),
+            ne UnnamedTestStruct::layout_hash()
+        );
+    }
+
+    mod layout_variations {
+        use iceoryx2_bb_derive_macros::ZeroCopySend;
+        use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct Original {
+            pub a: u32,
+            pub b: u64,
+            pub c: u16,
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct SwappedFields {
+            pub b: u64,
+            pub a: u32,
+            pub c: u16,
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct SameSizeDifferentType {
+            pub a: f32,
+            pub b: u64,
+            pub c: u16,
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct SameSizeDifferentSignedness {
+            pub a: u32,
+            pub b: i64,
+            pub c: u16,
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct UnnamedOriginal(pub u32, pub u64);
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub struct UnnamedSwappedFields(pub u64, pub u32);
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub enum OriginalEnum {
+            A(u32),
+            B(u64),
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub enum SwappedVariants {
+            B(u64),
+            A(u32),
+        }
+
+        #[derive(ZeroCopySend)]
+        #[repr(C)]
+        #[allow(dead_code)]
+        pub enum DifferentVariantType {
+            A(i32),
+            B(u64),
+        }
+    }
+
+    #[test]
+    fn zero_copy_send_derive_layout_hash_depends_on_field_order() {
+        use layout_variations::*;
+
+        assert_that!(Original::layout_hash(), ne SwappedFields::layout_hash());
+        assert_that!(
+            UnnamedOriginal::layout_hash(),
+            ne UnnamedSwappedFields::layout_hash()
+        );
+        assert_that!(OriginalEnum::layout_hash(), ne SwappedVariants::layout_hash());
+    }
+
+    #[test]
+    fn zero_copy_send_derive_layout_hash_depends_on_field_types() {
+        use layout_variations::*;
+
+        assert_that!(Original::layout_hash(), ne SameSizeDifferentType::layout_hash());
+        assert_that!(
+            Original::layout_hash(),
+            ne SameSizeDifferentSignedness::layout_hash()
+        );
+        assert_that!(
+            OriginalEnum::layout_hash(),
+            ne DifferentVariantType::layout_hash()
+        );
+    }
+
+    #[derive(ZeroCopySend)]
+    #[repr(C)]
+    #[allow(dead_code)]
+    struct LargeTestStruct {
+        value: u64,
+        data: [u8; 64 * 1024 * 1024],
+        tuple: (u8, [u64; 1024 * 1024]),
+    }
+
+    #[test]
+    fn zero_copy_send_derive_layout_hash_of_large_type_does_not_require_stack_memory() {
+        const STACK_SIZE: usize = 64 * 1024;
+
+        let layout_hash = std::thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(LargeTestStruct::layout_hash)
+            .unwrap()
+            .join()
+            .unwrap();
+
+        assert_that!(layout_hash, is_some);
+    }
+
+    #[test]
+    // the expected compiler diagnostics are only stable for recent compiler versions
+    #[rustversion::attr(before(1.85), ignore)]
+    fn zero_copy_send_derive_fails_for_types_that_are_not_zero_copy_send() {
+        let sut = trybuild::TestCases::new();
+        sut.compile_fail("tests/compile_fail/*.rs");
+    }
+}
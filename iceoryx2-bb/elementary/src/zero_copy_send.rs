@@ -0,0 +1,246 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Marker trait for types that can be shared via shared memory between processes.
+//! See [`ZeroCopySend`] for example.
+
+use core::marker::PhantomData;
+use core::mem::MaybeUninit;
+use std::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+
+use iceoryx2_pal_concurrency_sync::iox_atomic::*;
+
+/// Marks a type as safe to be transferred via zero-copy between processes. The type must
+/// be self-contained, meaning it must not own heap memory and must not contain pointers or
+/// references, since those are only valid in the address space of the sending process.
+///
+/// The trait should be implemented with the
+/// `iceoryx2_bb_derive_macros::ZeroCopySend` derive macro which verifies that all
+/// fields implement [`ZeroCopySend`] and provides a [`ZeroCopySend::layout_hash()`].
+///
+/// Two types are considered identical when they have the same [`ZeroCopySend::type_name()`]
+/// and, when both provide one, the same [`ZeroCopySend::layout_hash()`]. By default the type
+/// name is the output of [`core::any::type_name()`] which depends on the module path of the
+/// type. To interoperate with other languages or crates, the type name can be overridden.
+///
+/// ```
+/// use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+///
+/// #[repr(C)]
+/// struct MyPayload {
+///     value_1: u64,
+///     value_2: [u8; 128],
+/// }
+///
+/// unsafe impl ZeroCopySend for MyPayload {
+///     fn type_name() -> &'static str {
+///         "MyPayload"
+///     }
+/// }
+///
+/// assert_eq!(MyPayload::type_name(), "MyPayload");
+/// ```
+///
+/// # Safety
+///
+///  * the type and all of its fields must not own heap memory
+///  * the type must not contain pointers or references
+///  * the type must not contain resources that are bound to the process, like file descriptors
+///  * when [`ZeroCopySend::layout_hash()`] is provided, it must change whenever the memory
+///    layout of the type changes
+pub unsafe trait ZeroCopySend {
+    /// Returns the name that identifies the type across processes, crates and languages.
+    fn type_name() -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Returns a hash of the structural memory layout of the type that is independent of
+    /// the type name, or [`None`] when the layout is unknown.
+    fn layout_hash() -> Option<u64> {
+        None
+    }
+
+    #[doc(hidden)]
+    fn __type_identity() -> u64 {
+        match Self::layout_hash() {
+            Some(hash) => hash,
+            None => fnv1a_hash(FNV_OFFSET_BASIS, Self::type_name().as_bytes()),
+        }
+    }
+
+    #[doc(hidden)]
+    fn __hash_layout(values: &[u64]) -> u64 {
+        layout_hash(values)
+    }
+
+    #[doc(hidden)]
+    fn __with_uninit<R, F: FnOnce(*const Self) -> R>(f: F) -> R
+    where
+        Self: Sized,
+    {
+        with_uninit(f)
+    }
+}
+
+// Provides uninitialized memory for a `T` so that the addresses of its fields can be acquired
+// without an instance. The memory is acquired from the heap since it could exceed the stack size
+// for large types.
+fn with_uninit<T, R, F: FnOnce(*const T) -> R>(f: F) -> R {
+    // zero sized allocations are not supported by the allocator
+    let layout = unsafe {
+        Layout::from_size_align_unchecked(
+            core::mem::size_of::<MaybeUninit<T>>().max(1),
+            core::mem::align_of::<MaybeUninit<T>>(),
+        )
+    };
+
+    let memory = unsafe { alloc(layout) };
+    if memory.is_null() {
+        handle_alloc_error(layout);
+    }
+
+    let result = f(memory as *const MaybeUninit<T> as *const T);
+    unsafe { dealloc(memory, layout) };
+    result
+}
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+fn fnv1a_hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// Combines the provided layout properties, like sizes, offsets and
+/// [`ZeroCopySend::layout_hash()`]es of fields, into a platform independent hash.
+pub fn layout_hash(values: &[u64]) -> u64 {
+    values.iter().fold(FNV_OFFSET_BASIS, |hash, value| {
+        fnv1a_hash(hash, &value.to_le_bytes())
+    })
+}
+
+// tags that distinguish the layouts of the generic std types from each other
+const ARRAY_TAG: u64 = 1;
+const OPTION_TAG: u64 = 2;
+const TUPLE_TAG: u64 = 3;
+const PHANTOM_DATA_TAG: u64 = 4;
+
+macro_rules! Impl {
+    ($($type:ty),*) => {
+        $(unsafe impl ZeroCopySend for $type {})*
+    };
+}
+
+Impl!(
+    f32,
+    f64,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    usize,
+    char,
+    bool,
+    ()
+);
+Impl!(
+    IoxAtomicBool,
+    IoxAtomicU8,
+    IoxAtomicU16,
+    IoxAtomicU32,
+    IoxAtomicU64,
+    IoxAtomicI8,
+    IoxAtomicI16,
+    IoxAtomicI32,
+    IoxAtomicI64,
+    IoxAtomicIsize,
+    IoxAtomicUsize
+);
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for [T] {}
+
+unsafe impl<T: ZeroCopySend, const CAPACITY: usize> ZeroCopySend for [T; CAPACITY] {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            ARRAY_TAG,
+            T::__type_identity(),
+            CAPACITY as u64,
+        ]))
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for Option<T> {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[
+            OPTION_TAG,
+            T::__type_identity(),
+            core::mem::size_of::<Self>() as u64,
+            core::mem::align_of::<Self>() as u64,
+        ]))
+    }
+}
+
+unsafe impl<T: ZeroCopySend> ZeroCopySend for MaybeUninit<T> {
+    fn type_name() -> &'static str {
+        T::type_name()
+    }
+
+    fn layout_hash() -> Option<u64> {
+        T::layout_hash()
+    }
+}
+
+unsafe impl<T: ?Sized> ZeroCopySend for PhantomData<T> {
+    fn layout_hash() -> Option<u64> {
+        Some(layout_hash(&[PHANTOM_DATA_TAG]))
+    }
+}
+
+macro_rules! ImplTuple {
+    ($($type:ident $index:tt),*) => {
+        unsafe impl<$($type: ZeroCopySend),*> ZeroCopySend for ($($type,)*) {
+            fn layout_hash() -> Option<u64> {
+                with_uninit(|value: *const Self| {
+                    let base = value as usize;
+                    Some(layout_hash(&[
+                        TUPLE_TAG,
+                        core::mem::size_of::<Self>() as u64,
+                        core::mem::align_of::<Self>() as u64,
+                        $(
+                            (unsafe { core::ptr::addr_of!((*value).$index) } as usize - base)
+                                as u64,
+                            $type::__type_identity(),
+                        )*
+                    ]))
+                })
+            }
+        }
+    };
+}
+
+ImplTuple!(T1 0);
+ImplTuple!(T1 0, T2 1);
+ImplTuple!(T1 0, T2 1, T3 2);
+ImplTuple!(T1 0, T2 1, T3 2, T4 3);
+ImplTuple!(T1 0, T2 1, T3 2, T4 3, T5 4);
+ImplTuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5);
+ImplTuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6);
+ImplTuple!(T1 0, T2 1, T3 2, T4 3, T5 4, T6 5, T7 6, T8 7);
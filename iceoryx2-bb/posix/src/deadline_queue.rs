@@ -281,6 +281,10 @@ impl DeadlineQueue {
             CallbackProgression::Continue
         });
 
+        // the missed deadlines were handled, they must not be reported again in the next
+        // iteration
+        *self.previous_iteration.borrow_mut() = now;
+
         Ok(())
     }
 }
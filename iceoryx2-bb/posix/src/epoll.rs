@@ -0,0 +1,348 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux specific epoll mechanism. In contrast to the
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) it is not limited by
+//! `FD_SETSIZE` and only the triggered [`FileDescriptor`]s are reported on a wakeup instead of
+//! scanning all attached ones.
+//! Can be used to wait on multiple objects which implement the [`SynchronousMultiplexing`]
+//! trait.
+//!
+//! # Example
+//!
+//! ```ignore
+//! use iceoryx2_bb_posix::epoll::*;
+//! use iceoryx2_bb_posix::unix_datagram_socket::*;
+//! use std::time::Duration;
+//! use iceoryx2_bb_system_types::file_path::FilePath;
+//! use iceoryx2_bb_container::semantic_string::SemanticString;
+//!
+//! let socket_name = FilePath::new(b"some_socket").unwrap();
+//!
+//! let sut_receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+//!     .creation_mode(CreationMode::PurgeAndCreate)
+//!     .create()
+//!     .unwrap();
+//!
+//! let sut_sender = UnixDatagramSenderBuilder::new(&socket_name)
+//!     .create()
+//!     .unwrap();
+//!
+//! let epoll = EpollBuilder::new()
+//!     .trigger_mode(TriggerMode::Level)
+//!     .create()
+//!     .unwrap();
+//! let _guard = epoll.add(&sut_receiver).unwrap();
+//! let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+//! sut_sender.try_send(send_data.as_slice()).unwrap();
+//!
+//! let result = epoll.timed_wait(Duration::from_secs(1),
+//!     |fd| println!("Fd was triggered {}", unsafe { fd.native_handle() })).unwrap();
+//! ```
+
+use std::{fmt::Debug, sync::atomic::Ordering, time::Duration};
+
+use crate::{
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::SynchronousMultiplexing,
+    system_configuration::Limit,
+};
+use iceoryx2_bb_log::{fatal_panic, warn};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::posix::Struct;
+use iceoryx2_pal_posix::*;
+
+/// The maximum number of events that are acquired from the kernel in one wait call. When more
+/// [`FileDescriptor`]s are triggered they are reported in the next wait call.
+pub const MAX_NUMBER_OF_EVENTS_PER_WAIT: usize = 256;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollAddError {
+    AlreadyAttached,
+    NotSupportedByFileDescriptor,
+    InsufficientMemory,
+    SystemLimitOfWatchesReached,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EpollWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// Defines when an attached [`FileDescriptor`] is reported as triggered.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Default)]
+pub enum TriggerMode {
+    /// The [`FileDescriptor`] is reported on every wait call as long as there is data to
+    /// read. Equivalent to the behavior of `select`.
+    #[default]
+    Level,
+    /// The [`FileDescriptor`] is reported only once whenever new data arrives, independent
+    /// of whether all data was consumed or not.
+    Edge,
+}
+
+/// Removes the attached [`FileDescriptor`] from the [`Epoll`] when it goes out of scope.
+pub struct EpollGuard<'epoll, 'fd> {
+    epoll: &'epoll Epoll,
+    fd: &'fd FileDescriptor,
+}
+
+impl<'epoll, 'fd> EpollGuard<'epoll, 'fd> {
+    pub fn file_descriptor(&self) -> &'fd FileDescriptor {
+        self.fd
+    }
+}
+
+impl Drop for EpollGuard<'_, '_> {
+    fn drop(&mut self) {
+        self.epoll.remove(unsafe { self.fd.native_handle() })
+    }
+}
+
+/// Creates a new [`Epoll`] instance.
+#[derive(Debug, Default)]
+pub struct EpollBuilder {
+    trigger_mode: TriggerMode,
+}
+
+impl EpollBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the [`TriggerMode`] of all attachments. Default is [`TriggerMode::Level`].
+    pub fn trigger_mode(mut self, value: TriggerMode) -> Self {
+        self.trigger_mode = value;
+        self
+    }
+
+    /// Creates the [`Epoll`] instance.
+    pub fn create(self) -> Result<Epoll, EpollCreateError> {
+        let epoll_fd = unsafe { posix::epoll_create1(posix::EPOLL_CLOEXEC) };
+
+        if epoll_fd != -1 {
+            return Ok(Epoll {
+                epoll_fd: unsafe { FileDescriptor::new_unchecked(epoll_fd) },
+                trigger_mode: self.trigger_mode,
+                len: IoxAtomicUsize::new(0),
+            });
+        }
+
+        let msg = "Unable to create epoll instance";
+        handle_errno!(EpollCreateError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of open file descriptors was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system wide limit of open file descriptors was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// Abstraction of the Linux epoll mechanism to wait on multiple objects which implement the
+/// [`SynchronousMultiplexing`] trait.
+pub struct Epoll {
+    epoll_fd: FileDescriptor,
+    trigger_mode: TriggerMode,
+    len: IoxAtomicUsize,
+}
+
+impl Debug for Epoll {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Epoll {{ epoll_fd: {}, trigger_mode: {:?}, len: {} }}",
+            unsafe { self.epoll_fd.native_handle() },
+            self.trigger_mode,
+            self.len()
+        )
+    }
+}
+
+impl Epoll {
+    /// Adds a file descriptor
+    pub fn add<'epoll, 'fd, F: SynchronousMultiplexing>(
+        &'epoll self,
+        fd: &'fd F,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        self.add_impl(fd.file_descriptor())
+    }
+
+    fn add_impl<'epoll, 'fd>(
+        &'epoll self,
+        fd: &'fd FileDescriptor,
+    ) -> Result<EpollGuard<'epoll, 'fd>, EpollAddError> {
+        let raw_fd = unsafe { fd.native_handle() };
+        let mut event = posix::epoll_event::new();
+        event.events = match self.trigger_mode {
+            TriggerMode::Level => posix::EPOLLIN,
+            TriggerMode::Edge => posix::EPOLLIN | posix::EPOLLET,
+        };
+        event.data.fd = raw_fd;
+
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_ADD,
+                raw_fd,
+                &mut event,
+            )
+        } != -1
+        {
+            self.len.fetch_add(1, Ordering::Relaxed);
+            return Ok(EpollGuard { epoll: self, fd });
+        }
+
+        let msg = "Unable to add file descriptor";
+        handle_errno!(EpollAddError, from self,
+            fatal Errno::EBADF => ("This should never happen! {} {:?} since the file descriptor is invalid.", msg, fd);
+            fatal Errno::EINVAL => ("This should never happen! {} {:?} since either the epoll file descriptor is invalid or the file descriptor is the epoll instance itself.", msg, fd),
+            Errno::EEXIST => (AlreadyAttached, "{} {:?} since it is already attached.", msg, fd),
+            Errno::EPERM => (NotSupportedByFileDescriptor, "{} {:?} since the file descriptor does not support epoll.", msg, fd),
+            Errno::ENOMEM => (InsufficientMemory, "{} {:?} due to insufficient memory.", msg, fd),
+            Errno::ENOSPC => (SystemLimitOfWatchesReached, "{} {:?} since the system limit of epoll watches was reached.", msg, fd),
+            v => (UnknownError(v as i32), "{} {:?} since an unknown error occurred ({}).", msg, fd, v)
+        );
+    }
+
+    fn remove(&self, value: i32) {
+        if unsafe {
+            posix::epoll_ctl(
+                self.epoll_fd.native_handle(),
+                posix::EPOLL_CTL_DEL,
+                value,
+                core::ptr::null_mut(),
+            )
+        } == -1
+        {
+            warn!(from self, "Unable to remove file descriptor {} from epoll instance ({:?}). This should never happen!",
+                value, Errno::get());
+        }
+        self.len.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    /// Returns the maximum number of [`FileDescriptor`]s that can be attached. Since epoll
+    /// has no hard limit on its own it is the maximum number of files the process can open.
+    pub fn capacity() -> usize {
+        Limit::MaxNumberOfOpenFiles.value() as usize
+    }
+
+    /// Returns the [`TriggerMode`] of the attachments
+    pub fn trigger_mode(&self) -> TriggerMode {
+        self.trigger_mode
+    }
+
+    /// Returns the number of attached [`FileDescriptor`]s
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    /// Returns true if the [`Epoll`] is empty, otherwise false
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Does not block and calls the provided callback for every [`FileDescriptor`] that was
+    /// triggered. Returns the number of triggered [`FileDescriptor`]s.
+    pub fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        self.wait(0, fd_callback)
+    }
+
+    /// Blocks until at least one [`FileDescriptor`] was triggered and calls the provided
+    /// callback for every triggered [`FileDescriptor`]. Returns the number of triggered
+    /// [`FileDescriptor`]s.
+    pub fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        self.wait(-1, fd_callback)
+    }
+
+    /// Waits until either the timeout has passed or at least one [`FileDescriptor`] was
+    /// triggered. Calls the provided callback for every triggered [`FileDescriptor`] and
+    /// returns the number of triggered [`FileDescriptor`]s.
+    pub fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout: Duration,
+        fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        // epoll has a millisecond resolution, round up to never wake up before the timeout
+        let timeout_in_ms = timeout.as_nanos().div_ceil(1_000_000);
+        self.wait(
+            timeout_in_ms.min(posix::int::MAX as u128) as posix::int,
+            fd_callback,
+        )
+    }
+
+    fn wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        timeout_in_ms: posix::int,
+        mut fd_callback: F,
+    ) -> Result<usize, EpollWaitError> {
+        let mut events = [posix::epoll_event::new(); MAX_NUMBER_OF_EVENTS_PER_WAIT];
+
+        let number_of_notifications = unsafe {
+            posix::epoll_wait(
+                self.epoll_fd.native_handle(),
+                events.as_mut_ptr(),
+                MAX_NUMBER_OF_EVENTS_PER_WAIT as _,
+                timeout_in_ms,
+            )
+        };
+
+        if number_of_notifications == -1 {
+            let msg = "Failure while waiting for file descriptor events";
+            handle_errno!(EpollWaitError, from self,
+                fatal Errno::EBADF => ("This should never happen! {} since the epoll file descriptor is invalid.", msg);
+                fatal Errno::EFAULT => ("This should never happen! {} since the event buffer is not accessible.", msg);
+                fatal Errno::EINVAL => ("This should never happen! {} since the epoll instance or the number of events is invalid.", msg),
+                Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+                v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+            );
+        }
+
+        for event in events.iter().take(number_of_notifications as usize) {
+            let data = event.data;
+            match FileDescriptor::non_owning_new(unsafe { data.fd }) {
+                Some(fd) => fd_callback(&fd),
+                None => {
+                    fatal_panic!(from self,
+                        "This should never happen! The epoll instance reported the invalid file descriptor {}.",
+                        unsafe { data.fd });
+                }
+            }
+        }
+
+        Ok(number_of_notifications as _)
+    }
+}
+
+impl FileDescriptorBased for Epoll {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.epoll_fd
+    }
+}
+
+impl SynchronousMultiplexing for Epoll {}
@@ -0,0 +1,166 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux specific eventfd. An [`EventFd`] is a process local counter that is
+//! readable as long as it is not zero and can therefore be attached to a
+//! [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) or an
+//! [`Epoll`](crate::epoll::Epoll) instance.
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::eventfd::*;
+//!
+//! let event_fd = EventFdBuilder::new().create().unwrap();
+//!
+//! event_fd.notify(1).unwrap();
+//! event_fd.notify(2).unwrap();
+//!
+//! assert_eq!(event_fd.try_wait().unwrap(), Some(3));
+//! assert_eq!(event_fd.try_wait().unwrap(), None);
+//! ```
+
+use std::fmt::Debug;
+
+use crate::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use crate::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdCreateError {
+    PerProcessFileHandleLimitReached,
+    SystemWideFileHandleLimitReached,
+    InsufficientMemory,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdNotifyError {
+    Overflow,
+    Interrupt,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum EventFdWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+/// Creates a new [`EventFd`].
+#[derive(Debug, Default)]
+pub struct EventFdBuilder {
+    initial_value: u32,
+}
+
+impl EventFdBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Defines the initial value of the counter. Default is 0.
+    pub fn initial_value(mut self, value: u32) -> Self {
+        self.initial_value = value;
+        self
+    }
+
+    /// Creates the [`EventFd`].
+    pub fn create(self) -> Result<EventFd, EventFdCreateError> {
+        let fd =
+            unsafe { posix::eventfd(self.initial_value, posix::EFD_CLOEXEC | posix::EFD_NONBLOCK) };
+
+        if fd != -1 {
+            return Ok(EventFd {
+                fd: unsafe { FileDescriptor::new_unchecked(fd) },
+            });
+        }
+
+        let msg = "Unable to create eventfd";
+        handle_errno!(EventFdCreateError, from self,
+            Errno::EMFILE => (PerProcessFileHandleLimitReached, "{} since the per-process limit of open file descriptors was reached.", msg),
+            Errno::ENFILE => (SystemWideFileHandleLimitReached, "{} since the system wide limit of open file descriptors was reached.", msg),
+            Errno::ENOMEM => (InsufficientMemory, "{} due to insufficient memory.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+/// A process local counter that is readable as long as it is not zero. Every operation is
+/// non-blocking, to wait on it one can attach it to a
+/// [`FileDescriptorSet`](crate::file_descriptor_set::FileDescriptorSet) or an
+/// [`Epoll`](crate::epoll::Epoll) instance.
+pub struct EventFd {
+    fd: FileDescriptor,
+}
+
+impl Debug for EventFd {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "EventFd {{ fd: {} }}", unsafe {
+            self.fd.native_handle()
+        })
+    }
+}
+
+impl EventFd {
+    /// Adds the value to the counter and makes the [`EventFd`] readable.
+    pub fn notify(&self, value: u64) -> Result<(), EventFdNotifyError> {
+        if unsafe {
+            posix::write(
+                self.fd.native_handle(),
+                (&value as *const u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        } != -1
+        {
+            return Ok(());
+        }
+
+        let msg = "Unable to notify eventfd";
+        handle_errno!(EventFdNotifyError, from self,
+            Errno::EAGAIN => (Overflow, "{} since the counter would overflow.", msg),
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+
+    /// Returns the current value of the counter and resets it to zero. If the counter is
+    /// zero it returns [`None`].
+    pub fn try_wait(&self) -> Result<Option<u64>, EventFdWaitError> {
+        let mut value = 0u64;
+        if unsafe {
+            posix::read(
+                self.fd.native_handle(),
+                (&mut value as *mut u64).cast(),
+                core::mem::size_of::<u64>(),
+            )
+        } != -1
+        {
+            return Ok(Some(value));
+        }
+
+        let msg = "Unable to read eventfd";
+        handle_errno!(EventFdWaitError, from self,
+            success Errno::EAGAIN => None,
+            Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+            v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+        );
+    }
+}
+
+impl FileDescriptorBased for EventFd {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.fd
+    }
+}
+
+impl SynchronousMultiplexing for EventFd {}
@@ -0,0 +1,110 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Abstracts the Linux specific futex wait and wake operations. A futex is an [`IoxAtomicU32`]
+//! that can be placed in shared memory. Threads and processes can block until the value
+//! changes with [`wait()`] or [`timed_wait()`] and are woken up with [`wake()`].
+//!
+//! # Example
+//!
+//! ```
+//! use iceoryx2_bb_posix::futex;
+//! use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+//! use std::sync::atomic::Ordering;
+//! use std::time::Duration;
+//!
+//! let value = IoxAtomicU32::new(0);
+//!
+//! // returns after the timeout since the value is never changed
+//! let result = futex::timed_wait(&value, 0, Duration::from_millis(1)).unwrap();
+//! assert_eq!(result, futex::FutexWaitResult::TimedOut);
+//!
+//! value.store(1, Ordering::Relaxed);
+//! futex::wake(&value, 1).unwrap();
+//! ```
+
+use std::time::Duration;
+
+use crate::clock::AsTimespec;
+use iceoryx2_bb_log::fail;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+use iceoryx2_pal_posix::posix::errno::Errno;
+use iceoryx2_pal_posix::*;
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum FutexWaitError {
+    Interrupt,
+    UnknownError(i32),
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum FutexWakeError {
+    UnknownError(i32),
+}
+
+/// Describes why a [`wait()`] or [`timed_wait()`] call returned.
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+pub enum FutexWaitResult {
+    /// The call was woken up by [`wake()`]. Can also be a spurious wakeup.
+    WokenUp,
+    /// The value was already different from the expected value when the call started.
+    ValueChanged,
+    /// The timeout has passed.
+    TimedOut,
+}
+
+/// Blocks as long as `futex` contains `expected` until it is woken up with [`wake()`].
+pub fn wait(futex: &IoxAtomicU32, expected: u32) -> Result<FutexWaitResult, FutexWaitError> {
+    wait_impl(futex, expected, core::ptr::null())
+}
+
+/// Blocks as long as `futex` contains `expected` until it is woken up with [`wake()`] or
+/// the timeout has passed.
+pub fn timed_wait(
+    futex: &IoxAtomicU32,
+    expected: u32,
+    timeout: Duration,
+) -> Result<FutexWaitResult, FutexWaitError> {
+    let timeout = timeout.as_timespec();
+    wait_impl(futex, expected, &timeout)
+}
+
+fn wait_impl(
+    futex: &IoxAtomicU32,
+    expected: u32,
+    timeout: *const posix::timespec,
+) -> Result<FutexWaitResult, FutexWaitError> {
+    if unsafe { posix::futex_wait(futex.as_ptr(), expected, timeout) } == 0 {
+        return Ok(FutexWaitResult::WokenUp);
+    }
+
+    let msg = "Unable to wait on futex";
+    handle_errno!(FutexWaitError, from "futex::wait",
+        success Errno::EAGAIN => FutexWaitResult::ValueChanged;
+        success Errno::ETIMEDOUT => FutexWaitResult::TimedOut,
+        Errno::EINTR => (Interrupt, "{} since an interrupt signal was received.", msg),
+        v => (UnknownError(v as i32), "{} since an unknown error occurred ({}).", msg, v)
+    );
+}
+
+/// Wakes up at most `number_of_waiters` that are waiting on `futex` and returns the number
+/// of woken up waiters.
+pub fn wake(futex: &IoxAtomicU32, number_of_waiters: u32) -> Result<u32, FutexWakeError> {
+    let result = unsafe { posix::futex_wake(futex.as_ptr(), number_of_waiters) };
+    if result != -1 {
+        return Ok(result as u32);
+    }
+
+    let msg = "Unable to wake up futex waiters";
+    fail!(from "futex::wake", with FutexWakeError::UnknownError(Errno::get() as i32),
+        "{} since an unknown error occurred ({}).", msg, Errno::get());
+}
@@ -42,11 +42,17 @@ pub mod udp_socket;
 pub mod handle_errno;
 pub mod deadline_queue;
 pub mod directory;
+#[cfg(target_os = "linux")]
+pub mod epoll;
+#[cfg(target_os = "linux")]
+pub mod eventfd;
 pub mod file;
 pub mod file_descriptor;
 pub mod file_descriptor_set;
 pub mod file_lock;
 pub mod file_type;
+#[cfg(target_os = "linux")]
+pub mod futex;
 pub mod group;
 pub mod ipc_capable;
 pub mod memory;
@@ -156,4 +156,24 @@ mod deadline_queue {
         assert_that!(missed_deadline_counter, eq 1);
         assert_that!(deadline_idx, eq Some(guard_1.index()));
     }
+    #[test]
+    fn missed_deadline_is_reported_only_once() {
+        let sut = DeadlineQueueBuilder::new().create().unwrap();
+
+        let _guard_1 = sut
+            .add_deadline_interval(Duration::from_millis(100))
+            .unwrap();
+
+        std::thread::sleep(Duration::from_millis(110));
+
+        let mut missed_deadline_counter = 0;
+        sut.missed_deadlines(|_| missed_deadline_counter += 1)
+            .unwrap();
+        assert_that!(missed_deadline_counter, eq 1);
+
+        sut.missed_deadlines(|_| missed_deadline_counter += 1)
+            .unwrap();
+        assert_that!(missed_deadline_counter, eq 1);
+        assert_that!(sut.duration_until_next_deadline().unwrap(), ne Duration::ZERO);
+    }
 }
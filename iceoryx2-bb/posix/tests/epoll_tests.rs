@@ -0,0 +1,216 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_posix::config::*;
+use iceoryx2_bb_posix::epoll::*;
+use iceoryx2_bb_posix::file_descriptor::FileDescriptorBased;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_bb_posix::unix_datagram_socket::*;
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_bb_system_types::file_path::FilePath;
+use iceoryx2_bb_testing::assert_that;
+use std::time::Duration;
+use std::time::Instant;
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+fn generate_socket_name() -> FilePath {
+    let mut file = FileName::new(b"epoll_tests").unwrap();
+    file.push_bytes(
+        UniqueSystemId::new()
+            .unwrap()
+            .value()
+            .to_string()
+            .as_bytes(),
+    )
+    .unwrap();
+
+    FilePath::from_path_and_file(&test_directory(), &file).unwrap()
+}
+
+fn create_socket_pair() -> (UnixDatagramReceiver, UnixDatagramSender) {
+    let socket_name = generate_socket_name();
+
+    let receiver = UnixDatagramReceiverBuilder::new(&socket_name)
+        .creation_mode(CreationMode::PurgeAndCreate)
+        .create()
+        .unwrap();
+
+    let sender = UnixDatagramSenderBuilder::new(&socket_name)
+        .create()
+        .unwrap();
+
+    (receiver, sender)
+}
+
+#[test]
+fn epoll_timed_wait_blocks_at_least_timeout() {
+    let (sut_receiver, _sut_sender) = create_socket_pair();
+
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard = sut.add(&sut_receiver).unwrap();
+
+    let start = Instant::now();
+
+    let mut result = vec![];
+    sut.timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+    assert_that!(result, len 0);
+}
+
+#[test]
+fn epoll_add_and_remove_works() {
+    const NUMBER_OF_FDS: usize = 128;
+    let sut = EpollBuilder::new().create().unwrap();
+    let mut sockets = vec![];
+
+    for _ in 0..NUMBER_OF_FDS {
+        let socket_name = generate_socket_name();
+        sockets.push(
+            UnixDatagramReceiverBuilder::new(&socket_name)
+                .creation_mode(CreationMode::PurgeAndCreate)
+                .create()
+                .unwrap(),
+        );
+    }
+
+    assert_that!(sut.is_empty(), eq true);
+    let mut guards = vec![];
+    for (n, fd) in sockets.iter().enumerate() {
+        let guard = sut.add(fd);
+        assert_that!(guard, is_ok);
+        guards.push(guard);
+        assert_that!(sut.len(), eq n + 1);
+    }
+
+    for n in 0..NUMBER_OF_FDS {
+        assert_that!(sut.is_empty(), eq false);
+        guards.pop();
+        assert_that!(sut.len(), eq NUMBER_OF_FDS - n - 1);
+    }
+    assert_that!(sut.is_empty(), eq true);
+}
+
+#[test]
+fn epoll_add_same_fd_twice_fails() {
+    let (sut_receiver, _sut_sender) = create_socket_pair();
+    let sut = EpollBuilder::new().create().unwrap();
+
+    let _guard = sut.add(&sut_receiver).unwrap();
+
+    let result = sut.add(&sut_receiver);
+    assert_that!(result.err(), eq Some(EpollAddError::AlreadyAttached));
+    assert_that!(sut.len(), eq 1);
+}
+
+#[test]
+fn epoll_removed_fd_can_be_added_again() {
+    let (sut_receiver, _sut_sender) = create_socket_pair();
+    let sut = EpollBuilder::new().create().unwrap();
+
+    let guard = sut.add(&sut_receiver).unwrap();
+    drop(guard);
+
+    assert_that!(sut.add(&sut_receiver), is_ok);
+}
+
+#[test]
+fn epoll_blocking_wait_immediately_returns_notifications() {
+    let (sut_receiver, sut_sender) = create_socket_pair();
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard = sut.add(&sut_receiver).unwrap();
+
+    let send_data: Vec<u8> = vec![1u8, 3u8, 3u8, 7u8, 13u8, 37u8];
+    sut_sender.blocking_send(send_data.as_slice()).unwrap();
+
+    let mut result = vec![];
+    let number_of_notifications = sut
+        .blocking_wait(|fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(number_of_notifications, eq 1);
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe{sut_receiver.file_descriptor().native_handle()});
+}
+
+#[test]
+fn epoll_reports_only_triggered_fds() {
+    let (sut_receiver_1, _sut_sender_1) = create_socket_pair();
+    let (sut_receiver_2, sut_sender_2) = create_socket_pair();
+    let sut = EpollBuilder::new().create().unwrap();
+    let _guard_1 = sut.add(&sut_receiver_1).unwrap();
+    let _guard_2 = sut.add(&sut_receiver_2).unwrap();
+
+    sut_sender_2.blocking_send(&[1u8]).unwrap();
+
+    let mut result = vec![];
+    let number_of_notifications = sut
+        .timed_wait(TIMEOUT, |fd| result.push(unsafe { fd.native_handle() }))
+        .unwrap();
+
+    assert_that!(number_of_notifications, eq 1);
+    assert_that!(result, len 1);
+    assert_that!(result[0], eq unsafe{sut_receiver_2.file_descriptor().native_handle()});
+}
+
+#[test]
+fn epoll_level_triggered_reports_as_long_as_there_is_data_to_read() {
+    let (sut_receiver, sut_sender) = create_socket_pair();
+    let sut = EpollBuilder::new()
+        .trigger_mode(TriggerMode::Level)
+        .create()
+        .unwrap();
+    let _guard = sut.add(&sut_receiver).unwrap();
+
+    sut_sender.blocking_send(&[1u8]).unwrap();
+
+    for _ in 0..3 {
+        let number_of_notifications = sut.try_wait(|_| {}).unwrap();
+        assert_that!(number_of_notifications, eq 1);
+    }
+
+    let mut buffer = [0u8; 8];
+    sut_receiver.try_receive(&mut buffer).unwrap();
+
+    let number_of_notifications = sut.try_wait(|_| {}).unwrap();
+    assert_that!(number_of_notifications, eq 0);
+}
+
+#[test]
+fn epoll_edge_triggered_reports_only_new_data() {
+    let (sut_receiver, sut_sender) = create_socket_pair();
+    let sut = EpollBuilder::new()
+        .trigger_mode(TriggerMode::Edge)
+        .create()
+        .unwrap();
+    assert_that!(sut.trigger_mode(), eq TriggerMode::Edge);
+    let _guard = sut.add(&sut_receiver).unwrap();
+
+    sut_sender.blocking_send(&[1u8]).unwrap();
+
+    let number_of_notifications = sut.try_wait(|_| {}).unwrap();
+    assert_that!(number_of_notifications, eq 1);
+
+    // data was not consumed but no new data arrived
+    let number_of_notifications = sut.try_wait(|_| {}).unwrap();
+    assert_that!(number_of_notifications, eq 0);
+
+    sut_sender.blocking_send(&[2u8]).unwrap();
+
+    let number_of_notifications = sut.try_wait(|_| {}).unwrap();
+    assert_that!(number_of_notifications, eq 1);
+}
@@ -0,0 +1,70 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_posix::eventfd::*;
+use iceoryx2_bb_posix::file_descriptor_set::*;
+use iceoryx2_bb_testing::assert_that;
+use std::time::Duration;
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+#[test]
+fn eventfd_try_wait_without_notification_returns_none() {
+    let sut = EventFdBuilder::new().create().unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), is_none);
+}
+
+#[test]
+fn eventfd_try_wait_returns_sum_of_notifications_and_resets_counter() {
+    let sut = EventFdBuilder::new().create().unwrap();
+
+    sut.notify(4).unwrap();
+    sut.notify(5).unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq Some(9));
+    assert_that!(sut.try_wait().unwrap(), is_none);
+}
+
+#[test]
+fn eventfd_with_initial_value_is_readable() {
+    let sut = EventFdBuilder::new().initial_value(12).create().unwrap();
+
+    assert_that!(sut.try_wait().unwrap(), eq Some(12));
+}
+
+#[test]
+fn eventfd_is_readable_in_file_descriptor_set_until_reset() {
+    let sut = EventFdBuilder::new().create().unwrap();
+    let fd_set = FileDescriptorSet::new();
+    let _guard = fd_set.add(&sut).unwrap();
+
+    let mut counter = 0;
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |_| counter += 1)
+        .unwrap();
+    assert_that!(counter, eq 0);
+
+    sut.notify(1).unwrap();
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |_| counter += 1)
+        .unwrap();
+    assert_that!(counter, eq 1);
+
+    sut.try_wait().unwrap();
+    fd_set
+        .timed_wait(TIMEOUT, FileEvent::Read, |_| counter += 1)
+        .unwrap();
+    assert_that!(counter, eq 1);
+}
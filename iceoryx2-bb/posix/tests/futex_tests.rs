@@ -0,0 +1,65 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(target_os = "linux")]
+
+use iceoryx2_bb_posix::futex::*;
+use iceoryx2_bb_testing::assert_that;
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+use std::sync::atomic::Ordering;
+use std::time::{Duration, Instant};
+
+static TIMEOUT: Duration = Duration::from_millis(10);
+
+#[test]
+fn futex_wait_with_different_value_returns_immediately() {
+    let sut = IoxAtomicU32::new(1);
+
+    assert_that!(wait(&sut, 0).unwrap(), eq FutexWaitResult::ValueChanged);
+    assert_that!(timed_wait(&sut, 0, TIMEOUT).unwrap(), eq FutexWaitResult::ValueChanged);
+}
+
+#[test]
+fn futex_timed_wait_blocks_at_least_timeout() {
+    let sut = IoxAtomicU32::new(0);
+
+    let start = Instant::now();
+    assert_that!(timed_wait(&sut, 0, TIMEOUT).unwrap(), eq FutexWaitResult::TimedOut);
+    assert_that!(start.elapsed(), time_at_least TIMEOUT);
+}
+
+#[test]
+fn futex_wake_without_waiters_wakes_nobody() {
+    let sut = IoxAtomicU32::new(0);
+
+    assert_that!(wake(&sut, 1).unwrap(), eq 0);
+}
+
+#[test]
+fn futex_wake_wakes_up_blocked_waiter() {
+    let sut = IoxAtomicU32::new(0);
+
+    std::thread::scope(|s| {
+        let waiter = s.spawn(|| {
+            while sut.load(Ordering::Relaxed) == 0 {
+                wait(&sut, 0).unwrap();
+            }
+        });
+
+        std::thread::sleep(TIMEOUT);
+        sut.store(1, Ordering::Relaxed);
+        wake(&sut, 1).unwrap();
+        waiter.join().unwrap();
+    });
+
+    assert_that!(sut.load(Ordering::Relaxed), eq 1);
+}
@@ -535,6 +535,19 @@ pub mod details {
         }
     }
 
+    #[cfg(target_os = "linux")]
+    impl<
+            Tracker: IdTracker,
+            WaitMechanism: SignalMechanism,
+            Storage: DynamicStorage<Management<Tracker, WaitMechanism>>,
+        > crate::event::eventfd_bridge::NotificationWait
+        for Listener<Tracker, WaitMechanism, Storage>
+    {
+        fn blocking_wait_for_notification(&self) -> Result<(), crate::event::ListenerWaitError> {
+            unsafe { self.storage.get().signal_mechanism.blocking_wait() }
+        }
+    }
+
     #[derive(Debug)]
     pub struct ListenerBuilder<
         Tracker: IdTracker,
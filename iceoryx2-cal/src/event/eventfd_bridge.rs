@@ -0,0 +1,534 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Adapter that turns an [`Event`] whose listener is not based on a file descriptor into an
+//! [`Event`] with a pollable listener, so that it can be attached to a
+//! [`Reactor`](crate::reactor::Reactor).
+//!
+//! The forwarding is opt-in. As long as the [`FileDescriptor`] of a [`Listener`] was never
+//! acquired, all waits are performed directly on the underlying listener and no additional
+//! resources are used. The first call to [`FileDescriptorBased::file_descriptor()`], for
+//! instance when the [`Listener`] is attached to a [`Reactor`](crate::reactor::Reactor),
+//! starts a background thread named `iox2-eventfd-bridge` that is owned by the [`Listener`].
+//! It blocks on the underlying listener and forwards all received [`TriggerId`]s to a process
+//! local [`EventFd`]. The [`EventFd`] is readable as long as there are [`TriggerId`]s that were
+//! not yet acquired. The thread is stopped and joined when the [`Listener`] is dropped.
+//!
+//! The underlying listener has to implement [`NotificationWait`] so that the background thread
+//! can wait for notifications without acquiring the [`TriggerId`]s outside of the bridge.
+//! The notifier side is not affected by the bridge.
+
+use std::{
+    collections::HashSet,
+    fmt::Debug,
+    marker::PhantomData,
+    sync::{atomic::Ordering, Arc, Mutex, MutexGuard, OnceLock},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::{
+    eventfd::{EventFd, EventFdBuilder},
+    file_descriptor::{FileDescriptor, FileDescriptorBased},
+    file_descriptor_set::{
+        FileDescriptorSet, FileDescriptorSetWaitError, FileEvent, SynchronousMultiplexing,
+    },
+};
+use iceoryx2_bb_system_types::{file_name::FileName, path::Path};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::{
+    event::{Event, ListenerCreateError, ListenerWaitError, NotifierCreateError, TriggerId},
+    named_concept::{
+        NamedConcept, NamedConceptBuilder, NamedConceptDoesExistError, NamedConceptListError,
+        NamedConceptMgmt, NamedConceptPathHintRemoveError, NamedConceptRemoveError,
+    },
+};
+
+/// Blocks until a notification was received without acquiring the corresponding [`TriggerId`].
+/// A wake up does not guarantee that a [`TriggerId`] can be acquired afterwards.
+pub trait NotificationWait: crate::event::Listener {
+    fn blocking_wait_for_notification(&self) -> Result<(), ListenerWaitError>;
+}
+
+/// [`Event`] adapter that provides a [`Listener`] which implements [`FileDescriptorBased`].
+#[derive(Debug)]
+pub struct EventFdBridge<E: Event> {
+    _event: PhantomData<E>,
+}
+
+impl<E: Event> NamedConceptMgmt for EventFdBridge<E> {
+    type Configuration = E::Configuration;
+
+    unsafe fn remove_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptRemoveError> {
+        E::remove_cfg(name, cfg)
+    }
+
+    fn does_exist_cfg(
+        name: &FileName,
+        cfg: &Self::Configuration,
+    ) -> Result<bool, NamedConceptDoesExistError> {
+        E::does_exist_cfg(name, cfg)
+    }
+
+    fn list_cfg(cfg: &Self::Configuration) -> Result<Vec<FileName>, NamedConceptListError> {
+        E::list_cfg(cfg)
+    }
+
+    fn default_prefix() -> FileName {
+        E::default_prefix()
+    }
+
+    fn default_path_hint() -> Path {
+        E::default_path_hint()
+    }
+
+    fn remove_path_hint(value: &Path) -> Result<(), NamedConceptPathHintRemoveError> {
+        E::remove_path_hint(value)
+    }
+}
+
+impl<E: Event> Event for EventFdBridge<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    type Notifier = E::Notifier;
+    type NotifierBuilder = NotifierBuilder<E>;
+    type Listener = Listener<E>;
+    type ListenerBuilder = ListenerBuilder<E>;
+
+    fn default_suffix() -> FileName {
+        E::default_suffix()
+    }
+
+    fn has_trigger_id_limit() -> bool {
+        E::has_trigger_id_limit()
+    }
+}
+
+#[derive(Debug)]
+pub struct NotifierBuilder<E: Event> {
+    builder: E::NotifierBuilder,
+}
+
+impl<E: Event> NamedConceptBuilder<EventFdBridge<E>> for NotifierBuilder<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn new(name: &FileName) -> Self {
+        Self {
+            builder: E::NotifierBuilder::new(name),
+        }
+    }
+
+    fn config(mut self, config: &E::Configuration) -> Self {
+        self.builder = self.builder.config(config);
+        self
+    }
+}
+
+impl<E: Event> crate::event::NotifierBuilder<EventFdBridge<E>> for NotifierBuilder<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn timeout(mut self, timeout: Duration) -> Self {
+        self.builder = self.builder.timeout(timeout);
+        self
+    }
+
+    fn open(self) -> Result<E::Notifier, NotifierCreateError> {
+        self.builder.open()
+    }
+}
+
+#[derive(Debug)]
+struct Shared<L: crate::event::Listener> {
+    listener: L,
+    event_fd: EventFd,
+    pending_ids: Mutex<HashSet<TriggerId>>,
+    keep_running: IoxAtomicBool,
+    has_failed: IoxAtomicBool,
+}
+
+impl<L: NotificationWait> Shared<L> {
+    fn pending_ids(&self) -> MutexGuard<'_, HashSet<TriggerId>> {
+        match self.pending_ids.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    // the trigger ids are acquired only while holding the lock of the pending ids, otherwise
+    // a concurrent try_wait could miss an id that is currently forwarded
+    fn acquire_ids(&self, pending_ids: &mut HashSet<TriggerId>) -> Result<(), ListenerWaitError> {
+        crate::event::Listener::try_wait_all(&self.listener, |id| {
+            pending_ids.insert(id);
+        })
+    }
+
+    fn forward(&self) {
+        while self.keep_running.load(Ordering::Relaxed) {
+            match self.listener.blocking_wait_for_notification() {
+                Ok(()) => (),
+                Err(ListenerWaitError::InterruptSignal) => continue,
+                Err(e) => {
+                    warn!(from self,
+                        "Stop forwarding notifications of {:?} due to an internal failure ({:?}).",
+                        self.listener, e);
+                    self.has_failed.store(true, Ordering::Relaxed);
+                    let _ = self.event_fd.notify(1);
+                    return;
+                }
+            }
+
+            if !self.keep_running.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let mut pending_ids = self.pending_ids();
+            if let Err(e) = self.acquire_ids(&mut pending_ids) {
+                warn!(from self,
+                    "Stop forwarding notifications of {:?} since the trigger ids could not be acquired ({:?}).",
+                    self.listener, e);
+                self.has_failed.store(true, Ordering::Relaxed);
+                let _ = self.event_fd.notify(1);
+                return;
+            }
+
+            if pending_ids.is_empty() {
+                continue;
+            }
+
+            if let Err(e) = self.event_fd.notify(1) {
+                warn!(from self, "Unable to signal the received notifications of {:?} ({:?}).",
+                    self.listener, e);
+            }
+        }
+    }
+}
+
+/// Listener of the [`EventFdBridge`] that can be attached to a
+/// [`Reactor`](crate::reactor::Reactor). The forwarding thread is started when the
+/// [`FileDescriptor`] is acquired for the first time.
+pub struct Listener<E: Event> {
+    shared: Arc<Shared<E::Listener>>,
+    name: FileName,
+    shutdown_notifier: E::Notifier,
+    thread: OnceLock<Option<JoinHandle<()>>>,
+}
+
+impl<E: Event> Debug for Listener<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EventFdBridge::Listener<{}> {{ name: {}, event_fd: {:?} }}",
+            core::any::type_name::<E>(),
+            self.name,
+            self.shared.event_fd
+        )
+    }
+}
+
+impl<E: Event> Drop for Listener<E> {
+    fn drop(&mut self) {
+        if !self.is_forwarding() {
+            return;
+        }
+
+        self.shared.keep_running.store(false, Ordering::Relaxed);
+        // the thread checks keep_running after every wakeup, it does not matter if the
+        // notification is lost because the listener already failed
+        let _ = crate::event::Notifier::notify(&self.shutdown_notifier, TriggerId::new(0));
+
+        if let Some(thread) = self.thread.take().flatten() {
+            if thread.join().is_err() {
+                warn!(from self, "The forwarding thread terminated with a panic.");
+            }
+        }
+    }
+}
+
+impl<E: Event> NamedConcept for Listener<E> {
+    fn name(&self) -> &FileName {
+        &self.name
+    }
+}
+
+impl<E: Event> FileDescriptorBased for Listener<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.start_forwarding();
+        self.shared.event_fd.file_descriptor()
+    }
+}
+
+impl<E: Event> SynchronousMultiplexing for Listener<E> where
+    E::Listener: NotificationWait + Send + Sync + 'static
+{
+}
+
+impl<E: Event> Listener<E> {
+    fn is_forwarding(&self) -> bool {
+        self.thread.get().is_some()
+    }
+
+    fn verify_forwarding(&self) -> Result<(), ListenerWaitError> {
+        if self.shared.has_failed.load(Ordering::Relaxed) {
+            fail!(from self, with ListenerWaitError::InternalFailure,
+                "Unable to wait for notifications since the forwarding of the underlying listener failed.");
+        }
+
+        Ok(())
+    }
+
+    // returns true when at least one pending trigger id was provided to the callback
+    fn try_wait_all_pending<F: FnMut(TriggerId)>(
+        &self,
+        callback: &mut F,
+    ) -> Result<bool, ListenerWaitError>
+    where
+        E::Listener: NotificationWait,
+    {
+        let mut has_pending_ids = false;
+        crate::event::Listener::try_wait_all(self, |id| {
+            has_pending_ids = true;
+            callback(id)
+        })?;
+        Ok(has_pending_ids)
+    }
+
+    // the event fd is reset only when all pending ids are acquired, therefore it stays
+    // readable as long as ids are pending
+    fn reset_event_fd(&self) -> Result<(), ListenerWaitError> {
+        fail!(from self, when self.shared.event_fd.try_wait(),
+            with ListenerWaitError::InternalFailure,
+            "Unable to reset the underlying event fd.");
+        Ok(())
+    }
+
+    fn wait_until_readable(&self, timeout: Option<Duration>) -> Result<(), ListenerWaitError> {
+        let msg = "Unable to wait on the underlying event fd";
+        let fd_set = FileDescriptorSet::new();
+        let _guard = fail!(from self, when fd_set.add(&self.shared.event_fd),
+            with ListenerWaitError::InternalFailure,
+            "{} since it could not be attached to a file descriptor set.", msg);
+
+        let result = match timeout {
+            Some(timeout) => fd_set.timed_wait(timeout, FileEvent::Read, |_| {}),
+            None => fd_set.blocking_wait(FileEvent::Read, |_| {}),
+        };
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(FileDescriptorSetWaitError::Interrupt) => {
+                fail!(from self, with ListenerWaitError::InterruptSignal,
+                    "{} since an interrupt signal was received.", msg);
+            }
+            Err(e) => {
+                fail!(from self, with ListenerWaitError::InternalFailure,
+                    "{} due to an internal failure ({:?}).", msg, e);
+            }
+        }
+    }
+}
+
+impl<E: Event> Listener<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn start_forwarding(&self) {
+        self.thread.get_or_init(|| {
+            let thread_shared = self.shared.clone();
+            match std::thread::Builder::new()
+                .name("iox2-eventfd-bridge".into())
+                .spawn(move || thread_shared.forward())
+            {
+                Ok(thread) => Some(thread),
+                Err(e) => {
+                    // the failure is reported with the next wait call, the event fd wakes up
+                    // a reactor that waits on the listener
+                    warn!(from self, "Unable to spawn the forwarding thread ({:?}).", e);
+                    self.shared.has_failed.store(true, Ordering::Relaxed);
+                    let _ = self.shared.event_fd.notify(1);
+                    None
+                }
+            }
+        });
+    }
+}
+
+impl<E: Event> crate::event::Listener for Listener<E>
+where
+    E::Listener: NotificationWait,
+{
+    fn try_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        self.verify_forwarding()?;
+
+        let mut pending_ids = self.shared.pending_ids();
+        self.shared.acquire_ids(&mut pending_ids)?;
+        let id = pending_ids.iter().next().copied();
+        if let Some(id) = id {
+            pending_ids.remove(&id);
+        }
+
+        if pending_ids.is_empty() {
+            self.reset_event_fd()?;
+        }
+
+        Ok(id)
+    }
+
+    fn timed_wait_one(&self, timeout: Duration) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        if !self.is_forwarding() {
+            return self.shared.listener.timed_wait_one(timeout);
+        }
+
+        self.wait_until_readable(Some(timeout))?;
+        self.try_wait_one()
+    }
+
+    fn blocking_wait_one(&self) -> Result<Option<TriggerId>, ListenerWaitError> {
+        if let Some(id) = self.try_wait_one()? {
+            return Ok(Some(id));
+        }
+
+        if !self.is_forwarding() {
+            return self.shared.listener.blocking_wait_one();
+        }
+
+        self.wait_until_readable(None)?;
+        self.try_wait_one()
+    }
+
+    fn try_wait_all<F: FnMut(TriggerId)>(&self, callback: F) -> Result<(), ListenerWaitError> {
+        self.verify_forwarding()?;
+
+        let pending_ids: Vec<TriggerId> = {
+            let mut pending_ids = self.shared.pending_ids();
+            self.shared.acquire_ids(&mut pending_ids)?;
+            self.reset_event_fd()?;
+            pending_ids.drain().collect()
+        };
+
+        pending_ids.into_iter().for_each(callback);
+        Ok(())
+    }
+
+    fn timed_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+        timeout: Duration,
+    ) -> Result<(), ListenerWaitError> {
+        if !self.is_forwarding() {
+            if self.try_wait_all_pending(&mut callback)? {
+                return Ok(());
+            }
+            return self.shared.listener.timed_wait_all(callback, timeout);
+        }
+
+        self.wait_until_readable(Some(timeout))?;
+        self.try_wait_all(callback)
+    }
+
+    fn blocking_wait_all<F: FnMut(TriggerId)>(
+        &self,
+        mut callback: F,
+    ) -> Result<(), ListenerWaitError> {
+        if !self.is_forwarding() {
+            if self.try_wait_all_pending(&mut callback)? {
+                return Ok(());
+            }
+            return self.shared.listener.blocking_wait_all(callback);
+        }
+
+        self.wait_until_readable(None)?;
+        self.try_wait_all(callback)
+    }
+}
+
+#[derive(Debug)]
+pub struct ListenerBuilder<E: Event> {
+    name: FileName,
+    config: E::Configuration,
+    builder: E::ListenerBuilder,
+}
+
+impl<E: Event> NamedConceptBuilder<EventFdBridge<E>> for ListenerBuilder<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn new(name: &FileName) -> Self {
+        Self {
+            name: *name,
+            config: E::Configuration::default(),
+            builder: E::ListenerBuilder::new(name),
+        }
+    }
+
+    fn config(mut self, config: &E::Configuration) -> Self {
+        self.config = config.clone();
+        self.builder = self.builder.config(config);
+        self
+    }
+}
+
+impl<E: Event> crate::event::ListenerBuilder<EventFdBridge<E>> for ListenerBuilder<E>
+where
+    E::Listener: NotificationWait + Send + Sync + 'static,
+{
+    fn trigger_id_max(mut self, id: TriggerId) -> Self {
+        self.builder = self.builder.trigger_id_max(id);
+        self
+    }
+
+    fn create(self) -> Result<Listener<E>, ListenerCreateError> {
+        let msg = "Failed to create Listener";
+        let origin = format!("{:?}", self);
+        let listener = fail!(from origin, when self.builder.create(),
+            "{} since the underlying listener could not be created.", msg);
+
+        let shutdown_notifier = fail!(from origin,
+            when crate::event::NotifierBuilder::open(
+                E::NotifierBuilder::new(&self.name).config(&self.config)),
+            with ListenerCreateError::InternalFailure,
+            "{} since the notifier to shutdown the forwarding thread could not be opened.", msg);
+
+        let event_fd = fail!(from origin, when EventFdBuilder::new().create(),
+            with ListenerCreateError::InternalFailure,
+            "{} since the underlying event fd could not be created.", msg);
+
+        let shared = Arc::new(Shared {
+            listener,
+            event_fd,
+            pending_ids: Mutex::new(HashSet::new()),
+            keep_running: IoxAtomicBool::new(true),
+            has_failed: IoxAtomicBool::new(false),
+        });
+
+        Ok(Listener {
+            shared,
+            name: self.name,
+            shutdown_notifier,
+            thread: OnceLock::new(),
+        })
+    }
+}
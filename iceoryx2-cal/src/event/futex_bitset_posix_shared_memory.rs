@@ -0,0 +1,33 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Interprocess [`Event`](crate::event::Event) that tracks the triggered ids in a bitset in
+//! shared memory and uses the Linux futex mechanism to wake up the listener. It requires neither
+//! a socket nor a semaphore and a notification without a waiting listener does not require a
+//! syscall.
+//!
+//! The [`Event`] does not provide a file descriptor. When the listener shall be attached to a
+//! [`Reactor`](crate::reactor::Reactor), the [`PollableEvent`] can be used instead.
+
+use crate::dynamic_storage::posix_shared_memory::Storage;
+use crate::event::common::details::EventImpl;
+use crate::event::common::details::Management;
+use crate::event::eventfd_bridge::EventFdBridge;
+use crate::event::signal_mechanism::futex::Futex;
+use iceoryx2_bb_lock_free::mpmc::bit_set::RelocatableBitSet;
+
+pub type Event = EventImpl<RelocatableBitSet, Futex, Storage<Management<RelocatableBitSet, Futex>>>;
+
+/// The futex based [`Event`] with a listener that provides a pollable file descriptor. When
+/// the listener is attached to a [`Reactor`](crate::reactor::Reactor), it starts a background
+/// thread that forwards the notifications, see [`EventFdBridge`].
+pub type PollableEvent = EventFdBridge<Event>;
@@ -11,6 +11,10 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 pub mod common;
+#[cfg(target_os = "linux")]
+pub mod eventfd_bridge;
+#[cfg(target_os = "linux")]
+pub mod futex_bitset_posix_shared_memory;
 pub mod id_tracker;
 pub mod process_local;
 pub mod sem_bitset_posix_shared_memory;
@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    sync::atomic::Ordering,
+    time::{Duration, Instant},
+};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::futex::{self, FutexWaitError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU32;
+
+use crate::event::{ListenerCreateError, ListenerWaitError, NotifierNotifyError};
+
+use super::SignalMechanism;
+
+/// [`SignalMechanism`] based on a counter and the Linux futex wait and wake operations. The
+/// wake syscall is only performed when a listener is actually waiting, therefore a notification
+/// without a waiting listener is a pure atomic operation.
+#[derive(Debug)]
+#[repr(C)]
+pub struct Futex {
+    counter: IoxAtomicU32,
+    number_of_waiters: IoxAtomicU32,
+}
+
+impl Futex {
+    fn wait(&self, timeout: Option<Duration>) -> Result<bool, ListenerWaitError> {
+        let msg = "Failed to wait on underlying futex";
+        let start = Instant::now();
+
+        loop {
+            if unsafe { self.try_wait()? } {
+                return Ok(true);
+            }
+
+            // the number of waiters must be increased before the counter is checked by the
+            // kernel, otherwise a notification could be delivered without waking anyone up
+            self.number_of_waiters.fetch_add(1, Ordering::SeqCst);
+            let result = match timeout {
+                None => futex::wait(&self.counter, 0),
+                Some(timeout) => {
+                    let elapsed = start.elapsed();
+                    if timeout <= elapsed {
+                        self.number_of_waiters.fetch_sub(1, Ordering::SeqCst);
+                        return Ok(false);
+                    }
+                    futex::timed_wait(&self.counter, 0, timeout - elapsed)
+                }
+            };
+            self.number_of_waiters.fetch_sub(1, Ordering::SeqCst);
+
+            match result {
+                Ok(_) => (),
+                Err(FutexWaitError::Interrupt) => {
+                    fail!(from self, with ListenerWaitError::InterruptSignal,
+                        "{} since an interrupt signal was received.", msg);
+                }
+                Err(e) => {
+                    fail!(from self, with ListenerWaitError::InternalFailure,
+                        "{} due to an internal failure ({:?}).", msg, e);
+                }
+            }
+        }
+    }
+}
+
+impl SignalMechanism for Futex {
+    fn new() -> Self {
+        Self {
+            counter: IoxAtomicU32::new(0),
+            number_of_waiters: IoxAtomicU32::new(0),
+        }
+    }
+
+    unsafe fn init(&mut self) -> Result<(), ListenerCreateError> {
+        Ok(())
+    }
+
+    unsafe fn notify(&self) -> Result<(), NotifierNotifyError> {
+        self.counter.fetch_add(1, Ordering::SeqCst);
+
+        if self.number_of_waiters.load(Ordering::SeqCst) == 0 {
+            return Ok(());
+        }
+
+        fail!(from self, when futex::wake(&self.counter, 1),
+            with NotifierNotifyError::InternalFailure,
+            "Failed to wake up the waiter of the underlying futex.");
+        Ok(())
+    }
+
+    unsafe fn try_wait(&self) -> Result<bool, ListenerWaitError> {
+        let mut current = self.counter.load(Ordering::Relaxed);
+        loop {
+            if current == 0 {
+                return Ok(false);
+            }
+
+            match self.counter.compare_exchange_weak(
+                current,
+                current - 1,
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return Ok(true),
+                Err(v) => current = v,
+            }
+        }
+    }
+
+    unsafe fn timed_wait(&self, timeout: Duration) -> Result<bool, ListenerWaitError> {
+        self.wait(Some(timeout))
+    }
+
+    unsafe fn blocking_wait(&self) -> Result<(), ListenerWaitError> {
+        self.wait(None)?;
+        Ok(())
+    }
+}
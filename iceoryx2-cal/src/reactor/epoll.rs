@@ -0,0 +1,177 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! [`Reactor`](crate::reactor::Reactor) based on the Linux epoll mechanism. It is not limited
+//! by `FD_SETSIZE` and does not scan every attachment on a wakeup, therefore it scales well
+//! with a large number of attachments.
+
+use std::{fmt::Debug, time::Duration};
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_bb_posix::{
+    epoll::{Epoll, EpollAddError, EpollBuilder, EpollCreateError, EpollGuard, EpollWaitError},
+    file_descriptor::FileDescriptor,
+};
+
+pub use iceoryx2_bb_posix::epoll::TriggerMode;
+
+use crate::reactor::{ReactorAttachError, ReactorCreateError, ReactorWaitError};
+
+impl crate::reactor::ReactorGuard<'_, '_> for EpollGuard<'_, '_> {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.file_descriptor()
+    }
+}
+
+#[derive(Debug)]
+pub struct Reactor {
+    epoll: Epoll,
+}
+
+impl Reactor {
+    fn wait<W: FnMut() -> Result<usize, EpollWaitError>>(
+        &self,
+        mut wait_call: W,
+    ) -> Result<usize, ReactorWaitError> {
+        let msg = "Unable to wait on Reactor";
+        match wait_call() {
+            Ok(number_of_notifications) => Ok(number_of_notifications),
+            Err(EpollWaitError::Interrupt) => {
+                fail!(from self, with ReactorWaitError::Interrupt,
+                    "{} since an interrupt signal was received while waiting.",
+                    msg);
+            }
+            Err(v) => {
+                fail!(from self, with ReactorWaitError::UnknownError,
+                    "{} since an unknown failure occurred in the underlying epoll instance ({:?}).",
+                    msg, v);
+            }
+        }
+    }
+}
+
+impl crate::reactor::Reactor for Reactor {
+    type Guard<'reactor, 'attachment> = EpollGuard<'reactor, 'attachment>;
+    type Builder = ReactorBuilder;
+
+    fn capacity(&self) -> usize {
+        Epoll::capacity()
+    }
+
+    fn len(&self) -> usize {
+        self.epoll.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.epoll.is_empty()
+    }
+
+    fn attach<
+        'reactor,
+        'attachment,
+        F: iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing + Debug,
+    >(
+        &'reactor self,
+        value: &'attachment F,
+    ) -> Result<Self::Guard<'reactor, 'attachment>, ReactorAttachError> {
+        let msg = format!("Unable to attach {:?} to the reactor", value);
+        if self.len() >= self.capacity() {
+            fail!(from self, with ReactorAttachError::CapacityExceeded,
+                "{msg} since the maximum number of open files {} of the process would be exceeded.",
+                self.capacity());
+        }
+
+        match self.epoll.add(value) {
+            Ok(guard) => Ok(guard),
+            Err(EpollAddError::AlreadyAttached) => {
+                fail!(from self, with ReactorAttachError::AlreadyAttached,
+                    "{msg} since it is already attached.");
+            }
+            Err(EpollAddError::SystemLimitOfWatchesReached) => {
+                fail!(from self, with ReactorAttachError::CapacityExceeded,
+                    "{msg} since the system limit of epoll watches was reached.");
+            }
+            Err(EpollAddError::NotSupportedByFileDescriptor) => {
+                fail!(from self, with ReactorAttachError::UnknownError(0),
+                    "{msg} since the file descriptor does not support epoll.");
+            }
+            Err(EpollAddError::InsufficientMemory) => {
+                fail!(from self, with ReactorAttachError::UnknownError(0),
+                    "{msg} due to insufficient memory.");
+            }
+            Err(EpollAddError::UnknownError(v)) => {
+                fail!(from self, with ReactorAttachError::UnknownError(v),
+                    "{msg} due to an unknown failure in the underlying epoll instance ({}).", v);
+            }
+        }
+    }
+
+    fn try_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<usize, ReactorWaitError> {
+        self.wait(|| self.epoll.try_wait(&mut fn_call))
+    }
+
+    fn timed_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        mut fn_call: F,
+        timeout: Duration,
+    ) -> Result<usize, ReactorWaitError> {
+        self.wait(|| self.epoll.timed_wait(timeout, &mut fn_call))
+    }
+
+    fn blocking_wait<F: FnMut(&FileDescriptor)>(
+        &self,
+        mut fn_call: F,
+    ) -> Result<usize, ReactorWaitError> {
+        self.wait(|| self.epoll.blocking_wait(&mut fn_call))
+    }
+}
+
+#[derive(Debug)]
+pub struct ReactorBuilder {
+    trigger_mode: TriggerMode,
+}
+
+impl ReactorBuilder {
+    /// Defines when an attachment is reported as triggered. With [`TriggerMode::Level`]
+    /// (default) it is reported as long as there is data to read, with [`TriggerMode::Edge`]
+    /// only once whenever new data arrives.
+    pub fn trigger_mode(mut self, value: TriggerMode) -> Self {
+        self.trigger_mode = value;
+        self
+    }
+}
+
+impl crate::reactor::ReactorBuilder<Reactor> for ReactorBuilder {
+    fn new() -> Self {
+        Self {
+            trigger_mode: TriggerMode::default(),
+        }
+    }
+
+    fn create(self) -> Result<Reactor, ReactorCreateError> {
+        let msg = "Unable to create reactor";
+        match EpollBuilder::new().trigger_mode(self.trigger_mode).create() {
+            Ok(epoll) => Ok(Reactor { epoll }),
+            Err(EpollCreateError::UnknownError(v)) => {
+                fail!(from self, with ReactorCreateError::UnknownError(v),
+                    "{msg} since an unknown failure occurred while creating the underlying epoll instance ({}).", v);
+            }
+            Err(e) => {
+                fail!(from self, with ReactorCreateError::UnknownError(0),
+                    "{msg} since the underlying epoll instance could not be created ({:?}).", e);
+            }
+        }
+    }
+}
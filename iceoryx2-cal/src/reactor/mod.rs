@@ -10,6 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(target_os = "linux")]
+pub mod epoll;
 pub mod posix_select;
 
 use std::{fmt::Debug, time::Duration};
@@ -0,0 +1,40 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Implements [`Serialize`] for bincode, a binary format that mirrors the memory layout
+//! of the types, see: <https://github.com/bincode-org/bincode>.
+
+use crate::serialize::Serialize;
+use iceoryx2_bb_log::fail;
+
+use super::{DeserializeError, SerializeError};
+
+/// bincode [`Serialize`]
+pub struct Bincode {}
+
+impl Serialize for Bincode {
+    const NAME: &'static str = "bincode";
+
+    fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+        Ok(
+            fail!(from "Bincode::serialize", when ::bincode::serialize(value),
+                with SerializeError::InternalError, "Failed to serialize object"),
+        )
+    }
+
+    fn deserialize<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, DeserializeError> {
+        Ok(
+            fail!(from "Bincode::deserialize", when ::bincode::deserialize::<T>(bytes),
+                with DeserializeError::InternalError, "Failed to deserialize object."),
+        )
+    }
+}
@@ -23,6 +23,8 @@ use super::{DeserializeError, SerializeError};
 pub struct Cdr {}
 
 impl Serialize for Cdr {
+    const NAME: &'static str = "cdr";
+
     fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
         Ok(
             fail!(from "Cdr::serialize", when cdr::serialize::<_, _, CdrBe>(&value, Infinite),
@@ -37,7 +37,9 @@
 //! }
 //! ```
 
+pub mod bincode;
 pub mod cdr;
+pub mod postcard;
 pub mod toml;
 
 /// Failure emitted by [`Serialize::serialize()`]
@@ -55,6 +57,10 @@ pub enum DeserializeError {
 /// Serialize and deserialize constructs which implement [`serde::Serialize`] and
 /// [`serde::de::DeserializeOwned`]
 pub trait Serialize {
+    /// Stable name of the serialization format. It is stored in the service configuration
+    /// to identify the format independently of the Rust type path of the implementation.
+    const NAME: &'static str;
+
     /// Serializes a value
     fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError>;
 
@@ -22,6 +22,8 @@ use super::{DeserializeError, SerializeError};
 pub struct Toml {}
 
 impl Serialize for Toml {
+    const NAME: &'static str = "toml";
+
     fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
         let msg = "Failed to serialize object";
         let mut buffer = String::new();
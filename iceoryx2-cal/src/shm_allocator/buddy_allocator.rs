@@ -0,0 +1,382 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A **threadsafe** buddy [`ShmAllocator`] that manages memory chunks of variable size. Every
+//! allocation is rounded up to the next power of two multiple of the minimum block size, so that
+//! small samples do not occupy memory that is sized for the largest possible sample.
+//!
+//! The state of every block is stored in the management memory that is acquired in
+//! [`ShmAllocator::init()`], therefore the allocator can be placed in shared memory.
+
+use std::{alloc::Layout, ptr::NonNull, sync::atomic::Ordering};
+
+use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
+use iceoryx2_bb_elementary::{
+    allocator::{AllocationError, BaseAllocator},
+    math::align,
+    relocatable_ptr::{PointerTrait, RelocatablePointer},
+};
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use super::{PointerOffset, SampleAllocator, ShmAllocationError, ShmAllocatorInitError};
+
+/// The default size of the smallest block the [`BuddyAllocator`] hands out.
+pub const DEFAULT_MIN_BLOCK_SIZE: usize = 64;
+
+/// When the [`BuddyAllocator`] manages samples, the block of the maximum sample layout is
+/// partitioned into at most this number of minimum blocks. It bounds the number of chunks
+/// a data segment consists of, see [`SampleAllocator::max_number_of_chunks()`].
+pub const MAX_NUMBER_OF_MIN_BLOCKS_PER_SAMPLE: usize = 32;
+
+const BLOCK_IS_USED: u8 = 0x80;
+const BLOCK_ORDER_MASK: u8 = 0x7f;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Config {
+    /// The size of the smallest block is the next power of two of the layouts size and the
+    /// alignment is the maximum supported alignment of the allocator.
+    pub min_block_layout: Layout,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            min_block_layout: unsafe {
+                Layout::from_size_align_unchecked(DEFAULT_MIN_BLOCK_SIZE, 8)
+            },
+        }
+    }
+}
+
+impl ShmAllocatorConfig for Config {}
+
+impl Config {
+    fn min_block_size(&self) -> usize {
+        self.min_block_layout
+            .size()
+            .max(self.min_block_layout.align())
+            .next_power_of_two()
+    }
+}
+
+#[derive(Debug)]
+pub struct BuddyAllocator {
+    // stores for every minimum block the state of the block that starts there
+    //   bit 7: is used, bit 0..6: order of the block
+    block_states: RelocatablePointer<u8>,
+    number_of_min_blocks: usize,
+    min_block_size: usize,
+    min_block_alignment: usize,
+    // the allocator only manages a range of numbers, see PoolAllocator
+    start: usize,
+    base_address: usize,
+    max_supported_alignment_by_memory: usize,
+    is_locked: IoxAtomicBool,
+    is_memory_initialized: IoxAtomicBool,
+}
+
+unsafe impl Send for BuddyAllocator {}
+unsafe impl Sync for BuddyAllocator {}
+
+impl BuddyAllocator {
+    /// Returns the size of the smallest block that can be allocated.
+    pub fn min_block_size(&self) -> usize {
+        self.min_block_size
+    }
+
+    /// Returns the number of smallest blocks the memory is partitioned in.
+    pub fn number_of_min_blocks(&self) -> usize {
+        self.number_of_min_blocks
+    }
+
+    /// Returns the size of the block that is required to store the provided layout.
+    pub fn block_size(&self, layout: Layout) -> usize {
+        self.min_block_size << self.order_of(layout)
+    }
+
+    fn max_block_size(max_sample_layout: Layout) -> usize {
+        max_sample_layout
+            .size()
+            .max(max_sample_layout.align())
+            .next_power_of_two()
+    }
+
+    fn calc_number_of_min_blocks(
+        ptr: usize,
+        size: usize,
+        min_block_size: usize,
+        alignment: usize,
+    ) -> usize {
+        let adjusted_start = align(ptr, alignment);
+        if ptr + size < adjusted_start {
+            return 0;
+        }
+
+        (ptr + size - adjusted_start) / min_block_size
+    }
+
+    fn order_of(&self, layout: Layout) -> u8 {
+        let number_of_min_blocks = layout.size().max(1).div_ceil(self.min_block_size);
+        number_of_min_blocks.next_power_of_two().trailing_zeros() as u8
+    }
+
+    fn verify_init(&self, source: &str) {
+        debug_assert!(
+            self.is_memory_initialized.load(Ordering::Relaxed),
+            "From: {:?}, Undefined behavior when calling \"{}\" and the object is not initialized.",
+            self,
+            source
+        );
+    }
+
+    fn lock(&self) {
+        while self
+            .is_locked
+            .compare_exchange_weak(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .is_err()
+        {
+            core::hint::spin_loop();
+        }
+    }
+
+    fn unlock(&self) {
+        self.is_locked.store(false, Ordering::Release);
+    }
+
+    unsafe fn state(&self, block: usize) -> u8 {
+        *self.block_states.as_ptr().add(block)
+    }
+
+    unsafe fn set_state(&self, block: usize, order: u8, is_used: bool) {
+        let state = match is_used {
+            true => order | BLOCK_IS_USED,
+            false => order,
+        };
+        *(self.block_states.as_ptr() as *mut u8).add(block) = state;
+    }
+
+    // partitions the memory into the largest possible blocks where every block is aligned
+    // to its own size
+    unsafe fn initialize_blocks(&self) {
+        if self.number_of_min_blocks == 0 {
+            return;
+        }
+
+        let max_order = self.number_of_min_blocks.ilog2();
+        let mut block = 0;
+        while block < self.number_of_min_blocks {
+            let mut order = block.trailing_zeros().min(max_order) as u8;
+            while block + (1 << order) > self.number_of_min_blocks {
+                order -= 1;
+            }
+
+            self.set_state(block, order, false);
+            block += 1 << order;
+        }
+    }
+
+    // the first free block with a sufficient size is used so that the memory is filled from
+    // the start and large blocks at the end remain available
+    unsafe fn acquire_block(&self, order: u8) -> Option<usize> {
+        let mut block = 0;
+        while block < self.number_of_min_blocks {
+            let state = self.state(block);
+            let mut block_order = state & BLOCK_ORDER_MASK;
+            if state & BLOCK_IS_USED == 0 && block_order >= order {
+                while block_order > order {
+                    block_order -= 1;
+                    self.set_state(block + (1 << block_order), block_order, false);
+                }
+                self.set_state(block, order, true);
+
+                return Some(block);
+            }
+            block += 1 << block_order;
+        }
+
+        None
+    }
+
+    unsafe fn release_block(&self, mut block: usize) {
+        let state = self.state(block);
+        if state & BLOCK_IS_USED == 0 {
+            fatal_panic!(from self,
+                "This should never happen! The block {} is released but it was never allocated.",
+                block);
+        }
+
+        let mut order = state & BLOCK_ORDER_MASK;
+        loop {
+            let buddy = block ^ (1 << order);
+            if buddy + (1 << order) > self.number_of_min_blocks
+                || self.state(buddy) != order
+                || order == BLOCK_ORDER_MASK
+            {
+                break;
+            }
+
+            block = block.min(buddy);
+            order += 1;
+        }
+
+        self.set_state(block, order, false);
+    }
+}
+
+impl SampleAllocator for BuddyAllocator {
+    fn sample_config(max_sample_layout: Layout) -> Self::Configuration {
+        let min_block_size = (Self::max_block_size(max_sample_layout)
+            / MAX_NUMBER_OF_MIN_BLOCKS_PER_SAMPLE)
+            .max(DEFAULT_MIN_BLOCK_SIZE)
+            .max(max_sample_layout.align());
+
+        Config {
+            min_block_layout: unsafe {
+                Layout::from_size_align_unchecked(min_block_size, max_sample_layout.align())
+            },
+        }
+    }
+
+    fn sample_memory_size(max_sample_layout: Layout, number_of_samples: usize) -> usize {
+        let block_size = Self::max_block_size(max_sample_layout)
+            .max(Self::sample_granularity(max_sample_layout));
+
+        block_size * number_of_samples + max_sample_layout.align() - 1
+    }
+
+    fn sample_granularity(max_sample_layout: Layout) -> usize {
+        Self::sample_config(max_sample_layout).min_block_size()
+    }
+
+    fn max_number_of_chunks(number_of_samples: usize) -> usize {
+        MAX_NUMBER_OF_MIN_BLOCKS_PER_SAMPLE * number_of_samples
+    }
+}
+
+impl ShmAllocator for BuddyAllocator {
+    type Configuration = Config;
+
+    fn management_size(memory_size: usize, config: &Self::Configuration) -> usize {
+        // every minimum block requires one byte to store its state
+        (memory_size / config.min_block_size()).max(1)
+    }
+
+    fn relative_start_address(&self) -> usize {
+        self.start - self.base_address
+    }
+
+    unsafe fn new_uninit(
+        max_supported_alignment_by_memory: usize,
+        managed_memory: NonNull<[u8]>,
+        config: &Self::Configuration,
+    ) -> Self {
+        let base_address = (managed_memory.as_ptr() as *mut u8) as usize;
+        let min_block_size = config.min_block_size();
+        let min_block_alignment = config.min_block_layout.align();
+
+        Self {
+            block_states: RelocatablePointer::new_uninit(),
+            number_of_min_blocks: Self::calc_number_of_min_blocks(
+                base_address,
+                managed_memory.len(),
+                min_block_size,
+                min_block_alignment,
+            ),
+            min_block_size,
+            min_block_alignment,
+            start: align(base_address, min_block_alignment),
+            base_address,
+            max_supported_alignment_by_memory,
+            is_locked: IoxAtomicBool::new(false),
+            is_memory_initialized: IoxAtomicBool::new(false),
+        }
+    }
+
+    fn max_alignment(&self) -> usize {
+        self.min_block_alignment
+    }
+
+    unsafe fn init<Allocator: BaseAllocator>(
+        &self,
+        mgmt_allocator: &Allocator,
+    ) -> Result<(), ShmAllocatorInitError> {
+        let msg = "Unable to initialize allocator";
+        if self.is_memory_initialized.load(Ordering::Relaxed) {
+            fatal_panic!(from self,
+                "Memory already initialized. Initializing it twice may lead to undefined behavior.");
+        }
+
+        if self.max_supported_alignment_by_memory < self.max_alignment() {
+            fail!(from self, with ShmAllocatorInitError::MaxSupportedMemoryAlignmentInsufficient,
+                "{} since the required alignment {} exceeds the maximum supported alignment {} of the memory.",
+                msg, self.max_alignment(), self.max_supported_alignment_by_memory);
+        }
+
+        let memory = fail!(from self,
+            when mgmt_allocator.allocate(Layout::from_size_align_unchecked(
+                self.number_of_min_blocks.max(1), 1)),
+            with ShmAllocatorInitError::AllocationFailed,
+            "{} since the allocation of the allocator managment memory failed.", msg);
+        self.block_states.init(memory);
+        self.initialize_blocks();
+
+        self.is_memory_initialized.store(true, Ordering::Relaxed);
+        Ok(())
+    }
+
+    fn unique_id() -> u8 {
+        2
+    }
+
+    unsafe fn allocate(&self, layout: Layout) -> Result<PointerOffset, ShmAllocationError> {
+        self.verify_init("allocate");
+
+        let msg = "Unable to allocate memory";
+        if layout.align() > self.max_alignment() {
+            fail!(from self, with ShmAllocationError::ExceedsMaxSupportedAlignment,
+                "{} since an alignment of {} exceeds the maximum supported alignment of {}.",
+                msg, layout.align(), self.max_alignment());
+        }
+
+        let order = self.order_of(layout);
+        self.lock();
+        let block = self.acquire_block(order);
+        self.unlock();
+
+        match block {
+            Some(block) => Ok(PointerOffset::new(block * self.min_block_size)),
+            None => {
+                fail!(from self, with ShmAllocationError::AllocationError(AllocationError::OutOfMemory),
+                    "{} since there is no free block left to allocate {} bytes with an alignment of {}.",
+                    msg, layout.size(), layout.align());
+            }
+        }
+    }
+
+    /// The size of the released block is restored from the management memory, therefore the
+    /// layout is not required to release the memory.
+    unsafe fn deallocate(&self, offset: PointerOffset, _layout: Layout) {
+        self.verify_init("deallocate");
+
+        debug_assert!(
+            offset.value() % self.min_block_size == 0
+                && offset.value() / self.min_block_size < self.number_of_min_blocks,
+            "The offset {:?} is not managed by this allocator.",
+            offset
+        );
+
+        self.lock();
+        self.release_block(offset.value() / self.min_block_size);
+        self.unlock();
+    }
+}
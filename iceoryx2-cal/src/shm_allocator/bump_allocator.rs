@@ -90,7 +90,7 @@ impl ShmAllocator for BumpAllocator {
 
     unsafe fn deallocate(&self, offset: PointerOffset, layout: Layout) {
         self.allocator.deallocate(
-            NonNull::new_unchecked((offset.0 + self.base_address) as *mut u8),
+            NonNull::new_unchecked((offset.value() + self.base_address) as *mut u8),
             layout,
         );
     }
@@ -10,6 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+pub mod buddy_allocator;
 pub mod bump_allocator;
 pub mod pool_allocator;
 
@@ -20,17 +21,49 @@ use iceoryx2_bb_elementary::{allocator::BaseAllocator, enum_gen};
 
 pub trait ShmAllocatorConfig: Copy + Default {}
 
+/// The maximum number of shared memory segments a [`PointerOffset`] can refer to.
+pub const MAX_NUMBER_OF_SEGMENTS: usize = u8::MAX as usize + 1;
+
+const SEGMENT_ID_BITS: usize = 8;
+const SEGMENT_ID_MASK: usize = (1 << SEGMENT_ID_BITS) - 1;
+
+/// Offset of a chunk relative to the payload start of the shared memory segment it was
+/// allocated from. The id of the segment is encoded alongside the offset so that a receiver
+/// is able to identify the segment when the sender owns more than one.
 #[derive(Clone, Copy, Eq, PartialEq, Debug)]
 pub struct PointerOffset(usize);
 
 impl PointerOffset {
+    /// Creates a new [`PointerOffset`] that refers to the first segment.
     pub fn new(value: usize) -> PointerOffset {
+        Self::from_offset_and_segment_id(value, 0)
+    }
+
+    /// Creates a new [`PointerOffset`] that refers to the segment with the provided id.
+    pub fn from_offset_and_segment_id(offset: usize, segment_id: u8) -> PointerOffset {
+        debug_assert!(offset <= (usize::MAX >> SEGMENT_ID_BITS));
+        Self((offset << SEGMENT_ID_BITS) | segment_id as usize)
+    }
+
+    /// Restores a [`PointerOffset`] from the value acquired with [`PointerOffset::as_value()`].
+    pub fn from_value(value: usize) -> PointerOffset {
         Self(value)
     }
 
-    pub fn value(&self) -> usize {
+    /// Returns the encoded value that contains the offset and the segment id.
+    pub fn as_value(&self) -> usize {
         self.0
     }
+
+    /// Returns the offset relative to the payload start of the segment.
+    pub fn value(&self) -> usize {
+        self.0 >> SEGMENT_ID_BITS
+    }
+
+    /// Returns the id of the segment the offset refers to.
+    pub fn segment_id(&self) -> u8 {
+        (self.0 & SEGMENT_ID_MASK) as u8
+    }
 }
 
 enum_gen! { ShmAllocationError
@@ -108,3 +141,26 @@ pub trait ShmAllocator: Send + Sync + 'static {
     ///
     unsafe fn deallocate(&self, distance: PointerOffset, layout: Layout);
 }
+
+/// A [`ShmAllocator`] that can be used to manage the samples of a data segment where every
+/// sample does not exceed a maximum [`Layout`].
+pub trait SampleAllocator: ShmAllocator {
+    /// Returns the [`ShmAllocator::Configuration`] that supports allocations up to the
+    /// provided maximum sample layout.
+    fn sample_config(max_sample_layout: Layout) -> Self::Configuration;
+
+    /// Returns the size of the managed memory that is required so that the provided number of
+    /// samples can be allocated in parallel, even when every sample has the maximum layout.
+    fn sample_memory_size(max_sample_layout: Layout, number_of_samples: usize) -> usize;
+
+    /// Returns the granularity of the allocated offsets. Every [`PointerOffset`] acquired
+    /// with [`ShmAllocator::allocate()`] is a multiple of it.
+    fn sample_granularity(max_sample_layout: Layout) -> usize;
+
+    /// Returns the maximum number of chunks with the size of
+    /// [`SampleAllocator::sample_granularity()`] the memory of
+    /// [`SampleAllocator::sample_memory_size()`] consists of. The bound is independent of the
+    /// maximum sample layout so that the chunks of data segments with a different maximum sample
+    /// layout can be tracked with the same capacity.
+    fn max_number_of_chunks(number_of_samples: usize) -> usize;
+}
@@ -16,7 +16,7 @@ use crate::shm_allocator::{ShmAllocator, ShmAllocatorConfig};
 use iceoryx2_bb_elementary::allocator::BaseAllocator;
 use iceoryx2_bb_log::fail;
 
-use super::{PointerOffset, ShmAllocationError, ShmAllocatorInitError};
+use super::{PointerOffset, SampleAllocator, ShmAllocationError, ShmAllocatorInitError};
 
 #[derive(Clone, Copy)]
 pub struct Config {
@@ -53,6 +53,26 @@ impl PoolAllocator {
     }
 }
 
+impl SampleAllocator for PoolAllocator {
+    fn sample_config(max_sample_layout: Layout) -> Self::Configuration {
+        Config {
+            bucket_layout: max_sample_layout,
+        }
+    }
+
+    fn sample_memory_size(max_sample_layout: Layout, number_of_samples: usize) -> usize {
+        max_sample_layout.size() * number_of_samples + max_sample_layout.align() - 1
+    }
+
+    fn sample_granularity(max_sample_layout: Layout) -> usize {
+        max_sample_layout.size()
+    }
+
+    fn max_number_of_chunks(number_of_samples: usize) -> usize {
+        number_of_samples
+    }
+}
+
 impl ShmAllocator for PoolAllocator {
     type Configuration = Config;
 
@@ -12,7 +12,7 @@
 
 #[doc(hidden)]
 pub mod details {
-    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8};
+    use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicU8, IoxAtomicUsize};
     use std::cell::UnsafeCell;
     use std::fmt::Debug;
     use std::marker::PhantomData;
@@ -24,6 +24,7 @@ pub mod details {
     };
     use crate::named_concept::*;
     pub use crate::zero_copy_connection::*;
+    use iceoryx2_bb_container::vec::RelocatableVec;
     use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
     use iceoryx2_bb_lock_free::spsc::{
         index_queue::RelocatableIndexQueue,
@@ -106,6 +107,7 @@ pub mod details {
         None = 0b00000000,
         Sender = 0b00000001,
         Receiver = 0b00000010,
+        DeadReceiver = 0b01000000,
         MarkedForDestruction = 0b10000000,
     }
 
@@ -121,11 +123,12 @@ pub mod details {
     ) {
         let mut current_state = storage.get().state.load(Ordering::Relaxed);
         loop {
-            let new_state = if current_state == state_to_remove.value() {
-                State::MarkedForDestruction.value()
-            } else {
-                current_state & !state_to_remove.value()
-            };
+            let new_state =
+                if current_state & !State::DeadReceiver.value() == state_to_remove.value() {
+                    State::MarkedForDestruction.value()
+                } else {
+                    current_state & !state_to_remove.value()
+                };
 
             match storage.get().state.compare_exchange(
                 current_state,
@@ -157,9 +160,13 @@ pub mod details {
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        max_number_of_segments: u8,
         state: IoxAtomicU8,
         init_state: IoxAtomicU64,
         enable_safe_overflow: bool,
+        missed_samples: IoxAtomicU64,
+        skipped_samples: IoxAtomicU64,
+        segment_generations: RelocatableVec<IoxAtomicU64>,
     }
 
     impl SharedManagementData {
@@ -170,6 +177,7 @@ pub mod details {
             max_borrowed_samples: usize,
             sample_size: usize,
             number_of_samples: usize,
+            max_number_of_segments: u8,
         ) -> Self {
             Self {
                 submission_channel: unsafe {
@@ -180,13 +188,23 @@ pub mod details {
                 completion_channel: unsafe {
                     RelocatableIndexQueue::new_uninit(completion_channel_buffer_capacity)
                 },
-                used_chunk_list: unsafe { RelocatableUsedChunkList::new_uninit(number_of_samples) },
+                used_chunk_list: unsafe {
+                    RelocatableUsedChunkList::new_uninit(
+                        number_of_samples * max_number_of_segments as usize,
+                    )
+                },
                 state: IoxAtomicU8::new(State::None.value()),
                 init_state: IoxAtomicU64::new(0),
                 enable_safe_overflow,
+                missed_samples: IoxAtomicU64::new(0),
+                skipped_samples: IoxAtomicU64::new(0),
+                segment_generations: unsafe {
+                    RelocatableVec::new_uninit(max_number_of_segments as usize)
+                },
                 sample_size,
                 max_borrowed_samples,
                 number_of_samples,
+                max_number_of_segments,
             }
         }
 
@@ -194,12 +212,16 @@ pub mod details {
             submission_channel_buffer_capacity: usize,
             completion_channel_buffer_capacity: usize,
             number_of_samples: usize,
+            max_number_of_segments: u8,
         ) -> usize {
             RelocatableIndexQueue::const_memory_size(completion_channel_buffer_capacity)
                 + RelocatableSafelyOverflowingIndexQueue::const_memory_size(
                     submission_channel_buffer_capacity,
                 )
-                + RelocatableUsedChunkList::const_memory_size(number_of_samples)
+                + RelocatableUsedChunkList::const_memory_size(
+                    number_of_samples * max_number_of_segments as usize,
+                )
+                + RelocatableVec::<IoxAtomicU64>::const_memory_size(max_number_of_segments as usize)
         }
     }
 
@@ -211,6 +233,7 @@ pub mod details {
         max_borrowed_samples: usize,
         sample_size: usize,
         number_of_samples: usize,
+        max_number_of_segments: u8,
         timeout: Duration,
         config: Configuration<Storage>,
     }
@@ -229,6 +252,7 @@ pub mod details {
                 self.submission_channel_size(),
                 self.completion_channel_size(),
                 self.number_of_samples,
+                self.max_number_of_segments,
             );
 
             let msg = "Failed to acquire underlying shared memory";
@@ -245,6 +269,9 @@ pub mod details {
                         "{} since the retrieve channel allocation failed. - This is an implementation bug!", msg);
             fatal_panic!(from self, when unsafe { data.used_chunk_list.init(allocator) },
                         "{} since the used chunk list allocation failed. - This is an implementation bug!", msg);
+            fatal_panic!(from self, when unsafe { data.segment_generations.init(allocator) },
+                        "{} since the segment generation allocation failed. - This is an implementation bug!", msg);
+            while unsafe { data.segment_generations.push(IoxAtomicU64::new(0)) } {}
 
             true
         })
@@ -256,6 +283,7 @@ pub mod details {
                                     self.max_borrowed_samples,
                                     self.sample_size,
                                     self.number_of_samples,
+                                    self.max_number_of_segments,
                                 )
             );
 
@@ -319,6 +347,12 @@ pub mod details {
                         "{} since the requested number of samples is set to {} but should be set to {}.",
                         msg, self.number_of_samples, storage.get().number_of_samples);
                 }
+
+                if storage.get().max_number_of_segments != self.max_number_of_segments {
+                    fail!(from self, with ZeroCopyCreationError::IncompatibleNumberOfSegments,
+                        "{} since the requested number of segments is set to {} but should be set to {}.",
+                        msg, self.max_number_of_segments, storage.get().max_number_of_segments);
+                }
             }
 
             Ok(storage)
@@ -368,6 +402,7 @@ pub mod details {
                 max_borrowed_samples: DEFAULT_MAX_BORROWED_SAMPLES,
                 sample_size: 0,
                 number_of_samples: 0,
+                max_number_of_segments: DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS,
                 config: Configuration::default(),
                 timeout: Duration::ZERO,
             }
@@ -407,6 +442,11 @@ pub mod details {
             self
         }
 
+        fn max_supported_shared_memory_segments(mut self, value: u8) -> Self {
+            self.max_number_of_segments = value.clamp(1, u8::MAX);
+            self
+        }
+
         fn create_sender(
             mut self,
             sample_size: usize,
@@ -420,8 +460,13 @@ pub mod details {
 
             self.reserve_port(storage.get(), State::Sender.value(), msg)?;
 
+            let segment_sample_size = (0..self.max_number_of_segments)
+                .map(|_| IoxAtomicUsize::new(self.sample_size))
+                .collect();
+
             Ok(Sender {
                 storage,
+                segment_sample_size,
                 name: self.name,
             })
         }
@@ -450,9 +495,49 @@ pub mod details {
     #[derive(Debug)]
     pub struct Sender<Storage: DynamicStorage<SharedManagementData>> {
         storage: Storage,
+        segment_sample_size: Vec<IoxAtomicUsize>,
         name: FileName,
     }
 
+    impl<Storage: DynamicStorage<SharedManagementData>> Sender<Storage> {
+        // The offset is a multiple of the chunk size of its segment, therefore every chunk of
+        // a segment has a unique index. Returns [`None`] when the offset does not belong to a
+        // chunk that can be tracked.
+        fn used_chunk_index(&self, ptr: PointerOffset) -> Option<usize> {
+            let segment_id = ptr.segment_id() as usize;
+            if segment_id >= self.segment_sample_size.len() {
+                return None;
+            }
+
+            let number_of_samples = self.storage.get().number_of_samples;
+            let chunk_size = self.segment_sample_size[segment_id].load(Ordering::Relaxed);
+            if chunk_size == 0 || ptr.value() % chunk_size != 0 {
+                return None;
+            }
+
+            let chunk = ptr.value() / chunk_size;
+            if chunk >= number_of_samples {
+                return None;
+            }
+
+            Some(segment_id * number_of_samples + chunk)
+        }
+
+        fn offset_of_used_chunk(&self, index: usize) -> PointerOffset {
+            let number_of_samples = self.storage.get().number_of_samples;
+            let segment_id = index / number_of_samples;
+            let chunk_size = self.segment_sample_size[segment_id].load(Ordering::Relaxed);
+            PointerOffset::from_offset_and_segment_id(
+                (index % number_of_samples) * chunk_size,
+                segment_id as u8,
+            )
+        }
+
+        fn has_dead_receiver(&self) -> bool {
+            self.storage.get().state.load(Ordering::Relaxed) & State::DeadReceiver.value() != 0
+        }
+    }
+
     impl<Storage: DynamicStorage<SharedManagementData>> Drop for Sender<Storage> {
         fn drop(&mut self) {
             cleanup_shared_memory(&self.storage, State::Sender);
@@ -478,6 +563,14 @@ pub mod details {
             self.storage.get().enable_safe_overflow
         }
 
+        fn missed_samples(&self) -> u64 {
+            self.storage.get().missed_samples.load(Ordering::Relaxed)
+        }
+
+        fn skipped_samples(&self) -> u64 {
+            self.storage.get().skipped_samples.load(Ordering::Relaxed)
+        }
+
         fn is_connected(&self) -> bool {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
@@ -485,7 +578,31 @@ pub mod details {
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopySender for Sender<Storage> {
-        fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        fn retire_segment(&self, segment_id: u8) {
+            if let Some(generation) = self
+                .storage
+                .get()
+                .segment_generations
+                .get(segment_id as usize)
+            {
+                generation.fetch_add(1, Ordering::Release);
+            }
+        }
+
+        fn record_skipped_samples(&self, value: u64) {
+            if value != 0 {
+                self.storage
+                    .get()
+                    .skipped_samples
+                    .fetch_add(value, Ordering::Relaxed);
+            }
+        }
+
+        fn try_send(
+            &self,
+            ptr: PointerOffset,
+            sample_size: usize,
+        ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             let msg = "Unable to send sample";
 
             if !self.storage.get().enable_safe_overflow
@@ -495,29 +612,44 @@ pub mod details {
                              "{} since the receive buffer is full.", msg);
             }
 
-            if !self
-                .storage
-                .get()
-                .used_chunk_list
-                .insert(ptr.value() / self.storage.get().sample_size)
-            {
+            let segment_id = ptr.segment_id() as usize;
+            if segment_id >= self.segment_sample_size.len() {
+                fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                    "{} since the segment id {} exceeds the maximum supported number of segments {}.",
+                    msg, segment_id, self.segment_sample_size.len());
+            }
+            self.segment_sample_size[segment_id].store(sample_size, Ordering::Relaxed);
+
+            let index = match self.used_chunk_index(ptr) {
+                Some(index) => index,
+                None => {
+                    fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
+                        "{} since the offset {:?} does not refer to a chunk that can be tracked with a chunk size of {} and {} chunks per segment.",
+                        msg, ptr, sample_size, self.storage.get().number_of_samples);
+                }
+            };
+
+            if !self.storage.get().used_chunk_list.insert(index) {
                 fail!(from self, with ZeroCopySendError::UsedChunkListFull,
                     "{} since the used chunk list is full.", msg);
             }
 
-            match unsafe { self.storage.get().submission_channel.push(ptr.value()) } {
+            match unsafe { self.storage.get().submission_channel.push(ptr.as_value()) } {
                 Some(v) => {
+                    let v = PointerOffset::from_value(v);
                     if !self
-                        .storage
-                        .get()
-                        .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
+                        .used_chunk_index(v)
+                        .is_some_and(|index| self.storage.get().used_chunk_list.remove(index))
                     {
                         fail!(from self, with ZeroCopySendError::ConnectionCorrupted,
                         "{} since an invalid offset was returned on overflow.", msg);
                     }
+                    self.storage
+                        .get()
+                        .missed_samples
+                        .fetch_add(1, Ordering::Relaxed);
 
-                    Ok(Some(PointerOffset::new(v)))
+                    Ok(Some(v))
                 }
                 None => Ok(None),
             }
@@ -526,42 +658,54 @@ pub mod details {
         fn blocking_send(
             &self,
             ptr: PointerOffset,
+            sample_size: usize,
         ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
             if !self.storage.get().enable_safe_overflow {
                 AdaptiveWaitBuilder::new()
                     .create()
                     .unwrap()
-                    .wait_while(|| self.storage.get().submission_channel.is_full())
+                    .wait_while(|| {
+                        self.storage.get().submission_channel.is_full() && !self.has_dead_receiver()
+                    })
                     .unwrap();
             }
 
-            self.try_send(ptr)
+            self.try_send(ptr, sample_size)
         }
 
         fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError> {
+            // the receiver will never return the samples it held, therefore all samples that
+            // are still in flight are returned regardless of the state of the channels
+            if self.has_dead_receiver() {
+                return Ok(self
+                    .storage
+                    .get()
+                    .used_chunk_list
+                    .pop()
+                    .map(|index| self.offset_of_used_chunk(index)));
+            }
+
             match unsafe { self.storage.get().completion_channel.pop() } {
                 None => Ok(None),
                 Some(v) => {
+                    let v = PointerOffset::from_value(v);
                     if !self
-                        .storage
-                        .get()
-                        .used_chunk_list
-                        .remove(v / self.storage.get().sample_size)
+                        .used_chunk_index(v)
+                        .is_some_and(|index| self.storage.get().used_chunk_list.remove(index))
                     {
                         fail!(from self, with ZeroCopyReclaimError::ReceiverReturnedCorruptedOffset,
-                        "Unable to reclaim sample since the receiver returned the corrupted offset {}.", v);
+                        "Unable to reclaim sample since the receiver returned the corrupted offset {:?}.", v);
                     }
-                    Ok(Some(PointerOffset::new(v)))
+                    Ok(Some(v))
                 }
             }
         }
 
         unsafe fn acquire_used_offsets<F: FnMut(PointerOffset)>(&self, mut callback: F) {
-            let sample_size = self.storage.get().sample_size;
             self.storage
                 .get()
                 .used_chunk_list
-                .remove_all(|index| callback(PointerOffset::new(index * sample_size)));
+                .remove_all(|index| callback(self.offset_of_used_chunk(index)));
         }
     }
 
@@ -608,6 +752,14 @@ pub mod details {
             self.storage.get().enable_safe_overflow
         }
 
+        fn missed_samples(&self) -> u64 {
+            self.storage.get().missed_samples.load(Ordering::Relaxed)
+        }
+
+        fn skipped_samples(&self) -> u64 {
+            self.storage.get().skipped_samples.load(Ordering::Relaxed)
+        }
+
         fn is_connected(&self) -> bool {
             self.storage.get().state.load(Ordering::Relaxed)
                 == State::Sender.value() | State::Receiver.value()
@@ -615,6 +767,14 @@ pub mod details {
     }
 
     impl<Storage: DynamicStorage<SharedManagementData>> ZeroCopyReceiver for Receiver<Storage> {
+        fn segment_generation(&self, segment_id: u8) -> u64 {
+            self.storage
+                .get()
+                .segment_generations
+                .get(segment_id as usize)
+                .map_or(0, |generation| generation.load(Ordering::Acquire))
+        }
+
         fn has_data(&self) -> bool {
             !self.storage.get().submission_channel.is_empty()
         }
@@ -630,13 +790,13 @@ pub mod details {
                 None => Ok(None),
                 Some(v) => {
                     *self.borrow_counter() += 1;
-                    Ok(Some(PointerOffset::new(v)))
+                    Ok(Some(PointerOffset::from_value(v)))
                 }
             }
         }
 
         fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError> {
-            match unsafe { self.storage.get().completion_channel.push(ptr.value()) } {
+            match unsafe { self.storage.get().completion_channel.push(ptr.as_value()) } {
                 true => {
                     *self.borrow_counter() -= 1;
                     Ok(())
@@ -701,5 +861,53 @@ pub mod details {
         fn has_configurable_buffer_size() -> bool {
             true
         }
+
+        unsafe fn remove_dead_receiver_cfg(
+            name: &FileName,
+            config: &Self::Configuration,
+        ) -> Result<bool, ZeroCopyRemoveReceiverError> {
+            let origin = "ZeroCopyConnection::remove_dead_receiver_cfg()";
+            let msg = "Unable to remove the dead receiver";
+            let storage = match <<Storage as DynamicStorage<SharedManagementData>>::Builder<'_> as NamedConceptBuilder<Storage>>::new(name)
+                .config(&config.dynamic_storage_config)
+                .open()
+            {
+                Ok(storage) => storage,
+                Err(DynamicStorageOpenError::DoesNotExist) => return Ok(false),
+                Err(DynamicStorageOpenError::VersionMismatch) => {
+                    fail!(from origin, with ZeroCopyRemoveReceiverError::VersionMismatch,
+                        "{} since the version of the connection \"{}\" does not match.", msg, name);
+                }
+                Err(DynamicStorageOpenError::InitializationNotYetFinalized) => {
+                    fail!(from origin, with ZeroCopyRemoveReceiverError::InitializationNotYetFinalized,
+                        "{} since the initialization of the connection \"{}\" is not finalized.", msg, name);
+                }
+                Err(e) => {
+                    fail!(from origin, with ZeroCopyRemoveReceiverError::InternalError,
+                        "{} of the connection \"{}\" due to an internal failure ({:?}).", msg, name, e);
+                }
+            };
+
+            let mut current_state = storage.get().state.load(Ordering::Relaxed);
+            loop {
+                if current_state & State::MarkedForDestruction.value() != 0 {
+                    break;
+                }
+
+                let new_state =
+                    (current_state & !State::Receiver.value()) | State::DeadReceiver.value();
+                match storage.get().state.compare_exchange(
+                    current_state,
+                    new_state,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => break,
+                    Err(s) => current_state = s,
+                }
+            }
+
+            Ok(true)
+        }
     }
 }
@@ -37,6 +37,7 @@ pub enum ZeroCopyCreationError {
     IncompatibleOverflowSetting,
     IncompatibleSampleSize,
     IncompatibleNumberOfSamples,
+    IncompatibleNumberOfSegments,
 }
 
 impl std::fmt::Display for ZeroCopyCreationError {
@@ -101,15 +102,37 @@ impl std::fmt::Display for ZeroCopyReleaseError {
 
 impl std::error::Error for ZeroCopyReleaseError {}
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroCopyRemoveReceiverError {
+    VersionMismatch,
+    InitializationNotYetFinalized,
+    InternalError,
+}
+
+impl std::fmt::Display for ZeroCopyRemoveReceiverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "{}::{:?}", std::stringify!(Self), self)
+    }
+}
+
+impl std::error::Error for ZeroCopyRemoveReceiverError {}
+
 pub const DEFAULT_BUFFER_SIZE: usize = 4;
 pub const DEFAULT_ENABLE_SAFE_OVERFLOW: bool = false;
 pub const DEFAULT_MAX_BORROWED_SAMPLES: usize = 4;
+pub const DEFAULT_MAX_SUPPORTED_SHARED_MEMORY_SEGMENTS: u8 = 1;
 
 pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<C> {
     fn buffer_size(self, value: usize) -> Self;
     fn enable_safe_overflow(self, value: bool) -> Self;
     fn receiver_max_borrowed_samples(self, value: usize) -> Self;
+    /// Defines the number of chunks every shared memory segment consists of. Every sample
+    /// offset of a segment must be smaller than the number of chunks times the chunk size that
+    /// is provided with [`ZeroCopySender::try_send()`].
     fn number_of_samples(self, value: usize) -> Self;
+    /// Defines how many shared memory segments the sender can use at most to send samples.
+    /// The segment of a sample is identified via [`PointerOffset::segment_id()`].
+    fn max_supported_shared_memory_segments(self, value: u8) -> Self;
     /// The timeout defines how long the [`ZeroCopyConnectionBuilder`] should wait for
     /// concurrent
     /// [`ZeroCopyConnectionBuilder::create_sender()`] or
@@ -124,16 +147,45 @@ pub trait ZeroCopyConnectionBuilder<C: ZeroCopyConnection>: NamedConceptBuilder<
 pub trait ZeroCopyPortDetails {
     fn buffer_size(&self) -> usize;
     fn has_enabled_safe_overflow(&self) -> bool;
+    /// Returns the number of samples that were overridden in the receive buffer due to a safe
+    /// overflow and were therefore never received.
+    fn missed_samples(&self) -> u64;
+    /// Returns the number of samples the sender has deliberately not sent to the receiver,
+    /// see [`ZeroCopySender::record_skipped_samples()`].
+    fn skipped_samples(&self) -> u64;
     fn max_borrowed_samples(&self) -> usize;
     fn is_connected(&self) -> bool;
 }
 
 pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
-    fn try_send(&self, ptr: PointerOffset) -> Result<Option<PointerOffset>, ZeroCopySendError>;
-
-    fn blocking_send(&self, ptr: PointerOffset)
-        -> Result<Option<PointerOffset>, ZeroCopySendError>;
-
+    /// Sends the sample with the provided offset. The `sample_size` is the chunk size of the
+    /// samples segment, every offset of the segment must be a multiple of it, otherwise
+    /// [`ZeroCopySendError::ConnectionCorrupted`] is returned.
+    fn try_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    fn blocking_send(
+        &self,
+        ptr: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError>;
+
+    /// Adds `value` to the number of samples the sender has deliberately not sent to the
+    /// receiver, for instance since they were rejected by a receiver side filter.
+    fn record_skipped_samples(&self, value: u64);
+
+    /// Announces that the segment with the provided id was released and that samples that are
+    /// sent afterwards with the same segment id refer to a newly created segment, see
+    /// [`ZeroCopyReceiver::segment_generation()`]. Must only be called when no sample of the
+    /// segment is in flight or borrowed by the receiver.
+    fn retire_segment(&self, segment_id: u8);
+
+    /// Returns a sample that was released by the receiver. When the receiver was removed
+    /// with [`ZeroCopyConnection::remove_dead_receiver_cfg()`] every sample that is still in
+    /// flight - stored in the receive buffer or borrowed by the receiver - is returned.
     fn reclaim(&self) -> Result<Option<PointerOffset>, ZeroCopyReclaimError>;
 
     /// # Safety
@@ -146,6 +198,10 @@ pub trait ZeroCopySender: Debug + ZeroCopyPortDetails + NamedConcept {
 }
 
 pub trait ZeroCopyReceiver: Debug + ZeroCopyPortDetails + NamedConcept {
+    /// Returns how often the segment with the provided id was retired with
+    /// [`ZeroCopySender::retire_segment()`]. When it changed since the receiver has accessed the
+    /// segment, the segment id refers to a newly created segment.
+    fn segment_generation(&self, segment_id: u8) -> u64;
     fn has_data(&self) -> bool;
     fn receive(&self) -> Result<Option<PointerOffset>, ZeroCopyReceiveError>;
     fn release(&self, ptr: PointerOffset) -> Result<(), ZeroCopyReleaseError>;
@@ -170,4 +226,16 @@ pub trait ZeroCopyConnection: Debug + Sized + NamedConceptMgmt {
     fn default_suffix() -> FileName {
         unsafe { FileName::new_unchecked(b".rx") }
     }
+
+    /// Removes the receiver of an existing connection whose process died. Afterwards
+    /// [`ZeroCopySender::reclaim()`] returns every sample the receiver still held so that the
+    /// sender can release them. Returns false when the connection does not exist.
+    ///
+    /// # Safety
+    ///
+    ///  * the receiver of the connection must not be used anymore
+    unsafe fn remove_dead_receiver_cfg(
+        name: &FileName,
+        config: &Self::Configuration,
+    ) -> Result<bool, ZeroCopyRemoveReceiverError>;
 }
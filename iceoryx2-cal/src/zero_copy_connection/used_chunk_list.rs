@@ -135,23 +135,36 @@ pub mod details {
             );
         }
 
-        fn set(&self, idx: usize, value: bool) -> bool {
+        // returns the previous value or [`None`] when the index is out of bounds
+        fn set(&self, idx: usize, value: bool) -> Option<bool> {
             self.verify_init("set");
-            debug_assert!(
-                idx < self.capacity,
-                "This should never happen. Out of bounds access with index {}.",
-                idx
-            );
+            if idx >= self.capacity {
+                return None;
+            }
 
-            unsafe { (*self.data_ptr.as_ptr().add(idx)).swap(value, Ordering::Relaxed) }
+            Some(unsafe { (*self.data_ptr.as_ptr().add(idx)).swap(value, Ordering::Relaxed) })
         }
 
+        /// Inserts the value into the list. Returns false when the value was already contained
+        /// or when it exceeds the capacity.
         pub fn insert(&self, value: usize) -> bool {
-            !self.set(value, true)
+            self.set(value, true) == Some(false)
         }
 
+        /// Removes the value from the list. Returns false when the value was not contained or
+        /// when it exceeds the capacity.
         pub fn remove(&self, value: usize) -> bool {
-            self.set(value, false)
+            self.set(value, false) == Some(true)
+        }
+
+        /// Removes an arbitrary value from the list and returns it. If the list is empty
+        /// [`None`] is returned.
+        pub fn pop(&self) -> Option<usize> {
+            self.verify_init("pop");
+
+            (0..self.capacity).find(|&i| unsafe {
+                (*self.data_ptr.as_ptr().add(i)).swap(false, Ordering::Relaxed)
+            })
         }
 
         pub fn remove_all<F: FnMut(usize)>(&self, mut callback: F) {
@@ -196,6 +209,10 @@ impl<const CAPACITY: usize> FixedSizeUsedChunkList<CAPACITY> {
         self.list.insert(value)
     }
 
+    pub fn pop(&self) -> Option<usize> {
+        self.list.pop()
+    }
+
     pub fn remove_all<F: FnMut(usize)>(&mut self, callback: F) {
         self.list.remove_all(callback)
     }
@@ -11,8 +11,10 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 mod dynamic_storage_posix_shared_memory {
+    use iceoryx2_bb_container::hash_map::RelocatableHashMap;
     use iceoryx2_bb_container::semantic_string::*;
     use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
     use iceoryx2_bb_posix::creation_mode::CreationMode;
     use iceoryx2_bb_posix::permission::Permission;
     use iceoryx2_bb_posix::shared_memory::SharedMemoryBuilder;
@@ -110,4 +112,36 @@ mod dynamic_storage_posix_shared_memory {
         assert_that!(sut.err().unwrap(), eq DynamicStorageOpenError::InitializationNotYetFinalized);
         assert_that!(start.elapsed().unwrap(), ge TIMEOUT);
     }
+
+    #[test]
+    fn relocatable_hash_map_can_be_stored() {
+        type Sut = iceoryx2_cal::dynamic_storage::posix_shared_memory::Storage<
+            RelocatableHashMap<u64, u64>,
+        >;
+        const CAPACITY: usize = 16;
+        let storage_name = generate_name();
+
+        let _sut =
+            <Sut as DynamicStorage<RelocatableHashMap<u64, u64>>>::Builder::new(&storage_name)
+                .supplementary_size(RelocatableHashMap::<u64, u64>::memory_size(CAPACITY))
+                .initializer(|map, allocator| {
+                    if unsafe { map.init(allocator) }.is_err() {
+                        return false;
+                    }
+
+                    (0..CAPACITY as u64).all(|i| unsafe { map.insert(i, i * 3) }.is_ok())
+                })
+                .create(unsafe { RelocatableHashMap::new_uninit(CAPACITY) })
+                .unwrap();
+
+        let sut =
+            <Sut as DynamicStorage<RelocatableHashMap<u64, u64>>>::Builder::new(&storage_name)
+                .open()
+                .unwrap();
+
+        assert_that!(sut.get(), len CAPACITY);
+        for i in 0..CAPACITY as u64 {
+            assert_that!(sut.get().get(&i), eq Some(&(i * 3)));
+        }
+    }
 }
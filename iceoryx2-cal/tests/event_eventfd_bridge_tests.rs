@@ -0,0 +1,163 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[cfg(target_os = "linux")]
+#[generic_tests::define]
+mod event_eventfd_bridge {
+    use iceoryx2_bb_container::semantic_string::*;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::event::futex_bitset_posix_shared_memory::PollableEvent;
+    use iceoryx2_cal::event::{TriggerId, *};
+    use iceoryx2_cal::reactor::{Reactor, *};
+    use std::time::Duration;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> FileName {
+        let mut file = FileName::new(b"event_eventfd_bridge_tests_").unwrap();
+        file.push_bytes(
+            UniqueSystemId::new()
+                .unwrap()
+                .value()
+                .to_string()
+                .as_bytes(),
+        )
+        .unwrap();
+        file
+    }
+
+    fn number_of_triggers<Sut: Reactor>(sut: &Sut) -> usize {
+        let mut counter = 0;
+        sut.timed_wait(|_| counter += 1, TIMEOUT).unwrap();
+        counter
+    }
+
+    #[test]
+    fn notification_wakes_up_reactor<Sut: Reactor>() {
+        let name = generate_name();
+        let listener = <PollableEvent as Event>::ListenerBuilder::new(&name)
+            .create()
+            .unwrap();
+        let notifier = <PollableEvent as Event>::NotifierBuilder::new(&name)
+            .open()
+            .unwrap();
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        assert_that!(number_of_triggers(&sut), eq 0);
+
+        notifier.notify(TriggerId::new(5)).unwrap();
+        assert_that!(number_of_triggers(&sut), eq 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(TriggerId::new(5)));
+    }
+
+    #[test]
+    fn reactor_triggers_until_all_notifications_are_acquired<Sut: Reactor>() {
+        let name = generate_name();
+        let listener = <PollableEvent as Event>::ListenerBuilder::new(&name)
+            .create()
+            .unwrap();
+        let notifier = <PollableEvent as Event>::NotifierBuilder::new(&name)
+            .open()
+            .unwrap();
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        notifier.notify(TriggerId::new(1)).unwrap();
+        notifier.notify(TriggerId::new(2)).unwrap();
+        assert_that!(number_of_triggers(&sut), eq 1);
+
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(number_of_triggers(&sut), eq 1);
+
+        assert_that!(listener.try_wait_one().unwrap(), is_some);
+        assert_that!(number_of_triggers(&sut), eq 0);
+        assert_that!(listener.try_wait_one().unwrap(), is_none);
+    }
+
+    #[test]
+    fn try_wait_all_resets_reactor_trigger<Sut: Reactor>() {
+        let name = generate_name();
+        let listener = <PollableEvent as Event>::ListenerBuilder::new(&name)
+            .create()
+            .unwrap();
+        let notifier = <PollableEvent as Event>::NotifierBuilder::new(&name)
+            .open()
+            .unwrap();
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        for i in 0..8 {
+            notifier.notify(TriggerId::new(i)).unwrap();
+        }
+        assert_that!(number_of_triggers(&sut), eq 1);
+
+        let mut ids = vec![];
+        listener.try_wait_all(|id| ids.push(id)).unwrap();
+        assert_that!(ids, len 8);
+        assert_that!(number_of_triggers(&sut), eq 0);
+    }
+
+    #[test]
+    fn dropping_listener_with_pending_notifications_works<Sut: Reactor>() {
+        let name = generate_name();
+        let listener = <PollableEvent as Event>::ListenerBuilder::new(&name)
+            .create()
+            .unwrap();
+        let notifier = <PollableEvent as Event>::NotifierBuilder::new(&name)
+            .open()
+            .unwrap();
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        {
+            let _guard = sut.attach(&listener).unwrap();
+            notifier.notify(TriggerId::new(3)).unwrap();
+            assert_that!(number_of_triggers(&sut), eq 1);
+        }
+
+        drop(listener);
+        assert_that!(sut.is_empty(), eq true);
+    }
+
+    #[test]
+    fn notifications_received_before_attaching_are_signaled_by_reactor<Sut: Reactor>() {
+        let name = generate_name();
+        let listener = <PollableEvent as Event>::ListenerBuilder::new(&name)
+            .create()
+            .unwrap();
+        let notifier = <PollableEvent as Event>::NotifierBuilder::new(&name)
+            .open()
+            .unwrap();
+
+        notifier.notify(TriggerId::new(4)).unwrap();
+        assert_that!(listener.timed_wait_one(TIMEOUT).unwrap(), eq Some(TriggerId::new(4)));
+        notifier.notify(TriggerId::new(6)).unwrap();
+
+        let sut = <<Sut as Reactor>::Builder>::new().create().unwrap();
+        let _guard = sut.attach(&listener).unwrap();
+
+        assert_that!(number_of_triggers(&sut), eq 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(TriggerId::new(6)));
+        assert_that!(number_of_triggers(&sut), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2_cal::reactor::posix_select::Reactor>)]
+    mod posix_select {}
+
+    #[instantiate_tests(<iceoryx2_cal::reactor::epoll::Reactor>)]
+    mod epoll {}
+}
@@ -43,4 +43,10 @@ mod serialize {
 
     #[instantiate_tests(<iceoryx2_cal::serialize::cdr::Cdr>)]
     mod cdr {}
+
+    #[instantiate_tests(<iceoryx2_cal::serialize::postcard::Postcard>)]
+    mod postcard {}
+
+    #[instantiate_tests(<iceoryx2_cal::serialize::bincode::Bincode>)]
+    mod bincode {}
 }
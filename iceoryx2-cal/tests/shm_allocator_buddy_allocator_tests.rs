@@ -0,0 +1,290 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod shm_allocator_buddy_allocator {
+    use std::{alloc::Layout, collections::HashSet, ptr::NonNull};
+
+    use iceoryx2_bb_container::semantic_string::SemanticString;
+    use iceoryx2_bb_elementary::allocator::AllocationError;
+    use iceoryx2_bb_elementary::math::ToB64;
+    use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_system_types::file_name::FileName;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::{
+        named_concept::NamedConceptBuilder,
+        shared_memory::{SharedMemory, SharedMemoryBuilder},
+        shm_allocator::{buddy_allocator::*, SampleAllocator, ShmAllocationError, ShmAllocator},
+        zero_copy_connection::PointerOffset,
+    };
+
+    const MAX_SUPPORTED_ALIGNMENT: usize = 4096;
+    const MIN_BLOCK_LAYOUT: Layout = unsafe { Layout::from_size_align_unchecked(32, 4) };
+    const MEM_SIZE: usize = 16384 * 10;
+    const PAYLOAD_SIZE: usize = 8192;
+
+    struct TestContext {
+        _payload_memory: Box<[u8; MEM_SIZE]>,
+        _base_address: NonNull<[u8]>,
+        sut: Box<BuddyAllocator>,
+    }
+
+    impl TestContext {
+        fn new(min_block_layout: Layout) -> Self {
+            Self::new_with_size(min_block_layout, PAYLOAD_SIZE)
+        }
+
+        fn new_with_size(min_block_layout: Layout, payload_size: usize) -> Self {
+            let mut payload_memory = Box::new([0u8; MEM_SIZE]);
+            let base_address =
+                unsafe { NonNull::<[u8]>::new_unchecked(&mut payload_memory[0..payload_size]) };
+            let allocator = BumpAllocator::new(
+                unsafe { NonNull::new_unchecked(payload_memory[PAYLOAD_SIZE..].as_mut_ptr()) },
+                MEM_SIZE,
+            );
+            let config = &Config { min_block_layout };
+            let sut = Box::new(unsafe {
+                BuddyAllocator::new_uninit(MAX_SUPPORTED_ALIGNMENT, base_address, config)
+            });
+
+            unsafe { sut.init(&allocator).unwrap() };
+
+            Self {
+                _payload_memory: payload_memory,
+                _base_address: base_address,
+                sut,
+            }
+        }
+    }
+
+    #[test]
+    fn is_setup_correctly() {
+        let test_context = TestContext::new(MIN_BLOCK_LAYOUT);
+
+        assert_that!(test_context.sut.min_block_size(), eq MIN_BLOCK_LAYOUT.size());
+        assert_that!(test_context.sut.number_of_min_blocks(), eq PAYLOAD_SIZE / MIN_BLOCK_LAYOUT.size());
+        assert_that!(test_context.sut.max_alignment(), eq MIN_BLOCK_LAYOUT.align());
+        assert_that!(test_context.sut.relative_start_address(), eq 0);
+    }
+
+    #[test]
+    fn min_block_size_is_rounded_up_to_power_of_two() {
+        let test_context = TestContext::new(Layout::from_size_align(40, 8).unwrap());
+
+        assert_that!(test_context.sut.min_block_size(), eq 64);
+    }
+
+    #[test]
+    fn allocations_are_rounded_up_to_power_of_two_blocks() {
+        let test_context = TestContext::new(MIN_BLOCK_LAYOUT);
+
+        assert_that!(test_context.sut.block_size(Layout::from_size_align(1, 1).unwrap()), eq 32);
+        assert_that!(test_context.sut.block_size(Layout::from_size_align(32, 1).unwrap()), eq 32);
+        assert_that!(test_context.sut.block_size(Layout::from_size_align(33, 1).unwrap()), eq 64);
+        assert_that!(test_context.sut.block_size(Layout::from_size_align(100, 1).unwrap()), eq 128);
+    }
+
+    #[test]
+    fn allocate_and_release_all_blocks_works() {
+        const REPETITIONS: usize = 10;
+        let test_context = TestContext::new(MIN_BLOCK_LAYOUT);
+
+        for _ in 0..REPETITIONS {
+            let mut mem_set = HashSet::new();
+            for _ in 0..test_context.sut.number_of_min_blocks() {
+                let memory = unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT).unwrap() };
+                // the returned offset must be a multiple of the block size
+                assert_that!(memory.value() % MIN_BLOCK_LAYOUT.size(), eq 0);
+                assert_that!(mem_set.insert(memory.value()), eq true);
+            }
+
+            assert_that!(unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT) }, eq Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)));
+
+            for memory in mem_set {
+                unsafe {
+                    test_context
+                        .sut
+                        .deallocate(PointerOffset::new(memory), MIN_BLOCK_LAYOUT)
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn released_blocks_are_merged_into_larger_blocks() {
+        let test_context = TestContext::new(MIN_BLOCK_LAYOUT);
+        let whole_memory = Layout::from_size_align(PAYLOAD_SIZE, 1).unwrap();
+
+        let mut offsets = vec![];
+        while let Ok(memory) = unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT) } {
+            offsets.push(memory);
+        }
+        assert_that!(unsafe { test_context.sut.allocate(whole_memory) }, is_err);
+
+        // release in an order that requires merging from both sides
+        for offset in offsets.iter().step_by(2) {
+            unsafe { test_context.sut.deallocate(*offset, MIN_BLOCK_LAYOUT) };
+        }
+        for offset in offsets.iter().skip(1).step_by(2) {
+            unsafe { test_context.sut.deallocate(*offset, MIN_BLOCK_LAYOUT) };
+        }
+
+        let memory = unsafe { test_context.sut.allocate(whole_memory) };
+        assert_that!(memory, is_ok);
+        assert_that!(memory.unwrap().value(), eq 0);
+    }
+
+    #[test]
+    fn mixed_sized_allocations_do_not_overlap() {
+        const REPETITIONS: usize = 10;
+        let test_context = TestContext::new(MIN_BLOCK_LAYOUT);
+
+        for _ in 0..REPETITIONS {
+            let mut chunks = vec![];
+            let mut n = 0;
+            loop {
+                let layout = Layout::from_size_align(1 + (n * 37) % 500, 4).unwrap();
+                match unsafe { test_context.sut.allocate(layout) } {
+                    Ok(memory) => chunks.push((memory, layout)),
+                    Err(_) => break,
+                }
+                n += 1;
+            }
+
+            let mut ranges: Vec<(usize, usize)> = chunks
+                .iter()
+                .map(|(memory, layout)| {
+                    (
+                        memory.value(),
+                        memory.value() + test_context.sut.block_size(*layout),
+                    )
+                })
+                .collect();
+            ranges.sort();
+            for window in ranges.windows(2) {
+                assert_that!(window[0].1, le window[1].0);
+            }
+            assert_that!(ranges.last().unwrap().1, le PAYLOAD_SIZE);
+
+            for (memory, layout) in chunks {
+                unsafe { test_context.sut.deallocate(memory, layout) };
+            }
+        }
+    }
+
+    #[test]
+    fn memory_with_a_size_that_is_not_a_power_of_two_is_fully_usable() {
+        const NUMBER_OF_BLOCKS: usize = 13;
+        let test_context = TestContext::new_with_size(
+            MIN_BLOCK_LAYOUT,
+            NUMBER_OF_BLOCKS * MIN_BLOCK_LAYOUT.size(),
+        );
+
+        for _ in 0..NUMBER_OF_BLOCKS {
+            assert_that!(
+                unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT) },
+                is_ok
+            );
+        }
+        assert_that!(
+            unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT) },
+            is_err
+        );
+    }
+
+    #[test]
+    fn sample_memory_size_is_sufficient_for_every_sample_with_max_layout() {
+        const NUMBER_OF_SAMPLES: usize = 7;
+        let max_sample_layout = Layout::from_size_align(200, 8).unwrap();
+        let config = BuddyAllocator::sample_config(max_sample_layout);
+        let size = BuddyAllocator::sample_memory_size(max_sample_layout, NUMBER_OF_SAMPLES);
+
+        let test_context = TestContext::new_with_size(config.min_block_layout, size);
+        for _ in 0..NUMBER_OF_SAMPLES {
+            let memory = unsafe { test_context.sut.allocate(max_sample_layout) };
+            assert_that!(memory, is_ok);
+            assert_that!(memory.unwrap().value() % BuddyAllocator::sample_granularity(max_sample_layout), eq 0);
+        }
+    }
+
+    #[test]
+    fn sample_memory_consists_of_at_most_max_number_of_chunks() {
+        const NUMBER_OF_SAMPLES: usize = 5;
+        for i in 0..20 {
+            for align in [1, 8, 128] {
+                let max_sample_layout =
+                    Layout::from_size_align(3 * 2_usize.pow(i) + 1, align).unwrap();
+                let size = BuddyAllocator::sample_memory_size(max_sample_layout, NUMBER_OF_SAMPLES);
+                let number_of_chunks = size / BuddyAllocator::sample_granularity(max_sample_layout);
+
+                assert_that!(number_of_chunks, le BuddyAllocator::max_number_of_chunks(NUMBER_OF_SAMPLES));
+            }
+        }
+    }
+
+    #[test]
+    fn allocated_memory_has_correct_alignment() {
+        for i in 0..10 {
+            let layout = Layout::from_size_align(2_usize.pow(i), 2_usize.pow(i)).unwrap();
+            let test_context = TestContext::new(layout);
+
+            let mut counter = 0;
+            while let Ok(memory) = unsafe { test_context.sut.allocate(layout) } {
+                assert_that!(memory.value() % layout.align(), eq 0);
+                counter += 1;
+            }
+
+            // just to make sure that actually samples are allocated
+            assert_that!(counter, ge 1);
+        }
+    }
+
+    #[test]
+    fn allocate_with_unsupported_alignment_fails() {
+        let test_context =
+            TestContext::new(Layout::from_size_align(MIN_BLOCK_LAYOUT.size(), 1).unwrap());
+        assert_that!(unsafe { test_context.sut.allocate(MIN_BLOCK_LAYOUT) }, eq Err(ShmAllocationError::ExceedsMaxSupportedAlignment));
+    }
+
+    #[test]
+    fn chunks_allocated_in_shared_memory_can_be_released_by_another_instance() {
+        type Sut = iceoryx2_cal::shared_memory::posix::Memory<BuddyAllocator>;
+        let mut name = FileName::new(b"test_").unwrap();
+        name.push_bytes(UniqueSystemId::new().unwrap().value().to_b64().as_bytes())
+            .unwrap();
+
+        let sut_create = <Sut as SharedMemory<BuddyAllocator>>::Builder::new(&name)
+            .size(PAYLOAD_SIZE)
+            .create(&Config {
+                min_block_layout: MIN_BLOCK_LAYOUT,
+            })
+            .unwrap();
+        let sut_open = <Sut as SharedMemory<BuddyAllocator>>::Builder::new(&name)
+            .open()
+            .unwrap();
+
+        let small_layout = Layout::from_size_align(MIN_BLOCK_LAYOUT.size(), 4).unwrap();
+        let large_layout = Layout::from_size_align(PAYLOAD_SIZE / 2, 4).unwrap();
+
+        let small_chunk = sut_create.allocate(small_layout).unwrap();
+        unsafe { (small_chunk.data_ptr as *mut u32).write(0xaffe) };
+        let address = small_chunk.offset.value() + sut_open.payload_start_address();
+        assert_that!(unsafe { *(address as *const u32) }, eq 0xaffe);
+
+        // the small chunk splits the memory, therefore only one half can be acquired
+        assert_that!(sut_create.allocate(large_layout), is_ok);
+        assert_that!(sut_create.allocate(large_layout), is_err);
+
+        unsafe { sut_open.deallocate(small_chunk.offset, small_layout) };
+        assert_that!(sut_create.allocate(large_layout), is_ok);
+    }
+}
@@ -52,6 +52,37 @@ mod used_chunk_list {
         }
     }
 
+    #[test]
+    fn used_chunk_list_insert_and_remove_out_of_bounds_fails<const CAPACITY: usize>() {
+        let sut = FixedSizeUsedChunkList::<CAPACITY>::new();
+
+        assert_that!(sut.insert(CAPACITY), eq false);
+        assert_that!(sut.remove(CAPACITY), eq false);
+        assert_that!(sut.insert(usize::MAX), eq false);
+        assert_that!(sut.pop(), eq None);
+    }
+
+    #[test]
+    fn used_chunk_list_insert_pop_works<const CAPACITY: usize>() {
+        let sut = FixedSizeUsedChunkList::<CAPACITY>::new();
+        assert_that!(sut.pop(), eq None);
+
+        for i in 0..sut.capacity() {
+            assert_that!(sut.insert(i), eq true);
+        }
+
+        let mut popped_indices = vec![false; sut.capacity()];
+        while let Some(index) = sut.pop() {
+            assert_that!(popped_indices[index], eq false);
+            popped_indices[index] = true;
+        }
+
+        for index in popped_indices {
+            assert_that!(index, eq true);
+        }
+        assert_that!(sut.remove(0), eq false);
+    }
+
     #[instantiate_tests(<1>)]
     mod capacity_1 {}
 
@@ -309,7 +309,7 @@ mod zero_copy_connection {
 
         let sample_offset = SAMPLE_SIZE * 2;
         assert_that!(
-            sut_sender.try_send(PointerOffset::new(sample_offset)),
+            sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
             is_ok
         );
         let sample = sut_receiver.receive().unwrap();
@@ -341,7 +341,7 @@ mod zero_copy_connection {
         let sample_offset = SAMPLE_SIZE * 2;
         assert_that!(sut_receiver.has_data(), eq false);
         assert_that!(
-            sut_sender.try_send(PointerOffset::new(sample_offset)),
+            sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
             is_ok
         );
 
@@ -362,12 +362,12 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
 
-        let result = sut_sender.try_send(PointerOffset::new(9));
+        let result = sut_sender.try_send(PointerOffset::new(9), SAMPLE_SIZE);
         assert_that!(result, is_err);
         assert_that!(result.err().unwrap(), eq ZeroCopySendError::ReceiveBufferFull);
     }
@@ -387,7 +387,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -395,12 +395,107 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let overflow_sample_offset = SAMPLE_SIZE * i;
             let sample_offset = SAMPLE_SIZE * (BUFFER_SIZE + i);
-            let result = sut_sender.try_send(PointerOffset::new(sample_offset));
+            let result = sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE);
             assert_that!(result, is_ok);
             assert_that!(result.ok().unwrap().unwrap().value(), eq overflow_sample_offset);
         }
     }
 
+    #[test]
+    fn overflows_are_counted_as_missed_samples<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const BUFFER_SIZE: usize = 12;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .enable_safe_overflow(true)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .buffer_size(BUFFER_SIZE)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .enable_safe_overflow(true)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        for i in 0..BUFFER_SIZE {
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(SAMPLE_SIZE * i), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+        assert_that!(sut_sender.missed_samples(), eq 0);
+        assert_that!(sut_receiver.missed_samples(), eq 0);
+
+        for i in 0..BUFFER_SIZE / 2 {
+            let sample_offset = SAMPLE_SIZE * (BUFFER_SIZE + i);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+            assert_that!(sut_sender.missed_samples(), eq i as u64 + 1);
+            assert_that!(sut_receiver.missed_samples(), eq i as u64 + 1);
+        }
+    }
+
+    #[test]
+    fn recorded_skipped_samples_are_visible_on_both_sides<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        assert_that!(sut_sender.skipped_samples(), eq 0);
+        assert_that!(sut_receiver.skipped_samples(), eq 0);
+
+        sut_sender.record_skipped_samples(3);
+        sut_sender.record_skipped_samples(0);
+        sut_sender.record_skipped_samples(4);
+
+        assert_that!(sut_sender.skipped_samples(), eq 7);
+        assert_that!(sut_receiver.skipped_samples(), eq 7);
+        assert_that!(sut_receiver.missed_samples(), eq 0);
+    }
+
+    #[test]
+    fn retired_segments_increase_the_segment_generation<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+        const NUMBER_OF_SEGMENTS: u8 = 3;
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        for segment_id in 0..NUMBER_OF_SEGMENTS {
+            assert_that!(sut_receiver.segment_generation(segment_id), eq 0);
+        }
+
+        sut_sender.retire_segment(1);
+        sut_sender.retire_segment(1);
+        sut_sender.retire_segment(2);
+        // segments that are not supported are ignored
+        sut_sender.retire_segment(NUMBER_OF_SEGMENTS);
+
+        assert_that!(sut_receiver.segment_generation(0), eq 0);
+        assert_that!(sut_receiver.segment_generation(1), eq 2);
+        assert_that!(sut_receiver.segment_generation(2), eq 1);
+        assert_that!(sut_receiver.segment_generation(NUMBER_OF_SEGMENTS), eq 0);
+    }
+
     #[test]
     fn receive_can_acquire_data_with_late_connection<Sut: ZeroCopyConnection>() {
         let name = generate_name();
@@ -416,7 +511,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -474,7 +569,7 @@ mod zero_copy_connection {
             for _ in 0..BUFFER_SIZE {
                 sample_offset += SAMPLE_SIZE;
                 assert_that!(
-                    sut_sender.try_send(PointerOffset::new(sample_offset)),
+                    sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                     is_ok
                 );
             }
@@ -544,11 +639,11 @@ mod zero_copy_connection {
             let now = Instant::now();
 
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_1)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_1), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(
-                sut_sender.blocking_send(PointerOffset::new(sample_offset_2)),
+                sut_sender.blocking_send(PointerOffset::new(sample_offset_2), SAMPLE_SIZE),
                 is_ok
             );
             assert_that!(now.elapsed(), time_at_least TIMEOUT);
@@ -577,7 +672,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * i;
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -614,7 +709,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -624,7 +719,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -661,7 +756,7 @@ mod zero_copy_connection {
         for i in 0..BUFFER_SIZE {
             let sample_offset = SAMPLE_SIZE * i;
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -705,7 +800,7 @@ mod zero_copy_connection {
             let sample_offset = SAMPLE_SIZE * (i + BUFFER_SIZE);
             offsets.insert(sample_offset);
             assert_that!(
-                sut_sender.try_send(PointerOffset::new(sample_offset)),
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
                 is_ok
             );
         }
@@ -725,6 +820,67 @@ mod zero_copy_connection {
         }
     }
 
+    #[test]
+    fn samples_in_flight_can_be_reclaimed_when_dead_receiver_is_removed<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+        for i in 0..BUFFER_SIZE {
+            let sample_offset = SAMPLE_SIZE * i;
+            offsets.insert(sample_offset);
+            assert_that!(
+                sut_sender.try_send(PointerOffset::new(sample_offset), SAMPLE_SIZE),
+                is_ok
+            );
+        }
+
+        // some samples are borrowed, one is returned and the rest remains in the buffer
+        for _ in 0..BUFFER_SIZE / 2 {
+            assert_that!(sut_receiver.receive().unwrap(), is_some);
+        }
+        sut_receiver.release(PointerOffset::new(0)).unwrap();
+
+        // the receiver is no longer used, like a receiver whose process died
+        std::mem::forget(sut_receiver);
+
+        let removed_receiver =
+            unsafe { Sut::remove_dead_receiver_cfg(&name, &Sut::Configuration::default()) };
+        assert_that!(removed_receiver, eq Ok(true));
+        assert_that!(sut_sender.is_connected(), eq false);
+
+        while let Some(offset) = sut_sender.reclaim().unwrap() {
+            assert_that!(offsets.remove(&offset.value()), eq true);
+        }
+        assert_that!(offsets, len 0);
+
+        let mut number_of_used_offsets = 0;
+        unsafe { sut_sender.acquire_used_offsets(|_| number_of_used_offsets += 1) };
+        assert_that!(number_of_used_offsets, eq 0);
+    }
+
+    #[test]
+    fn removing_dead_receiver_of_non_existing_connection_returns_false<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let removed_receiver =
+            unsafe { Sut::remove_dead_receiver_cfg(&name, &Sut::Configuration::default()) };
+        assert_that!(removed_receiver, eq Ok(false));
+    }
+
     #[test]
     fn list_connections_works<Sut: ZeroCopyConnection>() {
         let mut sut_names = vec![];
@@ -819,6 +975,125 @@ mod zero_copy_connection {
         assert_that!(unsafe {<Sut as NamedConceptMgmt>::remove_cfg(&sut_name, &config_2)}, eq Ok(false));
     }
 
+    #[test]
+    fn connection_with_different_number_of_segments_fails<Sut: ZeroCopyConnection>() {
+        let name = generate_name();
+
+        let _sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(3)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_SAMPLES)
+            .max_supported_shared_memory_segments(4)
+            .create_receiver(SAMPLE_SIZE);
+        assert_that!(sut_receiver, is_err);
+    }
+
+    #[test]
+    fn sending_offset_outside_of_the_tracked_chunks_fails<Sut: ZeroCopyConnection>() {
+        const NUMBER_OF_CHUNKS: usize = 8;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(NUMBER_OF_CHUNKS)
+            .buffer_size(NUMBER_OF_CHUNKS)
+            .max_supported_shared_memory_segments(2)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let beyond_last_chunk = PointerOffset::new(SAMPLE_SIZE * NUMBER_OF_CHUNKS);
+        let result = sut_sender.try_send(beyond_last_chunk, SAMPLE_SIZE);
+        assert_that!(result.err(), eq Some(ZeroCopySendError::ConnectionCorrupted));
+
+        let not_a_chunk_start = PointerOffset::new(SAMPLE_SIZE / 2);
+        let result = sut_sender.try_send(not_a_chunk_start, SAMPLE_SIZE);
+        assert_that!(result.err(), eq Some(ZeroCopySendError::ConnectionCorrupted));
+
+        let last_chunk_of_second_segment =
+            PointerOffset::from_offset_and_segment_id(SAMPLE_SIZE * (NUMBER_OF_CHUNKS - 1), 1);
+        assert_that!(
+            sut_sender.try_send(last_chunk_of_second_segment, SAMPLE_SIZE),
+            is_ok
+        );
+    }
+
+    #[test]
+    fn samples_from_multiple_segments_can_be_sent_and_reclaimed<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        const NUMBER_OF_SEGMENTS: u8 = 4;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(BUFFER_SIZE)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+        let sut_receiver = Sut::Builder::new(&name)
+            .number_of_samples(BUFFER_SIZE)
+            .buffer_size(BUFFER_SIZE)
+            .receiver_max_borrowed_samples(BUFFER_SIZE)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_receiver(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+        for i in 0..BUFFER_SIZE {
+            let segment_id = (i % NUMBER_OF_SEGMENTS as usize) as u8;
+            let sample_size = SAMPLE_SIZE * (segment_id as usize + 1);
+            let offset = PointerOffset::from_offset_and_segment_id(sample_size * i, segment_id);
+            offsets.insert(offset.as_value());
+            assert_that!(sut_sender.try_send(offset, sample_size), is_ok);
+        }
+
+        for _ in 0..BUFFER_SIZE {
+            let offset = sut_receiver.receive().unwrap().unwrap();
+            assert_that!(offsets.contains(&offset.as_value()), eq true);
+            sut_receiver.release(offset).unwrap();
+        }
+
+        for _ in 0..BUFFER_SIZE {
+            let offset = sut_sender.reclaim().unwrap().unwrap();
+            assert_that!(offsets.remove(&offset.as_value()), eq true);
+        }
+
+        assert_that!(offsets, len 0);
+    }
+
+    #[test]
+    fn samples_from_multiple_segments_can_be_acquired<Sut: ZeroCopyConnection>() {
+        const BUFFER_SIZE: usize = 10;
+        const NUMBER_OF_SEGMENTS: u8 = 3;
+        let name = generate_name();
+
+        let sut_sender = Sut::Builder::new(&name)
+            .number_of_samples(BUFFER_SIZE)
+            .buffer_size(BUFFER_SIZE)
+            .max_supported_shared_memory_segments(NUMBER_OF_SEGMENTS)
+            .create_sender(SAMPLE_SIZE)
+            .unwrap();
+
+        let mut offsets = HashSet::new();
+        for i in 0..BUFFER_SIZE {
+            let segment_id = (i % NUMBER_OF_SEGMENTS as usize) as u8;
+            let sample_size = SAMPLE_SIZE * (segment_id as usize + 1);
+            let offset = PointerOffset::from_offset_and_segment_id(sample_size * i, segment_id);
+            offsets.insert(offset.as_value());
+            assert_that!(sut_sender.try_send(offset, sample_size), is_ok);
+        }
+
+        unsafe {
+            sut_sender.acquire_used_offsets(|offset| {
+                assert_that!(offsets.remove(&offset.as_value()), eq true);
+            })
+        };
+        assert_that!(offsets, len 0);
+    }
+
     #[test]
     fn defaults_for_configuration_are_set_correctly<Sut: ZeroCopyConnection>() {
         let config = <Sut as NamedConceptMgmt>::Configuration::default();
@@ -10,6 +10,8 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::path::PathBuf;
+
 use clap::Args;
 use clap::Parser;
 use clap::Subcommand;
@@ -35,6 +37,13 @@ pub struct Cli {
 
     #[clap(long, short = 'f', value_enum, global = true, value_enum, default_value_t = Format::Ron)]
     pub format: Format,
+
+    #[clap(
+        long,
+        global = true,
+        help = "Path to the iceoryx2 config file [default: global config]"
+    )]
+    pub config: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Args)]
@@ -58,10 +67,77 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Args)]
+pub struct CleanupOptions {
+    #[clap(
+        long,
+        help = "Only list the dead nodes without removing their stale resources"
+    )]
+    pub dry_run: bool,
+}
+
+#[derive(Args)]
+pub struct WaitOptions {
+    #[clap(help = "Name, ID or PID of the node")]
+    pub node: NodeIdentifier,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 100,
+        help = "Interval in milliseconds in which the node state is checked"
+    )]
+    pub interval: u64,
+
+    #[clap(
+        short,
+        long,
+        help = "Timeout in milliseconds after which the command fails [default: waits until interrupted]"
+    )]
+    pub timeout: Option<u64>,
+}
+
+#[derive(Args)]
+pub struct WatchOptions {
+    #[clap(
+        short,
+        long,
+        default_value_t = 100,
+        help = "Interval in milliseconds in which the node states are checked"
+    )]
+    pub interval: u64,
+}
+
+#[derive(Args)]
+pub struct SuperviseOptions {
+    #[clap(
+        short,
+        long,
+        default_value_t = 100,
+        help = "Interval in milliseconds in which the node states are checked. A dead node is detected and cleaned up at most one interval after its death"
+    )]
+    pub interval: u64,
+
+    #[clap(
+        short,
+        long,
+        help = "Name of a publish-subscribe service on which a TOML serialized report with the node id, name and outcome is published after every cleanup"
+    )]
+    pub publish: Option<String>,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all nodes")]
     List(ListOptions),
     #[clap(about = "Show node details")]
     Details(DetailsOptions),
+    #[clap(about = "Remove the stale resources of all dead nodes")]
+    Cleanup(CleanupOptions),
+    #[clap(about = "Wait until a node is no longer alive")]
+    Wait(WaitOptions),
+    #[clap(about = "Continuously print node births and deaths")]
+    Watch(WatchOptions),
+    #[clap(about = "Remove the stale resources of every node shortly after it died")]
+    Supervise(SuperviseOptions),
 }
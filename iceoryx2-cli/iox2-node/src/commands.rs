@@ -10,20 +10,46 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use anyhow::{Context, Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use anyhow::{anyhow, Context, Error, Result};
+use iceoryx2::node::{DeadNodeView, NodeView};
 use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe_serialized::Toml;
+use iceoryx2_bb_container::semantic_string::SemanticString;
+use iceoryx2_bb_system_types::file_path::FilePath;
 use iceoryx2_cli::filter::Filter;
 use iceoryx2_cli::filter::NodeIdentifier;
+use iceoryx2_cli::output::NodeChange;
+use iceoryx2_cli::output::NodeCleanup;
+use iceoryx2_cli::output::NodeCleanupOutcome;
+use iceoryx2_cli::output::NodeCleanupReport;
 use iceoryx2_cli::output::NodeDescription;
 use iceoryx2_cli::output::NodeDescriptor;
+use iceoryx2_cli::output::NodeEvent;
 use iceoryx2_cli::output::NodeList;
 use iceoryx2_cli::Format;
 
 use crate::cli::OutputFilter;
 
-pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
+pub fn load_config(path: Option<&Path>) -> Result<Config> {
+    match path {
+        Some(path) => {
+            let file_path = FilePath::new(path.as_os_str().as_encoded_bytes()).map_err(|e| {
+                anyhow!("invalid config file path \"{}\" ({:?})", path.display(), e)
+            })?;
+            Config::from_file(&file_path)
+                .with_context(|| format!("failed to load config file \"{}\"", path.display()))
+        }
+        None => Ok(Config::global_config().clone()),
+    }
+}
+
+pub fn list(filter: OutputFilter, format: Format, config: &Config) -> Result<()> {
     let mut nodes = Vec::<NodeDescriptor>::new();
-    Node::<ipc::Service>::list(Config::global_config(), |node| {
+    Node::<ipc::Service>::list(config, |node| {
         if filter.matches(&node) {
             nodes.push(NodeDescriptor::from(&node));
         }
@@ -42,10 +68,15 @@ pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
     Ok(())
 }
 
-pub fn details(identifier: NodeIdentifier, filter: OutputFilter, format: Format) -> Result<()> {
+pub fn details(
+    identifier: NodeIdentifier,
+    filter: OutputFilter,
+    format: Format,
+    config: &Config,
+) -> Result<()> {
     let mut error: Option<Error> = None;
 
-    Node::<ipc::Service>::list(Config::global_config(), |node| {
+    Node::<ipc::Service>::list(config, |node| {
         if identifier.matches(&node) && filter.matches(&node) {
             match format.as_string(&NodeDescription::from(&node)) {
                 Ok(output) => {
@@ -62,3 +93,229 @@ pub fn details(identifier: NodeIdentifier, filter: OutputFilter, format: Format)
 
     Ok(())
 }
+
+pub fn cleanup(dry_run: bool, format: Format, config: &Config) -> Result<()> {
+    let mut dead_nodes = Vec::<DeadNodeView<ipc::Service>>::new();
+    Node::<ipc::Service>::list(config, |node| {
+        if let NodeState::Dead(view) = node {
+            dead_nodes.push(view);
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve nodes")?;
+
+    if dry_run {
+        let nodes: Vec<NodeDescriptor> = dead_nodes
+            .into_iter()
+            .map(|view| NodeDescriptor::from(&NodeState::Dead(view)))
+            .collect();
+        print!(
+            "{}",
+            format.as_string(&NodeList {
+                num: nodes.len(),
+                details: nodes
+            })?
+        );
+        return Ok(());
+    }
+
+    let mut report = NodeCleanup {
+        cleanups: Vec::new(),
+        failed_cleanups: Vec::new(),
+    };
+    for view in dead_nodes {
+        let descriptor = NodeDescriptor::from(&NodeState::Dead(view.clone()));
+        match view.remove_stale_resources() {
+            Ok(_) => report.cleanups.push(descriptor),
+            Err(_) => report.failed_cleanups.push(descriptor),
+        }
+    }
+
+    print!("{}", format.as_string(&report)?);
+
+    Ok(())
+}
+
+pub fn wait(
+    identifier: NodeIdentifier,
+    interval: Duration,
+    timeout: Option<Duration>,
+    config: &Config,
+) -> Result<()> {
+    let start = Instant::now();
+    let mut is_first_check = true;
+
+    loop {
+        let mut is_known = false;
+        let mut is_alive = false;
+        Node::<ipc::Service>::list(config, |node| {
+            if identifier.matches(&node) {
+                is_known = true;
+                is_alive |= matches!(node, NodeState::Alive(_));
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve nodes")?;
+
+        if is_first_check && !is_known {
+            return Err(anyhow!("no node matching \"{}\" exists", identifier));
+        }
+
+        if !is_alive {
+            return Ok(());
+        }
+
+        if timeout.is_some_and(|timeout| timeout <= start.elapsed()) {
+            return Err(anyhow!(
+                "node \"{}\" is still alive after the timeout",
+                identifier
+            ));
+        }
+
+        is_first_check = false;
+        std::thread::sleep(interval);
+    }
+}
+
+fn node_snapshot(config: &Config) -> Result<HashMap<u128, (bool, NodeDescriptor)>> {
+    let mut nodes = HashMap::new();
+    Node::<ipc::Service>::list(config, |node| {
+        nodes.insert(
+            node.node_id().value(),
+            (
+                matches!(node, NodeState::Dead(_)),
+                NodeDescriptor::from(&node),
+            ),
+        );
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve nodes")?;
+
+    Ok(nodes)
+}
+
+pub fn watch(interval: Duration, format: Format, config: &Config) -> Result<()> {
+    let mut known_nodes = node_snapshot(config)?;
+
+    loop {
+        std::thread::sleep(interval);
+        let current_nodes = node_snapshot(config)?;
+
+        let mut changes = Vec::new();
+        for (id, (is_dead, node)) in &current_nodes {
+            match known_nodes.get(id) {
+                None => changes.push(NodeChange {
+                    event: NodeEvent::Born,
+                    node: node.clone(),
+                }),
+                Some((false, _)) if *is_dead => changes.push(NodeChange {
+                    event: NodeEvent::Died,
+                    node: node.clone(),
+                }),
+                Some(_) => (),
+            }
+        }
+
+        for (id, (_, node)) in &known_nodes {
+            if !current_nodes.contains_key(id) {
+                changes.push(NodeChange {
+                    event: NodeEvent::Removed,
+                    node: node.clone(),
+                });
+            }
+        }
+
+        for change in changes {
+            println!("{}", format.as_string(&change)?);
+        }
+
+        known_nodes = current_nodes;
+    }
+}
+
+pub fn supervise(
+    interval: Duration,
+    publish: Option<String>,
+    format: Format,
+    config: &Config,
+) -> Result<()> {
+    // the supervisor cleans up dead nodes on its own so that every cleanup is reported
+    let mut node_config = config.clone();
+    node_config.global.node.cleanup_dead_nodes_on_creation = false;
+    node_config.global.node.cleanup_dead_nodes_on_destruction = false;
+
+    let node = NodeBuilder::new()
+        .name(&NodeName::new("iox2-node-supervise")?)
+        .config(&node_config)
+        .create::<ipc::Service>()
+        .context("failed to create node")?;
+
+    // the reports are serialized as TOML so that they can be inspected with `iox2 service echo`
+    let service = match publish {
+        Some(service_name) => Some(
+            node.service_builder(&ServiceName::new(&service_name)?)
+                .publish_subscribe_serialized::<NodeCleanupReport>()
+                .serializer::<Toml>()
+                .open_or_create()
+                .context("failed to open or create service")?,
+        ),
+        None => None,
+    };
+    let publisher = match &service {
+        Some(service) => Some(
+            service
+                .publisher_builder()
+                .create()
+                .context("failed to create publisher")?,
+        ),
+        None => None,
+    };
+
+    // failed cleanups are retried in every cycle but reported only once
+    let mut failed_cleanups = HashSet::<u128>::new();
+
+    loop {
+        let mut dead_nodes = Vec::<DeadNodeView<ipc::Service>>::new();
+        Node::<ipc::Service>::list(config, |node| {
+            if let NodeState::Dead(view) = node {
+                dead_nodes.push(view);
+            }
+            CallbackProgression::Continue
+        })
+        .context("failed to retrieve nodes")?;
+
+        for view in dead_nodes {
+            let id = view.id().value();
+            let (event, outcome) = match view.clone().remove_stale_resources() {
+                Ok(true) => {
+                    failed_cleanups.remove(&id);
+                    (NodeEvent::CleanedUp, NodeCleanupOutcome::CleanedUp)
+                }
+                // another process is already cleaning up the node
+                Ok(false) => continue,
+                Err(_) => {
+                    if !failed_cleanups.insert(id) {
+                        continue;
+                    }
+                    (NodeEvent::CleanupFailed, NodeCleanupOutcome::CleanupFailed)
+                }
+            };
+
+            let report = NodeCleanupReport::new(&view, outcome);
+            let node = NodeDescriptor::from(&NodeState::Dead(view));
+            println!("{}", format.as_string(&NodeChange { event, node })?);
+
+            if let Some(publisher) = &publisher {
+                publisher
+                    .send(&report)
+                    .context("failed to publish cleanup report")?;
+            }
+        }
+
+        // a dying process does not notify anyone, therefore the death of a node can only be
+        // detected by checking the node states and is detected at most one interval late
+        if node.wait(interval).is_err() {
+            return Ok(());
+        }
+    }
+}
@@ -14,6 +14,8 @@ mod cli;
 mod commands;
 mod filter;
 
+use std::time::Duration;
+
 use clap::CommandFactory;
 use clap::Parser;
 use cli::Action;
@@ -44,18 +46,61 @@ fn main() {
     match Cli::try_parse() {
         Ok(cli) => {
             if let Some(action) = cli.action {
+                let config = match commands::load_config(cli.config.as_deref()) {
+                    Ok(config) => config,
+                    Err(e) => {
+                        eprintln!("Failed to load config: {}", e);
+                        return;
+                    }
+                };
+
                 match action {
                     Action::List(options) => {
-                        if let Err(e) = commands::list(options.filter, cli.format) {
+                        if let Err(e) = commands::list(options.filter, cli.format, &config) {
                             eprintln!("Failed to list nodes: {}", e);
                         }
                     }
                     Action::Details(options) => {
-                        if let Err(e) = commands::details(options.node, options.filter, cli.format)
+                        if let Err(e) =
+                            commands::details(options.node, options.filter, cli.format, &config)
                         {
                             eprintln!("Failed to retrieve node details: {}", e);
                         }
                     }
+                    Action::Cleanup(options) => {
+                        if let Err(e) = commands::cleanup(options.dry_run, cli.format, &config) {
+                            eprintln!("Failed to cleanup dead nodes: {}", e);
+                        }
+                    }
+                    Action::Wait(options) => {
+                        if let Err(e) = commands::wait(
+                            options.node,
+                            Duration::from_millis(options.interval),
+                            options.timeout.map(Duration::from_millis),
+                            &config,
+                        ) {
+                            eprintln!("Failed to wait for node: {}", e);
+                        }
+                    }
+                    Action::Watch(options) => {
+                        if let Err(e) = commands::watch(
+                            Duration::from_millis(options.interval),
+                            cli.format,
+                            &config,
+                        ) {
+                            eprintln!("Failed to watch nodes: {}", e);
+                        }
+                    }
+                    Action::Supervise(options) => {
+                        if let Err(e) = commands::supervise(
+                            Duration::from_millis(options.interval),
+                            options.publish,
+                            cli.format,
+                            &config,
+                        ) {
+                            eprintln!("Failed to supervise nodes: {}", e);
+                        }
+                    }
                 }
             } else {
                 Cli::command().print_help().expect("Failed to print help");
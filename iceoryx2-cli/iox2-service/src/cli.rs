@@ -10,6 +10,9 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::path::PathBuf;
+
+use clap::ArgGroup;
 use clap::Args;
 use clap::Parser;
 use clap::Subcommand;
@@ -57,10 +60,104 @@ pub struct DetailsOptions {
     pub filter: OutputFilter,
 }
 
+#[derive(Parser)]
+pub struct StatsOptions {
+    #[clap(help = "Name of the publish-subscribe service e.g. \"My Service\"")]
+    pub service: String,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Interval in milliseconds between two samples of the statistics"
+    )]
+    pub interval: u64,
+
+    #[clap(
+        short,
+        long,
+        help = "Number of samples after which the command terminates [default: runs until interrupted]"
+    )]
+    pub count: Option<u64>,
+}
+
+#[derive(Parser)]
+pub struct EchoOptions {
+    #[clap(help = "Name of the publish-subscribe service e.g. \"My Service\"")]
+    pub service: String,
+
+    #[clap(
+        short,
+        long,
+        help = "Number of samples after which the command terminates [default: runs until interrupted]"
+    )]
+    pub count: Option<u64>,
+
+    #[clap(
+        short = 't',
+        long,
+        default_value_t = 10,
+        help = "Cycle time in milliseconds in which new samples are received"
+    )]
+    pub cycle_time: u64,
+}
+
+#[derive(Parser)]
+#[command(group(ArgGroup::new("payload").required(true).args(["payload_file", "hex"])))]
+pub struct PublishOptions {
+    #[clap(help = "Name of the publish-subscribe service e.g. \"My Service\"")]
+    pub service: String,
+
+    #[clap(long, help = "File whose content is sent as payload")]
+    pub payload_file: Option<PathBuf>,
+
+    #[clap(long, help = "Payload as hex string e.g. \"01 02 ff\"")]
+    pub hex: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Interval in milliseconds between two samples"
+    )]
+    pub interval: u64,
+
+    #[clap(short, long, default_value_t = 1, help = "Number of samples to send")]
+    pub count: u64,
+}
+
+#[derive(Parser)]
+pub struct NotifyOptions {
+    #[clap(help = "Name of the event service e.g. \"My Service\"")]
+    pub service: String,
+
+    #[clap(short, long, default_value_t = 0, help = "Event id that is notified")]
+    pub event_id: usize,
+
+    #[clap(
+        short,
+        long,
+        default_value_t = 1000,
+        help = "Interval in milliseconds between two notifications"
+    )]
+    pub interval: u64,
+
+    #[clap(short, long, default_value_t = 1, help = "Number of notifications")]
+    pub count: u64,
+}
+
 #[derive(Subcommand)]
 pub enum Action {
     #[clap(about = "List all services")]
     List(ListOptions),
     #[clap(about = "Show service details")]
     Details(DetailsOptions),
+    #[clap(about = "Periodically show the statistics of a publish-subscribe service")]
+    Stats(StatsOptions),
+    #[clap(about = "Print the samples of a publish-subscribe service")]
+    Echo(EchoOptions),
+    #[clap(about = "Send samples to a publish-subscribe service")]
+    Publish(PublishOptions),
+    #[clap(about = "Notify the listeners of an event service")]
+    Notify(NotifyOptions),
 }
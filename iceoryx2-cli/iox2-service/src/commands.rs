@@ -10,11 +10,23 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use anyhow::{Context, Error, Result};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{anyhow, Context, Error, Result};
 use iceoryx2::prelude::*;
+use iceoryx2::service::builder::publish_subscribe::CustomHeaderMarker;
+use iceoryx2::service::builder::publish_subscribe_serialized::{Serialize, Toml};
+use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
+use iceoryx2::service::static_config::message_type_details::TypeVariant;
+use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as StaticMessagingPattern;
+use iceoryx2::service::static_config::publish_subscribe::StaticConfig as PublishSubscribeStaticConfig;
 use iceoryx2_cli::filter::Filter;
+use iceoryx2_cli::output::SampleDescription;
+use iceoryx2_cli::output::SamplePayload;
 use iceoryx2_cli::output::ServiceDescription;
 use iceoryx2_cli::output::ServiceDescriptor;
+use iceoryx2_cli::output::ServiceStatistics;
 use iceoryx2_cli::Format;
 
 use crate::cli::OutputFilter;
@@ -33,7 +45,10 @@ pub fn list(filter: OutputFilter, format: Format) -> Result<()> {
     services.sort_by_key(|pattern| match pattern {
         ServiceDescriptor::PublishSubscribe(name) => (name.clone(), 0),
         ServiceDescriptor::Event(name) => (name.clone(), 1),
-        ServiceDescriptor::Undefined(name) => (name.to_string(), 2),
+        ServiceDescriptor::RequestResponse(name) => (name.clone(), 2),
+        ServiceDescriptor::Blackboard(name) => (name.clone(), 3),
+        ServiceDescriptor::Pipeline(name) => (name.clone(), 4),
+        ServiceDescriptor::Undefined(name) => (name.to_string(), 5),
     });
 
     print!("{}", format.as_string(&services)?);
@@ -66,3 +81,287 @@ pub fn details(service_name: String, filter: OutputFilter, format: Format) -> Re
     }
     Ok(())
 }
+
+fn statistics(service_name: &str) -> Result<Option<ServiceStatistics>> {
+    let mut statistics = None;
+
+    ipc::Service::list(Config::global_config(), |service| {
+        if service.static_details.name().as_str() == service_name {
+            if let Some(s) = service
+                .dynamic_details
+                .as_ref()
+                .and_then(|d| d.statistics.as_ref())
+            {
+                statistics = Some(ServiceStatistics::new(service_name, s));
+                return CallbackProgression::Stop;
+            }
+        }
+        CallbackProgression::Continue
+    })
+    .context("failed to retrieve services")?;
+
+    Ok(statistics)
+}
+
+pub fn stats(
+    service_name: String,
+    interval: Duration,
+    count: Option<u64>,
+    format: Format,
+) -> Result<()> {
+    let mut number_of_samples = 0;
+    while count.map_or(true, |count| number_of_samples < count) {
+        if number_of_samples != 0 {
+            std::thread::sleep(interval);
+        }
+
+        match statistics(&service_name)? {
+            Some(statistics) => print!("{}", format.as_string(&statistics)?),
+            None => {
+                return Err(anyhow!(
+                    "no publish-subscribe service with the name \"{}\" exists",
+                    service_name
+                ))
+            }
+        }
+        number_of_samples += 1;
+    }
+
+    Ok(())
+}
+
+type UntypedPortFactory = PortFactory<ipc::Service, [u8], CustomHeaderMarker>;
+
+/// Opens an existing publish-subscribe service without knowing its types at compile time. The
+/// type details and the serializer are taken from the static config of the service, therefore
+/// the payload and user header can only be accessed as raw bytes.
+fn open_untyped_publish_subscribe(
+    node: &Node<ipc::Service>,
+    service_name: &str,
+) -> Result<(UntypedPortFactory, PublishSubscribeStaticConfig)> {
+    let name = ServiceName::new(service_name)?;
+    let details = ipc::Service::details(
+        &name,
+        Config::global_config(),
+        MessagingPattern::PublishSubscribe,
+    )
+    .context("failed to retrieve service details")?
+    .ok_or_else(|| {
+        anyhow!(
+            "no publish-subscribe service with the name \"{}\" exists",
+            service_name
+        )
+    })?;
+
+    let static_config = match details.static_details.messaging_pattern() {
+        StaticMessagingPattern::PublishSubscribe(config) => config.clone(),
+        _ => {
+            return Err(anyhow!(
+                "\"{}\" is not a publish-subscribe service",
+                service_name
+            ))
+        }
+    };
+    let type_details = static_config.message_type_details();
+
+    // SAFETY: the type details are taken from the static config of the service itself, the
+    // samples are only accessed as raw bytes of the announced size
+    let mut builder = unsafe {
+        node.service_builder(&name)
+            .publish_subscribe::<[u8]>()
+            .user_header::<CustomHeaderMarker>()
+            .__internal_set_user_header_type_details(&type_details.user_header)
+            .__internal_set_payload_type_details(&type_details.payload)
+    };
+    if let Some(serializer) = static_config.serializer() {
+        builder = builder.__internal_set_serializer_name(serializer);
+    }
+
+    let service = builder.open().context("failed to open service")?;
+
+    Ok((service, static_config))
+}
+
+pub fn echo(
+    service_name: String,
+    cycle_time: Duration,
+    count: Option<u64>,
+    format: Format,
+) -> Result<()> {
+    let node = NodeBuilder::new()
+        .create::<ipc::Service>()
+        .context("failed to create node")?;
+    let (service, static_config) = open_untyped_publish_subscribe(&node, &service_name)?;
+    let user_header_size = static_config.message_type_details().user_header.size;
+    let is_self_describing = static_config.serializer() == Some(Toml::NAME);
+
+    let subscriber = service
+        .subscriber_builder()
+        .create()
+        .context("failed to create subscriber")?;
+
+    let mut number_of_samples = 0;
+    while count.map_or(true, |count| number_of_samples < count) {
+        match subscriber.receive().context("failed to receive sample")? {
+            Some(sample) => {
+                let payload = if is_self_describing {
+                    Toml::deserialize::<serde_json::Value>(sample.payload()).map_or_else(
+                        |_| SamplePayload::hex(sample.payload()),
+                        SamplePayload::Value,
+                    )
+                } else {
+                    SamplePayload::hex(sample.payload())
+                };
+
+                // SAFETY: the user header occupies user_header_size bytes in front of the
+                // payload as announced in the static config of the service
+                let user_header = unsafe {
+                    core::slice::from_raw_parts(
+                        (sample.user_header() as *const CustomHeaderMarker).cast::<u8>(),
+                        user_header_size,
+                    )
+                };
+
+                println!(
+                    "{}",
+                    format.as_string(&SampleDescription::new(
+                        sample.header(),
+                        user_header,
+                        payload
+                    ))?
+                );
+                number_of_samples += 1;
+            }
+            None => {
+                if node.wait(cycle_time).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_hex(value: &str) -> Result<Vec<u8>> {
+    let digits: String = value.split_whitespace().collect();
+    let digits = digits.strip_prefix("0x").unwrap_or(&digits);
+    if digits.len() % 2 != 0 {
+        return Err(anyhow!(
+            "the hex payload \"{}\" has an odd number of digits",
+            value
+        ));
+    }
+
+    (0..digits.len())
+        .step_by(2)
+        .map(|n| {
+            u8::from_str_radix(&digits[n..n + 2], 16)
+                .with_context(|| format!("the hex payload \"{}\" is invalid", value))
+        })
+        .collect()
+}
+
+pub fn publish(
+    service_name: String,
+    payload_file: Option<PathBuf>,
+    hex: Option<String>,
+    interval: Duration,
+    count: u64,
+) -> Result<()> {
+    let payload = match (payload_file, hex) {
+        (Some(path), _) => std::fs::read(&path)
+            .with_context(|| format!("failed to read payload file \"{}\"", path.display()))?,
+        (None, Some(hex)) => parse_hex(&hex)?,
+        (None, None) => return Err(anyhow!("no payload was provided")),
+    };
+
+    let node = NodeBuilder::new()
+        .create::<ipc::Service>()
+        .context("failed to create node")?;
+    let (service, static_config) = open_untyped_publish_subscribe(&node, &service_name)?;
+    let type_details = static_config.message_type_details();
+    let payload_size = type_details.payload.size;
+    let user_header_size = type_details.user_header.size;
+
+    let number_of_elements = match type_details.payload.variant {
+        TypeVariant::Dynamic if payload_size != 0 && payload.len() % payload_size == 0 => {
+            payload.len() / payload_size
+        }
+        TypeVariant::FixedSize if payload.len() == payload_size => 1,
+        TypeVariant::Dynamic => {
+            return Err(anyhow!(
+                "the payload size of {} bytes is not a multiple of the element size of {} bytes of \"{}\"",
+                payload.len(),
+                payload_size,
+                type_details.payload.type_name
+            ))
+        }
+        TypeVariant::FixedSize => {
+            return Err(anyhow!(
+                "the payload size of {} bytes does not match the size of {} bytes of \"{}\"",
+                payload.len(),
+                payload_size,
+                type_details.payload.type_name
+            ))
+        }
+    };
+
+    let publisher = service
+        .publisher_builder()
+        .max_slice_len(number_of_elements)
+        .create()
+        .context("failed to create publisher")?;
+
+    for n in 0..count {
+        if n != 0 && node.wait(interval).is_err() {
+            break;
+        }
+
+        let mut sample = publisher
+            .loan_slice_uninit(number_of_elements)
+            .context("failed to loan sample")?;
+
+        // SAFETY: the user header occupies user_header_size bytes as announced in the static
+        // config of the service and is zeroed since its content is unknown
+        unsafe {
+            (sample.user_header_mut() as *mut CustomHeaderMarker)
+                .cast::<u8>()
+                .write_bytes(0, user_header_size)
+        };
+
+        sample
+            .write_from_slice(&payload)
+            .send()
+            .context("failed to send sample")?;
+    }
+
+    Ok(())
+}
+
+pub fn notify(service_name: String, event_id: usize, interval: Duration, count: u64) -> Result<()> {
+    let node = NodeBuilder::new()
+        .create::<ipc::Service>()
+        .context("failed to create node")?;
+    let service = node
+        .service_builder(&ServiceName::new(&service_name)?)
+        .event()
+        .open()
+        .context("failed to open service")?;
+    let notifier = service
+        .notifier_builder()
+        .create()
+        .context("failed to create notifier")?;
+
+    for n in 0..count {
+        if n != 0 && node.wait(interval).is_err() {
+            break;
+        }
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(event_id))
+            .context("failed to notify")?;
+    }
+
+    Ok(())
+}
@@ -19,6 +19,8 @@ mod cli;
 mod commands;
 mod filter;
 
+use std::time::Duration;
+
 use clap::CommandFactory;
 use clap::Parser;
 use cli::Action;
@@ -57,6 +59,47 @@ fn main() {
                             eprintln!("Failed to retrieve service details: {}", e);
                         }
                     }
+                    Action::Stats(options) => {
+                        if let Err(e) = commands::stats(
+                            options.service,
+                            Duration::from_millis(options.interval),
+                            options.count,
+                            cli.format,
+                        ) {
+                            eprintln!("Failed to retrieve service statistics: {}", e);
+                        }
+                    }
+                    Action::Echo(options) => {
+                        if let Err(e) = commands::echo(
+                            options.service,
+                            Duration::from_millis(options.cycle_time),
+                            options.count,
+                            cli.format,
+                        ) {
+                            eprintln!("Failed to echo service samples: {}", e);
+                        }
+                    }
+                    Action::Publish(options) => {
+                        if let Err(e) = commands::publish(
+                            options.service,
+                            options.payload_file,
+                            options.hex,
+                            Duration::from_millis(options.interval),
+                            options.count,
+                        ) {
+                            eprintln!("Failed to publish samples: {}", e);
+                        }
+                    }
+                    Action::Notify(options) => {
+                        if let Err(e) = commands::notify(
+                            options.service,
+                            options.event_id,
+                            Duration::from_millis(options.interval),
+                            options.count,
+                        ) {
+                            eprintln!("Failed to notify event: {}", e);
+                        }
+                    }
                 }
             } else {
                 Cli::command().print_help().expect("Failed to print help");
@@ -18,6 +18,7 @@ use iceoryx2::service::ipc::Service;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern;
 use iceoryx2::service::ServiceDetails;
 use iceoryx2_pal_posix::posix::pid_t;
+use std::fmt;
 use std::fmt::Debug;
 use std::str::FromStr;
 
@@ -38,6 +39,16 @@ impl NodeIdentifier {
     }
 }
 
+impl fmt::Display for NodeIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NodeIdentifier::Name(name) => write!(f, "{}", name),
+            NodeIdentifier::Id(id) => write!(f, "{}", id),
+            NodeIdentifier::Pid(pid) => write!(f, "{}", pid),
+        }
+    }
+}
+
 impl FromStr for NodeIdentifier {
     type Err = String;
 
@@ -115,6 +126,9 @@ impl Filter<NodeState<Service>> for StateFilter {
 pub enum MessagingPatternFilter {
     PublishSubscribe,
     Event,
+    RequestResponse,
+    Blackboard,
+    Pipeline,
     #[default]
     All,
 }
@@ -127,6 +141,18 @@ impl Filter<ServiceDetails<Service>> for MessagingPatternFilter {
                 MessagingPatternFilter::PublishSubscribe,
                 MessagingPattern::PublishSubscribe(_)
             ) | (MessagingPatternFilter::Event, MessagingPattern::Event(_))
+                | (
+                    MessagingPatternFilter::RequestResponse,
+                    MessagingPattern::RequestResponse(_)
+                )
+                | (
+                    MessagingPatternFilter::Blackboard,
+                    MessagingPattern::Blackboard(_)
+                )
+                | (
+                    MessagingPatternFilter::Pipeline,
+                    MessagingPattern::Pipeline(_)
+                )
                 | (MessagingPatternFilter::All, _)
         )
     }
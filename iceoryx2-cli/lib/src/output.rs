@@ -11,12 +11,17 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 use std::ops::Deref;
+use std::time::Duration;
 
 use iceoryx2::node::NodeDetails as IceoryxNodeDetails;
 use iceoryx2::node::NodeId as IceoryxNodeId;
 use iceoryx2::node::NodeState as IceoryxNodeState;
 use iceoryx2::node::NodeView as IceoryxNodeView;
 use iceoryx2::service::attribute::AttributeSet as IceoryxAttributeSet;
+use iceoryx2::service::dynamic_config::publish_subscribe::PublisherStatistics as IceoryxPublisherStatistics;
+use iceoryx2::service::dynamic_config::publish_subscribe::Statistics as IceoryxStatistics;
+use iceoryx2::service::dynamic_config::publish_subscribe::SubscriberStatistics as IceoryxSubscriberStatistics;
+use iceoryx2::service::header::publish_subscribe::Header as IceoryxHeader;
 use iceoryx2::service::static_config::messaging_pattern::MessagingPattern as IceoryxMessagingPattern;
 use iceoryx2::service::Service as IceoryxService;
 use iceoryx2::service::ServiceDetails as IceoryxServiceDetails;
@@ -27,6 +32,9 @@ use iceoryx2_pal_posix::posix::pid_t;
 pub enum ServiceDescriptor {
     PublishSubscribe(String),
     Event(String),
+    RequestResponse(String),
+    Blackboard(String),
+    Pipeline(String),
     Undefined(String),
 }
 
@@ -42,6 +50,15 @@ where
             IceoryxMessagingPattern::Event(_) => {
                 ServiceDescriptor::Event(service.static_details.name().to_string())
             }
+            IceoryxMessagingPattern::RequestResponse(_) => {
+                ServiceDescriptor::RequestResponse(service.static_details.name().to_string())
+            }
+            IceoryxMessagingPattern::Blackboard(_) => {
+                ServiceDescriptor::Blackboard(service.static_details.name().to_string())
+            }
+            IceoryxMessagingPattern::Pipeline(_) => {
+                ServiceDescriptor::Pipeline(service.static_details.name().to_string())
+            }
             _ => ServiceDescriptor::Undefined("Undefined".to_string()),
         }
     }
@@ -108,7 +125,7 @@ impl PartialEq<&str> for NodeIdString {
     }
 }
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub enum NodeState {
     Alive,
     Dead,
@@ -116,7 +133,7 @@ pub enum NodeState {
     Undefined,
 }
 
-#[derive(serde::Serialize)]
+#[derive(Clone, serde::Serialize)]
 pub struct NodeDescriptor {
     state: NodeState,
     id: NodeIdString,
@@ -235,3 +252,184 @@ where
         }
     }
 }
+
+#[derive(serde::Serialize)]
+pub struct NodeCleanup {
+    pub cleanups: Vec<NodeDescriptor>,
+    pub failed_cleanups: Vec<NodeDescriptor>,
+}
+
+#[derive(serde::Serialize)]
+pub enum NodeEvent {
+    Born,
+    Died,
+    Removed,
+    CleanedUp,
+    CleanupFailed,
+}
+
+#[derive(serde::Serialize)]
+pub struct NodeChange {
+    pub event: NodeEvent,
+    pub node: NodeDescriptor,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum NodeCleanupOutcome {
+    CleanedUp,
+    CleanupFailed,
+}
+
+/// Payload that is published by `iox2 node supervise` after the cleanup of a dead node.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct NodeCleanupReport {
+    pub id: String,
+    pub pid: pid_t,
+    pub executable: Option<String>,
+    pub name: Option<String>,
+    pub outcome: NodeCleanupOutcome,
+}
+
+impl NodeCleanupReport {
+    pub fn new(view: &impl IceoryxNodeView, outcome: NodeCleanupOutcome) -> Self {
+        NodeCleanupReport {
+            id: NodeIdString::from(view.id()).0,
+            pid: view.id().pid().value(),
+            executable: view
+                .details()
+                .as_ref()
+                .map(|details| details.executable().to_string()),
+            name: view
+                .details()
+                .as_ref()
+                .map(|details| details.name().as_str().to_string()),
+            outcome,
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct PublisherStatistics {
+    id: String,
+    node: NodeIdString,
+    sent_samples: u64,
+    overflowed_samples: u64,
+    loan_failures: u64,
+    blocked_send_time: Duration,
+}
+
+impl From<&IceoryxPublisherStatistics> for PublisherStatistics {
+    fn from(statistics: &IceoryxPublisherStatistics) -> Self {
+        PublisherStatistics {
+            id: format!("{:032x}", statistics.publisher_id().value()),
+            node: NodeIdString::from(statistics.node_id()),
+            sent_samples: statistics.sent_samples(),
+            overflowed_samples: statistics.overflowed_samples(),
+            loan_failures: statistics.loan_failures(),
+            blocked_send_time: statistics.blocked_send_time(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct SubscriberStatistics {
+    id: String,
+    node: NodeIdString,
+    buffer_size: usize,
+    pending_samples: u64,
+    delivered_samples: u64,
+    received_samples: u64,
+    overflowed_samples: u64,
+}
+
+impl From<&IceoryxSubscriberStatistics> for SubscriberStatistics {
+    fn from(statistics: &IceoryxSubscriberStatistics) -> Self {
+        SubscriberStatistics {
+            id: format!("{:032x}", statistics.subscriber_id().value()),
+            node: NodeIdString::from(statistics.node_id()),
+            buffer_size: statistics.buffer_size(),
+            pending_samples: statistics.pending_samples(),
+            delivered_samples: statistics.delivered_samples(),
+            received_samples: statistics.received_samples(),
+            overflowed_samples: statistics.overflowed_samples(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ServiceStatistics {
+    pub service_name: String,
+    pub sent_samples: u64,
+    pub received_samples: u64,
+    pub overflowed_samples: u64,
+    pub publishers: Vec<PublisherStatistics>,
+    pub subscribers: Vec<SubscriberStatistics>,
+}
+
+impl ServiceStatistics {
+    pub fn new(service_name: &str, statistics: &IceoryxStatistics) -> Self {
+        ServiceStatistics {
+            service_name: service_name.to_string(),
+            sent_samples: statistics.sent_samples(),
+            received_samples: statistics.received_samples(),
+            overflowed_samples: statistics.overflowed_samples(),
+            publishers: statistics
+                .publishers()
+                .iter()
+                .map(PublisherStatistics::from)
+                .collect(),
+            subscribers: statistics
+                .subscribers()
+                .iter()
+                .map(SubscriberStatistics::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+pub enum SamplePayload {
+    Hex(String),
+    Value(serde_json::Value),
+}
+
+impl SamplePayload {
+    pub fn hex(bytes: &[u8]) -> Self {
+        SamplePayload::Hex(hex_dump(bytes))
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct SampleDescription {
+    pub publisher_id: String,
+    pub sequence_number: u64,
+    pub timestamp: Duration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_header: Option<String>,
+    pub payload: SamplePayload,
+}
+
+impl SampleDescription {
+    pub fn new(header: &IceoryxHeader, user_header: &[u8], payload: SamplePayload) -> Self {
+        SampleDescription {
+            publisher_id: format!("{:032x}", header.publisher_id().value()),
+            sequence_number: header.sequence_number(),
+            timestamp: header.timestamp().as_duration(),
+            user_header: if user_header.is_empty() {
+                None
+            } else {
+                Some(hex_dump(user_header))
+            },
+            payload,
+        }
+    }
+}
+
+fn hex_dump(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -18,6 +18,7 @@
 #include "iox2/callback_progression.hpp"
 #include "iox2/config_creation_error.hpp"
 #include "iox2/connection_failure.hpp"
+#include "iox2/heartbeat_enums.hpp"
 #include "iox2/iceoryx2.h"
 #include "iox2/listener_error.hpp"
 #include "iox2/log_level.hpp"
@@ -151,6 +152,12 @@ constexpr auto from<iox2::MessagingPattern, iox2_messaging_pattern_e>(const iox2
         return iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE;
     case iox2::MessagingPattern::Event:
         return iox2_messaging_pattern_e_EVENT;
+    case iox2::MessagingPattern::RequestResponse:
+        return iox2_messaging_pattern_e_REQUEST_RESPONSE;
+    case iox2::MessagingPattern::Blackboard:
+        return iox2_messaging_pattern_e_BLACKBOARD;
+    case iox2::MessagingPattern::Pipeline:
+        return iox2_messaging_pattern_e_PIPELINE;
     }
 
     IOX_UNREACHABLE();
@@ -326,6 +333,12 @@ constexpr auto from<int, iox2::PublishSubscribeOpenOrCreateError>(const int valu
         return iox2::PublishSubscribeOpenOrCreateError::OpenExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenOrCreateError::OpenIsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleDeadline;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIVELINESS_LEASE:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleLivelinessLease;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SERIALIZER:
+        return iox2::PublishSubscribeOpenOrCreateError::OpenIncompatibleSerializer;
 
     case iox2_pub_sub_open_or_create_error_e_C_SERVICE_IN_CORRUPTED_STATE:
         return iox2::PublishSubscribeOpenOrCreateError::CreateServiceInCorruptedState;
@@ -386,6 +399,12 @@ constexpr auto from<int, iox2::PublishSubscribeOpenError>(const int value) noexc
         return iox2::PublishSubscribeOpenError::ExceedsMaxNumberOfNodes;
     case iox2_pub_sub_open_or_create_error_e_O_IS_MARKED_FOR_DESTRUCTION:
         return iox2::PublishSubscribeOpenError::IsMarkedForDestruction;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_DEADLINE:
+        return iox2::PublishSubscribeOpenError::IncompatibleDeadline;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_LIVELINESS_LEASE:
+        return iox2::PublishSubscribeOpenError::IncompatibleLivelinessLease;
+    case iox2_pub_sub_open_or_create_error_e_O_INCOMPATIBLE_SERIALIZER:
+        return iox2::PublishSubscribeOpenError::IncompatibleSerializer;
     default:
         IOX_UNREACHABLE();
     }
@@ -488,6 +507,8 @@ constexpr auto from<int, iox2::SubscriberCreateError>(const int value) noexcept
         return iox2::SubscriberCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService;
     case iox2_subscriber_create_error_e_EXCEEDS_MAX_SUPPORTED_SUBSCRIBERS:
         return iox2::SubscriberCreateError::ExceedsMaxSupportedSubscribers;
+    case iox2_subscriber_create_error_e_SAMPLE_FILTER_EXCEEDS_USER_HEADER:
+        return iox2::SubscriberCreateError::SampleFilterExceedsUserHeader;
     }
 
     IOX_UNREACHABLE();
@@ -582,6 +603,12 @@ constexpr auto from<int, iox2::MessagingPattern>(const int value) noexcept -> io
         return iox2::MessagingPattern::Event;
     case iox2_messaging_pattern_e_PUBLISH_SUBSCRIBE:
         return iox2::MessagingPattern::PublishSubscribe;
+    case iox2_messaging_pattern_e_REQUEST_RESPONSE:
+        return iox2::MessagingPattern::RequestResponse;
+    case iox2_messaging_pattern_e_BLACKBOARD:
+        return iox2::MessagingPattern::Blackboard;
+    case iox2_messaging_pattern_e_PIPELINE:
+        return iox2::MessagingPattern::Pipeline;
     }
 
     IOX_UNREACHABLE();
@@ -743,6 +770,41 @@ constexpr auto from<int, iox2::WaitSetRunError>(const int value) noexcept -> iox
     IOX_UNREACHABLE();
 }
 
+template <>
+constexpr auto from<int, iox2::HeartbeatState>(const int value) noexcept -> iox2::HeartbeatState {
+    const auto variant = static_cast<iox2_heartbeat_state_e>(value);
+    switch (variant) {
+    case iox2_heartbeat_state_e_ALIVE:
+        return iox2::HeartbeatState::Alive;
+    case iox2_heartbeat_state_e_UNRESPONSIVE:
+        return iox2::HeartbeatState::Unresponsive;
+    case iox2_heartbeat_state_e_DEAD:
+        return iox2::HeartbeatState::Dead;
+    case iox2_heartbeat_state_e_REMOVED:
+        return iox2::HeartbeatState::Removed;
+    }
+
+    IOX_UNREACHABLE();
+}
+
+template <>
+constexpr auto from<int, iox2::HeartbeatMonitorCreateError>(const int value) noexcept
+    -> iox2::HeartbeatMonitorCreateError {
+    const auto error = static_cast<iox2_heartbeat_monitor_create_error_e>(value);
+    switch (error) {
+    case iox2_heartbeat_monitor_create_error_e_HEARTBEATS_NOT_ENABLED:
+        return iox2::HeartbeatMonitorCreateError::HeartbeatsNotEnabled;
+    case iox2_heartbeat_monitor_create_error_e_INSUFFICIENT_PERMISSIONS:
+        return iox2::HeartbeatMonitorCreateError::InsufficientPermissions;
+    case iox2_heartbeat_monitor_create_error_e_EXCEEDS_MAX_SUPPORTED_MONITORS:
+        return iox2::HeartbeatMonitorCreateError::ExceedsMaxSupportedMonitors;
+    case iox2_heartbeat_monitor_create_error_e_INTERNAL_FAILURE:
+        return iox2::HeartbeatMonitorCreateError::InternalFailure;
+    }
+
+    IOX_UNREACHABLE();
+}
+
 } // namespace iox
 
 #endif
@@ -22,6 +22,8 @@ class FileDescriptorView {
   private:
     template <ServiceType>
     friend class WaitSet;
+    template <ServiceType>
+    friend class HeartbeatMonitor;
     friend class FileDescriptor;
 
     explicit FileDescriptorView(iox2_file_descriptor_ptr handle);
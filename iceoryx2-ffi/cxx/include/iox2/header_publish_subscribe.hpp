@@ -13,6 +13,7 @@
 #ifndef IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 #define IOX2_HEADER_PUBLISH_SUBSCRIBE_HPP
 
+#include "iox/duration.hpp"
 #include "iox/layout.hpp"
 #include "iox2/internal/iceoryx2.hpp"
 #include "unique_port_id.hpp"
@@ -33,6 +34,13 @@ class HeaderPublishSubscribe {
     /// Returns the [`Layout`] of the corresponding payload.
     auto payload_type_layout() const -> iox::Layout;
 
+    /// Returns the sequence number of the sample. Every [`Publisher`] numbers
+    /// its sent samples consecutively, starting with 0.
+    auto sequence_number() const -> uint64_t;
+
+    /// Returns the monotonic timestamp at which the sample was sent.
+    auto timestamp() const -> iox::units::Duration;
+
   private:
     template <ServiceType, typename, typename>
     friend class Sample;
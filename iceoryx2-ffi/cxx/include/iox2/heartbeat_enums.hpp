@@ -0,0 +1,47 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_HEARTBEAT_ENUMS_HPP
+#define IOX2_HEARTBEAT_ENUMS_HPP
+
+#include <cstdint>
+
+namespace iox2 {
+/// The state of a monitored [`Node`] reported by [`HeartbeatMonitor::state()`].
+enum class HeartbeatState : uint8_t {
+    /// The last heartbeat was received within the timeout.
+    Alive,
+    /// No heartbeat was received within the timeout but the process of the [`Node`] is still
+    /// running, it is most likely hung.
+    Unresponsive,
+    /// No heartbeat was received within the timeout and the process of the [`Node`] died.
+    Dead,
+    /// The [`Node`] does not exist anymore, either it was shut down or the stale resources were
+    /// removed after its death.
+    Removed
+};
+
+/// Defines the failures that can occur when calling [`Node::heartbeat_monitor()`].
+enum class HeartbeatMonitorCreateError : uint8_t {
+    /// The monitored [`Node`] does not exist or was not created with heartbeats enabled.
+    HeartbeatsNotEnabled,
+    /// The process has insufficient permissions to open the heartbeat service of the
+    /// monitored [`Node`].
+    InsufficientPermissions,
+    /// The heartbeat service of the monitored [`Node`] does not support any more monitors.
+    ExceedsMaxSupportedMonitors,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure
+};
+} // namespace iox2
+
+#endif
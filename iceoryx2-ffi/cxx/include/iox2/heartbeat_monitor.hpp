@@ -0,0 +1,68 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_HEARTBEAT_MONITOR_HPP
+#define IOX2_HEARTBEAT_MONITOR_HPP
+
+#include "iox/duration.hpp"
+#include "iox2/file_descriptor.hpp"
+#include "iox2/heartbeat_enums.hpp"
+#include "iox2/internal/iceoryx2.hpp"
+#include "iox2/node_id.hpp"
+#include "iox2/service_type.hpp"
+
+namespace iox2 {
+/// Monitors the heartbeats of a [`Node`] and reports its [`HeartbeatState`]. Every received
+/// heartbeat wakes up a [`WaitSet`] the [`HeartbeatMonitor::file_descriptor()`] is attached to.
+///
+/// Can be created via [`Node::heartbeat_monitor()`].
+template <ServiceType>
+class HeartbeatMonitor {
+  public:
+    HeartbeatMonitor(HeartbeatMonitor&&) noexcept;
+    auto operator=(HeartbeatMonitor&&) noexcept -> HeartbeatMonitor&;
+    ~HeartbeatMonitor();
+
+    HeartbeatMonitor(const HeartbeatMonitor&) = delete;
+    auto operator=(const HeartbeatMonitor&) -> HeartbeatMonitor& = delete;
+
+    /// Returns the [`NodeId`] of the monitored [`Node`].
+    auto node_id() const -> NodeId;
+
+    /// Returns the timeout after which a [`Node`] without a heartbeat is no longer
+    /// [`HeartbeatState::Alive`].
+    auto timeout() const -> iox::units::Duration;
+
+    /// Collects all received heartbeats and returns the current [`HeartbeatState`] of the
+    /// monitored [`Node`].
+    auto state() -> HeartbeatState;
+
+    /// Returns a [`FileDescriptorView`] that can be attached to a [`WaitSet`], ideally with
+    /// [`WaitSet::attach_deadline()`] and the [`HeartbeatMonitor::timeout()`].
+    ///
+    /// # Safety
+    ///
+    /// * The [`HeartbeatMonitor`] must life at least as long as the returned [`FileDescriptorView`].
+    auto file_descriptor() const -> FileDescriptorView;
+
+  private:
+    template <ServiceType>
+    friend class Node;
+
+    explicit HeartbeatMonitor(iox2_heartbeat_monitor_h handle);
+    void drop();
+
+    iox2_heartbeat_monitor_h m_handle = nullptr;
+};
+} // namespace iox2
+
+#endif
@@ -0,0 +1,64 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#ifndef IOX2_INTERNAL_TYPE_NAME_HPP
+#define IOX2_INTERNAL_TYPE_NAME_HPP
+
+#include <cstdint>
+#include <type_traits>
+#include <typeinfo>
+
+namespace iox2::internal {
+template <typename T, typename = void>
+struct HasTypeName : std::false_type { };
+
+template <typename T>
+struct HasTypeName<T, std::void_t<decltype(T::IOX2_TYPE_NAME)>> : std::true_type { };
+
+/// Returns the name that identifies the type across languages. It is either the user defined
+/// `static constexpr const char* IOX2_TYPE_NAME` member of the type, the name of the
+/// corresponding Rust type for primitives or the output of `typeid(T).name()`.
+template <typename T>
+auto get_type_name() -> const char* {
+    if constexpr (HasTypeName<T>::value) {
+        return T::IOX2_TYPE_NAME;
+    } else if constexpr (std::is_void_v<T>) {
+        return "()";
+    } else if constexpr (std::is_same_v<T, bool>) {
+        return "bool";
+    } else if constexpr (std::is_same_v<T, uint8_t>) {
+        return "u8";
+    } else if constexpr (std::is_same_v<T, uint16_t>) {
+        return "u16";
+    } else if constexpr (std::is_same_v<T, uint32_t>) {
+        return "u32";
+    } else if constexpr (std::is_same_v<T, uint64_t>) {
+        return "u64";
+    } else if constexpr (std::is_same_v<T, int8_t>) {
+        return "i8";
+    } else if constexpr (std::is_same_v<T, int16_t>) {
+        return "i16";
+    } else if constexpr (std::is_same_v<T, int32_t>) {
+        return "i32";
+    } else if constexpr (std::is_same_v<T, int64_t>) {
+        return "i64";
+    } else if constexpr (std::is_same_v<T, float>) {
+        return "f32";
+    } else if constexpr (std::is_same_v<T, double>) {
+        return "f64";
+    } else {
+        return typeid(T).name();
+    }
+}
+} // namespace iox2::internal
+
+#endif
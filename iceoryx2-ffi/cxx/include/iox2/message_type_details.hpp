@@ -25,7 +25,8 @@ class TypeDetail {
     /// The [`TypeVariant`] of the type
     auto variant() const -> TypeVariant;
 
-    /// Contains the output of [`typeid().name`].
+    /// Contains the name that identifies the type, either the `IOX2_TYPE_NAME` member of the
+    /// type, the Rust name of a primitive type or the output of [`typeid().name`].
     auto type_name() const -> const char*;
 
     /// The size of the underlying type.
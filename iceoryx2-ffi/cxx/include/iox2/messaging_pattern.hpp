@@ -18,19 +18,26 @@
 
 namespace iox2 {
 enum class MessagingPattern : uint8_t {
-    /// Unidirectional communication pattern where the
-    /// [`Publisher`](crate::port::publisher::Publisher) sends arbitrary data to
-    /// the
-    /// [`Subscriber`](crate::port::subscriber::Subscriber)
+    /// Unidirectional communication pattern where the [`Publisher`] sends
+    /// arbitrary data to the [`Subscriber`]
     PublishSubscribe = 0,
 
-    /// Unidirectional communication pattern where the
-    /// [`Notifier`](crate::port::notifier::Notifier)
-    /// sends signals/events to the
-    /// [`Listener`](crate::port::listener::Listener) which has the
-    /// ability to sleep until a signal/event arrives.
-    /// Building block to realize push-notifications.
+    /// Unidirectional communication pattern where the [`Notifier`] sends
+    /// signals/events to the [`Listener`] which has the ability to sleep until
+    /// a signal/event arrives. Building block to realize push-notifications.
     Event,
+
+    /// Bidirectional communication pattern where the [`Client`] sends requests
+    /// to the [`Server`] which responds with an arbitrary number of responses.
+    RequestResponse,
+
+    /// Key-value store where the [`Writer`] updates the values and the
+    /// [`Reader`]s always read the latest value of an entry.
+    Blackboard,
+
+    /// Work-queue where every work item of a [`Producer`] is delivered to
+    /// exactly one of the connected [`Consumer`]s.
+    Pipeline,
 };
 } // namespace iox2
 
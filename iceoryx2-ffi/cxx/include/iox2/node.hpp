@@ -20,6 +20,7 @@
 #include "iox/function.hpp"
 #include "iox2/callback_progression.hpp"
 #include "iox2/config.hpp"
+#include "iox2/heartbeat_monitor.hpp"
 #include "iox2/internal/iceoryx2.hpp"
 #include "iox2/node_id.hpp"
 #include "iox2/node_name.hpp"
@@ -55,8 +56,21 @@ class Node {
 
     /// Waits a given time for a [`WaitEvent`]. If the [`cycle_time`] has passed
     /// [`WaitEvent::Tick`] is returned.
+    /// When the [`Node`] was created with [`NodeBuilder::heartbeat_interval()`] the heartbeats
+    /// are sent while waiting.
     auto wait(iox::units::Duration cycle_time) const -> iox::expected<void, NodeWaitFailure>;
 
+    /// Sends a heartbeat when the [`Node`] was created with
+    /// [`NodeBuilder::heartbeat_interval()`], otherwise it does nothing. [`Node::wait()`] sends
+    /// the heartbeats automatically, this is only required when the [`Node`] uses a different
+    /// mechanism to wait.
+    void send_heartbeat() const;
+
+    /// Creates a [`HeartbeatMonitor`] for the [`Node`] with the provided [`NodeId`]. The
+    /// monitored [`Node`] must be created with [`NodeBuilder::heartbeat_interval()`].
+    auto heartbeat_monitor(const NodeId& node_id, iox::units::Duration timeout) const
+        -> iox::expected<HeartbeatMonitor<T>, HeartbeatMonitorCreateError>;
+
     /// Lists all [`Node`]s under a provided config. The provided callback is
     /// called for every [`Node`] and gets the [`NodeState`] as input argument.
     /// The callback can return [`CallbackProgression::Stop`] if the iteration
@@ -84,6 +98,11 @@ class NodeBuilder {
     /// is specified the [`Config::global_config()`] is used.
     IOX_BUILDER_OPTIONAL(Config, config);
 
+    /// Enables the heartbeats of the [`Node`]. The [`Node`] sends a heartbeat at least every
+    /// interval while it is in [`Node::wait()`] so that other [`Node`]s can monitor it with a
+    /// [`HeartbeatMonitor`].
+    IOX_BUILDER_OPTIONAL(iox::units::Duration, heartbeat_interval);
+
   public:
     NodeBuilder();
     NodeBuilder(NodeBuilder&&) = default;
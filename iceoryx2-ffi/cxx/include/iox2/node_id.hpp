@@ -13,8 +13,46 @@
 #ifndef IOX2_NODE_ID_HPP
 #define IOX2_NODE_ID_HPP
 
+#include "iox2/internal/iceoryx2.hpp"
+
+#include <cstdint>
+
 namespace iox2 {
-class NodeId { };
+/// The system-wide unique id of a [`Node`]. A default constructed [`NodeId`] is empty and
+/// does not refer to any [`Node`].
+class NodeId {
+  public:
+    NodeId() = default;
+    NodeId(const NodeId& rhs);
+    NodeId(NodeId&& rhs) noexcept;
+    auto operator=(const NodeId& rhs) -> NodeId&;
+    auto operator=(NodeId&& rhs) noexcept -> NodeId&;
+    ~NodeId();
+
+    /// Returns the process id of the process that owns the [`Node`].
+    auto pid() const -> int32_t;
+
+  private:
+    template <ServiceType>
+    friend class Node;
+    template <ServiceType>
+    friend class HeartbeatMonitor;
+    template <ServiceType>
+    friend auto list_callback(iox2_node_state_e,
+                              iox2_node_id_ptr,
+                              iox2_node_name_ptr,
+                              iox2_config_ptr,
+                              iox2_callback_context) -> iox2_callback_progression_e;
+    friend auto operator==(const NodeId&, const NodeId&) -> bool;
+
+    explicit NodeId(iox2_node_id_ptr ptr);
+    auto as_ptr() const -> iox2_node_id_ptr;
+    void drop();
+
+    iox2_node_id_h m_handle = nullptr;
+};
+
+auto operator==(const NodeId& lhs, const NodeId& rhs) -> bool;
 } // namespace iox2
 
 #endif
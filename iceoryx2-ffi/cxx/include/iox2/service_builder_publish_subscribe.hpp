@@ -19,11 +19,11 @@
 #include "iox2/attribute_specifier.hpp"
 #include "iox2/attribute_verifier.hpp"
 #include "iox2/internal/iceoryx2.hpp"
+#include "iox2/internal/type_name.hpp"
 #include "iox2/port_factory_publish_subscribe.hpp"
 #include "iox2/service_builder_publish_subscribe_error.hpp"
 #include "iox2/service_type.hpp"
 
-#include <typeinfo>
 
 namespace iox2 {
 /// Builder to create new [`MessagingPattern::PublishSubscribe`] based [`Service`]s
@@ -138,7 +138,7 @@ inline void ServiceBuilderPublishSubscribe<Payload, UserHeader, S>::set_paramete
     m_max_nodes.and_then([&](auto value) { iox2_service_builder_pub_sub_set_max_nodes(&m_handle, value); });
 
     // payload type details
-    const auto* payload_type_name = typeid(Payload).name();
+    const auto* payload_type_name = internal::get_type_name<Payload>();
     const auto payload_type_name_len = strlen(payload_type_name);
     const auto payload_type_size = sizeof(Payload);
     const auto payload_type_align = alignof(Payload);
@@ -156,7 +156,7 @@ inline void ServiceBuilderPublishSubscribe<Payload, UserHeader, S>::set_paramete
 
     // user header type details
     const auto header_layout = iox::Layout::from<UserHeader>();
-    const auto* user_header_type_name = typeid(UserHeader).name();
+    const auto* user_header_type_name = internal::get_type_name<UserHeader>();
     const auto user_header_type_name_len = strlen(user_header_type_name);
     const auto user_header_type_size = header_layout.size();
     const auto user_header_type_align = header_layout.alignment();
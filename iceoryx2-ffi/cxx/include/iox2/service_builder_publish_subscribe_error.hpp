@@ -66,6 +66,15 @@ enum class PublishSubscribeOpenError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     IsMarkedForDestruction,
+    /// The [`Service`] has a different publish deadline or deadline missed
+    /// event than requested.
+    IncompatibleDeadline,
+    /// The [`Service`] has a different liveliness lease or liveliness lost
+    /// event than requested.
+    IncompatibleLivelinessLease,
+    /// The [`Service`] transfers its payload with a different serializer than
+    /// requested.
+    IncompatibleSerializer,
 };
 
 /// Errors that can occur when a new [`MessagingPattern::PublishSubscribe`] [`Service`] shall be created.
@@ -151,6 +160,15 @@ enum class PublishSubscribeOpenOrCreateError : uint8_t {
     /// [`Service`] should be
     /// recreatable.
     OpenIsMarkedForDestruction,
+    /// The [`Service`] has a different publish deadline or deadline missed
+    /// event than requested.
+    OpenIncompatibleDeadline,
+    /// The [`Service`] has a different liveliness lease or liveliness lost
+    /// event than requested.
+    OpenIncompatibleLivelinessLease,
+    /// The [`Service`] transfers its payload with a different serializer than
+    /// requested.
+    OpenIncompatibleSerializer,
 
     /// Some underlying resources of the [`Service`] are either missing,
     /// corrupted or unaccessible.
@@ -43,6 +43,15 @@ class Subscriber {
     /// Returns the internal buffer size of the [`Subscriber`].
     auto buffer_size() const -> uint64_t;
 
+    /// Returns the number of [`Sample`]s that were lost since the [`Subscriber`]
+    /// was created. Lost samples are detected by gaps in the sequence numbers of
+    /// the samples received from the same [`Publisher`].
+    auto number_of_lost_samples() const -> uint64_t;
+
+    /// Returns the number of [`Sample`]s that were overridden in the buffer of
+    /// the [`Subscriber`] since the service has enabled the safe overflow.
+    auto missed_samples() const -> uint64_t;
+
     /// Receives a [`Sample`] from [`Publisher`]. If no sample could be
     /// received [`None`] is returned. If a failure occurs [`SubscriberReceiveError`] is returned.
     auto receive() const -> iox::expected<iox::optional<Sample<S, Payload, UserHeader>>, SubscriberReceiveError>;
@@ -127,6 +136,16 @@ inline auto Subscriber<S, Payload, UserHeader>::buffer_size() const -> uint64_t
     IOX_TODO();
 }
 
+template <ServiceType S, typename Payload, typename UserHeader>
+inline auto Subscriber<S, Payload, UserHeader>::number_of_lost_samples() const -> uint64_t {
+    return iox2_subscriber_number_of_lost_samples(&m_handle);
+}
+
+template <ServiceType S, typename Payload, typename UserHeader>
+inline auto Subscriber<S, Payload, UserHeader>::missed_samples() const -> uint64_t {
+    return iox2_subscriber_missed_samples(&m_handle);
+}
+
 template <ServiceType S, typename Payload, typename UserHeader>
 inline auto Subscriber<S, Payload, UserHeader>::receive() const
     -> iox::expected<iox::optional<Sample<S, Payload, UserHeader>>, SubscriberReceiveError> {
@@ -42,6 +42,9 @@ enum class SubscriberCreateError : uint8_t {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`] offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+
+    /// The sample filter compares bytes beyond the size of the user header.
+    SampleFilterExceedsUserHeader,
 };
 } // namespace iox2
 
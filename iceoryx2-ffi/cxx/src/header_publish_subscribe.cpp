@@ -57,4 +57,16 @@ auto HeaderPublishSubscribe::payload_type_layout() const -> iox::Layout {
 
     return iox::Layout::create(size, alignment).expect("Payload layout is always valid.");
 }
+
+auto HeaderPublishSubscribe::sequence_number() const -> uint64_t {
+    return iox2_publish_subscribe_header_sequence_number(&m_handle);
+}
+
+auto HeaderPublishSubscribe::timestamp() const -> iox::units::Duration {
+    uint64_t secs = 0;
+    uint32_t nsecs = 0;
+    iox2_publish_subscribe_header_timestamp(&m_handle, &secs, &nsecs);
+
+    return iox::units::Duration::fromSeconds(secs) + iox::units::Duration::fromNanoseconds(nsecs);
+}
 } // namespace iox2
@@ -0,0 +1,78 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/heartbeat_monitor.hpp"
+#include "iox/into.hpp"
+#include "iox2/enum_translation.hpp"
+
+namespace iox2 {
+template <ServiceType S>
+HeartbeatMonitor<S>::HeartbeatMonitor(iox2_heartbeat_monitor_h handle)
+    : m_handle { handle } {
+}
+
+template <ServiceType S>
+HeartbeatMonitor<S>::HeartbeatMonitor(HeartbeatMonitor&& rhs) noexcept {
+    *this = std::move(rhs);
+}
+
+template <ServiceType S>
+auto HeartbeatMonitor<S>::operator=(HeartbeatMonitor&& rhs) noexcept -> HeartbeatMonitor& {
+    if (this != &rhs) {
+        drop();
+        m_handle = std::move(rhs.m_handle);
+        rhs.m_handle = nullptr;
+    }
+
+    return *this;
+}
+
+template <ServiceType S>
+HeartbeatMonitor<S>::~HeartbeatMonitor() {
+    drop();
+}
+
+template <ServiceType S>
+void HeartbeatMonitor<S>::drop() {
+    if (m_handle != nullptr) {
+        iox2_heartbeat_monitor_drop(m_handle);
+        m_handle = nullptr;
+    }
+}
+
+template <ServiceType S>
+auto HeartbeatMonitor<S>::node_id() const -> NodeId {
+    return NodeId(iox2_heartbeat_monitor_node_id(&m_handle));
+}
+
+template <ServiceType S>
+auto HeartbeatMonitor<S>::timeout() const -> iox::units::Duration {
+    uint64_t secs = 0;
+    uint32_t nsecs = 0;
+    iox2_heartbeat_monitor_timeout(&m_handle, &secs, &nsecs);
+
+    return iox::units::Duration::fromSeconds(secs) + iox::units::Duration::fromNanoseconds(nsecs);
+}
+
+template <ServiceType S>
+auto HeartbeatMonitor<S>::state() -> HeartbeatState {
+    return iox::into<HeartbeatState>(static_cast<int>(iox2_heartbeat_monitor_state(&m_handle)));
+}
+
+template <ServiceType S>
+auto HeartbeatMonitor<S>::file_descriptor() const -> FileDescriptorView {
+    return FileDescriptorView(iox2_heartbeat_monitor_get_file_descriptor(&m_handle));
+}
+
+template class HeartbeatMonitor<ServiceType::Ipc>;
+template class HeartbeatMonitor<ServiceType::Local>;
+} // namespace iox2
@@ -18,6 +18,12 @@ auto operator<<(std::ostream& stream, const iox2::MessagingPattern& value) -> st
         stream << "iox2::MessagingPattern::PublishSubscribe";
     case iox2::MessagingPattern::Event:
         stream << "iox2::MessagingPattern::Event";
+    case iox2::MessagingPattern::RequestResponse:
+        stream << "iox2::MessagingPattern::RequestResponse";
+    case iox2::MessagingPattern::Blackboard:
+        stream << "iox2::MessagingPattern::Blackboard";
+    case iox2::MessagingPattern::Pipeline:
+        stream << "iox2::MessagingPattern::Pipeline";
     }
     return stream;
 }
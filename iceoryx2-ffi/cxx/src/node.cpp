@@ -11,6 +11,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
 #include "iox2/node.hpp"
+#include "iox/assertions.hpp"
 #include "iox/into.hpp"
 #include "iox2/internal/callback_context.hpp"
 
@@ -50,7 +51,7 @@ auto Node<T>::name() const -> NodeNameView {
 
 template <ServiceType T>
 auto Node<T>::id() const -> NodeId {
-    IOX_TODO();
+    return NodeId(iox2_node_id(&m_handle));
 }
 
 template <ServiceType T>
@@ -64,6 +65,28 @@ auto Node<T>::wait(iox::units::Duration cycle_time) const -> iox::expected<void,
     return iox::err(iox::into<NodeWaitFailure>(result));
 }
 
+template <ServiceType T>
+void Node<T>::send_heartbeat() const {
+    iox2_node_send_heartbeat(&m_handle);
+}
+
+template <ServiceType T>
+auto Node<T>::heartbeat_monitor(const NodeId& node_id, const iox::units::Duration timeout) const
+    -> iox::expected<HeartbeatMonitor<T>, HeartbeatMonitorCreateError> {
+    IOX_ASSERT(node_id.m_handle != nullptr, "An empty NodeId cannot be monitored.");
+    auto time = timeout.timespec();
+
+    iox2_heartbeat_monitor_h monitor_handle {};
+    const auto ret_val = iox2_node_heartbeat_monitor(
+        &m_handle, node_id.as_ptr(), time.tv_sec, time.tv_nsec, nullptr, &monitor_handle);
+
+    if (ret_val == IOX2_OK) {
+        return iox::ok(HeartbeatMonitor<T> { monitor_handle });
+    }
+
+    return iox::err(iox::into<HeartbeatMonitorCreateError>(ret_val));
+}
+
 template <ServiceType T>
 auto Node<T>::service_builder(const ServiceName& name) const -> ServiceBuilder<T> {
     return ServiceBuilder<T> { &m_handle, name.as_view().m_ptr };
@@ -87,13 +110,13 @@ auto list_callback(iox2_node_state_e node_state,
     auto node_state_object = [&] {
         switch (node_state) {
         case iox2_node_state_e_ALIVE:
-            return NodeState<T> { AliveNodeView<T> { NodeId {}, node_details } };
+            return NodeState<T> { AliveNodeView<T> { NodeId { node_id }, node_details } };
         case iox2_node_state_e_DEAD:
-            return NodeState<T> { DeadNodeView<T> { AliveNodeView<T> { NodeId {}, node_details } } };
+            return NodeState<T> { DeadNodeView<T> { AliveNodeView<T> { NodeId { node_id }, node_details } } };
         case iox2_node_state_e_UNDEFINED:
-            return NodeState<T> { iox2_node_state_e_UNDEFINED, NodeId {} };
+            return NodeState<T> { iox2_node_state_e_UNDEFINED, NodeId { node_id } };
         case iox2_node_state_e_INACCESSIBLE:
-            return NodeState<T> { iox2_node_state_e_INACCESSIBLE, NodeId {} };
+            return NodeState<T> { iox2_node_state_e_INACCESSIBLE, NodeId { node_id } };
         }
 
         IOX_UNREACHABLE();
@@ -142,6 +165,11 @@ auto NodeBuilder::create() const&& -> iox::expected<Node<T>, NodeCreationFailure
         iox2_node_builder_set_config(&m_handle, &m_config.value().m_handle);
     }
 
+    if (m_heartbeat_interval.has_value()) {
+        auto interval = m_heartbeat_interval->timespec();
+        iox2_node_builder_set_heartbeat_interval(&m_handle, interval.tv_sec, interval.tv_nsec);
+    }
+
     iox2_node_h node_handle {};
     const auto ret_val = iox2_node_builder_create(m_handle, nullptr, iox::into<iox2_service_type_e>(T), &node_handle);
 
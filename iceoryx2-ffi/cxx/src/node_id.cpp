@@ -0,0 +1,84 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#include "iox2/node_id.hpp"
+#include "iox/assertions.hpp"
+
+namespace iox2 {
+NodeId::NodeId(iox2_node_id_ptr ptr) {
+    if (ptr != nullptr) {
+        iox2_node_id_clone_from_ptr(nullptr, ptr, &m_handle);
+    }
+}
+
+NodeId::NodeId(const NodeId& rhs) {
+    *this = rhs;
+}
+
+NodeId::NodeId(NodeId&& rhs) noexcept {
+    *this = std::move(rhs);
+}
+
+auto NodeId::operator=(const NodeId& rhs) -> NodeId& {
+    if (this != &rhs) {
+        drop();
+
+        if (rhs.m_handle != nullptr) {
+            iox2_node_id_clone_from_ptr(nullptr, rhs.as_ptr(), &m_handle);
+        }
+    }
+
+    return *this;
+}
+
+auto NodeId::operator=(NodeId&& rhs) noexcept -> NodeId& {
+    if (this != &rhs) {
+        drop();
+        m_handle = std::move(rhs.m_handle);
+        rhs.m_handle = nullptr;
+    }
+
+    return *this;
+}
+
+NodeId::~NodeId() {
+    drop();
+}
+
+auto NodeId::pid() const -> int32_t {
+    IOX_ASSERT(m_handle != nullptr, "An empty NodeId has no pid.");
+    return iox2_node_id_pid(as_ptr());
+}
+
+auto NodeId::as_ptr() const -> iox2_node_id_ptr {
+    if (m_handle == nullptr) {
+        return nullptr;
+    }
+
+    return iox2_cast_node_id_ptr(m_handle);
+}
+
+void NodeId::drop() {
+    if (m_handle != nullptr) {
+        iox2_node_id_drop(m_handle);
+        m_handle = nullptr;
+    }
+}
+
+auto operator==(const NodeId& lhs, const NodeId& rhs) -> bool {
+    if (lhs.m_handle == nullptr || rhs.m_handle == nullptr) {
+        return lhs.m_handle == rhs.m_handle;
+    }
+
+    return iox2_node_id_eq(lhs.as_ptr(), rhs.as_ptr());
+}
+} // namespace iox2
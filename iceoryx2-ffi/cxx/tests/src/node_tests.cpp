@@ -13,6 +13,8 @@
 #include "iox2/node.hpp"
 #include "iox2/node_name.hpp"
 
+#include <chrono>
+#include <thread>
 #include <vector>
 
 #include "test.hpp"
@@ -77,4 +79,51 @@ TYPED_TEST(NodeTest, created_nodes_can_be_listed) {
     ASSERT_TRUE(result.has_value());
     ASSERT_THAT(counter, Eq(0));
 }
+
+TYPED_TEST(NodeTest, listed_node_has_the_id_of_the_created_node) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    auto sut = NodeBuilder().create<SERVICE_TYPE>().expect("");
+
+    bool has_found_node = false;
+    auto result = Node<SERVICE_TYPE>::list(Config::global_config(), [&](auto node_state) {
+        node_state.alive([&](auto& view) { has_found_node |= view.id() == sut.id(); });
+        return CallbackProgression::Continue;
+    });
+    ASSERT_TRUE(result.has_value());
+    ASSERT_TRUE(has_found_node);
+}
+
+TYPED_TEST(NodeTest, heartbeat_monitor_cannot_be_created_for_node_without_heartbeats) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    auto monitored_node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto sut = NodeBuilder().create<SERVICE_TYPE>().expect("");
+
+    auto monitor = sut.heartbeat_monitor(monitored_node.id(), iox::units::Duration::fromMilliseconds(100));
+    ASSERT_TRUE(monitor.has_error());
+    ASSERT_THAT(monitor.error(), Eq(HeartbeatMonitorCreateError::HeartbeatsNotEnabled));
+}
+
+TYPED_TEST(NodeTest, heartbeat_monitor_reports_state_of_monitored_node) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+    const auto timeout = iox::units::Duration::fromMilliseconds(100);
+
+    auto monitored_node = NodeBuilder()
+                              .heartbeat_interval(iox::units::Duration::fromMilliseconds(10))
+                              .create<SERVICE_TYPE>()
+                              .expect("");
+    auto sut = NodeBuilder().create<SERVICE_TYPE>().expect("");
+
+    auto monitor = sut.heartbeat_monitor(monitored_node.id(), timeout).expect("");
+    ASSERT_TRUE(monitor.node_id() == monitored_node.id());
+    ASSERT_THAT(monitor.timeout(), Eq(timeout));
+    ASSERT_THAT(monitor.state(), Eq(HeartbeatState::Alive));
+
+    std::this_thread::sleep_for(std::chrono::milliseconds(200));
+    ASSERT_THAT(monitor.state(), Eq(HeartbeatState::Unresponsive));
+
+    monitored_node.send_heartbeat();
+    ASSERT_THAT(monitor.state(), Eq(HeartbeatState::Alive));
+}
 } // namespace
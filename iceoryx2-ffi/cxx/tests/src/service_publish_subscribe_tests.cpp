@@ -25,6 +25,16 @@ struct TestHeader {
     std::array<uint64_t, CAPACITY> value;
 };
 
+struct TestPayloadWithTypeName {
+    static constexpr const char* IOX2_TYPE_NAME = "TestPayload";
+    uint64_t value;
+};
+
+struct TestHeaderWithTypeName {
+    static constexpr const char* IOX2_TYPE_NAME = "TestHeader";
+    uint32_t value;
+};
+
 template <typename T>
 class ServicePublishSubscribeTest : public ::testing::Test {
   public:
@@ -277,7 +287,25 @@ TYPED_TEST(ServicePublishSubscribeTest, setting_service_properties_works) {
     ASSERT_THAT(static_config.subscriber_max_borrowed_samples(), Eq(SUBSCRIBER_MAX_BORROWED_SAMPLES));
     ASSERT_THAT(static_config.message_type_details().payload().size(), Eq(sizeof(uint64_t)));
     ASSERT_THAT(static_config.message_type_details().payload().alignment(), Eq(alignof(uint64_t)));
-    ASSERT_THAT(static_config.message_type_details().payload().type_name(), StrEq(typeid(uint64_t).name()));
+    ASSERT_THAT(static_config.message_type_details().payload().type_name(), StrEq("u64"));
+}
+
+TYPED_TEST(ServicePublishSubscribeTest, type_name_can_be_defined_by_payload_and_user_header) {
+    constexpr ServiceType SERVICE_TYPE = TestFixture::TYPE;
+
+    const auto service_name = iox2_testing::generate_service_name();
+
+    auto node = NodeBuilder().create<SERVICE_TYPE>().expect("");
+    auto service = node.service_builder(service_name)
+                       .template publish_subscribe<TestPayloadWithTypeName>()
+                       .template user_header<TestHeaderWithTypeName>()
+                       .create()
+                       .expect("");
+
+    auto static_config = service.static_config();
+
+    ASSERT_THAT(static_config.message_type_details().payload().type_name(), StrEq("TestPayload"));
+    ASSERT_THAT(static_config.message_type_details().user_header().type_name(), StrEq("TestHeader"));
 }
 
 TYPED_TEST(ServicePublishSubscribeTest, safe_overflow_can_be_set) {
@@ -0,0 +1,281 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use crate::api::{
+    iox2_node_id_ptr, iox2_service_type_e, AssertNonNullHandle, HandleToType, IntoCInt, IOX2_OK,
+};
+use crate::iox2_file_descriptor_ptr;
+
+use iceoryx2::node::heartbeat::{HeartbeatMonitor, HeartbeatMonitorCreateError, HeartbeatState};
+use iceoryx2::prelude::*;
+use iceoryx2_bb_elementary::static_assert::*;
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use core::ffi::c_int;
+use core::mem::ManuallyDrop;
+
+// BEGIN types definition
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum iox2_heartbeat_monitor_create_error_e {
+    HEARTBEATS_NOT_ENABLED = IOX2_OK as isize + 1,
+    INSUFFICIENT_PERMISSIONS,
+    EXCEEDS_MAX_SUPPORTED_MONITORS,
+    INTERNAL_FAILURE,
+}
+
+impl IntoCInt for HeartbeatMonitorCreateError {
+    fn into_c_int(self) -> c_int {
+        (match self {
+            HeartbeatMonitorCreateError::HeartbeatsNotEnabled => {
+                iox2_heartbeat_monitor_create_error_e::HEARTBEATS_NOT_ENABLED
+            }
+            HeartbeatMonitorCreateError::InsufficientPermissions => {
+                iox2_heartbeat_monitor_create_error_e::INSUFFICIENT_PERMISSIONS
+            }
+            HeartbeatMonitorCreateError::ExceedsMaxSupportedMonitors => {
+                iox2_heartbeat_monitor_create_error_e::EXCEEDS_MAX_SUPPORTED_MONITORS
+            }
+            HeartbeatMonitorCreateError::InternalFailure => {
+                iox2_heartbeat_monitor_create_error_e::INTERNAL_FAILURE
+            }
+        }) as c_int
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone)]
+pub enum iox2_heartbeat_state_e {
+    ALIVE,
+    UNRESPONSIVE,
+    DEAD,
+    REMOVED,
+}
+
+impl From<HeartbeatState> for iox2_heartbeat_state_e {
+    fn from(value: HeartbeatState) -> Self {
+        match value {
+            HeartbeatState::Alive => iox2_heartbeat_state_e::ALIVE,
+            HeartbeatState::Unresponsive => iox2_heartbeat_state_e::UNRESPONSIVE,
+            HeartbeatState::Dead => iox2_heartbeat_state_e::DEAD,
+            HeartbeatState::Removed => iox2_heartbeat_state_e::REMOVED,
+        }
+    }
+}
+
+pub(super) union HeartbeatMonitorUnion {
+    ipc: ManuallyDrop<HeartbeatMonitor<ipc::Service>>,
+    local: ManuallyDrop<HeartbeatMonitor<local::Service>>,
+}
+
+impl HeartbeatMonitorUnion {
+    pub(super) fn new_ipc(monitor: HeartbeatMonitor<ipc::Service>) -> Self {
+        Self {
+            ipc: ManuallyDrop::new(monitor),
+        }
+    }
+    pub(super) fn new_local(monitor: HeartbeatMonitor<local::Service>) -> Self {
+        Self {
+            local: ManuallyDrop::new(monitor),
+        }
+    }
+}
+
+#[repr(C)]
+#[repr(align(8))] // alignment of Option<HeartbeatMonitorUnion>
+pub struct iox2_heartbeat_monitor_storage_t {
+    internal: [u8; 4608], // magic number obtained with size_of::<Option<HeartbeatMonitorUnion>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(HeartbeatMonitorUnion)]
+pub struct iox2_heartbeat_monitor_t {
+    service_type: iox2_service_type_e,
+    value: iox2_heartbeat_monitor_storage_t,
+    deleter: fn(*mut iox2_heartbeat_monitor_t),
+}
+
+impl iox2_heartbeat_monitor_t {
+    pub(super) fn init(
+        &mut self,
+        service_type: iox2_service_type_e,
+        value: HeartbeatMonitorUnion,
+        deleter: fn(*mut iox2_heartbeat_monitor_t),
+    ) {
+        self.service_type = service_type;
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_heartbeat_monitor_h_t;
+/// The owning handle for `iox2_heartbeat_monitor_t`. Passing the handle to an function transfers the ownership.
+pub type iox2_heartbeat_monitor_h = *mut iox2_heartbeat_monitor_h_t;
+/// The non-owning handle for `iox2_heartbeat_monitor_t`. Passing the handle to an function does not transfers the ownership.
+pub type iox2_heartbeat_monitor_h_ref = *const iox2_heartbeat_monitor_h;
+
+impl AssertNonNullHandle for iox2_heartbeat_monitor_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_heartbeat_monitor_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_heartbeat_monitor_h {
+    type Target = *mut iox2_heartbeat_monitor_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_heartbeat_monitor_h_ref {
+    type Target = *mut iox2_heartbeat_monitor_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END type definition
+
+// BEGIN C API
+
+/// This function needs to be called to destroy the heartbeat monitor!
+///
+/// # Arguments
+///
+/// * `monitor_handle` - A valid [`iox2_heartbeat_monitor_h`]
+///
+/// # Safety
+///
+/// * The `monitor_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+/// * The corresponding [`iox2_heartbeat_monitor_t`] can be re-used with a call to
+///   [`iox2_node_heartbeat_monitor`](crate::iox2_node_heartbeat_monitor)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_heartbeat_monitor_drop(monitor_handle: iox2_heartbeat_monitor_h) {
+    monitor_handle.assert_non_null();
+
+    let monitor = &mut *monitor_handle.as_type();
+
+    match monitor.service_type {
+        iox2_service_type_e::IPC => {
+            ManuallyDrop::drop(&mut monitor.value.as_mut().ipc);
+        }
+        iox2_service_type_e::LOCAL => {
+            ManuallyDrop::drop(&mut monitor.value.as_mut().local);
+        }
+    }
+    (monitor.deleter)(monitor);
+}
+
+/// Consumes all received heartbeats and returns the [`iox2_heartbeat_state_e`] of the monitored
+/// node.
+///
+/// # Safety
+///
+/// * The `monitor_handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_heartbeat_monitor_state(
+    monitor_handle: iox2_heartbeat_monitor_h_ref,
+) -> iox2_heartbeat_state_e {
+    monitor_handle.assert_non_null();
+
+    let monitor = &mut *monitor_handle.as_type();
+
+    match monitor.service_type {
+        iox2_service_type_e::IPC => monitor.value.as_ref().ipc.state().into(),
+        iox2_service_type_e::LOCAL => monitor.value.as_ref().local.state().into(),
+    }
+}
+
+/// Returns the [`iox2_node_id_ptr`] of the monitored node.
+///
+/// # Safety
+///
+/// * The `monitor_handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_heartbeat_monitor_node_id(
+    monitor_handle: iox2_heartbeat_monitor_h_ref,
+) -> iox2_node_id_ptr {
+    monitor_handle.assert_non_null();
+
+    let monitor = &mut *monitor_handle.as_type();
+
+    match monitor.service_type {
+        iox2_service_type_e::IPC => monitor.value.as_ref().ipc.node_id(),
+        iox2_service_type_e::LOCAL => monitor.value.as_ref().local.node_id(),
+    }
+}
+
+/// Returns the timeout of the heartbeat monitor.
+///
+/// # Safety
+///
+/// * The `monitor_handle` must be a valid handle.
+/// * `timeout_secs` and `timeout_nsecs` must be valid pointers.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_heartbeat_monitor_timeout(
+    monitor_handle: iox2_heartbeat_monitor_h_ref,
+    timeout_secs: *mut u64,
+    timeout_nsecs: *mut u32,
+) {
+    monitor_handle.assert_non_null();
+    debug_assert!(!timeout_secs.is_null());
+    debug_assert!(!timeout_nsecs.is_null());
+
+    let monitor = &mut *monitor_handle.as_type();
+
+    let timeout = match monitor.service_type {
+        iox2_service_type_e::IPC => monitor.value.as_ref().ipc.timeout(),
+        iox2_service_type_e::LOCAL => monitor.value.as_ref().local.timeout(),
+    };
+
+    *timeout_secs = timeout.as_secs();
+    *timeout_nsecs = timeout.subsec_nanos();
+}
+
+/// Returns the underlying non-owning file descriptor of the [`iox2_heartbeat_monitor_h`]. It
+/// can be attached to a waitset to wake up on every received heartbeat.
+///
+/// # Safety
+///
+/// * The `monitor_handle` must be a valid handle.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_heartbeat_monitor_get_file_descriptor(
+    monitor_handle: iox2_heartbeat_monitor_h_ref,
+) -> iox2_file_descriptor_ptr {
+    monitor_handle.assert_non_null();
+
+    let monitor = &mut *monitor_handle.as_type();
+
+    let fd = match monitor.service_type {
+        iox2_service_type_e::IPC => monitor.value.as_ref().ipc.file_descriptor(),
+        iox2_service_type_e::LOCAL => monitor.value.as_ref().local.file_descriptor(),
+    };
+
+    core::mem::transmute(fd as *const FileDescriptor)
+}
+
+// END C API
@@ -20,12 +20,14 @@ use core::ffi::{c_int, c_void};
 mod config;
 mod event_id;
 mod file_descriptor;
+mod heartbeat_monitor;
 mod iceoryx2_settings;
 mod listener;
 mod log;
 mod message_type_details;
 mod node;
 mod node_builder;
+mod node_id;
 mod node_name;
 mod notifier;
 mod port_factory_event;
@@ -45,8 +47,11 @@ mod service_builder_event;
 mod service_builder_pub_sub;
 mod service_name;
 mod static_config;
+mod static_config_blackboard;
 mod static_config_event;
+mod static_config_pipeline;
 mod static_config_publish_subscribe;
+mod static_config_request_response;
 mod subscriber;
 mod unique_listener_id;
 mod unique_notifier_id;
@@ -60,11 +65,13 @@ mod waitset_guard;
 pub use config::*;
 pub use event_id::*;
 pub use file_descriptor::*;
+pub use heartbeat_monitor::*;
 pub use iceoryx2_settings::*;
 pub use listener::*;
 pub use message_type_details::*;
 pub use node::*;
 pub use node_builder::*;
+pub use node_id::*;
 pub use node_name::*;
 pub use notifier::*;
 pub use port_factory_event::*;
@@ -84,8 +91,11 @@ pub use service_builder_event::*;
 pub use service_builder_pub_sub::*;
 pub use service_name::*;
 pub use static_config::*;
+pub use static_config_blackboard::*;
 pub use static_config_event::*;
+pub use static_config_pipeline::*;
 pub use static_config_publish_subscribe::*;
+pub use static_config_request_response::*;
 pub use subscriber::*;
 pub use unique_listener_id::*;
 pub use unique_notifier_id::*;
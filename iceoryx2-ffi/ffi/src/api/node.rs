@@ -13,12 +13,13 @@
 #![allow(non_camel_case_types)]
 
 use crate::api::{
-    iox2_callback_context, iox2_callback_progression_e, iox2_config_ptr, iox2_node_name_ptr,
-    iox2_service_builder_h, iox2_service_builder_t, iox2_service_name_ptr, iox2_service_type_e,
-    AssertNonNullHandle, HandleToType, IntoCInt, ServiceBuilderUnion, IOX2_OK,
+    iox2_callback_context, iox2_callback_progression_e, iox2_config_ptr, iox2_heartbeat_monitor_h,
+    iox2_heartbeat_monitor_t, iox2_node_id_ptr, iox2_node_name_ptr, iox2_service_builder_h,
+    iox2_service_builder_t, iox2_service_name_ptr, iox2_service_type_e, AssertNonNullHandle,
+    HandleToType, HeartbeatMonitorUnion, IntoCInt, ServiceBuilderUnion, IOX2_OK,
 };
 
-use iceoryx2::node::{NodeId, NodeListFailure, NodeView, NodeWaitFailure};
+use iceoryx2::node::{NodeListFailure, NodeView, NodeWaitFailure};
 use iceoryx2::prelude::*;
 use iceoryx2_bb_elementary::static_assert::*;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
@@ -86,7 +87,7 @@ impl NodeUnion {
 #[repr(C)]
 #[repr(align(8))] // alignment of Option<NodeUnion>
 pub struct iox2_node_storage_t {
-    internal: [u8; 16], // magic number obtained with size_of::<Option<NodeUnion>>()
+    internal: [u8; 208], // magic number obtained with size_of::<Option<NodeUnion>>()
 }
 
 #[repr(C)]
@@ -156,12 +157,6 @@ pub enum iox2_node_state_e {
     UNDEFINED,
 }
 
-// NOTE check the README.md for using opaque types with renaming
-/// The immutable pointer to the underlying `NodeId`
-pub type iox2_node_id_ptr = *const NodeId;
-/// The mutable pointer to the underlying `NodeId`
-pub type iox2_node_id_ptr_mut = *mut NodeId;
-
 /// The callback for [`iox2_node_list`]
 ///
 /// # Arguments
@@ -256,7 +251,108 @@ pub unsafe extern "C" fn iox2_node_config(node_handle: iox2_node_h_ref) -> iox2_
 #[no_mangle]
 pub unsafe extern "C" fn iox2_node_id(node_handle: iox2_node_h_ref) -> iox2_node_id_ptr {
     node_handle.assert_non_null();
-    todo!() // TODO: [#210] implement
+
+    let node = &mut *node_handle.as_type();
+
+    match node.service_type {
+        iox2_service_type_e::IPC => node.value.as_ref().ipc.id(),
+        iox2_service_type_e::LOCAL => node.value.as_ref().local.id(),
+    }
+}
+
+/// Sends a heartbeat when the node was created with
+/// [`iox2_node_builder_set_heartbeat_interval`](crate::iox2_node_builder_set_heartbeat_interval),
+/// otherwise it does nothing. [`iox2_node_wait`] sends the heartbeats automatically.
+///
+/// # Safety
+///
+/// * The `node_handle` must be valid and obtained by [`iox2_node_builder_create`](crate::iox2_node_builder_create)!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_send_heartbeat(node_handle: iox2_node_h_ref) {
+    node_handle.assert_non_null();
+
+    let node = &mut *node_handle.as_type();
+
+    match node.service_type {
+        iox2_service_type_e::IPC => node.value.as_ref().ipc.send_heartbeat(),
+        iox2_service_type_e::LOCAL => node.value.as_ref().local.send_heartbeat(),
+    }
+}
+
+/// Creates a heartbeat monitor for the node with the provided [`iox2_node_id_ptr`]. The monitored
+/// node must be created with
+/// [`iox2_node_builder_set_heartbeat_interval`](crate::iox2_node_builder_set_heartbeat_interval).
+///
+/// # Arguments
+///
+/// * `node_handle` - Must be a valid [`iox2_node_h_ref`] obtained by [`iox2_node_builder_create`](crate::iox2_node_builder_create)
+/// * `node_id_ptr` - Must be a valid [`iox2_node_id_ptr`] of the monitored node
+/// * `timeout_secs` and `timeout_nsecs` - The time after which a node without a heartbeat is
+///   considered unresponsive
+/// * `monitor_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_heartbeat_monitor_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `monitor_handle_ptr` - An uninitialized or dangling [`iox2_heartbeat_monitor_h`] handle which will be initialized by this function call.
+///
+/// Returns IOX2_OK on success, an [`iox2_heartbeat_monitor_create_error_e`](crate::iox2_heartbeat_monitor_create_error_e) otherwise.
+///
+/// # Safety
+///
+/// * The `node_handle` is still valid after the return of this function and can be use in another function call.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_heartbeat_monitor(
+    node_handle: iox2_node_h_ref,
+    node_id_ptr: iox2_node_id_ptr,
+    timeout_secs: u64,
+    timeout_nsecs: u32,
+    monitor_struct_ptr: *mut iox2_heartbeat_monitor_t,
+    monitor_handle_ptr: *mut iox2_heartbeat_monitor_h,
+) -> c_int {
+    node_handle.assert_non_null();
+    debug_assert!(!node_id_ptr.is_null());
+    debug_assert!(!monitor_handle_ptr.is_null());
+
+    let mut monitor_struct_ptr = monitor_struct_ptr;
+    fn no_op(_: *mut iox2_heartbeat_monitor_t) {}
+    let mut deleter: fn(*mut iox2_heartbeat_monitor_t) = no_op;
+    if monitor_struct_ptr.is_null() {
+        monitor_struct_ptr = iox2_heartbeat_monitor_t::alloc();
+        deleter = iox2_heartbeat_monitor_t::dealloc;
+    }
+    debug_assert!(!monitor_struct_ptr.is_null());
+
+    let node = &mut *node_handle.as_type();
+    let timeout = Duration::from_secs(timeout_secs) + Duration::from_nanos(timeout_nsecs as u64);
+
+    let monitor = match node.service_type {
+        iox2_service_type_e::IPC => node
+            .value
+            .as_ref()
+            .ipc
+            .heartbeat_monitor_builder(&*node_id_ptr)
+            .timeout(timeout)
+            .create()
+            .map(HeartbeatMonitorUnion::new_ipc),
+        iox2_service_type_e::LOCAL => node
+            .value
+            .as_ref()
+            .local
+            .heartbeat_monitor_builder(&*node_id_ptr)
+            .timeout(timeout)
+            .create()
+            .map(HeartbeatMonitorUnion::new_local),
+    };
+
+    match monitor {
+        Ok(monitor) => {
+            (*monitor_struct_ptr).init(node.service_type, monitor, deleter);
+            *monitor_handle_ptr = (*monitor_struct_ptr).as_handle();
+            IOX2_OK
+        }
+        Err(error) => {
+            deleter(monitor_struct_ptr);
+            error.into_c_int()
+        }
+    }
 }
 
 fn iox2_node_list_impl<S: Service>(
@@ -24,6 +24,7 @@ use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_ffi_macros::iceoryx2_ffi;
 
 use core::ffi::c_int;
+use core::time::Duration;
 
 // BEGIN types definition
 
@@ -186,6 +187,29 @@ pub unsafe extern "C" fn iox2_node_builder_set_config(
     node_builder_struct.set(node_builder);
 }
 
+/// Enables the heartbeats of the node. The node sends a heartbeat at least every provided
+/// interval while it is waiting in [`iox2_node_wait`](crate::iox2_node_wait), so that other nodes
+/// can monitor it with [`iox2_node_heartbeat_monitor`](crate::iox2_node_heartbeat_monitor).
+///
+/// # Safety
+///
+/// * `node_builder_handle` - Must be a valid [`iox2_node_builder_h_ref`] obtained by [`iox2_node_builder_new`].
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_builder_set_heartbeat_interval(
+    node_builder_handle: iox2_node_builder_h_ref,
+    interval_secs: u64,
+    interval_nsecs: u32,
+) {
+    node_builder_handle.assert_non_null();
+
+    let node_builder_struct = &mut *node_builder_handle.as_type();
+    let interval = Duration::from_secs(interval_secs) + Duration::from_nanos(interval_nsecs as u64);
+
+    let node_builder = node_builder_struct.take().unwrap();
+    let node_builder = node_builder.heartbeat_interval(interval);
+    node_builder_struct.set(node_builder);
+}
+
 // intentionally not public API
 unsafe fn iox2_node_builder_drop(node_builder_handle: iox2_node_builder_h) {
     debug_assert!(!node_builder_handle.is_null());
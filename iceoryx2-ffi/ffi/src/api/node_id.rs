@@ -0,0 +1,188 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use iceoryx2::node::NodeId;
+use iceoryx2_bb_elementary::static_assert::static_assert_ge;
+use iceoryx2_ffi_macros::iceoryx2_ffi;
+
+use crate::api::{AssertNonNullHandle, HandleToType};
+
+// BEGIN types definition
+
+/// The system-wide unique id of a `iox2_node_t`.
+#[repr(C)]
+#[repr(align(4))] // core::mem::align_of::<NodeId>()
+pub struct iox2_node_id_storage_t {
+    internal: [u8; 20], // core::mem::size_of::<Option<NodeId>>()
+}
+
+#[repr(C)]
+#[iceoryx2_ffi(NodeId)]
+pub struct iox2_node_id_t {
+    pub value: iox2_node_id_storage_t,
+    pub(super) deleter: fn(*mut iox2_node_id_t),
+}
+
+impl iox2_node_id_t {
+    pub(super) fn init(&mut self, value: NodeId, deleter: fn(*mut iox2_node_id_t)) {
+        self.value.init(value);
+        self.deleter = deleter;
+    }
+}
+
+pub struct iox2_node_id_h_t;
+/// The owning handle for [`iox2_node_id_t`]. Passing the handle to an function transfers the ownership.
+pub type iox2_node_id_h = *mut iox2_node_id_h_t;
+/// The non-owning handle for [`iox2_node_id_t`]. Passing the handle to an function does not transfers the ownership.
+pub type iox2_node_id_h_ref = *const iox2_node_id_h;
+
+// NOTE check the README.md for using opaque types with renaming
+/// The immutable pointer to the underlying `NodeId`
+pub type iox2_node_id_ptr = *const NodeId;
+/// The mutable pointer to the underlying `NodeId`
+pub type iox2_node_id_ptr_mut = *mut NodeId;
+
+impl AssertNonNullHandle for iox2_node_id_h {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+    }
+}
+
+impl AssertNonNullHandle for iox2_node_id_h_ref {
+    fn assert_non_null(self) {
+        debug_assert!(!self.is_null());
+        unsafe {
+            debug_assert!(!(*self).is_null());
+        }
+    }
+}
+
+impl HandleToType for iox2_node_id_h {
+    type Target = *mut iox2_node_id_t;
+
+    fn as_type(self) -> Self::Target {
+        self as *mut _ as _
+    }
+}
+
+impl HandleToType for iox2_node_id_h_ref {
+    type Target = *mut iox2_node_id_t;
+
+    fn as_type(self) -> Self::Target {
+        unsafe { *self as *mut _ as _ }
+    }
+}
+
+// END types definition
+
+// BEGIN C API
+
+/// Creates an owning copy of the node id the [`iox2_node_id_ptr`] points to.
+///
+/// # Arguments
+///
+/// * `node_id_struct_ptr` - Must be either a NULL pointer or a pointer to a valid [`iox2_node_id_t`].
+///   If it is a NULL pointer, the storage will be allocated on the heap.
+/// * `node_id_ptr` - A valid [`iox2_node_id_ptr`] obtained by e.g. [`iox2_node_id`](crate::iox2_node_id)
+/// * `node_id_handle_ptr` - An uninitialized or dangling [`iox2_node_id_h`] handle which will be initialized by this function call.
+///
+/// # Safety
+///
+/// * `node_id_ptr` and `node_id_handle_ptr` must be valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_clone_from_ptr(
+    node_id_struct_ptr: *mut iox2_node_id_t,
+    node_id_ptr: iox2_node_id_ptr,
+    node_id_handle_ptr: *mut iox2_node_id_h,
+) {
+    debug_assert!(!node_id_ptr.is_null());
+    debug_assert!(!node_id_handle_ptr.is_null());
+
+    fn no_op(_: *mut iox2_node_id_t) {}
+    let mut deleter: fn(*mut iox2_node_id_t) = no_op;
+    let mut storage_ptr = node_id_struct_ptr;
+    if node_id_struct_ptr.is_null() {
+        deleter = iox2_node_id_t::dealloc;
+        storage_ptr = iox2_node_id_t::alloc();
+    }
+    debug_assert!(!storage_ptr.is_null());
+
+    (*storage_ptr).init(*node_id_ptr, deleter);
+    *node_id_handle_ptr = (*storage_ptr).as_handle();
+}
+
+/// This function casts a [`iox2_node_id_h`] into a [`iox2_node_id_ptr`]
+///
+/// # Arguments
+///
+/// * `node_id_handle` obtained by [`iox2_node_id_clone_from_ptr`]
+///
+/// Returns a [`iox2_node_id_ptr`]
+///
+/// # Safety
+///
+/// * The `node_id_handle` must be a valid handle.
+/// * The `node_id_handle` is still valid after the call to this function.
+#[no_mangle]
+pub unsafe extern "C" fn iox2_cast_node_id_ptr(node_id_handle: iox2_node_id_h) -> iox2_node_id_ptr {
+    node_id_handle.assert_non_null();
+
+    (*node_id_handle.as_type()).value.as_ref()
+}
+
+/// Returns the process id of the process that owns the node.
+///
+/// # Safety
+///
+/// * `node_id_ptr` must be a valid [`iox2_node_id_ptr`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_pid(node_id_ptr: iox2_node_id_ptr) -> i32 {
+    debug_assert!(!node_id_ptr.is_null());
+
+    (*node_id_ptr).pid().value()
+}
+
+/// Checks two [`iox2_node_id_ptr`] for equality.
+///
+/// # Safety
+///
+/// * `lhs` - Must be a valid [`iox2_node_id_ptr`]
+/// * `rhs` - Must be a valid [`iox2_node_id_ptr`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_eq(lhs: iox2_node_id_ptr, rhs: iox2_node_id_ptr) -> bool {
+    debug_assert!(!lhs.is_null());
+    debug_assert!(!rhs.is_null());
+
+    *lhs == *rhs
+}
+
+/// This function needs to be called to destroy the node id!
+///
+/// # Arguments
+///
+/// * `node_id_handle` - A valid [`iox2_node_id_h`]
+///
+/// # Safety
+///
+/// * The `node_id_handle` is invalid after the return of this function and leads to undefined behavior if used in another function call!
+#[no_mangle]
+pub unsafe extern "C" fn iox2_node_id_drop(node_id_handle: iox2_node_id_h) {
+    node_id_handle.assert_non_null();
+
+    let h = &mut *node_id_handle.as_type();
+    core::ptr::drop_in_place(h.value.as_option_mut());
+    (h.deleter)(h);
+}
+
+// END C API
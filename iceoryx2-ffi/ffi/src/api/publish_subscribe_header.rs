@@ -27,7 +27,7 @@ use crate::{
 #[repr(C)]
 #[repr(align(8))] // core::mem::align_of::<Option<Header>>()
 pub struct iox2_publish_subscribe_header_storage_t {
-    internal: [u8; 32], // core::mem::size_of::<Option<Header>>()
+    internal: [u8; 56], // core::mem::size_of::<Option<Header>>()
 }
 
 #[repr(C)]
@@ -196,4 +196,53 @@ pub unsafe extern "C" fn iox2_publish_subscribe_header_payload_type_alignment(
     header.value.as_ref().payload_type_layout().align()
 }
 
+/// Returns the sequence number of the sample.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///    [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_sequence_number(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+) -> u64 {
+    header_handle.assert_non_null();
+
+    let header = &mut *header_handle.as_type();
+
+    header.value.as_ref().sequence_number()
+}
+
+/// Returns the monotonic timestamp at which the sample was sent.
+///
+/// # Arguments
+///
+/// * `handle` is valid, non-null and was initialized with
+///    [`iox2_sample_header()`](crate::iox2_sample_header)
+///
+/// # Safety
+///
+/// * `header_handle` is valid and non-null
+/// * `secs` - A valid pointer pointing to a [`u64`].
+/// * `nsecs` - A valid pointer pointing to a [`u32`]
+#[no_mangle]
+pub unsafe extern "C" fn iox2_publish_subscribe_header_timestamp(
+    header_handle: iox2_publish_subscribe_header_h_ref,
+    secs: *mut u64,
+    nsecs: *mut u32,
+) {
+    header_handle.assert_non_null();
+    debug_assert!(!secs.is_null());
+    debug_assert!(!nsecs.is_null());
+
+    let header = &mut *header_handle.as_type();
+    let timestamp = header.value.as_ref().timestamp();
+    *secs = timestamp.seconds();
+    *nsecs = timestamp.nanoseconds();
+}
+
 // END C API
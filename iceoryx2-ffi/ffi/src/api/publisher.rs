@@ -206,7 +206,7 @@ unsafe fn send_copy<S: Service>(
     match sample.assume_init().send() {
         Ok(v) => {
             if !number_of_recipients.is_null() {
-                *number_of_recipients = v;
+                *number_of_recipients = v.number_of_recipients();
             }
         }
         Err(e) => return e.into_c_int(),
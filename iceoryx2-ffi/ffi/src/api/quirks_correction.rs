@@ -16,7 +16,8 @@
 pub type c_size_t = usize;
 
 use crate::{
-    iox2_config_creation_error_e, iox2_event_open_or_create_error_e, iox2_listener_create_error_e,
+    iox2_config_creation_error_e, iox2_event_open_or_create_error_e,
+    iox2_heartbeat_monitor_create_error_e, iox2_listener_create_error_e,
     iox2_listener_wait_error_e, iox2_node_creation_failure_e, iox2_node_list_failure_e,
     iox2_node_wait_failure_e, iox2_notifier_create_error_e, iox2_notifier_notify_error_e,
     iox2_pub_sub_open_or_create_error_e, iox2_publisher_create_error_e,
@@ -58,6 +59,14 @@ pub unsafe extern "C" fn __iox2_internal_node_wait_failure_stub() -> iox2_node_w
     iox2_node_wait_failure_e::INTERRUPT
 }
 
+#[doc(hidden)]
+#[no_mangle]
+// TODO: enums are only exported when they are actually used by some function
+pub unsafe extern "C" fn __iox2_internal_heartbeat_monitor_create_error_stub(
+) -> iox2_heartbeat_monitor_create_error_e {
+    iox2_heartbeat_monitor_create_error_e::INTERNAL_FAILURE
+}
+
 #[doc(hidden)]
 #[no_mangle]
 // TODO: enums are only exported when they are actually used by some function
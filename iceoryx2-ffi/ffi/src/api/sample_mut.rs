@@ -326,7 +326,7 @@ pub unsafe extern "C" fn iox2_sample_mut_send(
             match sample.assume_init().send() {
                 Ok(v) => {
                     if !number_of_recipients.is_null() {
-                        *number_of_recipients = v;
+                        *number_of_recipients = v.number_of_recipients();
                     }
                 }
                 Err(e) => {
@@ -339,7 +339,7 @@ pub unsafe extern "C" fn iox2_sample_mut_send(
             match sample.assume_init().send() {
                 Ok(v) => {
                     if !number_of_recipients.is_null() {
-                        *number_of_recipients = v;
+                        *number_of_recipients = v.number_of_recipients();
                     }
                 }
                 Err(e) => {
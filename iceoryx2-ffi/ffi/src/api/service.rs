@@ -42,6 +42,9 @@ pub enum iox2_service_type_e {
 pub enum iox2_messaging_pattern_e {
     PUBLISH_SUBSCRIBE = 0,
     EVENT,
+    REQUEST_RESPONSE,
+    BLACKBOARD,
+    PIPELINE,
 }
 
 impl From<iox2_messaging_pattern_e> for MessagingPattern {
@@ -49,6 +52,9 @@ impl From<iox2_messaging_pattern_e> for MessagingPattern {
         match value {
             iox2_messaging_pattern_e::EVENT => MessagingPattern::Event,
             iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE => MessagingPattern::PublishSubscribe,
+            iox2_messaging_pattern_e::REQUEST_RESPONSE => MessagingPattern::RequestResponse,
+            iox2_messaging_pattern_e::BLACKBOARD => MessagingPattern::Blackboard,
+            iox2_messaging_pattern_e::PIPELINE => MessagingPattern::Pipeline,
         }
     }
 }
@@ -64,6 +70,15 @@ impl From<&iceoryx2::service::static_config::messaging_pattern::MessagingPattern
             iceoryx2::service::static_config::messaging_pattern::MessagingPattern::PublishSubscribe(_) => {
                 iox2_messaging_pattern_e::PUBLISH_SUBSCRIBE
             }
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::RequestResponse(_) => {
+                iox2_messaging_pattern_e::REQUEST_RESPONSE
+            }
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Blackboard(_) => {
+                iox2_messaging_pattern_e::BLACKBOARD
+            }
+            iceoryx2::service::static_config::messaging_pattern::MessagingPattern::Pipeline(_) => {
+                iox2_messaging_pattern_e::PIPELINE
+            }
             _ => unreachable!()
         }
     }
@@ -55,6 +55,9 @@ pub enum iox2_pub_sub_open_or_create_error_e {
     O_HANGS_IN_CREATION,
     O_EXCEEDS_MAX_NUMBER_OF_NODES,
     O_IS_MARKED_FOR_DESTRUCTION,
+    O_INCOMPATIBLE_DEADLINE,
+    O_INCOMPATIBLE_LIVELINESS_LEASE,
+    O_INCOMPATIBLE_SERIALIZER,
     C_SERVICE_IN_CORRUPTED_STATE,
     C_SUBSCRIBER_BUFFER_MUST_BE_LARGER_THAN_HISTORY_SIZE,
     C_ALREADY_EXISTS,
@@ -117,6 +120,15 @@ impl IntoCInt for PublishSubscribeOpenError {
          PublishSubscribeOpenError::IsMarkedForDestruction => {
              iox2_pub_sub_open_or_create_error_e::O_IS_MARKED_FOR_DESTRUCTION
          }
+         PublishSubscribeOpenError::IncompatibleDeadline => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_DEADLINE
+         }
+         PublishSubscribeOpenError::IncompatibleLivelinessLease => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_LIVELINESS_LEASE
+         }
+         PublishSubscribeOpenError::IncompatibleSerializer => {
+             iox2_pub_sub_open_or_create_error_e::O_INCOMPATIBLE_SERIALIZER
+         }
         }) as c_int
     }
 }
@@ -249,6 +261,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_user_header_type_detai
         type_name,
         size,
         alignment,
+        layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
@@ -326,6 +339,7 @@ pub unsafe extern "C" fn iox2_service_builder_pub_sub_set_payload_type_details(
         type_name,
         size,
         alignment,
+        layout_hash: None,
     };
 
     let service_builder_struct = unsafe { &mut *service_builder_handle.as_type() };
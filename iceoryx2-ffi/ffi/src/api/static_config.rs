@@ -19,8 +19,9 @@ use iceoryx2::service::static_config::StaticConfig;
 use iceoryx2_bb_log::fatal_panic;
 
 use crate::{
-    iox2_messaging_pattern_e, iox2_static_config_event_t, iox2_static_config_publish_subscribe_t,
-    IOX2_SERVICE_ID_LENGTH, IOX2_SERVICE_NAME_LENGTH,
+    iox2_messaging_pattern_e, iox2_static_config_blackboard_t, iox2_static_config_event_t,
+    iox2_static_config_pipeline_t, iox2_static_config_publish_subscribe_t,
+    iox2_static_config_request_response_t, IOX2_SERVICE_ID_LENGTH, IOX2_SERVICE_NAME_LENGTH,
 };
 
 #[derive(Clone, Copy)]
@@ -28,6 +29,9 @@ use crate::{
 pub union iox2_static_config_details_t {
     pub event: iox2_static_config_event_t,
     pub publish_subscribe: iox2_static_config_publish_subscribe_t,
+    pub request_response: iox2_static_config_request_response_t,
+    pub blackboard: iox2_static_config_blackboard_t,
+    pub pipeline: iox2_static_config_pipeline_t,
 }
 
 #[derive(Clone, Copy)]
@@ -68,6 +72,15 @@ impl From<&StaticConfig> for iox2_static_config_t {
                     MessagingPattern::PublishSubscribe(pubsub) => iox2_static_config_details_t {
                         publish_subscribe: pubsub.into(),
                     },
+                    MessagingPattern::RequestResponse(reqres) => iox2_static_config_details_t {
+                        request_response: reqres.into(),
+                    },
+                    MessagingPattern::Blackboard(blackboard) => iox2_static_config_details_t {
+                        blackboard: blackboard.into(),
+                    },
+                    MessagingPattern::Pipeline(pipeline) => iox2_static_config_details_t {
+                        pipeline: pipeline.into(),
+                    },
                     _ => {
                         fatal_panic!(from "StaticConfig", "missing implementation for messaging pattern.")
                     }
@@ -0,0 +1,45 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![allow(non_camel_case_types)]
+
+use iceoryx2::service::static_config::request_response::StaticConfig;
+
+use crate::iox2_message_type_details_t;
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct iox2_static_config_request_response_t {
+    pub max_clients: usize,
+    pub max_servers: usize,
+    pub max_nodes: usize,
+    pub max_active_requests: usize,
+    pub client_max_response_buffer_size: usize,
+    pub client_max_borrowed_responses: usize,
+    pub request_message_type_details: iox2_message_type_details_t,
+    pub response_message_type_details: iox2_message_type_details_t,
+}
+
+impl From<&StaticConfig> for iox2_static_config_request_response_t {
+    fn from(c: &StaticConfig) -> Self {
+        Self {
+            max_clients: c.max_clients(),
+            max_servers: c.max_servers(),
+            max_nodes: c.max_nodes(),
+            max_active_requests: c.max_active_requests(),
+            client_max_response_buffer_size: c.client_max_response_buffer_size(),
+            client_max_borrowed_responses: c.client_max_borrowed_responses(),
+            request_message_type_details: c.request_message_type_details().into(),
+            response_message_type_details: c.response_message_type_details().into(),
+        }
+    }
+}
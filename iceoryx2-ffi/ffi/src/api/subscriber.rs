@@ -96,7 +96,7 @@ impl SubscriberUnion {
 #[repr(C)]
 #[repr(align(16))] // alignment of Option<SubscriberUnion>
 pub struct iox2_subscriber_storage_t {
-    internal: [u8; 816], // magic number obtained with size_of::<Option<SubscriberUnion>>()
+    internal: [u8; 1488], // magic number obtained with size_of::<Option<SubscriberUnion>>()
 }
 
 #[repr(C)]
@@ -185,6 +185,55 @@ pub unsafe extern "C" fn iox2_subscriber_buffer_size(
     }
 }
 
+/// Returns the number of samples that were lost since the subscriber was created.
+///
+/// # Arguments
+///
+/// * `subscriber_handle` - Must be a valid [`iox2_subscriber_h_ref`]
+///   obtained by [`iox2_port_factory_subscriber_builder_create`](crate::iox2_port_factory_subscriber_builder_create).
+///
+/// # Safety
+///
+/// * `subscriber_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_number_of_lost_samples(
+    subscriber_handle: iox2_subscriber_h_ref,
+) -> u64 {
+    subscriber_handle.assert_non_null();
+
+    let subscriber = &mut *subscriber_handle.as_type();
+
+    match subscriber.service_type {
+        iox2_service_type_e::IPC => subscriber.value.as_ref().ipc.number_of_lost_samples(),
+        iox2_service_type_e::LOCAL => subscriber.value.as_ref().local.number_of_lost_samples(),
+    }
+}
+
+/// Returns the number of samples that were overridden in the buffer of the subscriber due to a
+/// safe overflow.
+///
+/// # Arguments
+///
+/// * `subscriber_handle` - Must be a valid [`iox2_subscriber_h_ref`]
+///   obtained by [`iox2_port_factory_subscriber_builder_create`](crate::iox2_port_factory_subscriber_builder_create).
+///
+/// # Safety
+///
+/// * `subscriber_handle` must be valid handles
+#[no_mangle]
+pub unsafe extern "C" fn iox2_subscriber_missed_samples(
+    subscriber_handle: iox2_subscriber_h_ref,
+) -> u64 {
+    subscriber_handle.assert_non_null();
+
+    let subscriber = &mut *subscriber_handle.as_type();
+
+    match subscriber.service_type {
+        iox2_service_type_e::IPC => subscriber.value.as_ref().ipc.missed_samples(),
+        iox2_service_type_e::LOCAL => subscriber.value.as_ref().local.missed_samples(),
+    }
+}
+
 /// Returns the unique port id of the subscriber.
 ///
 /// # Arguments
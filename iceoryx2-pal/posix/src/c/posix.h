@@ -28,7 +28,11 @@
 #endif
 
 #ifdef __linux__
+#include <linux/futex.h>
 #include <mqueue.h>
+#include <sys/epoll.h>
+#include <sys/eventfd.h>
+#include <sys/syscall.h>
 #endif
 
 #if !(defined(_WIN64) || defined(_WIN32))
@@ -19,6 +19,23 @@ pub const CPU_SETSIZE: usize = crate::internal::__CPU_SETSIZE as _;
 pub const FD_SETSIZE: usize = crate::internal::FD_SETSIZE as _;
 pub const NULL_TERMINATOR: c_char = 0;
 
+pub const EPOLL_CLOEXEC: int = crate::internal::EPOLL_CLOEXEC as _;
+pub const EPOLL_CTL_ADD: int = crate::internal::EPOLL_CTL_ADD as _;
+pub const EPOLL_CTL_MOD: int = crate::internal::EPOLL_CTL_MOD as _;
+pub const EPOLL_CTL_DEL: int = crate::internal::EPOLL_CTL_DEL as _;
+pub const EPOLLIN: uint = crate::internal::EPOLL_EVENTS_EPOLLIN as _;
+pub const EPOLLOUT: uint = crate::internal::EPOLL_EVENTS_EPOLLOUT as _;
+pub const EPOLLERR: uint = crate::internal::EPOLL_EVENTS_EPOLLERR as _;
+pub const EPOLLHUP: uint = crate::internal::EPOLL_EVENTS_EPOLLHUP as _;
+pub const EPOLLET: uint = crate::internal::EPOLL_EVENTS_EPOLLET as _;
+
+pub const EFD_CLOEXEC: int = crate::internal::EFD_CLOEXEC as _;
+pub const EFD_NONBLOCK: int = crate::internal::EFD_NONBLOCK as _;
+pub const EFD_SEMAPHORE: int = crate::internal::EFD_SEMAPHORE as _;
+
+pub const FUTEX_WAIT: int = crate::internal::FUTEX_WAIT as _;
+pub const FUTEX_WAKE: int = crate::internal::FUTEX_WAKE as _;
+
 pub const O_RDONLY: int = crate::internal::O_RDONLY as _;
 pub const O_WRONLY: int = crate::internal::O_WRONLY as _;
 pub const O_RDWR: int = crate::internal::O_RDWR as _;
@@ -12,8 +12,11 @@
 
 pub mod constants;
 pub mod dirent;
+pub mod epoll;
 pub mod errno;
+pub mod eventfd;
 pub mod fcntl;
+pub mod futex;
 pub mod inet;
 pub mod mman;
 pub mod mqueue;
@@ -36,8 +39,11 @@ pub mod unistd;
 
 pub use crate::linux::constants::*;
 pub use crate::linux::dirent::*;
+pub use crate::linux::epoll::*;
 pub use crate::linux::errno::*;
+pub use crate::linux::eventfd::*;
 pub use crate::linux::fcntl::*;
+pub use crate::linux::futex::*;
 pub use crate::linux::inet::*;
 pub use crate::linux::mman::*;
 pub use crate::linux::mqueue::*;
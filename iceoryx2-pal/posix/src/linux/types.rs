@@ -158,6 +158,9 @@ impl Struct for timeval {}
 pub type fd_set = crate::internal::fd_set;
 impl Struct for fd_set {}
 
+pub type epoll_event = crate::internal::epoll_event;
+impl Struct for epoll_event {}
+
 pub type dirent = crate::internal::dirent;
 impl Struct for dirent {}
 
@@ -0,0 +1,265 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received: {:?}", *active_request);
+//!     println!("header client id {:?}", active_request.header().client_id());
+//!
+//!     active_request.send_copy(*active_request + 1)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::Deref, sync::Arc};
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_cal::{
+    shm_allocator::PointerOffset,
+    zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError},
+};
+
+use crate::{
+    port::{
+        details::client_connections::ClientConnection,
+        port_identifiers::UniqueClientId,
+        server::{ServerBackend, ServerLoanError, ServerSendError},
+    },
+    response_mut::ResponseMut,
+    response_mut_uninit::ResponseMutUninit,
+    service::header::request_response::{RequestHeader, ResponseHeader},
+};
+
+/// A request that was received by a [`crate::port::server::Server`] via
+/// [`crate::port::server::Server::receive()`]. It stores the payload of the request and is
+/// used to send the corresponding responses back to the
+/// [`crate::port::client::Client`]. When it goes out of scope, the request is released.
+pub struct ActiveRequest<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    server_backend: Arc<ServerBackend<Service>>,
+    connection: Arc<ClientConnection<Service>>,
+    offset: PointerOffset,
+    header: *const RequestHeader,
+    payload: *const RequestPayload,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Debug
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ActiveRequest<{}, {}, {}> {{ header: {:?}, offset: {:?} }}",
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.header(),
+            self.offset
+        )
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Deref
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    type Target = RequestPayload;
+    fn deref(&self) -> &Self::Target {
+        self.payload()
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        match self.connection.request_receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The clients retrieve channel is full and the request cannot be returned.");
+            }
+        }
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        server_backend: &Arc<ServerBackend<Service>>,
+        connection: &Arc<ClientConnection<Service>>,
+        offset: PointerOffset,
+        header: *const RequestHeader,
+        payload: *const RequestPayload,
+    ) -> Self {
+        Self {
+            server_backend: server_backend.clone(),
+            connection: connection.clone(),
+            offset,
+            header,
+            payload,
+            _response_payload: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        unsafe { &*self.header }
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`crate::port::client::Client`] that sent the
+    /// request.
+    pub fn origin(&self) -> UniqueClientId {
+        self.connection.client_id
+    }
+
+    /// Loans/allocates a [`ResponseMutUninit`] from the underlying data segment of the
+    /// [`crate::port::server::Server`]. The user has to initialize the payload before it can
+    /// be sent.
+    ///
+    /// On failure it returns [`ServerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .request_response::<u64, u64>()
+    /// #   .open_or_create()?;
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let response = active_request.loan_uninit()?;
+    ///     let response = response.write_payload(42);
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<ResponseMutUninit<Service, ResponsePayload>, ServerLoanError> {
+        let chunk = self.server_backend.allocate()?;
+        let header_ptr = chunk.data_ptr as *mut ResponseHeader;
+        let payload_ptr = self
+            .server_backend
+            .static_config()
+            .response_message_type_details()
+            .payload_ptr_from_header(header_ptr.cast())
+            as *mut MaybeUninit<ResponsePayload>;
+
+        unsafe {
+            header_ptr.write(ResponseHeader::new(
+                self.server_backend.client_connections.server_id(),
+                self.header().request_id(),
+            ))
+        };
+
+        Ok(ResponseMutUninit::new(
+            &self.server_backend,
+            &self.connection,
+            header_ptr,
+            payload_ptr,
+            chunk.offset,
+        ))
+    }
+
+    /// Copies the input `value` into a [`ResponseMut`] and sends it to the
+    /// [`crate::port::client::Client`] that sent the request. On failure it returns a
+    /// [`ServerSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .request_response::<u64, u64>()
+    /// #   .open_or_create()?;
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     active_request.send_copy(1234)?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: ResponsePayload) -> Result<(), ServerSendError> {
+        let msg = "Unable to send copy of response";
+        let response = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a response failed.", msg);
+
+        response.write_payload(value).send()
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Default + Debug>
+    ActiveRequest<Service, RequestPayload, ResponsePayload>
+{
+    /// Loans/allocates a [`ResponseMut`] from the underlying data segment of the
+    /// [`crate::port::server::Server`] and initialize it with the default value. This can be a
+    /// performance hit and [`ActiveRequest::loan_uninit`] can be used to loan a
+    /// [`core::mem::MaybeUninit<ResponsePayload>`].
+    ///
+    /// On failure it returns [`ServerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #   .request_response::<u64, u64>()
+    /// #   .open_or_create()?;
+    /// # let server = service.server_builder().create()?;
+    ///
+    /// while let Some(active_request) = server.receive()? {
+    ///     let mut response = active_request.loan()?;
+    ///     *response.payload_mut() = 42;
+    ///     response.send()?;
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<ResponseMut<Service, ResponsePayload>, ServerLoanError> {
+        Ok(self
+            .loan_uninit()?
+            .write_payload(ResponsePayload::default()))
+    }
+}
@@ -80,6 +80,7 @@ use std::time::Duration;
 
 use iceoryx2_bb_log::{debug, fail, trace, warn};
 
+use crate::service::port_factory::producer::DeliveryStrategy;
 use crate::service::port_factory::publisher::UnableToDeliverStrategy;
 
 /// Path to the default config file
@@ -125,6 +126,8 @@ pub struct Service {
     pub connection_suffix: FileName,
     /// The suffix of a one-to-one connection
     pub event_connection_suffix: FileName,
+    /// The suffix of the blackboard management data segment
+    pub blackboard_mgmt_suffix: FileName,
 }
 
 /// All configurable settings of a [`crate::node::Node`].
@@ -215,6 +218,12 @@ pub struct Defaults {
     pub publish_subscribe: PublishSubscribe,
     /// Default settings for the messaging pattern event
     pub event: Event,
+    /// Default settings for the messaging pattern request-response
+    pub request_response: RequestResponse,
+    /// Default settings for the messaging pattern blackboard
+    pub blackboard: Blackboard,
+    /// Default settings for the messaging pattern pipeline
+    pub pipeline: Pipeline,
 }
 
 /// Default settings for the publish-subscribe messaging pattern. These settings are used unless
@@ -238,6 +247,10 @@ pub struct PublishSubscribe {
     /// The maximum amount of [`crate::sample_mut::SampleMut`]s a [`crate::port::publisher::Publisher`] can
     /// loan at the same time.
     pub publisher_max_loaned_samples: usize,
+    /// The maximum number of data segments a [`crate::port::publisher::Publisher`] with a
+    /// growing data segment can use at the same time. Every connection reserves resources for
+    /// all of them.
+    pub publisher_max_number_of_segments: u8,
     /// The maximum history size a [`crate::port::subscriber::Subscriber`] can request from a
     /// [`crate::port::publisher::Publisher`].
     pub publisher_history_size: usize,
@@ -274,6 +287,79 @@ pub struct Event {
     pub event_id_max_value: usize,
 }
 
+/// Default settings for the request-response messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct RequestResponse {
+    /// The maximum amount of supported [`crate::port::client::Client`]
+    pub max_clients: usize,
+    /// The maximum amount of supported [`crate::port::server::Server`]
+    pub max_servers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum amount of requests a [`crate::port::client::Client`] can have in flight
+    /// at the same time, meaning requests whose
+    /// [`PendingResponse`](crate::pending_response::PendingResponse) is still alive.
+    pub max_active_requests: usize,
+    /// The maximum buffer size for [`Response`](crate::response::Response)s a
+    /// [`crate::port::client::Client`] can have.
+    pub client_max_response_buffer_size: usize,
+    /// The maximum amount of [`Response`](crate::response::Response)s a
+    /// [`crate::port::client::Client`] can hold at the same time.
+    pub client_max_borrowed_responses: usize,
+    /// The maximum amount of [`RequestMut`](crate::request_mut::RequestMut)s a
+    /// [`crate::port::client::Client`] can loan at the same time.
+    pub client_max_loaned_requests: usize,
+    /// The maximum amount of [`ResponseMut`](crate::response_mut::ResponseMut)s a
+    /// [`crate::port::server::Server`] can loan at the same time.
+    pub server_max_loaned_responses: usize,
+}
+
+/// Default settings for the blackboard messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Blackboard {
+    /// The maximum amount of supported [`crate::port::reader::Reader`]
+    pub max_readers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+}
+
+/// Default settings for the pipeline messaging pattern. These settings are used unless
+/// the user specifies custom QoS or port settings.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub struct Pipeline {
+    /// The maximum amount of supported [`crate::port::producer::Producer`]
+    pub max_producers: usize,
+    /// The maximum amount of supported [`crate::port::consumer::Consumer`]
+    pub max_consumers: usize,
+    /// The maximum amount of supported [`crate::node::Node`]s. Defines indirectly how many
+    /// processes can open the service at the same time.
+    pub max_nodes: usize,
+    /// The maximum buffer size a [`crate::port::consumer::Consumer`] can have
+    pub consumer_max_buffer_size: usize,
+    /// The maximum amount of [`crate::work_item::WorkItem`]s a
+    /// [`crate::port::consumer::Consumer`] can hold at the same time.
+    pub consumer_max_borrowed_work_items: usize,
+    /// The maximum amount of [`crate::work_item_mut::WorkItemMut`]s a
+    /// [`crate::port::producer::Producer`] can loan at the same time.
+    pub producer_max_loaned_work_items: usize,
+    /// Defines how a [`crate::port::producer::Producer`] selects the
+    /// [`crate::port::consumer::Consumer`] that receives the next work item.
+    pub delivery_strategy: DeliveryStrategy,
+    /// Default strategy for a [`crate::port::producer::Producer`] when no
+    /// [`crate::port::consumer::Consumer`] has space left in its buffer.
+    pub unable_to_deliver_strategy: UnableToDeliverStrategy,
+}
+
 /// Represents the configuration that iceoryx2 will utilize. It is divided into two sections:
 /// the [Global] settings, which must align with the iceoryx2 instance the application intends to
 /// join, and the [Defaults] for communication within that iceoryx2 instance. The user has the
@@ -305,6 +391,7 @@ impl Default for Config {
                     creation_timeout: Duration::from_millis(500),
                     connection_suffix: FileName::new(b".connection").unwrap(),
                     event_connection_suffix: FileName::new(b".event").unwrap(),
+                    blackboard_mgmt_suffix: FileName::new(b".blackboard_mgmt").unwrap(),
                 },
                 node: Node {
                     directory: Path::new(b"nodes").unwrap(),
@@ -324,6 +411,7 @@ impl Default for Config {
                     subscriber_max_buffer_size: 2,
                     subscriber_max_borrowed_samples: 2,
                     publisher_max_loaned_samples: 2,
+                    publisher_max_number_of_segments: 8,
                     enable_safe_overflow: true,
                     unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
                     subscriber_expired_connection_buffer: 128,
@@ -334,6 +422,30 @@ impl Default for Config {
                     max_nodes: 36,
                     event_id_max_value: 4294967295,
                 },
+                request_response: RequestResponse {
+                    max_clients: 8,
+                    max_servers: 2,
+                    max_nodes: 20,
+                    max_active_requests: 4,
+                    client_max_response_buffer_size: 4,
+                    client_max_borrowed_responses: 2,
+                    client_max_loaned_requests: 2,
+                    server_max_loaned_responses: 2,
+                },
+                blackboard: Blackboard {
+                    max_readers: 8,
+                    max_nodes: 20,
+                },
+                pipeline: Pipeline {
+                    max_producers: 2,
+                    max_consumers: 8,
+                    max_nodes: 20,
+                    consumer_max_buffer_size: 2,
+                    consumer_max_borrowed_work_items: 2,
+                    producer_max_loaned_work_items: 2,
+                    delivery_strategy: DeliveryStrategy::RoundRobin,
+                    unable_to_deliver_strategy: UnableToDeliverStrategy::Block,
+                },
             },
         }
     }
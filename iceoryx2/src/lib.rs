@@ -22,9 +22,9 @@
 //!
 //! - Publish-Subscribe
 //! - Events
-//! - Request-Response (planned)
-//! - Pipeline (planned)
-//! - Blackboard (planned)
+//! - Request-Response
+//! - Blackboard
+//! - Pipeline
 //!
 //! For a comprehensive list of all planned features, please refer to the
 //! [GitHub Roadmap](https://github.com/eclipse-iceoryx/iceoryx2/blob/main/ROADMAP.md).
@@ -174,6 +174,133 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! Explore a simple request-response setup where the client sends a request to the server and
+//! receives the corresponding responses until the processes are gracefully terminated by the
+//! user with `CTRL+C`.
+//!
+//! **Server (Process 1)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service.server_builder().create()?;
+//!
+//! while node.wait(CYCLE_TIME).is_ok() {
+//!     while let Some(active_request) = server.receive()? {
+//!         println!("received request: {:?}", *active_request);
+//!         let response = active_request.loan_uninit()?;
+//!         let response = response.write_payload(*active_request * 2);
+//!         response.send()?;
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Client (Process 2)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service.client_builder().create()?;
+//!
+//! let mut counter: u64 = 0;
+//! while node.wait(CYCLE_TIME).is_ok() {
+//!     let pending_response = client.send_copy(counter)?;
+//!     counter += 1;
+//!
+//!     while let Some(response) = pending_response.receive()? {
+//!         println!("received response: {:?}", *response);
+//!     }
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! This minimal example showcases a writer that updates the entries of a shared key-value store
+//! while a reader always reads the latest value of an entry.
+//!
+//! **Writer (Process 1)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .add::<f64>(1, 0.0)
+//!     .create()?;
+//!
+//! let writer = service.writer_builder().create()?;
+//! let counter_entry = writer.entry::<i32>(&0)?;
+//! let temperature_entry = writer.entry::<f64>(&1)?;
+//!
+//! let mut counter = 0;
+//! while node.wait(CYCLE_TIME).is_ok() {
+//!     counter += 1;
+//!     counter_entry.update_with_copy(counter);
+//!     temperature_entry.update_with_copy(counter as f64 * 0.5);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! **Reader (Process 2)**
+//!
+//! ```no_run
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! const CYCLE_TIME: Duration = Duration::from_secs(1);
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_opener::<u64>()
+//!     .open()?;
+//!
+//! let reader = service.reader_builder().create()?;
+//! let counter_entry = reader.entry::<i32>(&0)?;
+//!
+//! while node.wait(CYCLE_TIME).is_ok() {
+//!     println!("counter: {}", counter_entry.get());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Quality Of Services
 //!
 //! Quality of service settings, or service settings, play a crucial role in determining memory
@@ -233,6 +360,53 @@
 //! # }
 //! ```
 //!
+//! ## Request-Response
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"ReqResQos".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     // the maximum amount of requests a client can have in flight
+//!     .max_active_requests(4)
+//!     // the maximum buffer size for responses of a client
+//!     .client_max_response_buffer_size(4)
+//!     // how many responses a client can borrow in parallel
+//!     .client_max_borrowed_responses(2)
+//!     // the maximum amount of clients of this service
+//!     .max_clients(5)
+//!     // the maximum amount of servers of this service
+//!     .max_servers(2)
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Blackboard
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//!
+//! let service = node.service_builder(&"BlackboardQos".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     // the maximum amount of readers of this service
+//!     .max_readers(4)
+//!     // the maximum amount of nodes that can open this service
+//!     .max_nodes(3)
+//!     .add::<u64>(0, 0)
+//!     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
 //! # Port Behavior
 //!
 //! Certain ports in iceoryx2 provide users with the flexibility to define custom behaviors in
@@ -267,6 +441,13 @@
 //!
 //! # Feature Flags
 //!
+//!  * `async` - Enables the runtime independent async API, like
+//!     [`Listener::wait_one_async()`](crate::port::listener::Listener::wait_one_async()),
+//!     [`Listener::stream()`](crate::port::listener::Listener::stream()),
+//!     [`Subscriber::receive_async()`](crate::port::subscriber::Subscriber::receive_async()) and
+//!     [`WaitSet::wait_and_process_async()`](crate::port::waitset::WaitSet::wait_and_process_async()).
+//!     The futures are woken up by a background thread that waits with the reactor of the
+//!     service on the file descriptors of all pending futures.
 //!  * `dev_permissions` - The permissions of all resources will be set to read, write, execute
 //!     for everyone. This shall not be used in production and is meant to be enabled in a docker
 //!     environment with inconsistent user configuration.
@@ -285,17 +466,40 @@
 #[cfg(doctest)]
 mod compiletests;
 
+/// A request that was received by a [`Server`](crate::port::server::Server) and is used to
+/// send the corresponding responses.
+pub mod active_request;
+
 /// Handles iceoryx2s global configuration
 pub mod config;
 
 /// Central instance that owns all service entities and can handle incoming event in an event loop
 pub mod node;
 
+/// A request that was sent by a [`Client`](crate::port::client::Client) and waits for the
+/// corresponding responses.
+pub mod pending_response;
+
 /// The ports or communication endpoints of iceoryx2
 pub mod port;
 
 pub(crate) mod raw_sample;
 
+/// The payload that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut;
+
+/// The uninitialized payload that is sent by a [`Client`](crate::port::client::Client).
+pub mod request_mut_uninit;
+
+/// The payload that is received by a [`Client`](crate::port::client::Client).
+pub mod response;
+
+/// The payload that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut;
+
+/// The uninitialized payload that is sent by a [`Server`](crate::port::server::Server).
+pub mod response_mut_uninit;
+
 /// The payload that is received by a [`Subscriber`](crate::port::subscriber::Subscriber).
 pub mod sample;
 
@@ -308,5 +512,14 @@ pub mod sample_mut_uninit;
 /// [`MessagingPattern`](crate::service::messaging_pattern::MessagingPattern)
 pub mod service;
 
+/// The payload that is received by a [`Consumer`](crate::port::consumer::Consumer).
+pub mod work_item;
+
+/// The payload that is sent by a [`Producer`](crate::port::producer::Producer).
+pub mod work_item_mut;
+
+/// The uninitialized payload that is sent by a [`Producer`](crate::port::producer::Producer).
+pub mod work_item_mut_uninit;
+
 /// Loads a meaninful subset to cover 90% of the iceoryx2 communication use cases.
 pub mod prelude;
@@ -0,0 +1,336 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Opt-in health monitoring of [`Node`]s. A [`Node`] that was created with
+//! [`NodeBuilder::heartbeat_interval()`](crate::node::NodeBuilder::heartbeat_interval())
+//! sends heartbeats on an internal event service whenever it calls [`Node::wait()`] or
+//! [`Node::send_heartbeat()`]. Any other [`Node`] can create a [`HeartbeatMonitor`] for it
+//! that reports the [`HeartbeatState`] and detects not only crashed but also hung processes.
+//!
+//! The [`HeartbeatMonitor`] can be attached to a [`WaitSet`](crate::port::waitset::WaitSet)
+//! with a deadline so that the user is woken up when a heartbeat was received or is overdue.
+//!
+//! # Example
+//!
+//! ```
+//! use core::time::Duration;
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::node::heartbeat::HeartbeatState;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let monitored_node = NodeBuilder::new()
+//!     .heartbeat_interval(Duration::from_millis(100))
+//!     .create::<ipc::Service>()?;
+//!
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let monitor = node
+//!     .heartbeat_monitor_builder(monitored_node.id())
+//!     .timeout(Duration::from_millis(500))
+//!     .create()?;
+//!
+//! let waitset = WaitSetBuilder::new().create::<ipc::Service>()?;
+//! let guard = waitset.attach_deadline(&monitor, monitor.timeout())?;
+//!
+//! let on_event = |attachment_id: WaitSetAttachmentId<ipc::Service>| {
+//!     if attachment_id.has_event_from(&guard) || attachment_id.has_missed_deadline(&guard) {
+//!         match monitor.state() {
+//!             HeartbeatState::Alive => (),
+//!             state => println!("the monitored node is {:?}", state),
+//!         }
+//!     }
+//! };
+//!
+//! waitset.try_wait_and_process(on_event)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use core::cell::Cell;
+use core::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+
+use crate::config::Config;
+use crate::node::{Node, NodeCreationFailure, NodeId, NodeState, NodeView, SharedNode};
+use crate::port::listener::Listener;
+use crate::port::notifier::Notifier;
+use crate::service;
+use crate::service::builder::event::EventOpenError;
+use crate::service::builder::Builder;
+use crate::service::service_name::ServiceName;
+
+/// The timeout that is used by the [`HeartbeatMonitorBuilder`] when no timeout is set
+/// explicitly.
+pub const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(1);
+
+fn heartbeat_service_name(node_id: &NodeId) -> ServiceName {
+    fatal_panic!(from "heartbeat_service_name()",
+        when ServiceName::new(&format!("iox2://node/{:032x}/heartbeat", node_id.value())),
+        "This should never happen! The heartbeat service name of the node {:?} is invalid.", node_id)
+}
+
+fn now() -> Time {
+    fatal_panic!(from "heartbeat::now()",
+        when Time::now_with_clock(ClockType::Monotonic),
+        "This should never happen! Unable to acquire the current time of the monotonic clock.")
+}
+
+/// The state of a monitored [`Node`] reported by [`HeartbeatMonitor::state()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatState {
+    /// The last heartbeat was received within the timeout.
+    Alive,
+    /// No heartbeat was received within the timeout but the process of the [`Node`] is still
+    /// running, it is most likely hung.
+    Unresponsive,
+    /// No heartbeat was received within the timeout and the process of the [`Node`] died.
+    Dead,
+    /// The [`Node`] does not exist anymore, either it was shut down or the stale resources were
+    /// removed after its death.
+    Removed,
+}
+
+/// Failures that can occur when a [`HeartbeatMonitor`] is created with the
+/// [`HeartbeatMonitorBuilder`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeartbeatMonitorCreateError {
+    /// The monitored [`Node`] does not exist or was not created with heartbeats enabled.
+    HeartbeatsNotEnabled,
+    /// The process has insufficient permissions to open the heartbeat service of the
+    /// monitored [`Node`].
+    InsufficientPermissions,
+    /// The heartbeat service of the monitored [`Node`] does not support any more monitors.
+    ExceedsMaxSupportedMonitors,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl std::fmt::Display for HeartbeatMonitorCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "HeartbeatMonitorCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for HeartbeatMonitorCreateError {}
+
+#[derive(Debug)]
+struct HeartbeatSender<Service: service::Service> {
+    notifier: Notifier<Service>,
+    last_heartbeat: Option<Time>,
+}
+
+/// Sends the heartbeats of a [`Node`], owned by the [`Node`] itself.
+#[derive(Debug)]
+pub(crate) struct Heartbeat<Service: service::Service> {
+    interval: Duration,
+    sender: Mutex<HeartbeatSender<Service>>,
+}
+
+impl<Service: service::Service> Heartbeat<Service> {
+    pub(crate) fn new(
+        node: &Node<Service>,
+        interval: Duration,
+    ) -> Result<Self, NodeCreationFailure> {
+        let msg = "Unable to create heartbeat of node";
+        let origin = format!("Heartbeat::new({:?})", node.id());
+        let service = fail!(from origin,
+            when node.service_builder(&heartbeat_service_name(node.id()))
+                .event()
+                .max_notifiers(1)
+                .open_or_create(),
+            with NodeCreationFailure::InternalError,
+            "{msg} since the heartbeat service could not be created.");
+
+        let notifier = fail!(from origin,
+            when service.notifier_builder().create(),
+            with NodeCreationFailure::InternalError,
+            "{msg} since the heartbeat notifier could not be created.");
+
+        Ok(Self {
+            interval,
+            sender: Mutex::new(HeartbeatSender {
+                notifier,
+                last_heartbeat: None,
+            }),
+        })
+    }
+
+    pub(crate) fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    pub(crate) fn send(&self) {
+        let mut sender = self.sender.lock().unwrap();
+        warn!(from self, when sender.notifier.notify(),
+            "Unable to send heartbeat.");
+        sender.last_heartbeat = Some(now());
+    }
+
+    pub(crate) fn send_if_due(&self) {
+        let is_due = match self.sender.lock().unwrap().last_heartbeat {
+            Some(last_heartbeat) => last_heartbeat
+                .elapsed()
+                .map_or(true, |elapsed| self.interval <= elapsed),
+            None => true,
+        };
+
+        if is_due {
+            self.send();
+        }
+    }
+}
+
+/// Builder to create a [`HeartbeatMonitor`] for a [`Node`] that was created with heartbeats
+/// enabled. Created via [`Node::heartbeat_monitor_builder()`].
+#[derive(Debug)]
+pub struct HeartbeatMonitorBuilder<Service: service::Service> {
+    shared_node: Arc<SharedNode<Service>>,
+    node_id: NodeId,
+    timeout: Duration,
+}
+
+impl<Service: service::Service> HeartbeatMonitorBuilder<Service> {
+    pub(crate) fn new(shared_node: Arc<SharedNode<Service>>, node_id: &NodeId) -> Self {
+        Self {
+            shared_node,
+            node_id: *node_id,
+            timeout: DEFAULT_HEARTBEAT_TIMEOUT,
+        }
+    }
+
+    /// Defines the time after the last received heartbeat until the monitored [`Node`] is
+    /// no longer considered as [`HeartbeatState::Alive`]. It should be a multiple of the
+    /// heartbeat interval of the monitored [`Node`]. If it is not set
+    /// [`DEFAULT_HEARTBEAT_TIMEOUT`] is used.
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = value;
+        self
+    }
+
+    /// Creates the [`HeartbeatMonitor`] or returns a [`HeartbeatMonitorCreateError`] on
+    /// failure.
+    pub fn create(self) -> Result<HeartbeatMonitor<Service>, HeartbeatMonitorCreateError> {
+        let msg = "Unable to create heartbeat monitor";
+        let service = match Builder::new(
+            &heartbeat_service_name(&self.node_id),
+            self.shared_node.clone(),
+        )
+        .event()
+        .open()
+        {
+            Ok(service) => service,
+            Err(EventOpenError::DoesNotExist) => {
+                fail!(from self, with HeartbeatMonitorCreateError::HeartbeatsNotEnabled,
+                    "{msg} since the node {:?} does not exist or has no heartbeats enabled.", self.node_id);
+            }
+            Err(EventOpenError::InsufficientPermissions) => {
+                fail!(from self, with HeartbeatMonitorCreateError::InsufficientPermissions,
+                    "{msg} due to insufficient permissions to open the heartbeat service.");
+            }
+            Err(EventOpenError::ExceedsMaxNumberOfNodes) => {
+                fail!(from self, with HeartbeatMonitorCreateError::ExceedsMaxSupportedMonitors,
+                    "{msg} since the heartbeat service does not support any more nodes.");
+            }
+            Err(e) => {
+                fail!(from self, with HeartbeatMonitorCreateError::InternalFailure,
+                    "{msg} since the heartbeat service could not be opened ({:?}).", e);
+            }
+        };
+
+        let listener = fail!(from self, when service.listener_builder().create(),
+            with HeartbeatMonitorCreateError::ExceedsMaxSupportedMonitors,
+            "{msg} since the heartbeat listener could not be created.");
+
+        Ok(HeartbeatMonitor {
+            node_id: self.node_id,
+            config: self.shared_node.config().clone(),
+            timeout: self.timeout,
+            listener,
+            last_heartbeat: Cell::new(now()),
+        })
+    }
+}
+
+/// Monitors the heartbeats of a [`Node`] and reports its [`HeartbeatState`]. Every received
+/// heartbeat wakes up a [`WaitSet`](crate::port::waitset::WaitSet) the monitor is attached to.
+#[derive(Debug)]
+pub struct HeartbeatMonitor<Service: service::Service> {
+    node_id: NodeId,
+    config: Config,
+    timeout: Duration,
+    listener: Listener<Service>,
+    last_heartbeat: Cell<Time>,
+}
+
+impl<Service: service::Service> FileDescriptorBased for HeartbeatMonitor<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: FileDescriptorBased,
+{
+    fn file_descriptor(&self) -> &FileDescriptor {
+        self.listener.file_descriptor()
+    }
+}
+
+impl<Service: service::Service> SynchronousMultiplexing for HeartbeatMonitor<Service> where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing
+{
+}
+
+impl<Service: service::Service> HeartbeatMonitor<Service> {
+    /// Returns the [`NodeId`] of the monitored [`Node`].
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the timeout after which the monitored [`Node`] is no longer considered as
+    /// [`HeartbeatState::Alive`].
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Collects all received heartbeats and returns the current [`HeartbeatState`] of the
+    /// monitored [`Node`]. When the last heartbeat is older than the timeout the state of the
+    /// [`Node`]s process is acquired to distinguish a hung from a dead [`Node`].
+    pub fn state(&self) -> HeartbeatState {
+        let mut has_received_heartbeat = false;
+        warn!(from self, when self.listener.try_wait_all(|_| has_received_heartbeat = true),
+            "Unable to collect the received heartbeats.");
+
+        if has_received_heartbeat {
+            self.last_heartbeat.set(now());
+        }
+
+        let is_overdue = self
+            .last_heartbeat
+            .get()
+            .elapsed()
+            .map_or(true, |elapsed| self.timeout < elapsed);
+
+        if !is_overdue {
+            return HeartbeatState::Alive;
+        }
+
+        match NodeState::<Service>::new(&self.node_id, &self.config) {
+            Ok(Some(NodeState::Dead(_))) => HeartbeatState::Dead,
+            // nodes of the own process are always reported as alive, a removed node is detected
+            // by its missing details
+            Ok(Some(NodeState::Alive(view))) if view.details().is_none() => HeartbeatState::Removed,
+            Ok(None) => HeartbeatState::Removed,
+            _ => HeartbeatState::Unresponsive,
+        }
+    }
+}
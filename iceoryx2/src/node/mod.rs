@@ -115,9 +115,12 @@
 /// The name for a node.
 pub mod node_name;
 
+pub mod heartbeat;
+
 #[doc(hidden)]
 pub mod testing;
 
+use crate::node::heartbeat::{Heartbeat, HeartbeatMonitorBuilder};
 use crate::node::node_name::NodeName;
 use crate::service::builder::{Builder, OpenDynamicStorageFailure};
 use crate::service::config_scheme::{
@@ -148,6 +151,8 @@ use std::sync::atomic::Ordering;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
+const MIN_HEARTBEAT_INTERVAL: Duration = Duration::from_millis(1);
+
 /// The system-wide unique id of a [`Node`]
 #[derive(
     Debug, Eq, Hash, PartialEq, Clone, Copy, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
@@ -726,6 +731,7 @@ impl<Service: service::Service> Drop for SharedNode<Service> {
 #[derive(Debug)]
 pub struct Node<Service: service::Service> {
     shared: Arc<SharedNode<Service>>,
+    heartbeat: Option<Heartbeat<Service>>,
 }
 
 unsafe impl<Service: service::Service> Send for Node<Service> {}
@@ -751,6 +757,23 @@ impl<Service: service::Service> Node<Service> {
         Builder::new(name, self.shared.clone())
     }
 
+    /// Instantiates a [`HeartbeatMonitorBuilder`] to monitor the heartbeats of the [`Node`]
+    /// with the provided [`NodeId`]. The monitored [`Node`] must be created with
+    /// [`NodeBuilder::heartbeat_interval()`].
+    pub fn heartbeat_monitor_builder(&self, node_id: &NodeId) -> HeartbeatMonitorBuilder<Service> {
+        HeartbeatMonitorBuilder::new(self.shared.clone(), node_id)
+    }
+
+    /// Sends a heartbeat when the [`Node`] was created with
+    /// [`NodeBuilder::heartbeat_interval()`], otherwise it does nothing. [`Node::wait()`] sends
+    /// the heartbeats automatically, this is only required when the [`Node`] uses a different
+    /// event loop, like a [`WaitSet`](crate::port::waitset::WaitSet).
+    pub fn send_heartbeat(&self) {
+        if let Some(heartbeat) = &self.heartbeat {
+            heartbeat.send();
+        }
+    }
+
     /// Calls the provided callback for all [`Node`]s in the system under a given [`Config`] and
     /// provides [`NodeState<Service>`] as input argument. With every iteration the callback has to
     /// return [`CallbackProgression::Continue`] to perform the next iteration or
@@ -806,6 +829,8 @@ impl<Service: service::Service> Node<Service> {
     /// Waits until the cycle time has passed. It returns [`NodeWaitFailure::TerminationRequest`]
     /// when a `SIGTERM` signal was received or [`NodeWaitFailure::Interrupt`] when a `SIGINT`
     /// signal was received.
+    /// When the [`Node`] was created with [`NodeBuilder::heartbeat_interval()`] the heartbeats
+    /// are sent while waiting.
     pub fn wait(&self, cycle_time: Duration) -> Result<(), NodeWaitFailure> {
         let msg = "Unable to wait on node";
         if SignalHandler::termination_requested() {
@@ -813,7 +838,7 @@ impl<Service: service::Service> Node<Service> {
                 "{msg} since a termination request was received.");
         }
 
-        match nanosleep(cycle_time) {
+        match self.sleep(cycle_time) {
             Ok(()) => {
                 if SignalHandler::termination_requested() {
                     fail!(from self, with NodeWaitFailure::TerminationRequest,
@@ -834,6 +859,25 @@ impl<Service: service::Service> Node<Service> {
         }
     }
 
+    fn sleep(&self, cycle_time: Duration) -> Result<(), NanosleepError> {
+        let heartbeat = match &self.heartbeat {
+            Some(heartbeat) => heartbeat,
+            None => return nanosleep(cycle_time),
+        };
+
+        let mut remaining_time = cycle_time;
+        loop {
+            heartbeat.send_if_due();
+            if remaining_time.is_zero() {
+                return Ok(());
+            }
+
+            let sleep_time = remaining_time.min(heartbeat.interval());
+            nanosleep(sleep_time)?;
+            remaining_time -= sleep_time;
+        }
+    }
+
     /// Removes the stale system resources of all dead [`Node`]s. The dead [`Node`]s are also
     /// removed from all registered [`Service`](crate::service::Service)s.
     ///
@@ -1082,6 +1126,7 @@ impl<Service: service::Service> Node<Service> {
 pub struct NodeBuilder {
     name: Option<NodeName>,
     config: Option<Config>,
+    heartbeat_interval: Option<Duration>,
 }
 
 impl NodeBuilder {
@@ -1103,6 +1148,15 @@ impl NodeBuilder {
         self
     }
 
+    /// Enables the heartbeats of the [`Node`]. The [`Node`] sends a heartbeat at least every
+    /// interval while it is in [`Node::wait()`] so that other [`Node`]s can monitor it with a
+    /// [`HeartbeatMonitor`](crate::node::heartbeat::HeartbeatMonitor). An interval of zero is
+    /// not supported and adjusted to the smallest supported interval of one millisecond.
+    pub fn heartbeat_interval(mut self, value: Duration) -> Self {
+        self.heartbeat_interval = Some(value);
+        self
+    }
+
     /// Creates a new [`Node`] for a specific [`service::Service`]. All entities owned by the
     /// [`Node`] will have the same [`service::Service`].
     pub fn create<Service: service::Service>(self) -> Result<Node<Service>, NodeCreationFailure> {
@@ -1135,7 +1189,7 @@ impl NodeBuilder {
             self.create_node_details_storage::<Service>(&config, &NodeId(node_id))?;
         let monitoring_token = self.create_token::<Service>(&config, &monitor_name)?;
 
-        Ok(Node {
+        let mut node = Node {
             shared: Arc::new(SharedNode {
                 id: NodeId(node_id),
                 monitoring_token: UnsafeCell::new(Some(monitoring_token)),
@@ -1145,7 +1199,22 @@ impl NodeBuilder {
                 _details_storage: details_storage,
                 details,
             }),
-        })
+            heartbeat: None,
+        };
+
+        if let Some(mut interval) = self.heartbeat_interval {
+            if interval.is_zero() {
+                warn!(from self,
+                    "Setting the heartbeat interval to 0 is not supported. Adjust it to {:?}, the smallest supported value.",
+                    MIN_HEARTBEAT_INTERVAL);
+                interval = MIN_HEARTBEAT_INTERVAL;
+            }
+
+            node.heartbeat = Some(Heartbeat::new(&node, interval)?);
+            node.send_heartbeat();
+        }
+
+        Ok(node)
     }
 
     fn create_token<Service: service::Service>(
@@ -0,0 +1,136 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .request_response::<u64, u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(1234)?;
+//! println!("request {} was delivered to {} servers",
+//!     pending_response.request_id(), pending_response.number_of_server_connections());
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {:?} from {:?}", *response, response.origin());
+//! }
+//!
+//! // dropping the pending response informs the client that no more responses are expected
+//! drop(pending_response);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use crate::{
+    port::client::{ClientBackend, ClientReceiveError},
+    response::Response,
+};
+
+/// Represents a request that was sent by a [`crate::port::client::Client`] and is waiting
+/// for responses. Every [`Response`] received via [`PendingResponse::receive()`] belongs to
+/// the corresponding request. When the [`PendingResponse`] goes out of scope, all responses
+/// that arrive later for this request are discarded.
+pub struct PendingResponse<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    client_backend: Arc<ClientBackend<Service>>,
+    request_id: u64,
+    number_of_server_connections: usize,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Debug
+    for PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PendingResponse<{}, {}, {}> {{ request_id: {}, number_of_server_connections: {} }}",
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.request_id,
+            self.number_of_server_connections
+        )
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.client_backend.deactivate_request(self.request_id);
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    PendingResponse<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        client_backend: &Arc<ClientBackend<Service>>,
+        request_id: u64,
+        number_of_server_connections: usize,
+    ) -> Self {
+        Self {
+            client_backend: client_backend.clone(),
+            request_id,
+            number_of_server_connections,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        }
+    }
+
+    /// Returns the id of the request this [`PendingResponse`] belongs to.
+    pub fn request_id(&self) -> u64 {
+        self.request_id
+    }
+
+    /// Returns the number of [`crate::port::server::Server`]s the request was delivered to.
+    pub fn number_of_server_connections(&self) -> usize {
+        self.number_of_server_connections
+    }
+
+    /// Receives a [`Response`] that belongs to the request of this [`PendingResponse`]. If no
+    /// response is available [`None`] is returned. On failure [`ClientReceiveError`] is
+    /// returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<Response<Service, ResponsePayload>>, ClientReceiveError> {
+        match self.client_backend.receive_response(self.request_id)? {
+            None => Ok(None),
+            Some(details) => {
+                let header_ptr = details.header_ptr();
+                let payload_ptr = self
+                    .client_backend
+                    .static_config()
+                    .response_message_type_details()
+                    .payload_ptr_from_header(header_ptr.cast())
+                    as *const ResponsePayload;
+
+                Ok(Some(Response::new(details, header_ptr, payload_ptr)))
+            }
+        }
+    }
+}
@@ -0,0 +1,818 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let client = service
+//!     .client_builder()
+//!     // defines how many requests can be loaned in parallel
+//!     .max_loaned_requests(2)
+//!     .create()?;
+//!
+//! // loan some initialized memory and send it
+//! // the payload type must implement the [`core::default::Default`] trait in order to be able to use this API
+//! let mut request = client.loan()?;
+//! *request.payload_mut() = 1337;
+//! let pending_response = request.send()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let request = client.loan_uninit()?;
+//! let request = request.write_payload(1337);
+//! let other_pending_response = request.send()?;
+//!
+//! // send a copy of the value
+//! drop(pending_response);
+//! let pending_response = client.send_copy(313)?;
+//!
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received response: {:?}", *response);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::port_identifiers::UniqueClientId;
+use crate::pending_response::PendingResponse;
+use crate::port::details::data_segment::DataSegment;
+use crate::port::details::server_connections::*;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::request_mut::RequestMut;
+use crate::request_mut_uninit::RequestMutUninit;
+use crate::service::config_scheme::{connection_config, data_segment_config};
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::{RequestHeader, ResponseHeader};
+use crate::service::naming_scheme::{client_data_segment_name, is_connection_of_port};
+use crate::service::port_factory::client::LocalClientConfig;
+use crate::service::static_config::request_response::StaticConfig;
+use crate::{config, service};
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{debug, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::{SharedMemory, ShmPointer};
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopyReleaseError,
+    ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicU64, IoxAtomicUsize};
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::{marker::PhantomData, mem::MaybeUninit};
+
+/// Defines a failure that can occur when a [`Client`] is created with
+/// [`crate::service::port_factory::client::PortFactoryClient`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientCreateError {
+    /// The maximum amount of [`Client`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Client`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedClients,
+    /// The datasegment in which the requests of the [`Client`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ClientCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ClientCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientCreateError {}
+
+/// Defines a failure that can occur in [`Client::loan()`] and [`Client::loan_uninit()`]
+/// or is part of [`ClientSendError`] emitted in [`Client::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ClientLoanError {
+    /// The [`Client`]s data segment does not have any more memory left
+    OutOfMemory,
+    /// The maximum amount of [`RequestMut`]s a user can borrow with [`Client::loan()`] or
+    /// [`Client::loan_uninit()`] is
+    /// defined in [`crate::config::Config`]. When this is exceeded those calls will fail.
+    ExceedsMaxLoanedRequests,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl std::fmt::Display for ClientLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ClientLoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientLoanError {}
+
+/// Failure that can be emitted when a [`RequestMut`] is sent via [`RequestMut::send()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ClientSendError {
+    /// The [`Client`] has already the maximum amount of [`PendingResponse`]s that is defined in
+    /// [`crate::config::Config`]. A [`PendingResponse`] has to be dropped before another
+    /// request can be sent.
+    ExceedsMaxActiveRequests,
+    /// A connection between a [`Server`](crate::port::server::Server) and a
+    /// [`Client`] is corrupted.
+    ConnectionCorrupted,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(ClientLoanError),
+    /// A failure occurred while establishing a connection to a
+    /// [`Server`](crate::port::server::Server)
+    ConnectionError(ConnectionFailure),
+}
+
+impl From<ClientLoanError> for ClientSendError {
+    fn from(value: ClientLoanError) -> Self {
+        ClientSendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for ClientSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        ClientSendError::ConnectionError(value)
+    }
+}
+
+impl std::fmt::Display for ClientSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ClientSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientSendError {}
+
+/// Defines the failure that can occur when receiving a response with
+/// [`PendingResponse::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ClientReceiveError {
+    /// The maximum amount of [`Response`](crate::response::Response)s a [`Client`] can borrow
+    /// is defined in [`crate::config::Config`]. Responses that belong to other
+    /// [`PendingResponse`]s are held back until they are received and count as borrowed as
+    /// well. When this is exceeded [`PendingResponse::receive()`] fails.
+    ExceedsMaxBorrowedResponses,
+
+    /// Occurs when a [`Client`] is unable to connect to a corresponding
+    /// [`Server`](crate::port::server::Server).
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ClientReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ClientReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ClientReceiveError {}
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub(crate) enum RemoveReqResPortFromAllConnectionsError {
+    InsufficientPermissions,
+    InternalError,
+}
+
+#[derive(Debug)]
+pub(crate) struct ReceivedResponse<Service: service::Service> {
+    pub(crate) connection: Arc<ServerConnection<Service>>,
+    pub(crate) offset: PointerOffset,
+    pub(crate) request_id: u64,
+}
+
+impl<Service: service::Service> ReceivedResponse<Service> {
+    pub(crate) fn header_ptr(&self) -> *const ResponseHeader {
+        (self.offset.value()
+            + self
+                .connection
+                .response_data_segment
+                .payload_start_address()) as *const ResponseHeader
+    }
+
+    pub(crate) fn release(&self) {
+        match self.connection.response_receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The servers retrieve channel is full and the response cannot be returned.");
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientBackend<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    pub(crate) server_connections: ServerConnections<Service>,
+    server_list_state: UnsafeCell<ContainerState<ServerDetails>>,
+    config: LocalClientConfig,
+    loan_counter: IoxAtomicUsize,
+    request_id_counter: IoxAtomicU64,
+    active_requests: UnsafeCell<Vec<u64>>,
+    stashed_responses: UnsafeCell<Vec<ReceivedResponse<Service>>>,
+}
+
+impl<Service: service::Service> ClientBackend<Service> {
+    pub(crate) fn static_config(&self) -> &StaticConfig {
+        &self.server_connections.static_config
+    }
+
+    fn retrieve_returned_requests(&self) {
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    match connection.request_sender.reclaim() {
+                        Ok(Some(offset)) => self.data_segment.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim requests from connection {:?} due to {:?}. This may lead to a situation where no more requests will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.server_connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered requests
+            unsafe {
+                connection
+                    .request_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+
+            self.server_connections.remove(i);
+        }
+    }
+
+    fn populate_server_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.server_connections.capacity(), None);
+
+        unsafe {
+            (*self.server_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.server_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.server_id != details.server_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        if let Err(e) = self.server_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new server {:?}.", details.server_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        ret_val
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.server_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .servers
+                .update_state(&mut *self.server_list_state.get())
+        } {
+            fail!(from self, when self.populate_server_channels(),
+                "Connections were updated only partially since at least one connection to a Server port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn allocate(&self) -> Result<ShmPointer, ClientLoanError> {
+        let msg = "Unable to allocate request";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_requests {
+            fail!(from self, with ClientLoanError::ExceedsMaxLoanedRequests,
+                "{} since already {} requests were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned request to loan another request.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_requests);
+        }
+
+        self.retrieve_returned_requests();
+
+        match self.data_segment.allocate() {
+            Ok(chunk) => {
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(chunk)
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ClientLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ClientLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    pub(crate) fn return_loaned_request(&self, offset: PointerOffset) {
+        self.data_segment.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_request(
+        &self,
+        offset: PointerOffset,
+        request_id: u64,
+    ) -> Result<usize, ClientSendError> {
+        let msg = "Unable to send request";
+        let active_requests = unsafe { &mut *self.active_requests.get() };
+        if active_requests.len() >= self.static_config().max_active_requests {
+            fail!(from self, with ClientSendError::ExceedsMaxActiveRequests,
+                "{} since it would exceed the maximum of {} active requests. Drop a PendingResponse to send another request.",
+                msg, self.static_config().max_active_requests);
+        }
+
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_requests();
+
+        let mut number_of_recipients = 0;
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                match connection
+                    .request_sender
+                    .try_send(offset, self.data_segment.sample_granularity())
+                {
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => {
+                        warn!(from self,
+                            "Unable to deliver request {} to server {:?} since its buffer is full.",
+                            request_id, connection.server_id);
+                    }
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with ClientSendError::ConnectionCorrupted,
+                            "{} {} since a corrupted connection was detected with server {:?}.",
+                            msg, request_id, connection.server_id);
+                    }
+                    Ok(overflow) => {
+                        self.data_segment.borrow_sample(offset);
+                        number_of_recipients += 1;
+
+                        if let Some(old) = overflow {
+                            self.data_segment.release_sample(old)
+                        }
+                    }
+                }
+            }
+        }
+
+        // a request that was not delivered to any server can never be answered
+        if number_of_recipients > 0 {
+            active_requests.push(request_id);
+        }
+        Ok(number_of_recipients)
+    }
+
+    pub(crate) fn receive_response(
+        &self,
+        request_id: u64,
+    ) -> Result<Option<ReceivedResponse<Service>>, ClientReceiveError> {
+        let msg = "Unable to receive response";
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ClientReceiveError::ConnectionFailure(e),
+                "{} since not all connections to servers could be established.", msg);
+        }
+
+        let stashed_responses = unsafe { &mut *self.stashed_responses.get() };
+        if let Some(pos) = stashed_responses
+            .iter()
+            .position(|r| r.request_id == request_id)
+        {
+            return Ok(Some(stashed_responses.remove(pos)));
+        }
+
+        let active_requests = unsafe { &*self.active_requests.get() };
+        for i in 0..self.server_connections.len() {
+            if let Some(ref connection) = self.server_connections.get(i) {
+                loop {
+                    let offset = match connection.response_receiver.receive() {
+                        Ok(Some(offset)) => offset,
+                        Ok(None) => break,
+                        Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                            fail!(from self, with ClientReceiveError::ExceedsMaxBorrowedResponses,
+                                "{} since it would exceed the maximum {} of borrowed responses.",
+                                msg, connection.response_receiver.max_borrowed_samples());
+                        }
+                    };
+
+                    let mut response = ReceivedResponse {
+                        connection: connection.clone(),
+                        offset,
+                        request_id: 0,
+                    };
+                    response.request_id = unsafe { (*response.header_ptr()).request_id() };
+
+                    if response.request_id == request_id {
+                        return Ok(Some(response));
+                    } else if active_requests.contains(&response.request_id) {
+                        stashed_responses.push(response);
+                    } else {
+                        debug!(from self,
+                            "Discard response for request {} since the corresponding pending response does not exist anymore.",
+                            response.request_id);
+                        response.release();
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub(crate) fn deactivate_request(&self, request_id: u64) {
+        let active_requests = unsafe { &mut *self.active_requests.get() };
+        active_requests.retain(|id| *id != request_id);
+
+        let stashed_responses = unsafe { &mut *self.stashed_responses.get() };
+        stashed_responses.retain(|r| {
+            if r.request_id == request_id {
+                r.release();
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+/// Sending endpoint of a request-response based communication. Sends requests to all connected
+/// [`Server`](crate::port::server::Server)s and receives the corresponding responses via the
+/// returned [`PendingResponse`].
+#[derive(Debug)]
+pub struct Client<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> {
+    pub(crate) backend: Arc<ClientBackend<Service>>,
+    dynamic_client_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for Client<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_client_handle {
+            self.backend
+                .server_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_client_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    Client<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalClientConfig,
+    ) -> Result<Self, ClientCreateError> {
+        let msg = "Unable to create Client port";
+        let origin = "Client::new()";
+        let client_id = UniqueClientId::new();
+        let server_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .servers;
+
+        let number_of_requests =
+            static_config.required_amount_of_requests_per_data_segment(config.max_loaned_requests);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(
+                    &client_data_segment_name(&client_id),
+                    service.__internal_state().shared_node.config(),
+                    static_config.request_message_type_details().sample_layout(1),
+                    number_of_requests),
+                with ClientCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let backend = Arc::new(ClientBackend {
+            data_segment,
+            server_connections: ServerConnections::new(
+                server_list.capacity(),
+                client_id,
+                service.__internal_state().clone(),
+                static_config,
+                number_of_requests,
+            ),
+            server_list_state: UnsafeCell::new(unsafe { server_list.get_state() }),
+            config,
+            loan_counter: IoxAtomicUsize::new(0),
+            request_id_counter: IoxAtomicU64::new(0),
+            active_requests: UnsafeCell::new(Vec::with_capacity(static_config.max_active_requests)),
+            stashed_responses: UnsafeCell::new(vec![]),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_client_handle: None,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_server_channels() {
+            warn!(from new_self, "The new Client port is unable to connect to every Server port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a client is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_client_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_client_id(ClientDetails {
+                client_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                number_of_requests,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ClientCreateError::ExceedsMaxSupportedClients,
+                    "{} since it would exceed the maximum supported amount of clients of {}.",
+                    msg, static_config.max_clients);
+            }
+        };
+
+        new_self.dynamic_client_handle = Some(dynamic_client_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueClientId`] of the [`Client`]
+    pub fn id(&self) -> UniqueClientId {
+        self.backend.server_connections.client_id()
+    }
+
+    /// Copies the input `value` into a [`RequestMut`] and sends it to all connected
+    /// [`Server`](crate::port::server::Server)s. On success it returns a [`PendingResponse`]
+    /// that can be used to receive the corresponding responses, otherwise a
+    /// [`ClientSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let pending_response = client.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(
+        &self,
+        value: RequestPayload,
+    ) -> Result<PendingResponse<Service, RequestPayload, ResponsePayload>, ClientSendError> {
+        let msg = "Unable to send copy of request";
+        let request = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a request failed.", msg);
+
+        request.write_payload(value).send()
+    }
+
+    /// Loans/allocates a [`RequestMutUninit`] from the underlying data segment of the [`Client`].
+    /// The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`ClientLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(42); // alternatively `request.payload_mut()` can be use to access the `MaybeUninit<RequestPayload>`
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(
+        &self,
+    ) -> Result<RequestMutUninit<Service, RequestPayload, ResponsePayload>, ClientLoanError> {
+        let chunk = self.backend.allocate()?;
+        let header_ptr = chunk.data_ptr as *mut RequestHeader;
+        let payload_ptr = self
+            .backend
+            .static_config()
+            .request_message_type_details()
+            .payload_ptr_from_header(header_ptr.cast())
+            as *mut MaybeUninit<RequestPayload>;
+
+        let request_id = self
+            .backend
+            .request_id_counter
+            .fetch_add(1, Ordering::Relaxed);
+        unsafe { header_ptr.write(RequestHeader::new(self.id(), request_id)) };
+
+        Ok(RequestMutUninit::new(
+            &self.backend,
+            header_ptr,
+            payload_ptr,
+            chunk.offset,
+        ))
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Default + Debug, ResponsePayload: Debug>
+    Client<Service, RequestPayload, ResponsePayload>
+{
+    /// Loans/allocates a [`RequestMut`] from the underlying data segment of the [`Client`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Client::loan_uninit`] can be used to loan a [`core::mem::MaybeUninit<RequestPayload>`].
+    ///
+    /// On failure it returns [`ClientLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 42;
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(
+        &self,
+    ) -> Result<RequestMut<Service, RequestPayload, ResponsePayload>, ClientLoanError> {
+        Ok(self.loan_uninit()?.write_payload(RequestPayload::default()))
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> UpdateConnections
+    for Client<Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_client<Service: service::Service>(
+    port_id: &UniqueClientId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_client::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &client_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the clients data segment."
+    );
+
+    Ok(())
+}
+
+pub(crate) unsafe fn remove_client_from_all_connections<Service: service::Service>(
+    port_id: &UniqueClientId,
+    config: &config::Config,
+) -> Result<(), RemoveReqResPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_client_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    remove_port_from_all_connections::<Service>(
+        &origin,
+        "Unable to remove the client from all connections",
+        &port_id.0,
+        config,
+    )
+}
+
+pub(crate) unsafe fn remove_port_from_all_connections<Service: service::Service>(
+    origin: &str,
+    msg: &str,
+    port_id: &UniqueSystemId,
+    config: &config::Config,
+) -> Result<(), RemoveReqResPortFromAllConnectionsError> {
+    let connection_config = connection_config::<Service>(config);
+    let connection_list = match <Service::Connection as NamedConceptMgmt>::list_cfg(
+        &connection_config,
+    ) {
+        Ok(list) => list,
+        Err(NamedConceptListError::InsufficientPermissions) => {
+            fail!(from origin, with RemoveReqResPortFromAllConnectionsError::InsufficientPermissions,
+                "{} due to insufficient permissions to list all connections.", msg);
+        }
+        Err(NamedConceptListError::InternalError) => {
+            fail!(from origin, with RemoveReqResPortFromAllConnectionsError::InternalError,
+                "{} due to an internal error while listing all connections.", msg);
+        }
+    };
+
+    let mut ret_val = Ok(());
+    for connection in connection_list {
+        if is_connection_of_port(&connection, port_id) {
+            match <Service::Connection as NamedConceptMgmt>::remove_cfg(
+                &connection,
+                &connection_config,
+            ) {
+                Ok(_) => (),
+                Err(NamedConceptRemoveError::InsufficientPermissions) => {
+                    debug!(from origin, "{} due to insufficient permissions to remove the connection ({:?}).", msg, connection);
+                    ret_val = Err(RemoveReqResPortFromAllConnectionsError::InsufficientPermissions);
+                }
+                Err(NamedConceptRemoveError::InternalError) => {
+                    debug!(from origin, "{} due to an internal error while removing the connection ({:?}).", msg, connection);
+                    ret_val = Err(RemoveReqResPortFromAllConnectionsError::InternalError);
+                }
+            }
+        }
+    }
+
+    ret_val
+}
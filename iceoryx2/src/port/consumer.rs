@@ -0,0 +1,374 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = service
+//!     .consumer_builder()
+//!     // defines how many work items can be queued for this consumer
+//!     .buffer_size(1)
+//!     .create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::port_identifiers::UniqueConsumerId;
+use crate::port::client::{
+    remove_port_from_all_connections, RemoveReqResPortFromAllConnectionsError,
+};
+use crate::port::details::producer_connections::*;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::header::pipeline::Header;
+use crate::service::port_factory::consumer::LocalConsumerConfig;
+use crate::service::static_config::pipeline::StaticConfig;
+use crate::work_item::WorkItem;
+use crate::{config, service};
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::shared_memory::SharedMemory;
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver,
+};
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+
+/// Defines a failure that can occur when a [`Consumer`] is created with
+/// [`crate::service::port_factory::consumer::PortFactoryConsumer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConsumerCreateError {
+    /// The maximum amount of [`Consumer`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Consumer`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedConsumers,
+    /// When the [`Consumer`] requires a larger buffer size than the
+    /// [`Service`](crate::service::Service) offers the creation will fail.
+    BufferSizeExceedsMaxSupportedBufferSizeOfService,
+}
+
+impl std::fmt::Display for ConsumerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ConsumerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ConsumerCreateError {}
+
+/// Defines the failure that can occur when receiving a work item with [`Consumer::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ConsumerReceiveError {
+    /// The maximum amount of [`WorkItem`]s a [`Consumer`] can hold in parallel per
+    /// [`Producer`](crate::port::producer::Producer) is defined in [`crate::config::Config`].
+    /// When this is exceeded no more work items can be received from that
+    /// [`Producer`](crate::port::producer::Producer).
+    ExceedsMaxBorrowedWorkItems,
+
+    /// Occurs when a [`Consumer`] is unable to connect to a corresponding
+    /// [`Producer`](crate::port::producer::Producer).
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ConsumerReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ConsumerReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ConsumerReceiveError {}
+
+/// Receiving endpoint of a pipeline based communication. Receives the work items that the
+/// connected [`Producer`](crate::port::producer::Producer)s assigned to it. Every work item is
+/// received by exactly one [`Consumer`].
+#[derive(Debug)]
+pub struct Consumer<Service: service::Service, Payload: Debug> {
+    producer_connections: ProducerConnections<Service>,
+    producer_list_state: UnsafeCell<ContainerState<ProducerDetails>>,
+    dynamic_consumer_handle: Option<ContainerHandle>,
+    buffer_size: usize,
+    _payload: PhantomData<Payload>,
+}
+
+impl<Service: service::Service, Payload: Debug> Drop for Consumer<Service, Payload> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_consumer_handle {
+            self.producer_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_consumer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug> Consumer<Service, Payload> {
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalConsumerConfig,
+    ) -> Result<Self, ConsumerCreateError> {
+        let msg = "Unable to create Consumer port";
+        let origin = "Consumer::new()";
+        let consumer_id = UniqueConsumerId::new();
+        let producer_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .producers;
+
+        let buffer_size = match config.buffer_size {
+            Some(buffer_size) => {
+                if static_config.consumer_max_buffer_size < buffer_size {
+                    fail!(from origin, with ConsumerCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService,
+                        "{} since the requested buffer size {} exceeds the maximum supported buffer size {} of the service.",
+                        msg, buffer_size, static_config.consumer_max_buffer_size);
+                }
+                buffer_size
+            }
+            None => static_config.consumer_max_buffer_size,
+        };
+
+        let mut new_self = Self {
+            producer_connections: ProducerConnections::new(
+                producer_list.capacity(),
+                consumer_id,
+                service.__internal_state().clone(),
+                static_config,
+                buffer_size,
+            ),
+            producer_list_state: UnsafeCell::new(unsafe { producer_list.get_state() }),
+            dynamic_consumer_handle: None,
+            buffer_size,
+            _payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.populate_producer_channels() {
+            warn!(from new_self, "The new Consumer port is unable to connect to every Producer port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a consumer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_consumer_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_consumer_id(ConsumerDetails {
+                consumer_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                buffer_size,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ConsumerCreateError::ExceedsMaxSupportedConsumers,
+                    "{} since it would exceed the maximum supported amount of consumers of {}.",
+                    msg, static_config.max_consumers);
+            }
+        };
+
+        new_self.dynamic_consumer_handle = Some(dynamic_consumer_handle);
+
+        Ok(new_self)
+    }
+
+    fn static_config(&self) -> &StaticConfig {
+        &self.producer_connections.static_config
+    }
+
+    fn populate_producer_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.producer_connections.capacity(), None);
+
+        unsafe {
+            (*self.producer_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.producer_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.producer_id != details.producer_id;
+                            if is_outdated {
+                                self.producer_connections.remove(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        if let Err(e) = self.producer_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new producer {:?}.", details.producer_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.producer_connections.remove(i),
+            }
+        }
+
+        ret_val
+    }
+
+    /// Returns the [`UniqueConsumerId`] of the [`Consumer`]
+    pub fn id(&self) -> UniqueConsumerId {
+        self.producer_connections.consumer_id()
+    }
+
+    /// Returns the internal buffer size of the [`Consumer`].
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns true if the [`Consumer`] has work items in its buffer.
+    pub fn has_work_items(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.update_connections(),
+            "Some work items are not being received since not all connections to producers could be established.");
+
+        for i in 0..self.producer_connections.len() {
+            if let Some(ref connection) = self.producer_connections.get(i) {
+                if connection.receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Receives a [`WorkItem`] that was assigned to this [`Consumer`] by one of the connected
+    /// [`Producer`](crate::port::producer::Producer)s. If no work item is available it returns
+    /// [`None`]. On failure it returns [`ConsumerReceiveError`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// # let consumer = service.consumer_builder().create()?;
+    ///
+    /// while let Some(work_item) = consumer.receive()? {
+    ///     println!("process: {:?}", *work_item);
+    /// }
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn receive(&self) -> Result<Option<WorkItem<Service, Payload>>, ConsumerReceiveError> {
+        let msg = "Unable to receive work item";
+        if let Err(e) = self.update_connections() {
+            fail!(from self, with ConsumerReceiveError::ConnectionFailure(e),
+                "{} since not all connections to producers could be established.", msg);
+        }
+
+        for i in 0..self.producer_connections.len() {
+            if let Some(ref connection) = self.producer_connections.get(i) {
+                match connection.receiver.receive() {
+                    Ok(Some(offset)) => {
+                        let header_ptr = (offset.value()
+                            + connection.data_segment.payload_start_address())
+                            as *const Header;
+                        let payload_ptr = self
+                            .static_config()
+                            .message_type_details()
+                            .payload_ptr_from_header(header_ptr.cast())
+                            as *const Payload;
+
+                        return Ok(Some(WorkItem::new(
+                            connection,
+                            offset,
+                            header_ptr,
+                            payload_ptr,
+                        )));
+                    }
+                    Ok(None) => (),
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ConsumerReceiveError::ExceedsMaxBorrowedWorkItems,
+                            "{} since it would exceed the maximum {} of borrowed work items from producer {:?}.",
+                            msg, connection.receiver.max_borrowed_samples(), connection.producer_id);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Service: service::Service, Payload: Debug> UpdateConnections for Consumer<Service, Payload> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.producer_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .producers
+                .update_state(&mut *self.producer_list_state.get())
+        } {
+            fail!(from self, when self.populate_producer_channels(),
+                "Connections were updated only partially since at least one connection to a Producer port failed.");
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) unsafe fn remove_consumer_from_all_connections<Service: service::Service>(
+    port_id: &UniqueConsumerId,
+    config: &config::Config,
+) -> Result<(), RemoveReqResPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_consumer_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    remove_port_from_all_connections::<Service>(
+        &origin,
+        "Unable to remove the consumer from all connections",
+        &port_id.0,
+        config,
+    )
+}
@@ -0,0 +1,540 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! The runtime independent driver of the async API. Every [`Service`] type has one background
+//! thread that waits with the [`Service::Reactor`] on the [`FileDescriptor`]s of all pending
+//! futures and wakes them up as soon as one of their [`FileDescriptor`]s becomes readable or
+//! their deadline has passed. Whenever a future registers itself, the thread is woken up with
+//! an event service that the driver creates with its own [`Node`], so that the dead node
+//! cleanup removes the event of a crashed process like any other stale resource.
+//!
+//! A [`FileDescriptor`] is only attached to the reactor while a future waits on it. A readable
+//! [`FileDescriptor`] of a future that was already woken up but not yet polled again would
+//! otherwise wake up the thread continuously.
+//!
+//! The thread is started with the first [`Registration`] of a [`Service`] type and stopped as
+//! soon as no [`Registration`] of this type exists anymore. It is shared by all ports of a
+//! [`Service`] type since a [`WaitSet`](crate::port::waitset::WaitSet) has no [`Node`].
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
+use std::sync::{mpsc, Arc, Mutex, MutexGuard, Weak};
+use std::task::{Context, Poll, Waker};
+use std::thread::{JoinHandle, ThreadId};
+use std::time::Instant;
+
+use iceoryx2_bb_log::{fail, warn};
+use iceoryx2_bb_posix::file_descriptor::{FileDescriptor, FileDescriptorBased};
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+use iceoryx2_cal::event::Event;
+use iceoryx2_cal::reactor::{Reactor, ReactorBuilder, ReactorWaitError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicBool;
+
+use crate::node::node_name::NodeName;
+use crate::node::{Node, NodeBuilder};
+use crate::port::listener::Listener;
+use crate::port::notifier::Notifier;
+use crate::service::port_factory::event;
+use crate::service::service_name::ServiceName;
+use crate::service::Service;
+
+static DRIVERS: Mutex<Vec<(TypeId, Weak<dyn Any + Send + Sync>)>> = Mutex::new(Vec::new());
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AsyncDriverCreateError {
+    WakeupEventUnavailable,
+    ReactorCreationFailed,
+    ThreadSpawnFailed,
+}
+
+impl std::fmt::Display for AsyncDriverCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "AsyncDriverCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for AsyncDriverCreateError {}
+
+#[derive(Debug)]
+struct PendingFuture {
+    token: u64,
+    file_descriptors: Vec<i32>,
+    deadline: Option<Instant>,
+    waker: Waker,
+}
+
+impl PendingFuture {
+    fn is_ready(&self, triggered: &[i32], now: Instant) -> bool {
+        self.deadline.is_some_and(|deadline| deadline <= now)
+            || self
+                .file_descriptors
+                .iter()
+                .any(|fd| triggered.contains(fd))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Registrations {
+    next_token: u64,
+    pending: Vec<PendingFuture>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    registrations: Mutex<Registrations>,
+    keep_running: IoxAtomicBool,
+}
+
+impl Shared {
+    fn registrations(&self) -> MutexGuard<'_, Registrations> {
+        match self.registrations.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+// only the raw value of a file descriptor of a pending future is known to the driver thread,
+// the future ensures that it stays valid as long as it is registered
+#[derive(Debug)]
+struct Attachment(FileDescriptor);
+
+impl FileDescriptorBased for Attachment {
+    fn file_descriptor(&self) -> &FileDescriptor {
+        &self.0
+    }
+}
+
+impl SynchronousMultiplexing for Attachment {}
+
+// The reactor guard borrows the attachment. The guard is declared first so that it is dropped
+// before the boxed attachment it refers to.
+struct AttachedFileDescriptor<'reactor, R: Reactor + 'reactor> {
+    _guard: R::Guard<'reactor, 'reactor>,
+    _attachment: Box<Attachment>,
+}
+
+impl<'reactor, R: Reactor + 'reactor> AttachedFileDescriptor<'reactor, R> {
+    fn new(reactor: &'reactor R, file_descriptor: i32) -> Option<Self> {
+        let attachment = Box::new(Attachment(FileDescriptor::non_owning_new(file_descriptor)?));
+        // SAFETY: the attachment is stored on the heap next to the guard and is never accessed
+        //         mutably, the guard is dropped first
+        let attachment_ref: &'reactor Attachment = unsafe { &*(&*attachment as *const Attachment) };
+
+        Some(Self {
+            _guard: reactor.attach(attachment_ref).ok()?,
+            _attachment: attachment,
+        })
+    }
+}
+
+// The notifier is only used while the mutex is locked, like the ports it can be moved to another
+// thread as long as it is not used concurrently.
+#[derive(Debug)]
+struct WakeupNotifier<S: Service>(Mutex<Notifier<S>>);
+
+unsafe impl<S: Service> Send for WakeupNotifier<S> {}
+unsafe impl<S: Service> Sync for WakeupNotifier<S> {}
+
+#[derive(Debug)]
+pub(crate) struct AsyncDriver<S: Service> {
+    shared: Arc<Shared>,
+    wakeup: WakeupNotifier<S>,
+    thread: Option<JoinHandle<()>>,
+    thread_id: ThreadId,
+}
+
+impl<S: Service> Drop for AsyncDriver<S> {
+    fn drop(&mut self) {
+        self.shared.keep_running.store(false, Ordering::Relaxed);
+        self.wake_up_thread();
+
+        // a waker may drop the last registration directly in the driver thread, the thread
+        // then stops on its own
+        if std::thread::current().id() != self.thread_id {
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+}
+
+impl<S: Service + 'static> AsyncDriver<S>
+where
+    <S::Event as Event>::Listener: SynchronousMultiplexing,
+    <S::Event as Event>::Notifier: Send + Sync,
+{
+    fn get() -> Result<Arc<Self>, AsyncDriverCreateError> {
+        let mut drivers = match DRIVERS.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        drivers.retain(|(_, driver)| driver.strong_count() != 0);
+        if let Some(driver) = drivers
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<Self>())
+            .and_then(|(_, driver)| driver.upgrade())
+        {
+            if let Ok(driver) = driver.downcast::<Self>() {
+                return Ok(driver);
+            }
+        }
+
+        let new_driver = Arc::new(Self::create()?);
+        let weak_driver: Weak<dyn Any + Send + Sync> =
+            Arc::downgrade(&(new_driver.clone() as Arc<dyn Any + Send + Sync>));
+        drivers.push((TypeId::of::<Self>(), weak_driver));
+        Ok(new_driver)
+    }
+
+    fn create() -> Result<Self, AsyncDriverCreateError> {
+        let origin = "AsyncDriver::create()";
+        let msg = "Unable to create the driver of the async API";
+
+        let node_name = fail!(from origin, when NodeName::new("iox2-async-driver"),
+            with AsyncDriverCreateError::WakeupEventUnavailable,
+            "{} since the name of the node is invalid.", msg);
+        let node = fail!(from origin, when NodeBuilder::new().name(&node_name).create::<S>(),
+            with AsyncDriverCreateError::WakeupEventUnavailable,
+            "{} since the node that owns the wakeup event could not be created.", msg);
+        let event = Self::create_wakeup_event(&node)?;
+        let wakeup = fail!(from origin, when event.notifier_builder().create(),
+            with AsyncDriverCreateError::WakeupEventUnavailable,
+            "{} since the notifier of the wakeup event could not be created.", msg);
+
+        let shared = Arc::new(Shared {
+            registrations: Mutex::new(Registrations::default()),
+            keep_running: IoxAtomicBool::new(true),
+        });
+
+        // the reactor and the wakeup listener never leave the driver thread
+        let (ready_sender, ready_receiver) = mpsc::channel();
+        let thread_shared = shared.clone();
+        let thread = fail!(from origin,
+            when std::thread::Builder::new()
+                .name("iox2-async".into())
+                .spawn(move || Self::start(&thread_shared, &event, ready_sender)),
+            with AsyncDriverCreateError::ThreadSpawnFailed,
+            "{} since the driver thread could not be started.", msg);
+
+        let thread_result = match ready_receiver.recv() {
+            Ok(result) => result,
+            Err(_) => Err(AsyncDriverCreateError::ThreadSpawnFailed),
+        };
+        if let Err(e) = thread_result {
+            let _ = thread.join();
+            fail!(from origin, with e,
+                "{} since the driver thread could not be initialized ({:?}).", msg, e);
+        }
+
+        Ok(Self {
+            shared,
+            wakeup: WakeupNotifier(Mutex::new(wakeup)),
+            thread_id: thread.thread().id(),
+            thread: Some(thread),
+        })
+    }
+
+    // the ports keep the node and the event service alive as long as the driver exists
+    fn create_wakeup_event(
+        node: &Node<S>,
+    ) -> Result<event::PortFactory<S>, AsyncDriverCreateError> {
+        let origin = "AsyncDriver::create_wakeup_event()";
+        let msg = "Unable to create the wakeup event of the async driver";
+
+        let service_name = fail!(from origin,
+            when ServiceName::new(&format!("iox2://async_driver/{:032x}/wakeup", node.id().value())),
+            with AsyncDriverCreateError::WakeupEventUnavailable,
+            "{} since the service name is invalid.", msg);
+        let event = fail!(from origin,
+            when node.service_builder(&service_name)
+                .event()
+                .max_listeners(1)
+                .max_notifiers(1)
+                .create(),
+            with AsyncDriverCreateError::WakeupEventUnavailable,
+            "{} since the event service could not be created.", msg);
+
+        Ok(event)
+    }
+
+    fn start(
+        shared: &Shared,
+        event: &event::PortFactory<S>,
+        ready: mpsc::Sender<Result<(), AsyncDriverCreateError>>,
+    ) {
+        let origin = "AsyncDriver::start()";
+        let wakeup_listener = match event.listener_builder().create() {
+            Ok(listener) => listener,
+            Err(e) => {
+                warn!(from origin, "Unable to create the listener of the wakeup event ({:?}).", e);
+                let _ = ready.send(Err(AsyncDriverCreateError::WakeupEventUnavailable));
+                return;
+            }
+        };
+
+        let reactor = match <S::Reactor as Reactor>::Builder::new().create() {
+            Ok(reactor) => reactor,
+            Err(e) => {
+                warn!(from origin, "Unable to create the reactor ({:?}).", e);
+                let _ = ready.send(Err(AsyncDriverCreateError::ReactorCreationFailed));
+                return;
+            }
+        };
+
+        let _wakeup_guard = match reactor.attach(&wakeup_listener) {
+            Ok(guard) => guard,
+            Err(e) => {
+                warn!(from origin, "Unable to attach the wakeup event to the reactor ({:?}).", e);
+                let _ = ready.send(Err(AsyncDriverCreateError::ReactorCreationFailed));
+                return;
+            }
+        };
+
+        let _ = ready.send(Ok(()));
+        Self::run(shared, &reactor, &wakeup_listener);
+    }
+
+    fn run(shared: &Shared, reactor: &S::Reactor, wakeup_listener: &Listener<S>) {
+        let mut attached: HashMap<i32, AttachedFileDescriptor<S::Reactor>> = HashMap::new();
+        let mut triggered = vec![];
+
+        while shared.keep_running.load(Ordering::Relaxed) {
+            let _ = wakeup_listener.try_wait_all(|_| {});
+
+            let (file_descriptors, next_deadline) = {
+                let registrations = shared.registrations();
+                let mut file_descriptors: Vec<i32> = registrations
+                    .pending
+                    .iter()
+                    .flat_map(|p| p.file_descriptors.iter().copied())
+                    .collect();
+                file_descriptors.sort_unstable();
+                file_descriptors.dedup();
+
+                (
+                    file_descriptors,
+                    registrations
+                        .pending
+                        .iter()
+                        .filter_map(|p| p.deadline)
+                        .min(),
+                )
+            };
+
+            // a file descriptor that cannot be attached, for instance since it was closed
+            // already, wakes up its future right away so that it can register itself again
+            triggered.clear();
+            attached.retain(|fd, _| file_descriptors.binary_search(fd).is_ok());
+            for fd in file_descriptors {
+                if let std::collections::hash_map::Entry::Vacant(entry) = attached.entry(fd) {
+                    match AttachedFileDescriptor::new(reactor, fd) {
+                        Some(attachment) => {
+                            entry.insert(attachment);
+                        }
+                        None => triggered.push(fd),
+                    }
+                }
+            }
+
+            let wait_result = if !triggered.is_empty() {
+                Ok(0)
+            } else {
+                let on_event = |fd: &FileDescriptor| triggered.push(unsafe { fd.native_handle() });
+                match next_deadline {
+                    Some(deadline) => reactor
+                        .timed_wait(on_event, deadline.saturating_duration_since(Instant::now())),
+                    None => reactor.blocking_wait(on_event),
+                }
+            };
+
+            let wake_all = match wait_result {
+                Ok(_) => false,
+                // a future may react on the signal, like the WaitSet
+                Err(ReactorWaitError::Interrupt) => true,
+                Err(_) => {
+                    // a file descriptor of a future that was just dropped may be closed
+                    // already, all attachments are renewed
+                    attached.clear();
+                    true
+                }
+            };
+
+            let now = Instant::now();
+            let wakers: Vec<Waker> = {
+                let mut registrations = shared.registrations();
+                let (ready, pending): (Vec<_>, Vec<_>) = registrations
+                    .pending
+                    .drain(..)
+                    .partition(|p| wake_all || p.is_ready(&triggered, now));
+                registrations.pending = pending;
+                ready.into_iter().map(|p| p.waker).collect()
+            };
+
+            for waker in wakers {
+                waker.wake();
+            }
+        }
+    }
+}
+
+impl<S: Service> AsyncDriver<S> {
+    fn wake_up_thread(&self) {
+        let wakeup = match self.wakeup.0.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        // a full wakeup buffer means the thread will wake up anyway
+        let _ = wakeup.notify();
+    }
+}
+
+/// Registers the [`Waker`] of a pending future at the [`AsyncDriver`] of the [`Service`] and
+/// removes it again when it goes out of scope. When the [`AsyncDriver`] is not available the
+/// [`Waker`] is woken up immediately so that the future is polled continuously.
+#[derive(Debug)]
+pub(crate) struct Registration<S: Service> {
+    driver: Option<Arc<AsyncDriver<S>>>,
+    token: u64,
+}
+
+impl<S: Service> Drop for Registration<S> {
+    fn drop(&mut self) {
+        if let Some(driver) = &self.driver {
+            let was_pending = {
+                let mut registrations = driver.shared.registrations();
+                let len = registrations.pending.len();
+                registrations.pending.retain(|p| p.token != self.token);
+                len != registrations.pending.len()
+            };
+
+            // the file descriptor may be closed right after the future is dropped
+            if was_pending {
+                driver.wake_up_thread();
+            }
+        }
+    }
+}
+
+impl<S: Service + 'static> Registration<S>
+where
+    <S::Event as Event>::Listener: SynchronousMultiplexing,
+    <S::Event as Event>::Notifier: Send + Sync,
+{
+    pub(crate) fn new() -> Self {
+        let driver = match AsyncDriver::get() {
+            Ok(driver) => driver,
+            Err(e) => {
+                warn!(from "Registration::new()",
+                    "The async driver is not available ({:?}), pending futures are polled continuously.", e);
+                return Self {
+                    driver: None,
+                    token: 0,
+                };
+            }
+        };
+
+        let token = {
+            let mut registrations = driver.shared.registrations();
+            registrations.next_token += 1;
+            registrations.next_token
+        };
+
+        Self {
+            driver: Some(driver),
+            token,
+        }
+    }
+
+    /// Wakes the [`Waker`] up once as soon as one of the [`FileDescriptor`]s is readable or the
+    /// deadline has passed. The [`FileDescriptor`]s must stay valid as long as the
+    /// [`Registration`] exists.
+    pub(crate) fn wake_when_ready(
+        &self,
+        file_descriptors: Vec<i32>,
+        deadline: Option<Instant>,
+        waker: &Waker,
+    ) {
+        let driver = match &self.driver {
+            Some(driver) => driver,
+            None => return waker.wake_by_ref(),
+        };
+
+        {
+            let mut registrations = driver.shared.registrations();
+            if let Some(pending) = registrations
+                .pending
+                .iter_mut()
+                .find(|p| p.token == self.token)
+            {
+                if pending.file_descriptors == file_descriptors && pending.deadline == deadline {
+                    pending.waker.clone_from(waker);
+                    return;
+                }
+                registrations.pending.retain(|p| p.token != self.token);
+            }
+
+            registrations.pending.push(PendingFuture {
+                token: self.token,
+                file_descriptors,
+                deadline,
+                waker: waker.clone(),
+            });
+        }
+
+        driver.wake_up_thread();
+    }
+
+    /// Wakes the [`Waker`] up once as soon as the [`FileDescriptor`] is readable.
+    pub(crate) fn wake_when_readable<T: FileDescriptorBased>(&self, value: &T, waker: &Waker) {
+        let file_descriptor = unsafe { value.file_descriptor().native_handle() };
+        self.wake_when_ready(vec![file_descriptor], None, waker)
+    }
+}
+
+/// Polls a non-blocking operation whose result is signaled with an event on the provided
+/// [`Listener`]. All pending events are consumed before the operation is called, when it has no
+/// result yet, the future is woken up with the next event. Without a [`Listener`] the future is
+/// polled continuously.
+pub(crate) fn poll_when_notified<S: Service + 'static, T, E, F: FnMut() -> Result<Option<T>, E>>(
+    listener: Option<&Listener<S>>,
+    registration: &mut Option<Registration<S>>,
+    cx: &mut Context<'_>,
+    mut operation: F,
+) -> Poll<Result<T, E>>
+where
+    <S::Event as Event>::Listener: SynchronousMultiplexing,
+    <S::Event as Event>::Notifier: Send + Sync,
+{
+    if let Some(listener) = listener {
+        if let Err(e) = listener.try_wait_all(|_| {}) {
+            warn!(from listener,
+                "Unable to consume the events of the listener ({:?}), the future may be woken up spuriously.", e);
+        }
+    }
+
+    match operation() {
+        Ok(Some(value)) => Poll::Ready(Ok(value)),
+        Ok(None) => {
+            match listener {
+                Some(listener) => registration
+                    .get_or_insert_with(Registration::new)
+                    .wake_when_readable(listener, cx.waker()),
+                None => cx.waker().wake_by_ref(),
+            }
+            Poll::Pending
+        }
+        Err(e) => Poll::Ready(Err(e)),
+    }
+}
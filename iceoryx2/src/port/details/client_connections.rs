@@ -0,0 +1,161 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+use std::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::SampleAllocator;
+use iceoryx2_cal::{
+    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder, zero_copy_connection::*,
+};
+
+use crate::port::port_identifiers::{UniqueClientId, UniqueServerId};
+use crate::port::update_connections::ConnectionFailure;
+use crate::service::{
+    self,
+    config_scheme::{connection_config, data_segment_config},
+    dynamic_config::request_response::ClientDetails,
+    naming_scheme::{client_data_segment_name, request_connection_name, response_connection_name},
+    static_config::request_response::StaticConfig,
+    ServiceState,
+};
+
+/// The connection of a [`Server`](crate::port::server::Server) to a
+/// [`Client`](crate::port::client::Client). Requests are received via the `request_receiver`
+/// from the clients data segment and the responses are sent via the `response_sender`.
+#[derive(Debug)]
+pub(crate) struct ClientConnection<Service: service::Service> {
+    pub(crate) request_receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) request_data_segment: Service::SharedMemory,
+    pub(crate) response_sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) client_id: UniqueClientId,
+}
+
+impl<Service: service::Service> ClientConnection<Service> {
+    fn new(
+        this: &ClientConnections<Service>,
+        details: &ClientDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to client {:?} from server {:?}",
+            details.client_id, this.server_id
+        );
+        let config = this.service_state.shared_node.config();
+
+        let request_receiver = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&request_connection_name(details.client_id, this.server_id))
+                                .config(&connection_config::<Service>(config))
+                                .buffer_size(this.static_config.max_active_requests)
+                                .receiver_max_borrowed_samples(this.static_config.max_active_requests)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(Service::ShmAllocator::max_number_of_chunks(details.number_of_requests))
+                                .timeout(config.global.service.creation_timeout)
+                                .create_receiver(this.static_config.request_message_type_details().sample_layout(1).size()),
+                        "{} since the request connection could not be established.", msg);
+
+        let response_sender = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&response_connection_name(this.server_id, details.client_id))
+                                .config(&connection_config::<Service>(config))
+                                .buffer_size(this.static_config.client_max_response_buffer_size)
+                                .receiver_max_borrowed_samples(this.static_config.client_max_borrowed_responses)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(Service::ShmAllocator::max_number_of_chunks(this.number_of_responses))
+                                .timeout(config.global.service.creation_timeout)
+                                .create_sender(this.static_config.response_message_type_details().sample_layout(1).size()),
+                        "{} since the response connection could not be established.", msg);
+
+        let request_data_segment = fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<Service::ShmAllocator>>::
+                                Builder::new(&client_data_segment_name(&details.client_id))
+                                .config(&data_segment_config::<Service>(config))
+                                .timeout(config.global.service.creation_timeout)
+                                .open(),
+                            "{} since the clients data segment could not be opened.", msg);
+
+        Ok(Self {
+            request_receiver,
+            request_data_segment,
+            response_sender,
+            client_id: details.client_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ClientConnections<Service: service::Service> {
+    connections: Vec<UnsafeCell<Option<Arc<ClientConnection<Service>>>>>,
+    server_id: UniqueServerId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    number_of_responses: usize,
+}
+
+impl<Service: service::Service> ClientConnections<Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        server_id: UniqueServerId,
+        service_state: Arc<ServiceState<Service>>,
+        static_config: &StaticConfig,
+        number_of_responses: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            server_id,
+            service_state,
+            static_config: static_config.clone(),
+            number_of_responses,
+        }
+    }
+
+    pub(crate) fn server_id(&self) -> UniqueServerId {
+        self.server_id
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Arc<ClientConnection<Service>>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Arc<ClientConnection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        details: &ClientDetails,
+    ) -> Result<(), ConnectionFailure> {
+        *self.get_mut(index) = Some(Arc::new(ClientConnection::new(self, details)?));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+use std::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::SampleAllocator;
+use iceoryx2_cal::zero_copy_connection::*;
+
+use crate::port::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use crate::port::update_connections::ConnectionFailure;
+use crate::service::{
+    self, config_scheme::connection_config, dynamic_config::pipeline::ConsumerDetails,
+    naming_scheme::pipeline_connection_name, static_config::pipeline::StaticConfig, ServiceState,
+};
+
+/// The connection of a [`Producer`](crate::port::producer::Producer) to a
+/// [`Consumer`](crate::port::consumer::Consumer). Work items are sent via the `sender`.
+#[derive(Debug)]
+pub(crate) struct ConsumerConnection<Service: service::Service> {
+    pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
+    pub(crate) consumer_id: UniqueConsumerId,
+}
+
+impl<Service: service::Service> ConsumerConnection<Service> {
+    fn new(
+        this: &ConsumerConnections<Service>,
+        details: &ConsumerDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to consumer {:?} from producer {:?}",
+            details.consumer_id, this.producer_id
+        );
+        let config = this.service_state.shared_node.config();
+
+        let sender = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&pipeline_connection_name(this.producer_id, details.consumer_id))
+                                .config(&connection_config::<Service>(config))
+                                .buffer_size(details.buffer_size)
+                                .receiver_max_borrowed_samples(this.static_config.consumer_max_borrowed_work_items)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(Service::ShmAllocator::max_number_of_chunks(this.number_of_work_items))
+                                .timeout(config.global.service.creation_timeout)
+                                .create_sender(this.static_config.message_type_details().sample_layout(1).size()),
+                        "{} since the connection could not be established.", msg);
+
+        Ok(Self {
+            sender,
+            consumer_id: details.consumer_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ConsumerConnections<Service: service::Service> {
+    connections: Vec<UnsafeCell<Option<Arc<ConsumerConnection<Service>>>>>,
+    producer_id: UniqueProducerId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    number_of_work_items: usize,
+}
+
+impl<Service: service::Service> ConsumerConnections<Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        producer_id: UniqueProducerId,
+        service_state: Arc<ServiceState<Service>>,
+        static_config: &StaticConfig,
+        number_of_work_items: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            producer_id,
+            service_state,
+            static_config: static_config.clone(),
+            number_of_work_items,
+        }
+    }
+
+    pub(crate) fn producer_id(&self) -> UniqueProducerId {
+        self.producer_id
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Arc<ConsumerConnection<Service>>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Arc<ConsumerConnection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        details: &ConsumerDetails,
+    ) -> Result<(), ConnectionFailure> {
+        *self.get_mut(index) = Some(Arc::new(ConsumerConnection::new(self, details)?));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::alloc::Layout;
+use std::sync::atomic::Ordering;
+
+use iceoryx2_bb_log::{fail, fatal_panic};
+use iceoryx2_bb_system_types::file_name::FileName;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shared_memory::{
+    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
+};
+use iceoryx2_cal::shm_allocator::{PointerOffset, SampleAllocator, ShmAllocationError};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicU64;
+
+use crate::config;
+use crate::service::{self, config_scheme::data_segment_config};
+
+/// The data segment a port owns to store the samples it sends. Every sample is reference
+/// counted and returned to the underlying allocator as soon as no one is using it anymore.
+#[derive(Debug)]
+pub(crate) struct DataSegment<Service: service::Service> {
+    memory: Service::SharedMemory,
+    sample_reference_counter: Vec<IoxAtomicU64>,
+    sample_layout: Layout,
+    sample_granularity: usize,
+}
+
+impl<Service: service::Service> DataSegment<Service> {
+    pub(crate) fn create(
+        name: &FileName,
+        global_config: &config::Config,
+        sample_layout: Layout,
+        number_of_samples: usize,
+    ) -> Result<Self, SharedMemoryCreateError> {
+        let allocator_config = Service::ShmAllocator::sample_config(sample_layout);
+        let size = Service::ShmAllocator::sample_memory_size(sample_layout, number_of_samples);
+        let sample_granularity = Service::ShmAllocator::sample_granularity(sample_layout);
+
+        let memory = fail!(from "DataSegment::create()",
+            when <<Service::SharedMemory as SharedMemory<Service::ShmAllocator>>::Builder as NamedConceptBuilder<
+            Service::SharedMemory,
+                >>::new(name)
+                .config(&data_segment_config::<Service>(global_config))
+                .size(size)
+                .create(&allocator_config),
+            "Unable to create the data segment.");
+
+        Ok(Self {
+            memory,
+            sample_reference_counter: (0..size / sample_granularity)
+                .map(|_| IoxAtomicU64::new(0))
+                .collect(),
+            sample_layout,
+            sample_granularity,
+        })
+    }
+
+    pub(crate) fn is_unused(&self) -> bool {
+        self.sample_reference_counter
+            .iter()
+            .all(|counter| counter.load(Ordering::Relaxed) == 0)
+    }
+
+    /// Every sample offset is a multiple of the granularity. It is the chunk size the
+    /// connections use to track the samples in flight.
+    pub(crate) fn sample_granularity(&self) -> usize {
+        self.sample_granularity
+    }
+
+    fn sample_index(&self, offset: PointerOffset) -> usize {
+        offset.value() / self.sample_granularity
+    }
+
+    /// Returns the address of the sample in the memory of the current process.
+    pub(crate) fn sample_address(&self, offset: PointerOffset) -> usize {
+        self.memory.payload_start_address() + offset.value()
+    }
+
+    pub(crate) fn allocate(&self) -> Result<ShmPointer, ShmAllocationError> {
+        self.allocate_with_layout(self.sample_layout)
+    }
+
+    /// Allocates a sample that is smaller than the maximum sample layout. Depending on the
+    /// [`SampleAllocator`] less memory is occupied by the sample.
+    pub(crate) fn allocate_with_layout(
+        &self,
+        layout: Layout,
+    ) -> Result<ShmPointer, ShmAllocationError> {
+        let ptr = self.memory.allocate(layout)?;
+        if self.sample_reference_counter[self.sample_index(ptr.offset)]
+            .fetch_add(1, Ordering::Relaxed)
+            != 0
+        {
+            fatal_panic!(from self,
+                "Unable to allocate sample since the allocated sample is already in use! This should never happen!");
+        }
+
+        Ok(ptr)
+    }
+
+    pub(crate) fn borrow_sample(&self, offset: PointerOffset) {
+        self.sample_reference_counter[self.sample_index(offset)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn release_sample(&self, offset: PointerOffset) {
+        if self.sample_reference_counter[self.sample_index(offset)].fetch_sub(1, Ordering::Relaxed)
+            == 1
+        {
+            unsafe {
+                self.memory.deallocate(offset, self.sample_layout);
+            }
+        }
+    }
+}
@@ -10,5 +10,14 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+#[cfg(feature = "async")]
+pub(crate) mod async_driver;
+pub(crate) mod client_connections;
+pub(crate) mod consumer_connections;
+pub(crate) mod data_segment;
+pub(crate) mod producer_connections;
 pub(crate) mod publisher_connections;
+pub(crate) mod qos_monitor;
+pub(crate) mod sample_notification;
+pub(crate) mod server_connections;
 pub(crate) mod subscriber_connections;
@@ -0,0 +1,147 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::cell::UnsafeCell;
+use std::sync::Arc;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::SampleAllocator;
+use iceoryx2_cal::{
+    shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder, zero_copy_connection::*,
+};
+
+use crate::port::port_identifiers::{UniqueConsumerId, UniqueProducerId};
+use crate::port::update_connections::ConnectionFailure;
+use crate::service::{
+    self,
+    config_scheme::{connection_config, data_segment_config},
+    dynamic_config::pipeline::ProducerDetails,
+    naming_scheme::{pipeline_connection_name, producer_data_segment_name},
+    static_config::pipeline::StaticConfig,
+    ServiceState,
+};
+
+/// The connection of a [`Consumer`](crate::port::consumer::Consumer) to a
+/// [`Producer`](crate::port::producer::Producer). Work items are received via the `receiver`
+/// from the producers data segment.
+#[derive(Debug)]
+pub(crate) struct ProducerConnection<Service: service::Service> {
+    pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
+    pub(crate) data_segment: Service::SharedMemory,
+    pub(crate) producer_id: UniqueProducerId,
+}
+
+impl<Service: service::Service> ProducerConnection<Service> {
+    fn new(
+        this: &ProducerConnections<Service>,
+        details: &ProducerDetails,
+    ) -> Result<Self, ConnectionFailure> {
+        let msg = format!(
+            "Unable to establish connection to producer {:?} from consumer {:?}",
+            details.producer_id, this.consumer_id
+        );
+        let config = this.service_state.shared_node.config();
+
+        let receiver = fail!(from this,
+                        when <Service::Connection as ZeroCopyConnection>::
+                            Builder::new(&pipeline_connection_name(details.producer_id, this.consumer_id))
+                                .config(&connection_config::<Service>(config))
+                                .buffer_size(this.buffer_size)
+                                .receiver_max_borrowed_samples(this.static_config.consumer_max_borrowed_work_items)
+                                .enable_safe_overflow(false)
+                                .number_of_samples(Service::ShmAllocator::max_number_of_chunks(details.number_of_work_items))
+                                .timeout(config.global.service.creation_timeout)
+                                .create_receiver(this.static_config.message_type_details().sample_layout(1).size()),
+                        "{} since the connection could not be established.", msg);
+
+        let data_segment = fail!(from this,
+                            when <Service::SharedMemory as SharedMemory<Service::ShmAllocator>>::
+                                Builder::new(&producer_data_segment_name(&details.producer_id))
+                                .config(&data_segment_config::<Service>(config))
+                                .timeout(config.global.service.creation_timeout)
+                                .open(),
+                            "{} since the producers data segment could not be opened.", msg);
+
+        Ok(Self {
+            receiver,
+            data_segment,
+            producer_id: details.producer_id,
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct ProducerConnections<Service: service::Service> {
+    connections: Vec<UnsafeCell<Option<Arc<ProducerConnection<Service>>>>>,
+    consumer_id: UniqueConsumerId,
+    pub(crate) service_state: Arc<ServiceState<Service>>,
+    pub(crate) static_config: StaticConfig,
+    buffer_size: usize,
+}
+
+impl<Service: service::Service> ProducerConnections<Service> {
+    pub(crate) fn new(
+        capacity: usize,
+        consumer_id: UniqueConsumerId,
+        service_state: Arc<ServiceState<Service>>,
+        static_config: &StaticConfig,
+        buffer_size: usize,
+    ) -> Self {
+        Self {
+            connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
+            consumer_id,
+            service_state,
+            static_config: static_config.clone(),
+            buffer_size,
+        }
+    }
+
+    pub(crate) fn consumer_id(&self) -> UniqueConsumerId {
+        self.consumer_id
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &Option<Arc<ProducerConnection<Service>>> {
+        unsafe { &*self.connections[index].get() }
+    }
+
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn get_mut(&self, index: usize) -> &mut Option<Arc<ProducerConnection<Service>>> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.connections[index].get()
+        }
+    }
+
+    pub(crate) fn create(
+        &self,
+        index: usize,
+        details: &ProducerDetails,
+    ) -> Result<(), ConnectionFailure> {
+        *self.get_mut(index) = Some(Arc::new(ProducerConnection::new(self, details)?));
+
+        Ok(())
+    }
+
+    pub(crate) fn remove(&self, index: usize) {
+        *self.get_mut(index) = None;
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.connections.len()
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.connections.capacity()
+    }
+}
@@ -10,7 +10,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::{cell::UnsafeCell, sync::Arc};
+use std::{cell::UnsafeCell, sync::Arc, time::Duration};
 
 use crate::{
     port::port_identifiers::{UniquePublisherId, UniqueSubscriberId},
@@ -18,7 +18,7 @@ use crate::{
         self,
         config_scheme::{connection_config, data_segment_config},
         dynamic_config::publish_subscribe::PublisherDetails,
-        naming_scheme::{connection_name, data_segment_name},
+        naming_scheme::{connection_name, resized_data_segment_name},
         static_config::publish_subscribe::StaticConfig,
         ServiceState,
     },
@@ -26,17 +26,27 @@ use crate::{
 
 use crate::port::update_connections::ConnectionFailure;
 use iceoryx2_bb_log::fail;
-use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::named_concept::{NamedConceptBuilder, NamedConceptMgmt};
+use iceoryx2_cal::shm_allocator::SampleAllocator;
 use iceoryx2_cal::{
     shared_memory::SharedMemory, shared_memory::SharedMemoryBuilder,
-    shm_allocator::pool_allocator::PoolAllocator, zero_copy_connection::*,
+    shared_memory::SharedMemoryOpenError, zero_copy_connection::*,
 };
 
+// a data segment of the publisher together with its generation when it was mapped
+#[derive(Debug)]
+struct MappedDataSegment<Service: service::Service> {
+    memory: Service::SharedMemory,
+    generation: u64,
+}
+
 #[derive(Debug)]
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) receiver: <Service::Connection as ZeroCopyConnection>::Receiver,
-    pub(crate) data_segment: Service::SharedMemory,
+    data_segments: Vec<UnsafeCell<Option<MappedDataSegment<Service>>>>,
     pub(crate) publisher_id: UniquePublisherId,
+    data_segment_config: <Service::SharedMemory as NamedConceptMgmt>::Configuration,
+    timeout: Duration,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -56,24 +66,78 @@ impl<Service: service::Service> Connection<Service> {
                                     .buffer_size(this.buffer_size)
                                     .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                     .enable_safe_overflow(this.static_config.enable_safe_overflow)
-                                    .number_of_samples(details.number_of_samples)
+                                    .number_of_samples(Service::ShmAllocator::max_number_of_chunks(details.number_of_samples))
+                                    .max_supported_shared_memory_segments(details.max_number_of_segments)
                                     .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
                                     .create_receiver(this.static_config.message_type_details().sample_layout(details.max_slice_len).size()),
                         "{} since the zero copy connection could not be established.", msg);
 
-        let data_segment = fail!(from this,
-                            when <Service::SharedMemory as SharedMemory<PoolAllocator>>::
-                                Builder::new(&data_segment_name(&details.publisher_id))
-                                .config(&data_segment_config::<Service>(this.service_state.shared_node.config()))
-                                .timeout(this.service_state.shared_node.config().global.service.creation_timeout)
-                                .open(),
-                            "{} since the publishers data segment could not be opened.", msg);
-
-        Ok(Self {
+        let new_self = Self {
             receiver,
-            data_segment,
+            data_segments: (0..details.max_number_of_segments)
+                .map(|_| UnsafeCell::new(None))
+                .collect(),
             publisher_id: details.publisher_id,
-        })
+            data_segment_config: data_segment_config::<Service>(
+                this.service_state.shared_node.config(),
+            ),
+            timeout: this
+                .service_state
+                .shared_node
+                .config()
+                .global
+                .service
+                .creation_timeout,
+        };
+
+        // a publisher with a static data segment has always exactly one segment that is mapped
+        // right away, all other segments are mapped as soon as a sample refers to them
+        if details.max_number_of_segments == 1 {
+            if let Err(e) = new_self.payload_start_address(0) {
+                fail!(from this, with ConnectionFailure::UnableToMapPublishersDataSegment(e),
+                    "{} since the publishers data segment could not be opened.", msg);
+            }
+        }
+
+        Ok(new_self)
+    }
+
+    /// Returns the start address of the publishers data segment with the provided id. If the
+    /// data segment was not yet mapped or was replaced by the publisher it will be opened.
+    pub(crate) fn payload_start_address(
+        &self,
+        segment_id: u8,
+    ) -> Result<usize, SharedMemoryOpenError> {
+        // the segment id is read from shared memory and cannot be trusted
+        let data_segment = match self.data_segments.get(segment_id as usize) {
+            Some(data_segment) => unsafe { &mut *data_segment.get() },
+            None => {
+                fail!(from self, with SharedMemoryOpenError::DoesNotExist,
+                    "Unable to map the data segment {} of publisher {:?} since the publisher supports only {} data segments.",
+                    segment_id, self.publisher_id, self.data_segments.len());
+            }
+        };
+
+        // the publisher replaces a segment only when all of its samples were returned, therefore
+        // the outdated mapping is no longer referenced by any sample
+        let generation = self.receiver.segment_generation(segment_id);
+        if let Some(mapped) = data_segment {
+            if mapped.generation == generation {
+                return Ok(mapped.memory.payload_start_address());
+            }
+        }
+
+        let memory = fail!(from self,
+            when <Service::SharedMemory as SharedMemory<Service::ShmAllocator>>::
+                Builder::new(&resized_data_segment_name(&self.publisher_id, segment_id))
+                .config(&self.data_segment_config)
+                .timeout(self.timeout)
+                .open(),
+            "Unable to map the data segment {} of publisher {:?}.", segment_id, self.publisher_id);
+        let payload_start_address = memory.payload_start_address();
+        *data_segment = Some(MappedDataSegment { memory, generation });
+
+        Ok(payload_start_address)
     }
 }
 #[derive(Debug)]
@@ -0,0 +1,180 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{cell::RefCell, sync::Arc, time::Duration};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::ContainerState;
+use iceoryx2_bb_log::warn;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::{
+    port::{event_id::EventId, notifier::Notifier, port_identifiers::UniquePublisherId},
+    service::{
+        self,
+        builder::Builder,
+        dynamic_config::publish_subscribe::{PublisherActivity, PublisherDetails},
+        ServiceState,
+    },
+};
+
+/// Detects the [`crate::port::publisher::Publisher`]s of a publish-subscribe service that
+/// violate the publish deadline or the liveliness lease and emits every violation once on the
+/// event service with the same name.
+#[derive(Debug)]
+pub(crate) struct QosMonitor<Service: service::Service> {
+    service_state: Arc<ServiceState<Service>>,
+    publisher_list_state: RefCell<ContainerState<PublisherDetails>>,
+    notifier: RefCell<Option<Notifier<Service>>>,
+}
+
+impl<Service: service::Service> QosMonitor<Service> {
+    pub(crate) fn new(service_state: Arc<ServiceState<Service>>) -> Self {
+        let publisher_list_state = unsafe {
+            service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publishers
+                .get_state()
+        };
+
+        Self {
+            service_state,
+            publisher_list_state: RefCell::new(publisher_list_state),
+            notifier: RefCell::new(None),
+        }
+    }
+
+    /// Returns true when the service emits at least one violation as event.
+    pub(crate) fn has_events(&self) -> bool {
+        let static_config = self.service_state.static_config.publish_subscribe();
+        static_config.deadline_missed_event().is_some()
+            || static_config.liveliness_lost_event().is_some()
+    }
+
+    /// Detects all violations without reporting them, so that they are emitted as event even
+    /// when no one asks for them.
+    pub(crate) fn emit_violations(&self) {
+        if self.has_events() {
+            self.missed_deadlines(|_| ());
+            self.expired_liveliness_leases(|_| ());
+        }
+    }
+
+    pub(crate) fn missed_deadlines<F: FnMut(UniquePublisherId)>(&self, call: F) {
+        let static_config = self.service_state.static_config.publish_subscribe();
+        self.detect_violations(
+            static_config.publish_deadline(),
+            static_config.deadline_missed_event(),
+            PublisherActivity::time_since_last_delivery,
+            PublisherActivity::claim_missed_deadline_report,
+            call,
+        )
+    }
+
+    pub(crate) fn expired_liveliness_leases<F: FnMut(UniquePublisherId)>(&self, call: F) {
+        let static_config = self.service_state.static_config.publish_subscribe();
+        self.detect_violations(
+            static_config.liveliness_lease(),
+            static_config.liveliness_lost_event(),
+            PublisherActivity::time_since_last_liveliness_assertion,
+            PublisherActivity::claim_expired_lease_report,
+            call,
+        )
+    }
+
+    fn detect_violations<
+        F: Fn(&PublisherActivity) -> Option<(u64, Duration)>,
+        R: Fn(&PublisherActivity, u64) -> bool,
+        C: FnMut(UniquePublisherId),
+    >(
+        &self,
+        limit: Option<Duration>,
+        event_id: Option<EventId>,
+        time_since_last_activity: F,
+        claim_report: R,
+        mut call: C,
+    ) {
+        let limit = match limit {
+            Some(limit) => limit,
+            None => return,
+        };
+
+        let dynamic_config = self.service_state.dynamic_storage.get().publish_subscribe();
+
+        // the state is not updated when the detection is triggered again from within the
+        // callback, the outer detection still iterates over it
+        if let Ok(mut publisher_list_state) = self.publisher_list_state.try_borrow_mut() {
+            unsafe {
+                dynamic_config
+                    .publishers
+                    .update_state(&mut publisher_list_state)
+            };
+        }
+
+        self.publisher_list_state
+            .borrow()
+            .for_each(|handle, details| {
+                let activity = dynamic_config.publisher_activity(handle);
+                if let Some((timestamp, elapsed)) = time_since_last_activity(activity) {
+                    if limit < elapsed {
+                        // a violation is emitted only once by one of the ports until the
+                        // publisher becomes active again
+                        if let Some(event_id) = event_id {
+                            if claim_report(activity, timestamp) {
+                                self.emit(event_id);
+                            }
+                        }
+                        call(details.publisher_id);
+                    }
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    fn emit(&self, event_id: EventId) {
+        let msg = "Unable to emit the quality of service event";
+        let mut notifier = self.notifier.borrow_mut();
+        if notifier.is_none() {
+            let event = match Builder::new(
+                self.service_state.static_config.name(),
+                self.service_state.shared_node.clone(),
+            )
+            .event()
+            .open()
+            {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!(from self, "{} {:?} since the corresponding event service could not be opened ({:?}).",
+                        msg, event_id, e);
+                    return;
+                }
+            };
+
+            match event.notifier_builder().create() {
+                Ok(new_notifier) => *notifier = Some(new_notifier),
+                Err(e) => {
+                    warn!(from self, "{} {:?} since the notifier could not be created ({:?}).",
+                        msg, event_id, e);
+                    return;
+                }
+            }
+        }
+
+        if let Some(notifier) = notifier.as_ref() {
+            if let Err(e) = notifier.notify_with_custom_event_id(event_id) {
+                warn!(from self, "{} {:?} ({:?}).", msg, event_id, e);
+            }
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use iceoryx2_bb_log::fatal_panic;
+
+use crate::service::{
+    self, builder::event::EventOpenOrCreateError, builder::Builder, port_factory::event,
+    service_name::ServiceName, ServiceState,
+};
+
+fn sample_notification_service_name<Service: service::Service>(
+    service_state: &ServiceState<Service>,
+) -> ServiceName {
+    let service_id = service_state.static_config.service_id();
+    fatal_panic!(from "sample_notification_service_name()",
+        when ServiceName::new(&format!("iox2://publish_subscribe/{}/samples", service_id.as_str())),
+        "This should never happen! The sample notification service name of the service {:?} is invalid.",
+        service_id)
+}
+
+/// Opens or creates the internal event service on which the
+/// [`crate::port::publisher::Publisher`]s notify the [`crate::port::subscriber::Subscriber`]s
+/// that requested it after every delivered sample. The service is created with the first
+/// [`crate::port::subscriber::Subscriber`] that requests notifications and is owned by the
+/// nodes of its ports, so that the dead node cleanup removes it.
+pub(crate) fn open_or_create_sample_notification_service<Service: service::Service>(
+    service_state: &ServiceState<Service>,
+) -> Result<event::PortFactory<Service>, EventOpenOrCreateError> {
+    let static_config = service_state.static_config.publish_subscribe();
+    Builder::new(
+        &sample_notification_service_name(service_state),
+        service_state.shared_node.clone(),
+    )
+    .event()
+    .max_nodes(static_config.max_nodes())
+    .max_listeners(static_config.max_subscribers())
+    .max_notifiers(static_config.max_publishers())
+    .open_or_create()
+}
@@ -10,16 +10,18 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, UnsafeCell};
 use std::sync::Arc;
 
 use iceoryx2_bb_log::fail;
 use iceoryx2_cal::named_concept::NamedConceptBuilder;
+use iceoryx2_cal::shm_allocator::SampleAllocator;
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError,
+    ZeroCopyConnection, ZeroCopyConnectionBuilder, ZeroCopyCreationError, ZeroCopySender,
 };
 
 use crate::node::SharedNode;
+use crate::port::subscriber::SampleFilter;
 use crate::service::config_scheme::connection_config;
 use crate::service::dynamic_config::publish_subscribe::SubscriberDetails;
 use crate::{
@@ -32,6 +34,9 @@ use crate::{
 pub(crate) struct Connection<Service: service::Service> {
     pub(crate) sender: <Service::Connection as ZeroCopyConnection>::Sender,
     pub(crate) subscriber_id: UniqueSubscriberId,
+    pub(crate) filter: Option<SampleFilter>,
+    has_delivered_samples: Cell<bool>,
+    pending_skipped_samples: Cell<u64>,
 }
 
 impl<Service: service::Service> Connection<Service> {
@@ -57,7 +62,8 @@ impl<Service: service::Service> Connection<Service> {
                                 .buffer_size(subscriber_details.buffer_size)
                                 .receiver_max_borrowed_samples(this.static_config.subscriber_max_borrowed_samples)
                                 .enable_safe_overflow(this.static_config.enable_safe_overflow)
-                                .number_of_samples(number_of_samples)
+                                .number_of_samples(Service::ShmAllocator::max_number_of_chunks(number_of_samples))
+                                .max_supported_shared_memory_segments(this.max_number_of_segments)
                                 .timeout(this.shared_node.config().global.service.creation_timeout)
                                 .create_sender(this.static_config.message_type_details().sample_layout(max_slice_len).size()),
                         "{}.", msg);
@@ -65,8 +71,30 @@ impl<Service: service::Service> Connection<Service> {
         Ok(Self {
             sender,
             subscriber_id: subscriber_details.subscriber_id,
+            filter: subscriber_details.filter,
+            has_delivered_samples: Cell::new(false),
+            pending_skipped_samples: Cell::new(0),
         })
     }
+
+    /// Records that a sample was not delivered since it was rejected by the [`SampleFilter`].
+    /// Samples that are skipped before the first delivery are not recorded, since the
+    /// subscriber can only detect gaps after the first sample it received.
+    pub(crate) fn skip_sample(&self) {
+        if self.has_delivered_samples.get() {
+            self.pending_skipped_samples
+                .set(self.pending_skipped_samples.get() + 1);
+        }
+    }
+
+    /// Must be called before a sample is delivered. Publishes the samples that were skipped
+    /// since the last delivery to the subscriber, so that the subscriber never accounts a
+    /// skipped sample before it has received the sample that follows it.
+    pub(crate) fn prepare_delivery(&self) {
+        self.sender
+            .record_skipped_samples(self.pending_skipped_samples.replace(0));
+        self.has_delivered_samples.set(true);
+    }
 }
 
 #[derive(Debug)]
@@ -76,6 +104,7 @@ pub(crate) struct SubscriberConnections<Service: service::Service> {
     shared_node: Arc<SharedNode<Service>>,
     pub(crate) static_config: StaticConfig,
     number_of_samples: usize,
+    max_number_of_segments: u8,
 }
 
 impl<Service: service::Service> SubscriberConnections<Service> {
@@ -85,6 +114,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
         port_id: UniquePublisherId,
         static_config: &StaticConfig,
         number_of_samples: usize,
+        max_number_of_segments: u8,
     ) -> Self {
         Self {
             connections: (0..capacity).map(|_| UnsafeCell::new(None)).collect(),
@@ -92,6 +122,7 @@ impl<Service: service::Service> SubscriberConnections<Service> {
             port_id,
             static_config: static_config.clone(),
             number_of_samples,
+            max_number_of_segments,
         }
     }
 
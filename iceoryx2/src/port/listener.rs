@@ -78,6 +78,15 @@ use std::time::Duration;
 
 use super::event_id::EventId;
 
+#[cfg(feature = "async")]
+use crate::port::details::async_driver::Registration;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
 /// Defines the failures that can occur when a [`Listener`] is created with the
 /// [`crate::service::port_factory::listener::PortFactoryListener`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -255,6 +264,74 @@ impl<Service: service::Service> Listener<Service> {
     }
 }
 
+#[cfg(feature = "async")]
+impl<Service: service::Service + 'static> Listener<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    <Service::Event as iceoryx2_cal::event::Event>::Notifier: Send + Sync,
+{
+    /// Asynchronous wait for a new [`EventId`]. The returned future is woken up by the
+    /// reactor of the [`Service`](crate::service::Service) as soon as the file descriptor of the
+    /// [`Listener`] signals a new [`EventId`] and does not depend on a specific async runtime.
+    /// On error it returns [`ListenerWaitError`] is returned which describes the error
+    /// in detail.
+    pub fn wait_one_async(&self) -> impl Future<Output = Result<EventId, ListenerWaitError>> + '_ {
+        let mut registration = None;
+        std::future::poll_fn(move |cx| self.poll_wait_one(&mut registration, cx))
+    }
+
+    /// Returns a [`Stream`](futures_core::Stream) that yields every received [`EventId`]. The
+    /// stream never ends.
+    pub fn stream(&self) -> ListenerStream<'_, Service> {
+        ListenerStream {
+            listener: self,
+            registration: None,
+        }
+    }
+
+    fn poll_wait_one(
+        &self,
+        registration: &mut Option<Registration<Service>>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<EventId, ListenerWaitError>> {
+        match self.try_wait_one() {
+            Ok(Some(id)) => Poll::Ready(Ok(id)),
+            Ok(None) => {
+                registration
+                    .get_or_insert_with(Registration::new)
+                    .wake_when_readable(self, cx.waker());
+                Poll::Pending
+            }
+            Err(e) => Poll::Ready(Err(e)),
+        }
+    }
+}
+
+/// A [`Stream`](futures_core::Stream) of all [`EventId`]s a [`Listener`] receives. It is
+/// created with [`Listener::stream()`].
+#[cfg(feature = "async")]
+#[derive(Debug)]
+pub struct ListenerStream<'listener, Service: service::Service> {
+    listener: &'listener Listener<Service>,
+    registration: Option<Registration<Service>>,
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service + 'static> futures_core::Stream for ListenerStream<'_, Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    <Service::Event as iceoryx2_cal::event::Event>::Notifier: Send + Sync,
+{
+    type Item = Result<EventId, ListenerWaitError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        this.listener
+            .poll_wait_one(&mut this.registration, cx)
+            .map(Some)
+    }
+}
+
 pub(crate) unsafe fn remove_connection_of_listener<Service: service::Service>(
     listener_id: &UniqueListenerId,
     config: &Config,
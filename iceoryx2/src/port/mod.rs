@@ -16,6 +16,10 @@ use tiny_fn::tiny_fn;
 
 pub(crate) mod details;
 
+/// Sending endpoint (port) for request-response based communication
+pub mod client;
+/// Receiving endpoint (port) for pipeline based communication
+pub mod consumer;
 /// Defines the event id used to identify the source of an event.
 pub mod event_id;
 /// Receiving endpoint (port) for event based communication
@@ -24,8 +28,18 @@ pub mod listener;
 pub mod notifier;
 /// Defines port specific unique ids. Used to identify source/destination while communicating.
 pub mod port_identifiers;
+/// Sending endpoint (port) for pipeline based communication
+pub mod producer;
 /// Sending endpoint (port) for publish-subscribe based communication
 pub mod publisher;
+/// Reading endpoint (port) for blackboard based communication
+pub mod reader;
+/// Sending endpoint (port) for publish-subscribe based communication with serialized payloads
+pub mod serialized_publisher;
+/// Receiving endpoint (port) for publish-subscribe based communication with serialized payloads
+pub mod serialized_subscriber;
+/// Receiving endpoint (port) for request-response based communication
+pub mod server;
 /// Receiving endpoint (port) for publish-subscribe based communication
 pub mod subscriber;
 /// Interface to perform cyclic updates to the ports. Required to deliver history to new
@@ -34,6 +48,8 @@ pub mod update_connections;
 /// Event handling mechanism to wait on multiple [`Listener`](crate::port::listener::Listener)s
 /// in one call, realizing the reactor pattern. (Event multiplexer)
 pub mod waitset;
+/// Writing endpoint (port) for blackboard based communication
+pub mod writer;
 
 use crate::port::port_identifiers::*;
 use crate::service;
@@ -61,3 +77,16 @@ impl<'a> Debug for DegrationCallback<'a> {
         write!(f, "")
     }
 }
+
+tiny_fn! {
+    /// Is called by a [`publisher::Publisher`] whenever it overrides the oldest sample in the
+    /// full buffer of a [`subscriber::Subscriber`] due to a safe overflow. `missed_samples`
+    /// contains the total number of samples the subscriber has missed so far.
+    pub struct OverflowCallback = Fn(service: service::static_config::StaticConfig, publisher_id: UniquePublisherId, subscriber_id: UniqueSubscriberId, missed_samples: u64);
+}
+
+impl<'a> Debug for OverflowCallback<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "")
+    }
+}
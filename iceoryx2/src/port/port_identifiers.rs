@@ -35,6 +35,11 @@ macro_rules! generate_id {
             pub fn new() -> Self {
                 Self::default()
             }
+
+            /// Returns the underlying raw value of the id
+            pub fn value(&self) -> u128 {
+                self.0.value()
+            }
         }
     };
 }
@@ -55,6 +60,30 @@ generate_id! {
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     UniqueListenerId
 }
+generate_id! {
+    /// The system-wide unique id of a [`Client`](crate::port::client::Client).
+    UniqueClientId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Server`](crate::port::server::Server).
+    UniqueServerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Reader`](crate::port::reader::Reader).
+    UniqueReaderId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
+    UniqueWriterId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Producer`](crate::port::producer::Producer).
+    UniqueProducerId
+}
+generate_id! {
+    /// The system-wide unique id of a [`Consumer`](crate::port::consumer::Consumer).
+    UniqueConsumerId
+}
 
 /// Enum that contains the unique port id
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,4 +96,16 @@ pub enum UniquePortId {
     Notifier(UniqueNotifierId),
     /// The system-wide unique id of a [`Listener`](crate::port::listener::Listener).
     Listener(UniqueListenerId),
+    /// The system-wide unique id of a [`Client`](crate::port::client::Client).
+    Client(UniqueClientId),
+    /// The system-wide unique id of a [`Server`](crate::port::server::Server).
+    Server(UniqueServerId),
+    /// The system-wide unique id of a [`Reader`](crate::port::reader::Reader).
+    Reader(UniqueReaderId),
+    /// The system-wide unique id of a [`Writer`](crate::port::writer::Writer).
+    Writer(UniqueWriterId),
+    /// The system-wide unique id of a [`Producer`](crate::port::producer::Producer).
+    Producer(UniqueProducerId),
+    /// The system-wide unique id of a [`Consumer`](crate::port::consumer::Consumer).
+    Consumer(UniqueConsumerId),
 }
@@ -0,0 +1,709 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = service
+//!     .producer_builder()
+//!     // defines how many work items can be loaned in parallel
+//!     .max_loaned_work_items(2)
+//!     // every work item is delivered to the next consumer in line
+//!     .delivery_strategy(DeliveryStrategy::RoundRobin)
+//!     .create()?;
+//!
+//! // loan some initialized memory and send it
+//! // the payload type must implement the [`core::default::Default`] trait in order to be able to use this API
+//! let mut work_item = producer.loan()?;
+//! *work_item.payload_mut() = 1337;
+//! work_item.send()?;
+//!
+//! // loan some uninitialized memory and send it
+//! let work_item = producer.loan_uninit()?;
+//! let work_item = work_item.write_payload(1337);
+//! work_item.send()?;
+//!
+//! // send a copy of the value
+//! producer.send_copy(313)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::port_identifiers::UniqueProducerId;
+use crate::port::client::{
+    remove_port_from_all_connections, RemoveReqResPortFromAllConnectionsError,
+};
+use crate::port::details::consumer_connections::*;
+use crate::port::details::data_segment::DataSegment;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service::config_scheme::data_segment_config;
+use crate::service::dynamic_config::pipeline::{ConsumerDetails, ProducerDetails};
+use crate::service::header::pipeline::Header;
+use crate::service::naming_scheme::producer_data_segment_name;
+use crate::service::port_factory::producer::{DeliveryStrategy, LocalProducerConfig};
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+use crate::service::static_config::pipeline::StaticConfig;
+use crate::work_item_mut::WorkItemMut;
+use crate::work_item_mut_uninit::WorkItemMutUninit;
+use crate::{config, service};
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::NamedConceptRemoveError;
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{ZeroCopySendError, ZeroCopySender};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::{marker::PhantomData, mem::MaybeUninit};
+
+/// Defines a failure that can occur when a [`Producer`] is created with
+/// [`crate::service::port_factory::producer::PortFactoryProducer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ProducerCreateError {
+    /// The maximum amount of [`Producer`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Producer`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedProducers,
+    /// The datasegment in which the work items of the [`Producer`] are stored, could not be
+    /// created.
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ProducerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ProducerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ProducerCreateError {}
+
+/// Defines a failure that can occur in [`Producer::loan()`] and [`Producer::loan_uninit()`]
+/// or is part of [`ProducerSendError`] emitted in [`Producer::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ProducerLoanError {
+    /// The [`Producer`]s data segment does not have any more memory left
+    OutOfMemory,
+    /// The maximum amount of [`WorkItemMut`]s a user can borrow with [`Producer::loan()`] or
+    /// [`Producer::loan_uninit()`] is
+    /// defined in [`crate::config::Config`]. When this is exceeded those calls will fail.
+    ExceedsMaxLoanedWorkItems,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl std::fmt::Display for ProducerLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ProducerLoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ProducerLoanError {}
+
+/// Failure that can be emitted when a [`WorkItemMut`] is sent via [`WorkItemMut::send()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ProducerSendError {
+    /// A connection between a [`Consumer`](crate::port::consumer::Consumer) and a
+    /// [`Producer`] is corrupted.
+    ConnectionCorrupted,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(ProducerLoanError),
+    /// A failure occurred while establishing a connection to a
+    /// [`Consumer`](crate::port::consumer::Consumer)
+    ConnectionError(ConnectionFailure),
+}
+
+impl From<ProducerLoanError> for ProducerSendError {
+    fn from(value: ProducerLoanError) -> Self {
+        ProducerSendError::LoanError(value)
+    }
+}
+
+impl From<ConnectionFailure> for ProducerSendError {
+    fn from(value: ConnectionFailure) -> Self {
+        ProducerSendError::ConnectionError(value)
+    }
+}
+
+impl std::fmt::Display for ProducerSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ProducerSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ProducerSendError {}
+
+#[derive(Debug)]
+pub(crate) struct ProducerBackend<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    pub(crate) consumer_connections: ConsumerConnections<Service>,
+    consumer_list_state: UnsafeCell<ContainerState<ConsumerDetails>>,
+    config: LocalProducerConfig,
+    loan_counter: IoxAtomicUsize,
+    next_consumer: IoxAtomicUsize,
+    // work items that were delivered to a consumer that disconnected or died before it
+    // could release them, they are redelivered to the remaining consumers
+    undelivered_work_items: UnsafeCell<Vec<PointerOffset>>,
+}
+
+impl<Service: service::Service> ProducerBackend<Service> {
+    pub(crate) fn static_config(&self) -> &StaticConfig {
+        &self.consumer_connections.static_config
+    }
+
+    fn retrieve_returned_work_items(&self) {
+        for i in 0..self.consumer_connections.len() {
+            if let Some(ref connection) = self.consumer_connections.get(i) {
+                self.retrieve_returned_work_items_from(connection);
+            }
+        }
+    }
+
+    fn retrieve_returned_work_items_from(&self, connection: &ConsumerConnection<Service>) {
+        loop {
+            match connection.sender.reclaim() {
+                Ok(Some(offset)) => self.data_segment.release_sample(offset),
+                Ok(None) => break,
+                Err(e) => {
+                    warn!(from self, "Unable to reclaim work items from connection {:?} due to {:?}. This may lead to a situation where no more work items will be delivered to this connection.", connection, e);
+                    break;
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.consumer_connections.get(i) {
+            // work items that were already processed are returned to the data segment,
+            // all others are still unprocessed and must be delivered to another consumer
+            self.retrieve_returned_work_items_from(connection);
+
+            let undelivered_work_items = unsafe { &mut *self.undelivered_work_items.get() };
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered work items
+            unsafe {
+                connection
+                    .sender
+                    .acquire_used_offsets(|offset| undelivered_work_items.push(offset))
+            };
+
+            self.consumer_connections.remove(i);
+        }
+    }
+
+    fn populate_consumer_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.consumer_connections.capacity(), None);
+
+        unsafe {
+            (*self.consumer_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.consumer_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.consumer_id != details.consumer_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        if let Err(e) = self.consumer_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new consumer {:?}.", details.consumer_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        ret_val
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.consumer_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .consumers
+                .update_state(&mut *self.consumer_list_state.get())
+        } {
+            fail!(from self, when self.populate_consumer_channels(),
+                "Connections were updated only partially since at least one connection to a Consumer port failed.");
+        }
+
+        Ok(())
+    }
+
+    fn allocate(&self) -> Result<ShmPointer, ProducerLoanError> {
+        let msg = "Unable to allocate work item";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_work_items {
+            fail!(from self, with ProducerLoanError::ExceedsMaxLoanedWorkItems,
+                "{} since already {} work items were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned work item to loan another work item.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_work_items);
+        }
+
+        self.retrieve_returned_work_items();
+
+        match self.data_segment.allocate() {
+            Ok(chunk) => {
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(chunk)
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ProducerLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ProducerLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    pub(crate) fn return_loaned_work_item(&self, offset: PointerOffset) {
+        self.data_segment.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    fn has_connected_consumers(&self) -> bool {
+        (0..self.consumer_connections.len()).any(|i| self.consumer_connections.get(i).is_some())
+    }
+
+    // Delivers the work item to exactly one consumer selected by the configured
+    // DeliveryStrategy. Returns false when every consumer buffer is full.
+    fn deliver(&self, offset: PointerOffset) -> Result<bool, ProducerSendError> {
+        let msg = "Unable to deliver work item";
+        let number_of_connections = self.consumer_connections.len();
+        let start = match self.config.delivery_strategy {
+            DeliveryStrategy::RoundRobin => self.next_consumer.load(Ordering::Relaxed),
+            DeliveryStrategy::FirstFree => 0,
+        };
+
+        for n in 0..number_of_connections {
+            let i = (start + n) % number_of_connections;
+            if let Some(ref connection) = self.consumer_connections.get(i) {
+                match connection
+                    .sender
+                    .try_send(offset, self.data_segment.sample_granularity())
+                {
+                    Err(ZeroCopySendError::ReceiveBufferFull)
+                    | Err(ZeroCopySendError::UsedChunkListFull) => continue,
+                    Err(ZeroCopySendError::ConnectionCorrupted) => {
+                        fail!(from self, with ProducerSendError::ConnectionCorrupted,
+                            "{} since a corrupted connection was detected with consumer {:?}.",
+                            msg, connection.consumer_id);
+                    }
+                    Ok(overflow) => {
+                        self.data_segment.borrow_sample(offset);
+                        if let Some(old) = overflow {
+                            self.data_segment.release_sample(old)
+                        }
+
+                        self.next_consumer
+                            .store((i + 1) % number_of_connections, Ordering::Relaxed);
+                        return Ok(true);
+                    }
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn redeliver_work_items(&self) -> Result<(), ProducerSendError> {
+        let undelivered_work_items = unsafe { &mut *self.undelivered_work_items.get() };
+        while let Some(offset) = undelivered_work_items.last().copied() {
+            if !self.deliver(offset)? {
+                break;
+            }
+
+            // the reference of the undelivered work item is transferred to the consumer
+            undelivered_work_items.pop();
+            self.data_segment.release_sample(offset);
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn send_work_item(&self, offset: PointerOffset) -> Result<usize, ProducerSendError> {
+        let msg = "Unable to send work item";
+        fail!(from self, when self.update_connections(),
+            "{} since the connections could not be updated.", msg);
+
+        self.retrieve_returned_work_items();
+        fail!(from self, when self.redeliver_work_items(),
+            "{} since previously undelivered work items could not be delivered.", msg);
+
+        loop {
+            if !self.has_connected_consumers() {
+                warn!(from self,
+                    "{} since no consumer is connected. The work item is discarded.", msg);
+                return Ok(0);
+            }
+
+            if self.deliver(offset)? {
+                return Ok(1);
+            }
+
+            match self.config.unable_to_deliver_strategy {
+                UnableToDeliverStrategy::Block => {
+                    std::thread::yield_now();
+                    fail!(from self, when self.update_connections(),
+                        "{} since the connections could not be updated.", msg);
+                    self.retrieve_returned_work_items();
+                }
+                UnableToDeliverStrategy::DiscardSample => {
+                    warn!(from self,
+                        "{} since the buffers of all consumers are full. The work item is discarded.", msg);
+                    return Ok(0);
+                }
+            }
+        }
+    }
+
+    pub(crate) fn number_of_undelivered_work_items(&self) -> usize {
+        unsafe { &*self.undelivered_work_items.get() }.len()
+    }
+}
+
+impl<Service: service::Service> Drop for ProducerBackend<Service> {
+    fn drop(&mut self) {
+        for offset in self.undelivered_work_items.get_mut().drain(..) {
+            self.data_segment.release_sample(offset);
+        }
+    }
+}
+
+/// Sending endpoint of a pipeline based communication. Every work item is delivered to exactly
+/// one of the connected [`Consumer`](crate::port::consumer::Consumer)s, selected by the
+/// configured [`DeliveryStrategy`].
+#[derive(Debug)]
+pub struct Producer<Service: service::Service, Payload: Debug> {
+    pub(crate) backend: Arc<ProducerBackend<Service>>,
+    dynamic_producer_handle: Option<ContainerHandle>,
+    _payload: PhantomData<Payload>,
+}
+
+impl<Service: service::Service, Payload: Debug> Drop for Producer<Service, Payload> {
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_producer_handle {
+            self.backend
+                .consumer_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .pipeline()
+                .release_producer_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, Payload: Debug> Producer<Service, Payload> {
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalProducerConfig,
+    ) -> Result<Self, ProducerCreateError> {
+        let msg = "Unable to create Producer port";
+        let origin = "Producer::new()";
+        let producer_id = UniqueProducerId::new();
+        let consumer_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .consumers;
+
+        let number_of_work_items = static_config
+            .required_amount_of_work_items_per_data_segment(config.max_loaned_work_items);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(
+                    &producer_data_segment_name(&producer_id),
+                    service.__internal_state().shared_node.config(),
+                    static_config.message_type_details().sample_layout(1),
+                    number_of_work_items),
+                with ProducerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let backend = Arc::new(ProducerBackend {
+            data_segment,
+            consumer_connections: ConsumerConnections::new(
+                consumer_list.capacity(),
+                producer_id,
+                service.__internal_state().clone(),
+                static_config,
+                number_of_work_items,
+            ),
+            consumer_list_state: UnsafeCell::new(unsafe { consumer_list.get_state() }),
+            config,
+            loan_counter: IoxAtomicUsize::new(0),
+            next_consumer: IoxAtomicUsize::new(0),
+            undelivered_work_items: UnsafeCell::new(vec![]),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_producer_handle: None,
+            _payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_consumer_channels() {
+            warn!(from new_self, "The new Producer port is unable to connect to every Consumer port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a producer is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_producer_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .pipeline()
+            .add_producer_id(ProducerDetails {
+                producer_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                number_of_work_items,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ProducerCreateError::ExceedsMaxSupportedProducers,
+                    "{} since it would exceed the maximum supported amount of producers of {}.",
+                    msg, static_config.max_producers);
+            }
+        };
+
+        new_self.dynamic_producer_handle = Some(dynamic_producer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`Producer`]
+    pub fn id(&self) -> UniqueProducerId {
+        self.backend.consumer_connections.producer_id()
+    }
+
+    /// Returns the [`DeliveryStrategy`] of the [`Producer`]
+    pub fn delivery_strategy(&self) -> DeliveryStrategy {
+        self.backend.config.delivery_strategy
+    }
+
+    /// Returns the [`UnableToDeliverStrategy`] of the [`Producer`]
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.backend.config.unable_to_deliver_strategy
+    }
+
+    /// Returns the number of work items that were delivered to a
+    /// [`Consumer`](crate::port::consumer::Consumer) that disconnected or died before it
+    /// processed them and that could not yet be redelivered to another
+    /// [`Consumer`](crate::port::consumer::Consumer). They are redelivered with the next
+    /// [`UpdateConnections::update_connections()`] or send call.
+    pub fn number_of_undelivered_work_items(&self) -> usize {
+        self.backend.number_of_undelivered_work_items()
+    }
+
+    /// Copies the input `value` into a [`WorkItemMut`] and delivers it to exactly one
+    /// connected [`Consumer`](crate::port::consumer::Consumer). On success it returns the
+    /// number of [`Consumer`](crate::port::consumer::Consumer)s that received the work item,
+    /// otherwise a [`ProducerSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// producer.send_copy(1234)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send_copy(&self, value: Payload) -> Result<usize, ProducerSendError> {
+        let msg = "Unable to send copy of work item";
+        let work_item = fail!(from self, when self.loan_uninit(),
+                                    "{} since the loan of a work item failed.", msg);
+
+        work_item.write_payload(value).send()
+    }
+
+    /// Loans/allocates a [`WorkItemMutUninit`] from the underlying data segment of the
+    /// [`Producer`]. The user has to initialize the payload before it can be sent.
+    ///
+    /// On failure it returns [`ProducerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(42); // alternatively `work_item.payload_mut()` can be use to access the `MaybeUninit<Payload>`
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan_uninit(&self) -> Result<WorkItemMutUninit<Service, Payload>, ProducerLoanError> {
+        let chunk = self.backend.allocate()?;
+        let header_ptr = chunk.data_ptr as *mut Header;
+        let payload_ptr = self
+            .backend
+            .static_config()
+            .message_type_details()
+            .payload_ptr_from_header(header_ptr.cast())
+            as *mut MaybeUninit<Payload>;
+
+        unsafe { header_ptr.write(Header::new(self.id())) };
+
+        Ok(WorkItemMutUninit::new(
+            &self.backend,
+            header_ptr,
+            payload_ptr,
+            chunk.offset,
+        ))
+    }
+}
+
+impl<Service: service::Service, Payload: Default + Debug> Producer<Service, Payload> {
+    /// Loans/allocates a [`WorkItemMut`] from the underlying data segment of the [`Producer`]
+    /// and initialize it with the default value. This can be a performance hit and
+    /// [`Producer::loan_uninit`] can be used to loan a [`core::mem::MaybeUninit<Payload>`].
+    ///
+    /// On failure it returns [`ProducerLoanError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// #
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let mut work_item = producer.loan()?;
+    /// *work_item.payload_mut() = 42;
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn loan(&self) -> Result<WorkItemMut<Service, Payload>, ProducerLoanError> {
+        Ok(self.loan_uninit()?.write_payload(Payload::default()))
+    }
+}
+
+impl<Service: service::Service, Payload: Debug> UpdateConnections for Producer<Service, Payload> {
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        fail!(from self, when self.backend.update_connections(),
+            "Unable to update the connections of the producer.");
+        self.backend.retrieve_returned_work_items();
+        if let Err(e) = self.backend.redeliver_work_items() {
+            warn!(from self,
+                "Unable to redeliver work items of disconnected consumers ({:?}).", e);
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_producer<Service: service::Service>(
+    port_id: &UniqueProducerId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_producer::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &producer_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the producers data segment."
+    );
+
+    Ok(())
+}
+
+pub(crate) unsafe fn remove_producer_from_all_connections<Service: service::Service>(
+    port_id: &UniqueProducerId,
+    config: &config::Config,
+) -> Result<(), RemoveReqResPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_producer_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    remove_port_from_all_connections::<Service>(
+        &origin,
+        "Unable to remove the producer from all connections",
+        &port_id.0,
+        config,
+    )
+}
@@ -103,42 +103,49 @@
 
 use super::port_identifiers::UniquePublisherId;
 use super::UniqueSubscriberId;
+use crate::port::details::data_segment::DataSegment as MemorySegment;
+use crate::port::details::qos_monitor::QosMonitor;
+use crate::port::details::sample_notification::open_or_create_sample_notification_service;
 use crate::port::details::subscriber_connections::*;
+use crate::port::notifier::Notifier;
 use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
 use crate::port::DegrationAction;
 use crate::raw_sample::RawSampleMut;
 use crate::sample_mut_uninit::SampleMutUninit;
 use crate::service::config_scheme::{connection_config, data_segment_config};
-use crate::service::dynamic_config::publish_subscribe::{PublisherDetails, SubscriberDetails};
+use crate::service::dynamic_config::publish_subscribe::{
+    PublisherCounters, PublisherDetails, SubscriberDetails,
+};
 use crate::service::header::publish_subscribe::Header;
 use crate::service::naming_scheme::{
-    data_segment_name, extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    extract_publisher_id_from_connection, extract_subscriber_id_from_connection,
+    resized_data_segment_name,
+};
+use crate::service::port_factory::publisher::{
+    AllocationStrategy, LocalPublisherConfig, UnableToDeliverStrategy,
 };
-use crate::service::port_factory::publisher::{LocalPublisherConfig, UnableToDeliverStrategy};
 use crate::service::static_config::publish_subscribe::{self};
 use crate::service::{self, ServiceState};
 use crate::{config, sample_mut::SampleMut};
 use iceoryx2_bb_container::queue::Queue;
 use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{debug, error, fail, fatal_panic, warn};
+use iceoryx2_bb_posix::clock::{ClockType, Time};
 use iceoryx2_bb_system_types::file_name::FileName;
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
 use iceoryx2_cal::event::NamedConceptMgmt;
-use iceoryx2_cal::named_concept::{
-    NamedConceptBuilder, NamedConceptListError, NamedConceptRemoveError,
-};
-use iceoryx2_cal::shared_memory::{
-    SharedMemory, SharedMemoryBuilder, SharedMemoryCreateError, ShmPointer,
-};
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
-use iceoryx2_cal::shm_allocator::{self, PointerOffset, ShmAllocationError};
+use iceoryx2_cal::named_concept::{NamedConceptListError, NamedConceptRemoveError};
+use iceoryx2_cal::shared_memory::ShmPointer;
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
 use iceoryx2_cal::zero_copy_connection::{
-    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopySendError, ZeroCopySender,
+    ZeroCopyConnection, ZeroCopyCreationError, ZeroCopyPortDetails, ZeroCopySendError,
+    ZeroCopySender,
 };
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicUsize};
-use std::cell::UnsafeCell;
+use std::cell::{OnceCell, RefCell, UnsafeCell};
 use std::fmt::Debug;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -228,18 +235,51 @@ impl std::fmt::Display for PublisherSendError {
 
 impl std::error::Error for PublisherSendError {}
 
+/// Describes the outcome of a successful delivery with [`SampleMut::send()`] or
+/// [`Publisher::send_copy()`]. It contains only counters so that the delivery does not
+/// allocate, the [`Subscriber`](crate::port::subscriber::Subscriber)s that overflowed are
+/// reported by the [`OverflowCallback`](crate::port::OverflowCallback) and
+/// [`Publisher::overflow_counts()`].
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub struct DeliveryReport {
+    number_of_recipients: usize,
+    number_of_overflows: usize,
+}
+
+impl DeliveryReport {
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s that
+    /// received the sample.
+    pub fn number_of_recipients(&self) -> usize {
+        self.number_of_recipients
+    }
+
+    /// Returns the number of [`Subscriber`](crate::port::subscriber::Subscriber)s whose
+    /// oldest sample was overridden by the delivery since their buffer was full. It is zero
+    /// unless the service has enabled the safe overflow.
+    pub fn number_of_overflows(&self) -> usize {
+        self.number_of_overflows
+    }
+}
+
 #[derive(Debug, Eq, PartialEq, Clone, Copy)]
 pub(crate) enum RemovePubSubPortFromAllConnectionsError {
     InsufficientPermissions,
     InternalError,
 }
 
+#[derive(Debug)]
+struct Segments<Service: service::Service> {
+    memory: Vec<Option<MemorySegment<Service>>>,
+    current_segment_id: usize,
+    number_of_created_segments: usize,
+    max_slice_len: usize,
+}
+
 #[derive(Debug)]
 pub(crate) struct DataSegment<Service: service::Service> {
-    sample_reference_counter: Vec<IoxAtomicU64>,
-    memory: Service::SharedMemory,
-    payload_size: usize,
-    payload_type_layout: Layout,
+    segments: UnsafeCell<Segments<Service>>,
+    number_of_samples: usize,
+    max_number_of_segments: u8,
     port_id: UniquePublisherId,
     config: LocalPublisherConfig,
     service_state: Arc<ServiceState<Service>>,
@@ -249,47 +289,153 @@ pub(crate) struct DataSegment<Service: service::Service> {
     history: Option<UnsafeCell<Queue<usize>>>,
     static_config: crate::service::static_config::StaticConfig,
     loan_counter: IoxAtomicUsize,
+    sequence_number: IoxAtomicU64,
     is_active: IoxAtomicBool,
+    dynamic_publisher_handle: OnceCell<ContainerHandle>,
+    qos_monitor: QosMonitor<Service>,
+    sample_notifier: RefCell<Option<Notifier<Service>>>,
 }
 
 impl<Service: service::Service> DataSegment<Service> {
-    fn sample_index(&self, distance_to_chunk: usize) -> usize {
-        distance_to_chunk / self.payload_size
+    // only used internally as convinience function
+    #[allow(clippy::mut_from_ref)]
+    fn segments(&self) -> &mut Segments<Service> {
+        #[deny(clippy::mut_from_ref)]
+        unsafe {
+            &mut *self.segments.get()
+        }
+    }
+
+    fn segment(&self, offset: PointerOffset) -> &MemorySegment<Service> {
+        match &self.segments().memory[offset.segment_id() as usize] {
+            Some(segment) => segment,
+            None => {
+                fatal_panic!(from self,
+                    "This should never happen! The data segment {} of the sample {:?} does not exist.",
+                    offset.segment_id(), offset);
+            }
+        }
+    }
+
+    fn sample_granularity(&self, offset: PointerOffset) -> usize {
+        self.segment(offset).sample_granularity()
+    }
+
+    fn max_slice_len(&self) -> usize {
+        self.segments().max_slice_len
     }
 
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, ShmAllocationError> {
         self.retrieve_returned_samples();
 
-        let msg = "Unable to allocate Sample";
-        let ptr = self.memory.allocate(layout)?;
-        if self.sample_reference_counter[self.sample_index(ptr.offset.value())]
-            .fetch_add(1, Ordering::Relaxed)
-            != 0
-        {
-            fatal_panic!(from self,
-                "{} since the allocated sample is already in use! This should never happen!", msg);
-        }
+        let segment_id = self.segments().current_segment_id;
+        let ptr = self
+            .segment(PointerOffset::from_offset_and_segment_id(
+                0,
+                segment_id as u8,
+            ))
+            .allocate_with_layout(layout)?;
 
-        Ok(ptr)
+        Ok(ShmPointer {
+            offset: PointerOffset::from_offset_and_segment_id(ptr.offset.value(), segment_id as u8),
+            data_ptr: ptr.data_ptr,
+        })
     }
 
-    fn borrow_sample(&self, distance_to_chunk: usize) {
-        self.sample_reference_counter[self.sample_index(distance_to_chunk)]
-            .fetch_add(1, Ordering::Relaxed);
+    fn borrow_sample(&self, offset: PointerOffset) {
+        self.segment(offset).borrow_sample(offset);
     }
 
-    fn release_sample(&self, distance_to_chunk: PointerOffset) {
-        if self.sample_reference_counter[self.sample_index(distance_to_chunk.value())]
-            .fetch_sub(1, Ordering::Relaxed)
-            == 1
-        {
-            unsafe {
-                self.memory
-                    .deallocate(distance_to_chunk, self.payload_type_layout);
+    fn release_sample(&self, offset: PointerOffset) {
+        self.segment(offset).release_sample(offset);
+        self.release_segment_when_unused(offset.segment_id() as usize);
+    }
+
+    // Segments that were replaced by a larger one are kept until every sample that
+    // was allocated from them was returned. Afterwards the id is free for the next
+    // segment and the subscribers are informed that their mapping became outdated.
+    fn release_segment_when_unused(&self, segment_id: usize) {
+        let segments = self.segments();
+        if segment_id == segments.current_segment_id {
+            return;
+        }
+
+        if let Some(segment) = &segments.memory[segment_id] {
+            if segment.is_unused() {
+                segments.memory[segment_id] = None;
+                for i in 0..self.subscriber_connections.len() {
+                    if let Some(ref connection) = self.subscriber_connections.get(i) {
+                        connection.sender.retire_segment(segment_id as u8);
+                    }
+                }
             }
         }
     }
 
+    fn grow(&self, slice_len: usize) -> Result<(), PublisherLoanError> {
+        let msg = "Unable to grow the data segment";
+        let new_max_slice_len = match self.config.allocation_strategy {
+            AllocationStrategy::Static => {
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                    "{} to a slice length of {} since the allocation strategy is static.",
+                    msg, slice_len);
+            }
+            AllocationStrategy::BestFit => slice_len,
+            AllocationStrategy::PowerOfTwo => slice_len.next_power_of_two(),
+        };
+
+        // returned samples may release old segments whose ids can be reused
+        self.retrieve_returned_samples();
+        let segments = self.segments();
+        let segment_id = match segments.memory.iter().position(|s| s.is_none()) {
+            Some(segment_id) => segment_id,
+            None => {
+                fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                    "{} to a slice length of {} since all {} data segments are still in use.",
+                    msg, slice_len, self.max_number_of_segments);
+            }
+        };
+
+        // the segment is recorded before it is created so that the cleanup of a publisher
+        // that died during the creation also removes the partially created segment
+        let number_of_created_segments = segments.number_of_created_segments.max(segment_id + 1);
+        if let Some(handle) = self.dynamic_publisher_handle.get() {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .set_number_of_created_segments(*handle, number_of_created_segments as u8);
+        }
+        segments.number_of_created_segments = number_of_created_segments;
+
+        let sample_layout = self
+            .subscriber_connections
+            .static_config
+            .message_type_details
+            .sample_layout(new_max_slice_len);
+        let memory = match MemorySegment::create(
+            &resized_data_segment_name(&self.port_id, segment_id as u8),
+            self.service_state.shared_node.config(),
+            sample_layout,
+            self.number_of_samples,
+        ) {
+            Ok(memory) => memory,
+            Err(e) => {
+                fail!(from self, with PublisherLoanError::InternalFailure,
+                    "{} to a slice length of {} since the new data segment could not be created ({:?}).",
+                    msg, slice_len, e);
+            }
+        };
+
+        let previous_segment_id = segments.current_segment_id;
+        segments.memory[segment_id] = Some(memory);
+        segments.current_segment_id = segment_id;
+        segments.max_slice_len = new_max_slice_len;
+        self.release_segment_when_unused(previous_segment_id);
+
+        Ok(())
+    }
+
     fn retrieve_returned_samples(&self) {
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
@@ -327,36 +473,35 @@ impl<Service: service::Service> DataSegment<Service> {
         self.loan_counter.fetch_sub(1, Ordering::Relaxed);
     }
 
-    fn add_sample_to_history(&self, address_to_chunk: usize) {
+    fn add_sample_to_history(&self, offset: PointerOffset) {
         match &self.history {
             None => (),
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
-                self.borrow_sample(address_to_chunk);
-                match history.push_with_overflow(address_to_chunk) {
+                self.borrow_sample(offset);
+                match history.push_with_overflow(offset.as_value()) {
                     None => (),
-                    Some(old) => self.release_sample(PointerOffset::new(old)),
+                    Some(old) => self.release_sample(PointerOffset::from_value(old)),
                 }
             }
         }
     }
 
-    fn deliver_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
+    fn deliver_sample(&self, offset: PointerOffset) -> Result<DeliveryReport, PublisherSendError> {
         self.retrieve_returned_samples();
 
-        let deliver_call = match self.config.unable_to_deliver_strategy {
-            UnableToDeliverStrategy::Block => {
-                <Service::Connection as ZeroCopyConnection>::Sender::blocking_send
-            }
-            UnableToDeliverStrategy::DiscardSample => {
-                <Service::Connection as ZeroCopyConnection>::Sender::try_send
-            }
-        };
-
-        let mut number_of_recipients = 0;
+        let sample_granularity = self.sample_granularity(offset);
+        let mut report = DeliveryReport::default();
+        let mut notify_subscribers = false;
         for i in 0..self.subscriber_connections.len() {
             if let Some(ref connection) = self.subscriber_connections.get(i) {
-                match deliver_call(&connection.sender, PointerOffset::new(address_to_chunk)) {
+                if !self.is_accepted_by(connection, offset) {
+                    connection.skip_sample();
+                    continue;
+                }
+
+                connection.prepare_delivery();
+                match self.deliver_to_connection(&connection.sender, offset, sample_granularity) {
                     Err(ZeroCopySendError::ReceiveBufferFull)
                     | Err(ZeroCopySendError::UsedChunkListFull) => {
                         /* causes no problem
@@ -375,33 +520,148 @@ impl<Service: service::Service> DataSegment<Service> {
                                 DegrationAction::Warn => {
                                     error!(from self,
                                         "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
+                                        offset, connection.subscriber_id);
                                 }
                                 DegrationAction::Fail => {
                                     fail!(from self, with PublisherSendError::ConnectionCorrupted,
                                         "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                        address_to_chunk, connection.subscriber_id);
+                                        offset, connection.subscriber_id);
                                 }
                             },
                             None => {
                                 error!(from self,
                                     "While delivering the sample: {:?} a corrupted connection was detected with subscriber {:?}.",
-                                    address_to_chunk, connection.subscriber_id);
+                                    offset, connection.subscriber_id);
                             }
                         }
                     }
                     Ok(overflow) => {
-                        self.borrow_sample(address_to_chunk);
-                        number_of_recipients += 1;
+                        self.borrow_sample(offset);
+                        report.number_of_recipients += 1;
+                        let dynamic_config =
+                            self.service_state.dynamic_storage.get().publish_subscribe();
+                        notify_subscribers |= dynamic_config.wants_sample_notifications(i);
+                        let subscriber_counters = dynamic_config.subscriber_counters(i);
+                        subscriber_counters.record_delivered_sample();
 
                         if let Some(old) = overflow {
-                            self.release_sample(old)
+                            self.release_sample(old);
+                            subscriber_counters.record_overflowed_sample();
+                            if let Some(counters) = self.counters() {
+                                counters.record_overflowed_sample();
+                            }
+
+                            report.number_of_overflows += 1;
+
+                            if let Some(callback) = &self.config.overflow_callback {
+                                callback.call(
+                                    self.static_config.clone(),
+                                    self.port_id,
+                                    connection.subscriber_id,
+                                    connection.sender.missed_samples(),
+                                );
+                            }
                         }
                     }
                 }
             }
         }
-        Ok(number_of_recipients)
+
+        if notify_subscribers {
+            self.notify_subscribers();
+        }
+        Ok(report)
+    }
+
+    fn wants_sample_notifications(&self, index: usize) -> bool {
+        self.service_state
+            .dynamic_storage
+            .get()
+            .publish_subscribe()
+            .wants_sample_notifications(index)
+    }
+
+    // Subscribers that wait asynchronously for samples are woken up with an event. The
+    // notifier is created when the first subscriber requests it.
+    fn notify_subscribers(&self) {
+        let msg = "Unable to notify the subscribers about the delivered sample";
+        let mut sample_notifier = self.sample_notifier.borrow_mut();
+        if sample_notifier.is_none() {
+            let event = match open_or_create_sample_notification_service(&self.service_state) {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!(from self, "{} since the event service could not be opened ({:?}).",
+                        msg, e);
+                    return;
+                }
+            };
+
+            match event.notifier_builder().create() {
+                Ok(notifier) => *sample_notifier = Some(notifier),
+                Err(e) => {
+                    warn!(from self, "{} since the notifier could not be created ({:?}).",
+                        msg, e);
+                    return;
+                }
+            }
+        }
+
+        if let Some(notifier) = sample_notifier.as_ref() {
+            if let Err(e) = notifier.notify() {
+                warn!(from self, "{} ({:?}).", msg, e);
+            }
+        }
+    }
+
+    fn is_accepted_by(&self, connection: &Connection<Service>, offset: PointerOffset) -> bool {
+        match &connection.filter {
+            None => true,
+            Some(filter) => {
+                let header = self.segment(offset).sample_address(offset) as *const u8;
+                let message_type_details = &self
+                    .subscriber_connections
+                    .static_config
+                    .message_type_details;
+                let user_header = message_type_details.user_header_ptr_from_header(header);
+                unsafe { filter.accepts(user_header, message_type_details.user_header.size) }
+            }
+        }
+    }
+
+    fn deliver_to_connection(
+        &self,
+        sender: &<Service::Connection as ZeroCopyConnection>::Sender,
+        offset: PointerOffset,
+        sample_size: usize,
+    ) -> Result<Option<PointerOffset>, ZeroCopySendError> {
+        match self.config.unable_to_deliver_strategy {
+            UnableToDeliverStrategy::DiscardSample => sender.try_send(offset, sample_size),
+            // the send call is only timed when it actually has to block, so that the common
+            // case does not have to acquire the current time
+            UnableToDeliverStrategy::Block => match sender.try_send(offset, sample_size) {
+                Err(ZeroCopySendError::ReceiveBufferFull) => {
+                    let start = Time::now_with_clock(ClockType::Monotonic);
+                    let result = sender.blocking_send(offset, sample_size);
+                    if let (Some(counters), Ok(start)) = (self.counters(), start) {
+                        if let Ok(blocked_time) = start.elapsed() {
+                            counters.record_blocked_send_time(blocked_time);
+                        }
+                    }
+                    result
+                }
+                result => result,
+            },
+        }
+    }
+
+    fn counters(&self) -> Option<&PublisherCounters> {
+        self.dynamic_publisher_handle.get().map(|handle| {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .publisher_counters(*handle)
+        })
     }
 
     fn populate_subscriber_channels(&self) -> Result<(), ZeroCopyCreationError> {
@@ -437,7 +697,12 @@ impl<Service: service::Service> DataSegment<Service> {
                             self.config.max_slice_len,
                         ) {
                             Ok(()) => match &self.subscriber_connections.get(i) {
-                                Some(connection) => self.deliver_sample_history(connection),
+                                Some(connection) => {
+                                    self.deliver_sample_history(connection);
+                                    if self.wants_sample_notifications(i) {
+                                        self.notify_subscribers();
+                                    }
+                                }
                                 None => {
                                     fatal_panic!(from self, "This should never happen! Unable to acquire previously created subscriber connection.")
                                 }
@@ -498,10 +763,18 @@ impl<Service: service::Service> DataSegment<Service> {
             Some(history) => {
                 let history = unsafe { &mut *history.get() };
                 for i in 0..history.len() {
-                    let ptr_distance = unsafe { history.get_unchecked(i) };
+                    let offset = PointerOffset::from_value(unsafe { history.get_unchecked(i) });
+                    if !self.is_accepted_by(connection, offset) {
+                        connection.skip_sample();
+                        continue;
+                    }
 
-                    match connection.sender.try_send(PointerOffset::new(ptr_distance)) {
-                        Ok(_) => self.borrow_sample(ptr_distance),
+                    connection.prepare_delivery();
+                    match connection
+                        .sender
+                        .try_send(offset, self.sample_granularity(offset))
+                    {
+                        Ok(_) => self.borrow_sample(offset),
                         Err(e) => {
                             warn!(from self, "Failed to deliver history to new subscriber via {:?} due to {:?}", connection, e);
                         }
@@ -511,7 +784,11 @@ impl<Service: service::Service> DataSegment<Service> {
         }
     }
 
-    pub(crate) fn send_sample(&self, address_to_chunk: usize) -> Result<usize, PublisherSendError> {
+    pub(crate) fn send_sample(
+        &self,
+        offset: PointerOffset,
+        header: &mut Header,
+    ) -> Result<DeliveryReport, PublisherSendError> {
         let msg = "Unable to send sample";
         if !self.is_active.load(Ordering::Relaxed) {
             fail!(from self, with PublisherSendError::ConnectionBrokenSincePublisherNoLongerExists,
@@ -521,8 +798,60 @@ impl<Service: service::Service> DataSegment<Service> {
         fail!(from self, when self.update_connections(),
             "{} since the connections could not be updated.", msg);
 
-        self.add_sample_to_history(address_to_chunk);
-        self.deliver_sample(address_to_chunk)
+        // the sequence number is only consumed by samples that are actually delivered, so that
+        // a gap on the subscriber side always corresponds to a lost sample
+        let timestamp = fatal_panic!(from self,
+            when Time::now_with_clock(ClockType::Monotonic),
+            "This should never happen! Unable to acquire the current time of the monotonic clock.");
+        header.set_send_details(
+            self.sequence_number.fetch_add(1, Ordering::Relaxed),
+            timestamp,
+        );
+
+        self.add_sample_to_history(offset);
+        let report = self.deliver_sample(offset)?;
+        self.record_delivery();
+        // the violations of the other publishers are emitted even when no subscriber checks
+        // for them
+        self.qos_monitor.emit_violations();
+        if let Some(counters) = self.counters() {
+            counters.record_sent_sample();
+        }
+        Ok(report)
+    }
+
+    // the activity is only tracked when the service requires it, so that a publisher of a
+    // service without deadline or liveliness lease does not have to acquire the current time
+    fn has_tracked_activity(&self) -> bool {
+        let static_config = self.static_config.publish_subscribe();
+        static_config.publish_deadline.is_some() || static_config.liveliness_lease.is_some()
+    }
+
+    fn record_delivery(&self) {
+        if let Some(handle) = self.dynamic_publisher_handle.get() {
+            if self.has_tracked_activity() {
+                self.service_state
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .publisher_activity(*handle)
+                    .record_delivery();
+            }
+        }
+    }
+
+    fn record_liveliness_assertion(&self) {
+        if let Some(handle) = self.dynamic_publisher_handle.get() {
+            if self.has_tracked_activity() {
+                self.service_state
+                    .dynamic_storage
+                    .get()
+                    .publish_subscribe()
+                    .publisher_activity(*handle)
+                    .record_liveliness_assertion();
+            }
+        }
+        self.qos_monitor.emit_violations();
     }
 }
 
@@ -576,28 +905,31 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             .required_amount_of_samples_per_data_segment(config.max_loaned_samples);
 
         let data_segment = fail!(from origin,
-                when Self::create_data_segment(&port_id, service.__internal_state().shared_node.config(), number_of_samples, static_config, &config),
+                when MemorySegment::create(
+                    &resized_data_segment_name(&port_id, 0),
+                    service.__internal_state().shared_node.config(),
+                    static_config.message_type_details().sample_layout(config.max_slice_len),
+                    number_of_samples),
                 with PublisherCreateError::UnableToCreateDataSegment,
                 "{} since the data segment could not be acquired.", msg);
 
         let max_slice_len = config.max_slice_len;
+        let max_number_of_segments = match config.allocation_strategy {
+            AllocationStrategy::Static => 1,
+            _ => config.max_number_of_segments.max(1),
+        };
         let data_segment = Arc::new(DataSegment {
             is_active: IoxAtomicBool::new(true),
-            memory: data_segment,
-            payload_size: static_config
-                .message_type_details()
-                .sample_layout(config.max_slice_len)
-                .size(),
-            payload_type_layout: static_config
-                .message_type_details()
-                .payload_layout(config.max_slice_len),
-            sample_reference_counter: {
-                let mut v = Vec::with_capacity(number_of_samples);
-                for _ in 0..number_of_samples {
-                    v.push(IoxAtomicU64::new(0));
-                }
-                v
-            },
+            segments: UnsafeCell::new(Segments {
+                memory: core::iter::once(Some(data_segment))
+                    .chain((1..max_number_of_segments).map(|_| None))
+                    .collect(),
+                current_segment_id: 0,
+                number_of_created_segments: 1,
+                max_slice_len,
+            }),
+            number_of_samples,
+            max_number_of_segments,
             service_state: service.__internal_state().clone(),
             port_id,
             subscriber_connections: SubscriberConnections::new(
@@ -606,6 +938,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 port_id,
                 static_config,
                 number_of_samples,
+                max_number_of_segments,
             ),
             config,
             subscriber_list_state: unsafe { UnsafeCell::new(subscriber_list.get_state()) },
@@ -615,6 +948,10 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             },
             static_config: service.__internal_state().static_config.clone(),
             loan_counter: IoxAtomicUsize::new(0),
+            sequence_number: IoxAtomicU64::new(0),
+            dynamic_publisher_handle: OnceCell::new(),
+            qos_monitor: QosMonitor::new(service.__internal_state().clone()),
+            sample_notifier: RefCell::new(None),
         });
 
         let payload_size = data_segment
@@ -649,6 +986,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 publisher_id: port_id,
                 number_of_samples,
                 max_slice_len,
+                max_number_of_segments,
                 node_id: *service.__internal_state().shared_node.id(),
             }) {
             Some(unique_index) => unique_index,
@@ -660,44 +998,85 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         };
 
         new_self.dynamic_publisher_handle = Some(dynamic_publisher_handle);
+        let _ = new_self
+            .data_segment
+            .dynamic_publisher_handle
+            .set(dynamic_publisher_handle);
 
         Ok(new_self)
     }
 
-    fn create_data_segment(
-        port_id: &UniquePublisherId,
-        global_config: &config::Config,
-        number_of_samples: usize,
-        static_config: &publish_subscribe::StaticConfig,
-        config: &LocalPublisherConfig,
-    ) -> Result<Service::SharedMemory, SharedMemoryCreateError> {
-        let l = static_config
-            .message_type_details
-            .sample_layout(config.max_slice_len);
-        let allocator_config = shm_allocator::pool_allocator::Config { bucket_layout: l };
-
-        Ok(fail!(from "Publisher::create_data_segment()",
-            when <<Service::SharedMemory as SharedMemory<PoolAllocator>>::Builder as NamedConceptBuilder<
-            Service::SharedMemory,
-                >>::new(&data_segment_name(port_id))
-                .config(&data_segment_config::<Service>(global_config))
-                .size(l.size() * number_of_samples + l.align() - 1)
-                .create(&allocator_config),
-            "Unable to create the data segment."))
-    }
-
     /// Returns the [`UniquePublisherId`] of the [`Publisher`]
     pub fn id(&self) -> UniquePublisherId {
         self.data_segment.port_id
     }
 
+    /// Signals the [`Subscriber`](crate::port::subscriber::Subscriber)s that the [`Publisher`]
+    /// is still alive without sending a [`SampleMut`]. Must be called at least once within the
+    /// [`publish_subscribe::StaticConfig::liveliness_lease()`] when no [`SampleMut`] is sent,
+    /// otherwise the [`Publisher`] is reported in
+    /// [`Subscriber::expired_liveliness_leases()`](crate::port::subscriber::Subscriber::expired_liveliness_leases()).
+    pub fn assert_liveliness(&self) {
+        self.data_segment.record_liveliness_assertion();
+    }
+
     /// Returns the strategy the [`Publisher`] follows when a [`SampleMut`] cannot be delivered
     /// since the [`Subscriber`](crate::port::subscriber::Subscriber)s buffer is full.
     pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
         self.data_segment.config.unable_to_deliver_strategy
     }
 
+    /// Returns for every connected [`Subscriber`](crate::port::subscriber::Subscriber) the
+    /// number of samples that were overridden in its full buffer by a send call of the
+    /// [`Publisher`] since the service has enabled the safe overflow. The number of overflows of
+    /// a single delivery is reported by the [`DeliveryReport`] of [`SampleMut::send()`]. When
+    /// an [`OverflowCallback`](crate::port::OverflowCallback) is set, it is called on every
+    /// overflow additionally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .publish_subscribe::<u64>()
+    /// #     .open_or_create()?;
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// publisher.send_copy(1234)?;
+    ///
+    /// for (subscriber_id, missed_samples) in publisher.overflow_counts() {
+    ///     println!("subscriber {:?} missed {} samples", subscriber_id, missed_samples);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn overflow_counts(&self) -> Vec<(UniqueSubscriberId, u64)> {
+        let connections = &self.data_segment.subscriber_connections;
+        (0..connections.len())
+            .filter_map(|i| {
+                connections
+                    .get(i)
+                    .as_ref()
+                    .map(|c| (c.subscriber_id, c.sender.missed_samples()))
+            })
+            .collect()
+    }
+
     fn allocate(&self, layout: Layout) -> Result<ShmPointer, PublisherLoanError> {
+        let chunk = self.allocate_chunk(layout);
+        if chunk.is_err() {
+            if let Some(counters) = self.data_segment.counters() {
+                counters.record_loan_failure();
+            }
+        }
+        chunk
+    }
+
+    fn allocate_chunk(&self, layout: Layout) -> Result<ShmPointer, PublisherLoanError> {
         let msg = "Unable to allocate Sample with";
 
         if self.data_segment.loan_counter.load(Ordering::Relaxed)
@@ -768,12 +1147,16 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 ////////////////////////
 // BEGIN: typed API
 ////////////////////////
-impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
-    Publisher<Service, Payload, UserHeader>
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend + Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Publisher<Service, Payload, UserHeader>
 {
     /// Copies the input `value` into a [`crate::sample_mut::SampleMut`] and delivers it.
-    /// On success it returns the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data, otherwise a [`PublisherSendError`] describing the failure.
+    /// On success it returns a [`DeliveryReport`] with the number of
+    /// [`crate::port::subscriber::Subscriber`]s that received the data and the number of
+    /// overflows the delivery caused, otherwise a [`PublisherSendError`] describing the failure.
     ///
     /// # Example
     ///
@@ -793,7 +1176,7 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send_copy(&self, value: Payload) -> Result<usize, PublisherSendError> {
+    pub fn send_copy(&self, value: Payload) -> Result<DeliveryReport, PublisherSendError> {
         let msg = "Unable to send copy of payload";
         let sample = fail!(from self, when self.loan_uninit(),
                                     "{} since the loan of a sample failed.", msg);
@@ -856,8 +1239,11 @@ impl<Service: service::Service, Payload: Debug + Sized, UserHeader: Debug>
     }
 }
 
-impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: Debug>
-    Publisher<Service, Payload, UserHeader>
+impl<
+        Service: service::Service,
+        Payload: Default + Debug + ZeroCopySend + Sized,
+        UserHeader: Debug + ZeroCopySend,
+    > Publisher<Service, Payload, UserHeader>
 {
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`]
     /// and initialize it with the default value. This can be a performance hit and [`Publisher::loan_uninit`]
@@ -897,8 +1283,11 @@ impl<Service: service::Service, Payload: Default + Debug + Sized, UserHeader: De
 ////////////////////////
 // BEGIN: sliced API
 ////////////////////////
-impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
-    Publisher<Service, [Payload], UserHeader>
+impl<
+        Service: service::Service,
+        Payload: Default + Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > Publisher<Service, [Payload], UserHeader>
 {
     /// Loans/allocates a [`crate::sample_mut::SampleMut`] from the underlying data segment of the [`Publisher`]
     /// and initializes all slice elements with the default value. This can be a performance hit
@@ -940,8 +1329,11 @@ impl<Service: service::Service, Payload: Default + Debug, UserHeader: Debug>
     }
 }
 
-impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
-    Publisher<Service, [Payload], UserHeader>
+impl<
+        Service: service::Service,
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+    > Publisher<Service, [Payload], UserHeader>
 {
     /// Loans/allocates a [`SampleMutUninit`] from the underlying data segment of the [`Publisher`].
     /// The user has to initialize the payload before it can be sent.
@@ -975,11 +1367,20 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         slice_len: usize,
     ) -> Result<SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>, PublisherLoanError>
     {
-        let max_slice_len = self.data_segment.config.max_slice_len;
+        let max_slice_len = self.data_segment.max_slice_len();
         if max_slice_len < slice_len {
-            fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
-                "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
-                slice_len, max_slice_len);
+            match self.data_segment.config.allocation_strategy {
+                AllocationStrategy::Static => {
+                    fail!(from self, with PublisherLoanError::ExceedsMaxLoanSize,
+                        "Unable to loan slice with {} elements since it would exceed the max supported slice length of {}.",
+                        slice_len, max_slice_len);
+                }
+                _ => {
+                    fail!(from self, when self.data_segment.grow(slice_len),
+                        "Unable to loan slice with {} elements since the data segment could not be resized.",
+                        slice_len);
+                }
+            }
         }
 
         let sample_layout = self.sample_layout(slice_len);
@@ -1032,6 +1433,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> Upda
 
 pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>(
     port_id: &UniquePublisherId,
+    number_of_segments: u8,
     config: &config::Config,
 ) -> Result<(), NamedConceptRemoveError> {
     let origin = format!(
@@ -1040,11 +1442,15 @@ pub(crate) unsafe fn remove_data_segment_of_publisher<Service: service::Service>
         port_id
     );
 
-    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
-            &data_segment_name(port_id),
-            &data_segment_config::<Service>(config),
-        ), "Unable to remove the publishers data segment."
-    );
+    // a growing publisher may own multiple data segments, old segments can already be
+    // released therefore every segment id that was handed out is checked
+    for segment_id in 0..number_of_segments.max(1) {
+        fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+                &resized_data_segment_name(port_id, segment_id),
+                &data_segment_config::<Service>(config),
+            ), "Unable to remove the publishers data segment {}.", segment_id
+        );
+    }
 
     Ok(())
 }
@@ -1123,6 +1529,17 @@ pub(crate) unsafe fn remove_subscriber_from_all_connections<Service: service::Se
     for connection in connection_list {
         let subscriber_id = extract_subscriber_id_from_connection(&connection);
         if subscriber_id == *port_id {
+            // the publisher reclaims all samples the subscriber still held with its next
+            // allocation, even though the connection itself is already removed
+            if let Err(e) = <Service::Connection as ZeroCopyConnection>::remove_dead_receiver_cfg(
+                &connection,
+                &connection_config,
+            ) {
+                debug!(from origin, "{} since the subscriber could not be removed from the connection ({:?}) due to {:?}.", msg, connection, e);
+                ret_val = Err(RemovePubSubPortFromAllConnectionsError::InternalError);
+                continue;
+            }
+
             match <Service::Connection as NamedConceptMgmt>::remove_cfg(
                 &connection,
                 &connection_config,
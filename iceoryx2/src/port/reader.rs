@@ -0,0 +1,210 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 123)
+//!     .create()?;
+//!
+//! let reader = service.reader_builder().create()?;
+//!
+//! let entry = reader.entry::<i32>(&0)?;
+//! println!("value: {}", entry.get());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use iceoryx2_bb_lock_free::{mpmc::container::ContainerHandle, spmc::unrestricted_atomic::*};
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::{self, dynamic_config::blackboard::ReaderDetails, ServiceState};
+
+use super::port_identifiers::UniqueReaderId;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+/// Failures that can occur when a new [`Reader`] is created with the
+/// [`crate::service::port_factory::reader::PortFactoryReader`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ReaderCreateError {
+    /// The maximum amount of [`Reader`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Reader`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedReaders,
+}
+
+impl std::fmt::Display for ReaderCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ReaderCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ReaderCreateError {}
+
+/// Failures that can occur when an [`EntryHandle`] is acquired with [`Reader::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleError {
+    /// The blackboard does not contain an entry with the provided key and value type.
+    EntryDoesNotExist,
+}
+
+impl std::fmt::Display for EntryHandleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleError {}
+
+/// Reading endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Reader<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+> {
+    service_state: Arc<ServiceState<Service>>,
+    mgmt: Arc<Service::BlackboardMgmt>,
+    dynamic_reader_handle: Option<ContainerHandle>,
+    reader_id: UniqueReaderId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+    > Drop for Reader<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_reader_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .release_reader_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+    > Reader<Service, KeyType>
+{
+    pub(crate) fn new(
+        service: &Service,
+        mgmt: &Arc<Service::BlackboardMgmt>,
+    ) -> Result<Self, ReaderCreateError> {
+        let msg = "Unable to create Reader port";
+        let origin = "Reader::new()";
+        let reader_id = UniqueReaderId::new();
+
+        let mut new_self = Self {
+            service_state: service.__internal_state().clone(),
+            mgmt: mgmt.clone(),
+            dynamic_reader_handle: None,
+            reader_id,
+            _key: PhantomData,
+        };
+
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a reader is added to the dynamic config without
+        // being fully initialized
+        let dynamic_reader_handle = match new_self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_reader_id(ReaderDetails {
+                reader_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ReaderCreateError::ExceedsMaxSupportedReaders,
+                    "{} since it would exceed the maximum supported amount of readers of {}.",
+                    msg, service.__internal_state().static_config.blackboard().max_readers);
+            }
+        };
+        new_self.dynamic_reader_handle = Some(dynamic_reader_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueReaderId`] of the [`Reader`]
+    pub fn id(&self) -> UniqueReaderId {
+        self.reader_id
+    }
+
+    /// Acquires an [`EntryHandle`] to the entry with the provided key. If the blackboard does
+    /// not contain an entry with the key or the value type of the entry differs from
+    /// `ValueType` it returns [`EntryHandleError::EntryDoesNotExist`].
+    pub fn entry<ValueType: Copy + ZeroCopySend>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandle<'_, ValueType>, EntryHandleError> {
+        let mgmt = self.mgmt.get();
+
+        // SAFETY: the service was opened with the same key type, verified in the service
+        //         builder
+        match unsafe { mgmt.find_entry(key) } {
+            Some(entry) if entry.has_value_type::<ValueType>() => Ok(EntryHandle {
+                // SAFETY: the value type was verified and the atomic was constructed in the
+                //         blackboard management segment when the service was created
+                atomic: unsafe {
+                    &*(mgmt.ptr_from_offset(entry.value_offset)
+                        as *const UnrestrictedAtomic<ValueType>)
+                },
+            }),
+            _ => {
+                fail!(from self, with EntryHandleError::EntryDoesNotExist,
+                    "Unable to acquire entry handle since no entry with the key {:?} and the value type {} exists.",
+                    key, core::any::type_name::<ValueType>());
+            }
+        }
+    }
+}
+
+/// A read handle to a single entry of the blackboard. Acquired with [`Reader::entry()`].
+pub struct EntryHandle<'reader, ValueType: Copy + ZeroCopySend> {
+    atomic: &'reader UnrestrictedAtomic<ValueType>,
+}
+
+impl<ValueType: Copy + ZeroCopySend> Debug for EntryHandle<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandle<{}> {{ atomic: {:?} }}",
+            core::any::type_name::<ValueType>(),
+            self.atomic as *const UnrestrictedAtomic<ValueType>
+        )
+    }
+}
+
+impl<ValueType: Copy + ZeroCopySend> EntryHandle<'_, ValueType> {
+    /// Returns a copy of the latest value of the entry. The read is lock-free and always
+    /// returns a consistent value even when the [`crate::port::writer::Writer`] updates
+    /// the entry concurrently.
+    pub fn get(&self) -> ValueType {
+        self.atomic.load()
+    }
+}
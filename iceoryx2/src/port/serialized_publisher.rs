@@ -0,0 +1,185 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Vec<String>>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//!
+//! publisher.send(&vec!["hello".to_string(), "world".to_string()])?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use super::publisher::{DeliveryReport, Publisher, PublisherLoanError, PublisherSendError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+use crate::service::port_factory::publisher::UnableToDeliverStrategy;
+
+/// Failure that can be emitted when a payload is sent with [`SerializedPublisher::send()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SerializedPublisherSendError {
+    /// The payload could not be serialized.
+    SerializationFailed,
+    /// The serialized payload could not be delivered.
+    SendError(PublisherSendError),
+}
+
+impl From<PublisherSendError> for SerializedPublisherSendError {
+    fn from(value: PublisherSendError) -> Self {
+        SerializedPublisherSendError::SendError(value)
+    }
+}
+
+impl From<PublisherLoanError> for SerializedPublisherSendError {
+    fn from(value: PublisherLoanError) -> Self {
+        SerializedPublisherSendError::SendError(PublisherSendError::LoanError(value))
+    }
+}
+
+impl std::fmt::Display for SerializedPublisherSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SerializedPublisherSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SerializedPublisherSendError {}
+
+/// Sending endpoint of a publish-subscribe based communication with serialized payloads. The
+/// payload is serialized with the `Serializer` into a temporary buffer whose content is then
+/// copied into a loaned byte slice of the underlying [`Publisher`].
+pub struct SerializedPublisher<
+    Service: service::Service,
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+> {
+    publisher: Publisher<Service, [u8], ()>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for SerializedPublisher<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SerializedPublisher<{}, {}, {}> {{ publisher: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            Serializer::NAME,
+            self.publisher
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > SerializedPublisher<Service, Payload, Serializer>
+{
+    pub(crate) fn new(publisher: Publisher<Service, [u8], ()>) -> Self {
+        Self {
+            publisher,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniquePublisherId`] of the [`SerializedPublisher`]
+    pub fn id(&self) -> UniquePublisherId {
+        self.publisher.id()
+    }
+
+    /// See [`Publisher::assert_liveliness()`].
+    pub fn assert_liveliness(&self) {
+        self.publisher.assert_liveliness()
+    }
+
+    /// Returns the strategy the [`SerializedPublisher`] follows when a payload cannot be
+    /// delivered since the subscribers buffer is full.
+    pub fn unable_to_deliver_strategy(&self) -> UnableToDeliverStrategy {
+        self.publisher.unable_to_deliver_strategy()
+    }
+
+    /// See [`Publisher::overflow_counts()`].
+    pub fn overflow_counts(&self) -> Vec<(UniqueSubscriberId, u64)> {
+        self.publisher.overflow_counts()
+    }
+
+    /// Serializes the provided value, copies the serialized bytes into a loaned sample and
+    /// delivers it to all connected subscribers. On success the [`DeliveryReport`] is
+    /// returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe_serialized::<String>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = service.publisher_builder().create()?;
+    /// publisher.send(&"some text that does not fit into shared memory".to_string())?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(&self, value: &Payload) -> Result<DeliveryReport, SerializedPublisherSendError> {
+        let msg = "Unable to send serialized payload";
+        let bytes = fail!(from self, when Serializer::serialize(value),
+                with SerializedPublisherSendError::SerializationFailed,
+                "{} since the payload could not be serialized.", msg);
+
+        let sample = fail!(from self, when self.publisher.loan_slice_uninit(bytes.len()),
+                "{} since the sample for {} bytes could not be loaned.", msg, bytes.len());
+        let sample = sample.write_from_slice(&bytes);
+
+        Ok(fail!(from self, when sample.send(),
+                "{} since the sample could not be delivered.", msg))
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > UpdateConnections for SerializedPublisher<Service, Payload, Serializer>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.publisher.update_connections()
+    }
+}
@@ -0,0 +1,171 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Vec<String>>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! while let Some(value) = subscriber.receive()? {
+//!     println!("received: {:?}", value);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+use serde::de::DeserializeOwned;
+
+use super::port_identifiers::UniqueSubscriberId;
+use super::subscriber::{Subscriber, SubscriberReceiveError};
+use super::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service;
+
+/// Failure that can be emitted when a payload is received with
+/// [`SerializedSubscriber::receive()`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum SerializedSubscriberReceiveError {
+    /// The received payload could not be deserialized.
+    DeserializationFailed,
+    /// The serialized payload could not be received.
+    ReceiveError(SubscriberReceiveError),
+}
+
+impl From<SubscriberReceiveError> for SerializedSubscriberReceiveError {
+    fn from(value: SubscriberReceiveError) -> Self {
+        SerializedSubscriberReceiveError::ReceiveError(value)
+    }
+}
+
+impl std::fmt::Display for SerializedSubscriberReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "SerializedSubscriberReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for SerializedSubscriberReceiveError {}
+
+/// Receiving endpoint of a publish-subscribe based communication with serialized payloads. The
+/// payload is deserialized with the `Serializer` from the received byte slice of the
+/// underlying [`Subscriber`] and the sample is released right away.
+pub struct SerializedSubscriber<
+    Service: service::Service,
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+> {
+    subscriber: Subscriber<Service, [u8], ()>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for SerializedSubscriber<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "SerializedSubscriber<{}, {}, {}> {{ subscriber: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            Serializer::NAME,
+            self.subscriber
+        )
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > SerializedSubscriber<Service, Payload, Serializer>
+{
+    pub(crate) fn new(subscriber: Subscriber<Service, [u8], ()>) -> Self {
+        Self {
+            subscriber,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// Returns the [`UniqueSubscriberId`] of the [`SerializedSubscriber`]
+    pub fn id(&self) -> UniqueSubscriberId {
+        self.subscriber.id()
+    }
+
+    /// Returns the internal buffer size of the [`SerializedSubscriber`].
+    pub fn buffer_size(&self) -> usize {
+        self.subscriber.buffer_size()
+    }
+
+    /// See [`Subscriber::number_of_lost_samples()`].
+    pub fn number_of_lost_samples(&self) -> u64 {
+        self.subscriber.number_of_lost_samples()
+    }
+
+    /// See [`Subscriber::missed_samples()`].
+    pub fn missed_samples(&self) -> u64 {
+        self.subscriber.missed_samples()
+    }
+
+    /// Returns true if the [`SerializedSubscriber`] has samples in the buffer that can be
+    /// received with [`SerializedSubscriber::receive`].
+    pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
+        self.subscriber.has_samples()
+    }
+
+    /// Receives and deserializes a payload from a
+    /// [`SerializedPublisher`](crate::port::serialized_publisher::SerializedPublisher). If no
+    /// payload could be received [`None`] is returned. If a failure occurs
+    /// [`SerializedSubscriberReceiveError`] is returned.
+    pub fn receive(&self) -> Result<Option<Payload>, SerializedSubscriberReceiveError> {
+        let msg = "Unable to receive serialized payload";
+        let sample = match fail!(from self, when self.subscriber.receive(),
+                "{} since the sample could not be received.", msg)
+        {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        Ok(Some(
+            fail!(from self, when Serializer::deserialize::<Payload>(sample.payload()),
+                with SerializedSubscriberReceiveError::DeserializationFailed,
+                "{} since the payload could not be deserialized.", msg),
+        ))
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > UpdateConnections for SerializedSubscriber<Service, Payload, Serializer>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.subscriber.update_connections()
+    }
+}
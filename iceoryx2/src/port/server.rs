@@ -0,0 +1,595 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = service
+//!     .server_builder()
+//!     // defines how many responses can be loaned in parallel
+//!     .max_loaned_responses(2)
+//!     .create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     println!("received request: {:?}", *active_request);
+//!
+//!     // loan some uninitialized memory and send it
+//!     let response = active_request.loan_uninit()?;
+//!     let response = response.write_payload(1234);
+//!     response.send()?;
+//!
+//!     // send a copy of the value
+//!     active_request.send_copy(5678)?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::port_identifiers::UniqueServerId;
+use crate::active_request::ActiveRequest;
+use crate::port::details::client_connections::*;
+use crate::port::details::data_segment::DataSegment;
+use crate::port::update_connections::{ConnectionFailure, UpdateConnections};
+use crate::service::config_scheme::data_segment_config;
+use crate::service::dynamic_config::request_response::{ClientDetails, ServerDetails};
+use crate::service::header::request_response::RequestHeader;
+use crate::service::naming_scheme::server_data_segment_name;
+use crate::service::port_factory::server::LocalServerConfig;
+use crate::service::static_config::request_response::StaticConfig;
+use crate::{config, service};
+use iceoryx2_bb_elementary::allocator::AllocationError;
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
+use iceoryx2_bb_log::{fail, fatal_panic, warn};
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+use iceoryx2_cal::event::NamedConceptMgmt;
+use iceoryx2_cal::named_concept::NamedConceptRemoveError;
+use iceoryx2_cal::shared_memory::{SharedMemory, ShmPointer};
+use iceoryx2_cal::shm_allocator::{PointerOffset, ShmAllocationError};
+use iceoryx2_cal::zero_copy_connection::{
+    ZeroCopyPortDetails, ZeroCopyReceiveError, ZeroCopyReceiver, ZeroCopySendError, ZeroCopySender,
+};
+use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+use std::cell::UnsafeCell;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+
+use super::client::{remove_port_from_all_connections, RemoveReqResPortFromAllConnectionsError};
+
+/// Defines a failure that can occur when a [`Server`] is created with
+/// [`crate::service::port_factory::server::PortFactoryServer`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerCreateError {
+    /// The maximum amount of [`Server`]s that can connect to a
+    /// [`Service`](crate::service::Service) is
+    /// defined in [`crate::config::Config`]. When this is exceeded no more [`Server`]s
+    /// can be created for a specific [`Service`](crate::service::Service).
+    ExceedsMaxSupportedServers,
+    /// The datasegment in which the responses of the [`Server`] are stored, could not be created.
+    UnableToCreateDataSegment,
+}
+
+impl std::fmt::Display for ServerCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServerCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerCreateError {}
+
+/// Defines a failure that can occur in [`ActiveRequest::loan()`] and
+/// [`ActiveRequest::loan_uninit()`] or is part of [`ServerSendError`] emitted in
+/// [`ActiveRequest::send_copy()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash)]
+pub enum ServerLoanError {
+    /// The [`Server`]s data segment does not have any more memory left
+    OutOfMemory,
+    /// The maximum amount of [`ResponseMut`](crate::response_mut::ResponseMut)s a user can
+    /// borrow with [`ActiveRequest::loan()`] or [`ActiveRequest::loan_uninit()`] is
+    /// defined in [`crate::config::Config`]. When this is exceeded those calls will fail.
+    ExceedsMaxLoanedResponses,
+    /// Errors that indicate either an implementation issue or a wrongly configured system.
+    InternalFailure,
+}
+
+impl std::fmt::Display for ServerLoanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServerLoanError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerLoanError {}
+
+/// Failure that can be emitted when a [`ResponseMut`](crate::response_mut::ResponseMut) is
+/// sent via [`ResponseMut::send()`](crate::response_mut::ResponseMut::send()).
+#[derive(Debug, Eq, PartialEq, Copy, Clone)]
+pub enum ServerSendError {
+    /// The [`Client`](crate::port::client::Client) that sent the request no longer exists.
+    ConnectionBrokenSinceClientNoLongerExists,
+    /// A connection between a [`Server`] and a [`Client`](crate::port::client::Client) is
+    /// corrupted.
+    ConnectionCorrupted,
+    /// The response buffer of the [`Client`](crate::port::client::Client) is full and the
+    /// response cannot be delivered.
+    ResponseBufferFull,
+    /// A failure occurred while acquiring memory for the payload
+    LoanError(ServerLoanError),
+}
+
+impl From<ServerLoanError> for ServerSendError {
+    fn from(value: ServerLoanError) -> Self {
+        ServerSendError::LoanError(value)
+    }
+}
+
+impl std::fmt::Display for ServerSendError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServerSendError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerSendError {}
+
+/// Defines the failure that can occur when receiving a request with [`Server::receive()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum ServerReceiveError {
+    /// The maximum amount of [`ActiveRequest`]s a [`Server`] can hold in parallel per
+    /// [`Client`](crate::port::client::Client) is defined in [`crate::config::Config`].
+    /// When this is exceeded no more requests can be received from that
+    /// [`Client`](crate::port::client::Client).
+    ExceedsMaxBorrowedRequests,
+
+    /// Occurs when a [`Server`] is unable to connect to a corresponding
+    /// [`Client`](crate::port::client::Client).
+    ConnectionFailure(ConnectionFailure),
+}
+
+impl std::fmt::Display for ServerReceiveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "ServerReceiveError::{:?}", self)
+    }
+}
+
+impl std::error::Error for ServerReceiveError {}
+
+#[derive(Debug)]
+pub(crate) struct ServerBackend<Service: service::Service> {
+    data_segment: DataSegment<Service>,
+    pub(crate) client_connections: ClientConnections<Service>,
+    client_list_state: UnsafeCell<ContainerState<ClientDetails>>,
+    config: LocalServerConfig,
+    loan_counter: IoxAtomicUsize,
+    next_client: IoxAtomicUsize,
+}
+
+impl<Service: service::Service> ServerBackend<Service> {
+    pub(crate) fn static_config(&self) -> &StaticConfig {
+        &self.client_connections.static_config
+    }
+
+    fn retrieve_returned_responses(&self) {
+        for i in 0..self.client_connections.len() {
+            if let Some(ref connection) = self.client_connections.get(i) {
+                loop {
+                    match connection.response_sender.reclaim() {
+                        Ok(Some(offset)) => self.data_segment.release_sample(offset),
+                        Ok(None) => break,
+                        Err(e) => {
+                            warn!(from self, "Unable to reclaim responses from connection {:?} due to {:?}. This may lead to a situation where no more responses will be delivered to this connection.", connection, e);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn remove_connection(&self, i: usize) {
+        if let Some(connection) = self.client_connections.get(i) {
+            // # SAFETY: the receiver no longer exist, therefore we can
+            //           reacquire all delivered responses
+            unsafe {
+                connection
+                    .response_sender
+                    .acquire_used_offsets(|offset| self.data_segment.release_sample(offset))
+            };
+
+            self.client_connections.remove(i);
+        }
+    }
+
+    fn populate_client_channels(&self) -> Result<(), ConnectionFailure> {
+        let mut visited_indices = vec![];
+        visited_indices.resize(self.client_connections.capacity(), None);
+
+        unsafe {
+            (*self.client_list_state.get()).for_each(|h, details| {
+                visited_indices[h.index() as usize] = Some(*details);
+                CallbackProgression::Continue
+            })
+        };
+
+        let mut ret_val = Ok(());
+        for (i, index) in visited_indices.iter().enumerate() {
+            match index {
+                Some(details) => {
+                    let create_connection = match self.client_connections.get(i) {
+                        None => true,
+                        Some(connection) => {
+                            let is_outdated = connection.client_id != details.client_id;
+                            if is_outdated {
+                                self.remove_connection(i);
+                            }
+                            is_outdated
+                        }
+                    };
+
+                    if create_connection {
+                        if let Err(e) = self.client_connections.create(i, details) {
+                            warn!(from self, "Unable to establish connection to new client {:?}.", details.client_id);
+                            ret_val = Err(e);
+                        }
+                    }
+                }
+                None => self.remove_connection(i),
+            }
+        }
+
+        ret_val
+    }
+
+    pub(crate) fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        if unsafe {
+            self.client_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .clients
+                .update_state(&mut *self.client_list_state.get())
+        } {
+            fail!(from self, when self.populate_client_channels(),
+                "Connections were updated only partially since at least one connection to a Client port failed.");
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn allocate(&self) -> Result<ShmPointer, ServerLoanError> {
+        let msg = "Unable to allocate response";
+
+        if self.loan_counter.load(Ordering::Relaxed) >= self.config.max_loaned_responses {
+            fail!(from self, with ServerLoanError::ExceedsMaxLoanedResponses,
+                "{} since already {} responses were loaned and it would exceed the maximum of parallel loans of {}. Release or send a loaned response to loan another response.",
+                msg, self.loan_counter.load(Ordering::Relaxed), self.config.max_loaned_responses);
+        }
+
+        self.retrieve_returned_responses();
+
+        match self.data_segment.allocate() {
+            Ok(chunk) => {
+                self.loan_counter.fetch_add(1, Ordering::Relaxed);
+                Ok(chunk)
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::OutOfMemory)) => {
+                fail!(from self, with ServerLoanError::OutOfMemory,
+                    "{} since the underlying shared memory is out of memory.", msg);
+            }
+            Err(ShmAllocationError::AllocationError(AllocationError::SizeTooLarge))
+            | Err(ShmAllocationError::AllocationError(AllocationError::AlignmentFailure)) => {
+                fatal_panic!(from self, "{} since the system seems to be corrupted.", msg);
+            }
+            Err(v) => {
+                fail!(from self, with ServerLoanError::InternalFailure,
+                    "{} since an internal failure occurred ({:?}).", msg, v);
+            }
+        }
+    }
+
+    pub(crate) fn return_loaned_response(&self, offset: PointerOffset) {
+        self.data_segment.release_sample(offset);
+        self.loan_counter.fetch_sub(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn send_response(
+        &self,
+        connection: &Arc<ClientConnection<Service>>,
+        offset: PointerOffset,
+    ) -> Result<(), ServerSendError> {
+        let msg = "Unable to send response";
+
+        if let Err(e) = self.update_connections() {
+            warn!(from self,
+                "Some connections could not be established while sending a response ({:?}).", e);
+        }
+
+        self.retrieve_returned_responses();
+
+        let is_connected =
+            (0..self.client_connections.len()).any(|i| match self.client_connections.get(i) {
+                Some(c) => Arc::ptr_eq(c, connection),
+                None => false,
+            });
+
+        if !is_connected {
+            fail!(from self, with ServerSendError::ConnectionBrokenSinceClientNoLongerExists,
+                "{} since the client {:?} no longer exists.", msg, connection.client_id);
+        }
+
+        match connection
+            .response_sender
+            .try_send(offset, self.data_segment.sample_granularity())
+        {
+            Ok(overflow) => {
+                self.data_segment.borrow_sample(offset);
+                if let Some(old) = overflow {
+                    self.data_segment.release_sample(old);
+                }
+                Ok(())
+            }
+            Err(ZeroCopySendError::ReceiveBufferFull)
+            | Err(ZeroCopySendError::UsedChunkListFull) => {
+                fail!(from self, with ServerSendError::ResponseBufferFull,
+                    "{} since the response buffer of client {:?} is full.", msg, connection.client_id);
+            }
+            Err(ZeroCopySendError::ConnectionCorrupted) => {
+                fail!(from self, with ServerSendError::ConnectionCorrupted,
+                    "{} since a corrupted connection was detected with client {:?}.", msg, connection.client_id);
+            }
+        }
+    }
+}
+
+/// Receiving endpoint of a request-response based communication. Receives requests from all
+/// connected [`Client`](crate::port::client::Client)s as [`ActiveRequest`]s which are used to
+/// send the corresponding responses.
+#[derive(Debug)]
+pub struct Server<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> {
+    backend: Arc<ServerBackend<Service>>,
+    dynamic_server_handle: Option<ContainerHandle>,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for Server<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_server_handle {
+            self.backend
+                .client_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .request_response()
+                .release_server_handle(handle)
+        }
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    Server<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        service: &Service,
+        static_config: &StaticConfig,
+        config: LocalServerConfig,
+    ) -> Result<Self, ServerCreateError> {
+        let msg = "Unable to create Server port";
+        let origin = "Server::new()";
+        let server_id = UniqueServerId::new();
+        let client_list = &service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .clients;
+
+        let number_of_responses = static_config
+            .required_amount_of_responses_per_data_segment(config.max_loaned_responses);
+
+        let data_segment = fail!(from origin,
+                when DataSegment::create(
+                    &server_data_segment_name(&server_id),
+                    service.__internal_state().shared_node.config(),
+                    static_config.response_message_type_details().sample_layout(1),
+                    number_of_responses),
+                with ServerCreateError::UnableToCreateDataSegment,
+                "{} since the data segment could not be acquired.", msg);
+
+        let backend = Arc::new(ServerBackend {
+            data_segment,
+            client_connections: ClientConnections::new(
+                client_list.capacity(),
+                server_id,
+                service.__internal_state().clone(),
+                static_config,
+                number_of_responses,
+            ),
+            client_list_state: UnsafeCell::new(unsafe { client_list.get_state() }),
+            config,
+            loan_counter: IoxAtomicUsize::new(0),
+            next_client: IoxAtomicUsize::new(0),
+        });
+
+        let mut new_self = Self {
+            backend,
+            dynamic_server_handle: None,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        };
+
+        if let Err(e) = new_self.backend.populate_client_channels() {
+            warn!(from new_self, "The new Server port is unable to connect to every Client port, caused by {:?}.", e);
+        }
+
+        std::sync::atomic::compiler_fence(Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a server is added to the dynamic config without the
+        // creation of all required resources
+        let dynamic_server_handle = match service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+            .add_server_id(ServerDetails {
+                server_id,
+                node_id: *service.__internal_state().shared_node.id(),
+                number_of_responses,
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with ServerCreateError::ExceedsMaxSupportedServers,
+                    "{} since it would exceed the maximum supported amount of servers of {}.",
+                    msg, static_config.max_servers);
+            }
+        };
+
+        new_self.dynamic_server_handle = Some(dynamic_server_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`Server`]
+    pub fn id(&self) -> UniqueServerId {
+        self.backend.client_connections.server_id()
+    }
+
+    /// Returns true if the [`Server`] has requests in its buffer.
+    pub fn has_requests(&self) -> Result<bool, ConnectionFailure> {
+        fail!(from self, when self.backend.update_connections(),
+            "Some requests are not being received since not all connections to clients could be established.");
+
+        for i in 0..self.backend.client_connections.len() {
+            if let Some(ref connection) = self.backend.client_connections.get(i) {
+                if connection.request_receiver.has_data() {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Receives a request from one of the connected [`Client`](crate::port::client::Client)s.
+    /// If no request could be received [`None`] is returned. If a failure occurs
+    /// [`ServerReceiveError`] is returned.
+    pub fn receive(
+        &self,
+    ) -> Result<Option<ActiveRequest<Service, RequestPayload, ResponsePayload>>, ServerReceiveError>
+    {
+        let msg = "Unable to receive request";
+        if let Err(e) = self.backend.update_connections() {
+            fail!(from self, with ServerReceiveError::ConnectionFailure(e),
+                "{} since not all connections to clients could be established.", msg);
+        }
+
+        // the search starts at the client after the one that was served last so that a client
+        // which sends requests continuously cannot starve the other clients
+        let number_of_connections = self.backend.client_connections.len();
+        let start = self.backend.next_client.load(Ordering::Relaxed);
+        for n in 0..number_of_connections {
+            let i = (start + n) % number_of_connections;
+            if let Some(ref connection) = self.backend.client_connections.get(i) {
+                match connection.request_receiver.receive() {
+                    Ok(Some(offset)) => {
+                        self.backend
+                            .next_client
+                            .store((i + 1) % number_of_connections, Ordering::Relaxed);
+
+                        let header_ptr = (offset.value()
+                            + connection.request_data_segment.payload_start_address())
+                            as *const RequestHeader;
+                        let payload_ptr = self
+                            .backend
+                            .static_config()
+                            .request_message_type_details()
+                            .payload_ptr_from_header(header_ptr.cast())
+                            as *const RequestPayload;
+
+                        return Ok(Some(ActiveRequest::new(
+                            &self.backend,
+                            connection,
+                            offset,
+                            header_ptr,
+                            payload_ptr,
+                        )));
+                    }
+                    Ok(None) => (),
+                    Err(ZeroCopyReceiveError::ReceiveWouldExceedMaxBorrowValue) => {
+                        fail!(from self, with ServerReceiveError::ExceedsMaxBorrowedRequests,
+                            "{} since it would exceed the maximum {} of borrowed requests from client {:?}.",
+                            msg, connection.request_receiver.max_borrowed_samples(), connection.client_id);
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> UpdateConnections
+    for Server<Service, RequestPayload, ResponsePayload>
+{
+    fn update_connections(&self) -> Result<(), ConnectionFailure> {
+        self.backend.update_connections()
+    }
+}
+
+pub(crate) unsafe fn remove_data_segment_of_server<Service: service::Service>(
+    port_id: &UniqueServerId,
+    config: &config::Config,
+) -> Result<(), NamedConceptRemoveError> {
+    let origin = format!(
+        "remove_data_segment_of_server::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    fail!(from origin, when <Service::SharedMemory as NamedConceptMgmt>::remove_cfg(
+            &server_data_segment_name(port_id),
+            &data_segment_config::<Service>(config),
+        ), "Unable to remove the servers data segment."
+    );
+
+    Ok(())
+}
+
+pub(crate) unsafe fn remove_server_from_all_connections<Service: service::Service>(
+    port_id: &UniqueServerId,
+    config: &config::Config,
+) -> Result<(), RemoveReqResPortFromAllConnectionsError> {
+    let origin = format!(
+        "remove_server_from_all_connections::<{}>::({:?})",
+        core::any::type_name::<Service>(),
+        port_id
+    );
+
+    remove_port_from_all_connections::<Service>(
+        &origin,
+        "Unable to remove the server from all connections",
+        &port_id.0,
+        config,
+    )
+}
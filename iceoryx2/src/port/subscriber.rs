@@ -31,7 +31,8 @@
 //! # }
 //! ```
 
-use std::cell::UnsafeCell;
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::collections::HashMap;
 use std::fmt::Debug;
 use std::marker::PhantomData;
 use std::sync::atomic::Ordering;
@@ -42,7 +43,7 @@ use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::container::{ContainerHandle, ContainerState};
 use iceoryx2_bb_log::{fail, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorage;
-use iceoryx2_cal::{shared_memory::*, zero_copy_connection::*};
+use iceoryx2_cal::zero_copy_connection::*;
 
 use crate::port::DegrationAction;
 use crate::sample::SampleDetails;
@@ -53,10 +54,114 @@ use crate::service::static_config::publish_subscribe::StaticConfig;
 use crate::{raw_sample::RawSample, sample::Sample, service};
 
 use super::details::publisher_connections::{Connection, PublisherConnections};
-use super::port_identifiers::UniqueSubscriberId;
+use super::details::qos_monitor::QosMonitor;
+use super::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
 use super::update_connections::{ConnectionFailure, UpdateConnections};
 use super::DegrationCallback;
 
+#[cfg(feature = "async")]
+use super::details::async_driver::poll_when_notified;
+#[cfg(feature = "async")]
+use super::details::sample_notification::open_or_create_sample_notification_service;
+#[cfg(feature = "async")]
+use super::listener::Listener;
+#[cfg(feature = "async")]
+use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+#[cfg(feature = "async")]
+use std::cell::OnceCell;
+#[cfg(feature = "async")]
+use std::future::Future;
+
+const SAMPLE_FILTER_MAX_VALUE_SIZE: usize = 8;
+
+/// A primitive value of a user header field that can be compared by a [`SampleFilter`].
+pub trait SampleFilterValue: Copy {
+    #[doc(hidden)]
+    fn __internal_to_bytes(self) -> [u8; SAMPLE_FILTER_MAX_VALUE_SIZE];
+}
+
+macro_rules! impl_sample_filter_value {
+    ($($t:ty),*) => {
+        $(impl SampleFilterValue for $t {
+            fn __internal_to_bytes(self) -> [u8; SAMPLE_FILTER_MAX_VALUE_SIZE] {
+                let mut bytes = [0u8; SAMPLE_FILTER_MAX_VALUE_SIZE];
+                bytes[..core::mem::size_of::<$t>()].copy_from_slice(&self.to_ne_bytes());
+                bytes
+            }
+        })*
+    };
+}
+
+impl_sample_filter_value!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+/// Declarative filter that compares a field of the user header of a [`Sample`] with a value.
+/// It is stored in the dynamic config of the [`Service`](crate::service::Service) and
+/// evaluated by the [`Publisher`](crate::port::publisher::Publisher) before the sample is
+/// delivered. Rejected samples never reach the buffer of the [`Subscriber`].
+///
+/// The offset of the field can be acquired with `core::mem::offset_of!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleFilter {
+    offset: usize,
+    size: usize,
+    value: [u8; SAMPLE_FILTER_MAX_VALUE_SIZE],
+    mask: [u8; SAMPLE_FILTER_MAX_VALUE_SIZE],
+}
+
+impl SampleFilter {
+    /// Accepts all samples whose user header field at `offset` is equal to `value`.
+    pub fn equals<T: SampleFilterValue>(offset: usize, value: T) -> Self {
+        Self {
+            offset,
+            size: core::mem::size_of::<T>(),
+            value: value.__internal_to_bytes(),
+            mask: [u8::MAX; SAMPLE_FILTER_MAX_VALUE_SIZE],
+        }
+    }
+
+    /// Accepts all samples whose user header field at `offset` is equal to `value` in all bits
+    /// that are set in `mask`.
+    pub fn masked_equals<T: SampleFilterValue>(offset: usize, value: T, mask: T) -> Self {
+        Self {
+            offset,
+            size: core::mem::size_of::<T>(),
+            value: value.__internal_to_bytes(),
+            mask: mask.__internal_to_bytes(),
+        }
+    }
+
+    /// Returns the number of bytes the user header requires so that the filter can be applied
+    /// or [`None`] when it exceeds the address space.
+    pub(crate) fn required_user_header_size(&self) -> Option<usize> {
+        self.offset.checked_add(self.size)
+    }
+
+    /// Returns true when the filter can be applied to a user header of `user_header_size`
+    /// bytes, otherwise false.
+    pub(crate) fn fits_into(&self, user_header_size: usize) -> bool {
+        self.required_user_header_size()
+            .is_some_and(|required_size| required_size <= user_header_size)
+    }
+
+    /// Returns true when the user header is accepted by the filter. A filter that does not fit
+    /// into the user header rejects every user header.
+    ///
+    /// # Safety
+    ///
+    ///  * `user_header` must point to at least `user_header_size` initialized bytes
+    pub(crate) unsafe fn accepts(&self, user_header: *const u8, user_header_size: usize) -> bool {
+        if !self.fits_into(user_header_size) {
+            return false;
+        }
+
+        let field = core::slice::from_raw_parts(user_header.add(self.offset), self.size);
+        field
+            .iter()
+            .zip(self.value.iter().zip(self.mask.iter()))
+            .all(|(field, (value, mask))| field & mask == value & mask)
+    }
+}
+
 /// Defines the failure that can occur when receiving data with [`Subscriber::receive()`].
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum SubscriberReceiveError {
@@ -90,6 +195,8 @@ pub enum SubscriberCreateError {
     /// When the [`Subscriber`] requires a larger buffer size than the
     /// [`Service`](crate::service::Service) offers the creation will fail.
     BufferSizeExceedsMaxSupportedBufferSizeOfService,
+    /// The [`SampleFilter`] compares bytes beyond the size of the user header.
+    SampleFilterExceedsUserHeader,
 }
 
 impl std::fmt::Display for SubscriberCreateError {
@@ -100,6 +207,22 @@ impl std::fmt::Display for SubscriberCreateError {
 
 impl std::error::Error for SubscriberCreateError {}
 
+#[derive(Debug, Default)]
+struct SequenceTracking {
+    last_sequence_number: Option<u64>,
+    number_of_gaps: u64,
+    skipped_samples_of_removed_connection: Option<u64>,
+}
+
+impl SequenceTracking {
+    // the gaps in the sequence numbers are caused by lost and by skipped samples. The publisher
+    // accounts skipped samples right before it delivers the next sample, therefore they can
+    // exceed the gaps only until the subscriber has received that sample.
+    fn number_of_lost_samples(&self, skipped_samples: u64) -> u64 {
+        self.number_of_gaps.saturating_sub(skipped_samples)
+    }
+}
+
 /// The receiving endpoint of a publish-subscribe communication.
 #[derive(Debug)]
 pub struct Subscriber<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
@@ -110,6 +233,12 @@ pub struct Subscriber<Service: service::Service, Payload: Debug + ?Sized, UserHe
     degration_callback: Option<DegrationCallback<'static>>,
 
     publisher_list_state: UnsafeCell<ContainerState<PublisherDetails>>,
+    qos_monitor: QosMonitor<Service>,
+    #[cfg(feature = "async")]
+    sample_listener: OnceCell<Option<Listener<Service>>>,
+    sequence_tracking: RefCell<HashMap<UniquePublisherId, SequenceTracking>>,
+    missed_samples_of_removed_connections: Cell<u64>,
+    skipped_samples_of_removed_connections: Cell<u64>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -160,6 +289,15 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             None => static_config.subscriber_max_buffer_size,
         };
 
+        if let Some(filter) = &config.filter {
+            let user_header_size = static_config.message_type_details().user_header.size;
+            if !filter.fits_into(user_header_size) {
+                fail!(from origin, with SubscriberCreateError::SampleFilterExceedsUserHeader,
+                    "{} since the sample filter compares {} bytes at offset {} but the user header of the service has only {} bytes.",
+                    msg, filter.size, filter.offset, user_header_size);
+            }
+        }
+
         let publisher_connections = PublisherConnections::new(
             publisher_list.capacity(),
             subscriber_id,
@@ -183,6 +321,12 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             publisher_list_state: UnsafeCell::new(unsafe { publisher_list.get_state() }),
             dynamic_subscriber_handle: None,
             static_config: service.__internal_state().static_config.clone(),
+            qos_monitor: QosMonitor::new(service.__internal_state().clone()),
+            #[cfg(feature = "async")]
+            sample_listener: OnceCell::new(),
+            sequence_tracking: RefCell::new(HashMap::new()),
+            missed_samples_of_removed_connections: Cell::new(0),
+            skipped_samples_of_removed_connections: Cell::new(0),
             _payload: PhantomData,
             _user_header: PhantomData,
         };
@@ -204,6 +348,7 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                 subscriber_id,
                 buffer_size,
                 node_id: *service.__internal_state().shared_node.id(),
+                filter: config.filter,
             }) {
             Some(unique_index) => unique_index,
             None => {
@@ -231,6 +376,22 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 
         let prepare_connection_removal = |i| {
             if let Some(connection) = self.publisher_connections.get(i) {
+                self.missed_samples_of_removed_connections.set(
+                    self.missed_samples_of_removed_connections.get()
+                        + connection.receiver.missed_samples(),
+                );
+
+                // the publisher no longer skips samples, therefore the final number of skipped
+                // samples is stored for the samples that are still in the expired connection
+                let skipped_samples = connection.receiver.skipped_samples();
+                self.skipped_samples_of_removed_connections
+                    .set(self.skipped_samples_of_removed_connections.get() + skipped_samples);
+                self.sequence_tracking
+                    .borrow_mut()
+                    .entry(connection.publisher_id)
+                    .or_default()
+                    .skipped_samples_of_removed_connection = Some(skipped_samples);
+
                 if connection.receiver.has_data()
                     && !unsafe { &mut *self.to_be_removed_connections.get() }
                         .push(connection.clone())
@@ -299,8 +460,17 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
             Ok(data) => match data {
                 None => Ok(None),
                 Some(offset) => {
-                    let absolute_address =
-                        offset.value() + connection.data_segment.payload_start_address();
+                    let payload_start_address =
+                        match connection.payload_start_address(offset.segment_id()) {
+                            Ok(address) => address,
+                            Err(e) => {
+                                fail!(from self, with SubscriberReceiveError::ConnectionFailure(
+                                        ConnectionFailure::UnableToMapPublishersDataSegment(e)),
+                                    "{} since the publishers data segment {} could not be mapped.",
+                                    msg, offset.segment_id());
+                            }
+                        };
+                    let absolute_address = offset.value() + payload_start_address;
 
                     let details = SampleDetails {
                         publisher_connection: connection.clone(),
@@ -308,6 +478,9 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
                         origin: connection.publisher_id,
                     };
 
+                    self.track_sequence_number(absolute_address);
+                    self.record_received_sample();
+
                     Ok(Some((details, absolute_address)))
                 }
             },
@@ -319,6 +492,34 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         }
     }
 
+    fn record_received_sample(&self) {
+        if let Some(handle) = self.dynamic_subscriber_handle {
+            self.publisher_connections
+                .service_state
+                .dynamic_storage
+                .get()
+                .publish_subscribe()
+                .subscriber_counters(handle.index() as usize)
+                .record_received_sample();
+        }
+    }
+
+    fn track_sequence_number(&self, absolute_address: usize) {
+        let header = unsafe { &*(absolute_address as *const Header) };
+        let sequence_number = header.sequence_number();
+
+        let mut sequence_tracking = self.sequence_tracking.borrow_mut();
+        let tracking = sequence_tracking.entry(header.publisher_id()).or_default();
+
+        // the first sample of a publisher only establishes the reference point, samples that
+        // were sent before the subscriber connected are not considered as lost
+        if let Some(last) = tracking.last_sequence_number.replace(sequence_number) {
+            if sequence_number > last + 1 {
+                tracking.number_of_gaps += sequence_number - last - 1;
+            }
+        }
+    }
+
     /// Returns the [`UniqueSubscriberId`] of the [`Subscriber`]
     pub fn id(&self) -> UniqueSubscriberId {
         self.publisher_connections.subscriber_id()
@@ -329,6 +530,66 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         self.publisher_connections.buffer_size
     }
 
+    /// Returns the number of [`Sample`]s that were lost since the [`Subscriber`] was created.
+    /// Lost samples are detected by gaps in the [`Header::sequence_number()`]s of the samples
+    /// received from the same [`crate::port::publisher::Publisher`], for instance when the
+    /// publisher overrides samples in a full buffer with
+    /// [`StaticConfig::has_safe_overflow()`]. The samples that were rejected by a
+    /// [`SampleFilter`] are not considered as lost, see
+    /// [`Subscriber::number_of_skipped_samples()`].
+    pub fn number_of_lost_samples(&self) -> u64 {
+        let sequence_tracking = self.sequence_tracking.borrow();
+        let mut number_of_lost_samples = 0;
+
+        for i in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get(i) {
+                if let Some(tracking) = sequence_tracking.get(&connection.publisher_id) {
+                    if tracking.skipped_samples_of_removed_connection.is_none() {
+                        number_of_lost_samples +=
+                            tracking.number_of_lost_samples(connection.receiver.skipped_samples());
+                    }
+                }
+            }
+        }
+
+        for tracking in sequence_tracking.values() {
+            if let Some(skipped_samples) = tracking.skipped_samples_of_removed_connection {
+                number_of_lost_samples += tracking.number_of_lost_samples(skipped_samples);
+            }
+        }
+
+        number_of_lost_samples
+    }
+
+    /// Returns the number of [`Sample`]s that the [`crate::port::publisher::Publisher`]s did
+    /// not deliver to the [`Subscriber`] since they were rejected by its [`SampleFilter`].
+    /// A skipped sample is accounted as soon as the publisher delivers the next accepted
+    /// sample, samples that were rejected before the first delivery are not accounted.
+    pub fn number_of_skipped_samples(&self) -> u64 {
+        let mut skipped_samples = self.skipped_samples_of_removed_connections.get();
+        for i in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get(i) {
+                skipped_samples += connection.receiver.skipped_samples();
+            }
+        }
+        skipped_samples
+    }
+
+    /// Returns the number of [`Sample`]s that were overridden in the buffer of the
+    /// [`Subscriber`] by a [`crate::port::publisher::Publisher`] since the service has
+    /// enabled the safe overflow. In contrast to [`Subscriber::number_of_lost_samples()`] the
+    /// counter is maintained by the connection itself and does not require the [`Subscriber`]
+    /// to receive samples.
+    pub fn missed_samples(&self) -> u64 {
+        let mut missed_samples = self.missed_samples_of_removed_connections.get();
+        for i in 0..self.publisher_connections.len() {
+            if let Some(connection) = self.publisher_connections.get(i) {
+                missed_samples += connection.receiver.missed_samples();
+            }
+        }
+        missed_samples
+    }
+
     /// Returns true if the [`Subscriber`] has samples in the buffer that can be received with [`Subscriber::receive`].
     pub fn has_samples(&self) -> Result<bool, ConnectionFailure> {
         fail!(from self, when self.update_connections(),
@@ -377,6 +638,37 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
         Ok(None)
     }
 
+    /// Calls the provided callback with the [`UniquePublisherId`] of every
+    /// [`crate::port::publisher::Publisher`] that did not deliver a [`crate::sample::Sample`]
+    /// within the [`StaticConfig::publish_deadline()`] of the
+    /// [`Service`](crate::service::Service). If the service has no publish deadline, the
+    /// callback is never called.
+    ///
+    /// When the [`Service`](crate::service::Service) defines a
+    /// [`StaticConfig::deadline_missed_event()`], every miss is emitted once on the
+    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+    /// based service with the same name by the first port that detects it. Besides this call,
+    /// every [`crate::port::publisher::Publisher`] of the service detects misses when it sends.
+    pub fn missed_deadlines<F: FnMut(UniquePublisherId)>(&self, call: F) {
+        self.qos_monitor.missed_deadlines(call)
+    }
+
+    /// Calls the provided callback with the [`UniquePublisherId`] of every
+    /// [`crate::port::publisher::Publisher`] that did neither deliver a
+    /// [`crate::sample::Sample`] nor assert its liveliness within the
+    /// [`StaticConfig::liveliness_lease()`] of the [`Service`](crate::service::Service). If
+    /// the service has no liveliness lease, the callback is never called.
+    ///
+    /// When the [`Service`](crate::service::Service) defines a
+    /// [`StaticConfig::liveliness_lost_event()`], every expired lease is emitted once on the
+    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+    /// based service with the same name by the first port that detects it. Besides this call,
+    /// every [`crate::port::publisher::Publisher`] of the service detects expired leases when
+    /// it sends or asserts its liveliness.
+    pub fn expired_liveliness_leases<F: FnMut(UniquePublisherId)>(&self, call: F) {
+        self.qos_monitor.expired_liveliness_leases(call)
+    }
+
     fn payload_ptr(&self, header: *const Header) -> *const u8 {
         self.publisher_connections
             .static_config
@@ -464,3 +756,94 @@ impl<Service: service::Service, Payload: Debug, UserHeader: Debug>
         }))
     }
 }
+
+#[cfg(feature = "async")]
+impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
+    Subscriber<Service, Payload, UserHeader>
+{
+    // The listener is created with the first asynchronous receive call and requests the
+    // publishers to notify it after every delivered sample.
+    fn sample_listener(&self) -> Option<&Listener<Service>> {
+        self.sample_listener
+            .get_or_init(|| {
+                let msg = "Unable to wait asynchronously for samples";
+                let service_state = &self.publisher_connections.service_state;
+                let event = match open_or_create_sample_notification_service(service_state) {
+                    Ok(event) => event,
+                    Err(e) => {
+                        warn!(from self, "{} since the event service could not be opened ({:?}), the future is polled continuously.",
+                            msg, e);
+                        return None;
+                    }
+                };
+
+                let listener = match event.listener_builder().create() {
+                    Ok(listener) => listener,
+                    Err(e) => {
+                        warn!(from self, "{} since the listener could not be created ({:?}), the future is polled continuously.",
+                            msg, e);
+                        return None;
+                    }
+                };
+
+                if let Some(handle) = self.dynamic_subscriber_handle {
+                    service_state
+                        .dynamic_storage
+                        .get()
+                        .publish_subscribe()
+                        .request_sample_notifications(handle);
+                }
+
+                Some(listener)
+            })
+            .as_ref()
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service + 'static, Payload: Debug, UserHeader: Debug>
+    Subscriber<Service, Payload, UserHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    <Service::Event as iceoryx2_cal::event::Event>::Notifier: Send + Sync,
+{
+    /// Asynchronously receives a [`crate::sample::Sample`] from
+    /// [`crate::port::publisher::Publisher`]. With the first call the [`Subscriber`] requests
+    /// all [`crate::port::publisher::Publisher`]s to notify it after every delivered sample,
+    /// the returned future is woken up with these notifications.
+    pub fn receive_async(
+        &self,
+    ) -> impl Future<Output = Result<Sample<Service, Payload, UserHeader>, SubscriberReceiveError>> + '_
+    {
+        let mut registration = None;
+        std::future::poll_fn(move |cx| {
+            poll_when_notified(self.sample_listener(), &mut registration, cx, || {
+                self.receive()
+            })
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<Service: service::Service + 'static, Payload: Debug, UserHeader: Debug>
+    Subscriber<Service, [Payload], UserHeader>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    <Service::Event as iceoryx2_cal::event::Event>::Notifier: Send + Sync,
+{
+    /// Asynchronously receives a [`crate::sample::Sample`] from
+    /// [`crate::port::publisher::Publisher`]. With the first call the [`Subscriber`] requests
+    /// all [`crate::port::publisher::Publisher`]s to notify it after every delivered sample,
+    /// the returned future is woken up with these notifications.
+    pub fn receive_async(
+        &self,
+    ) -> impl Future<Output = Result<Sample<Service, [Payload], UserHeader>, SubscriberReceiveError>> + '_
+    {
+        let mut registration = None;
+        std::future::poll_fn(move |cx| {
+            poll_when_notified(self.sample_listener(), &mut registration, cx, || {
+                self.receive()
+            })
+        })
+    }
+}
@@ -197,6 +197,17 @@ use iceoryx2_bb_posix::{
 use iceoryx2_cal::reactor::*;
 use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicUsize};
 
+#[cfg(feature = "async")]
+use crate::port::details::async_driver::Registration;
+#[cfg(feature = "async")]
+use iceoryx2_bb_log::warn;
+#[cfg(feature = "async")]
+use std::{
+    future::Future,
+    task::{Context, Poll},
+    time::Instant,
+};
+
 /// States why the [`WaitSet::wait_and_process()`] method returned.
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
 pub enum WaitSetRunResult {
@@ -420,6 +431,16 @@ impl<'waitset, 'attachment, Service: crate::service::Service> Drop
             self.waitset
                 .remove_deadline(unsafe { r.file_descriptor().native_handle() }, t.index())
         }
+
+        #[cfg(feature = "async")]
+        if let GuardType::Deadline(r, _) | GuardType::Notification(r) = &self.guard_type {
+            let fd = unsafe { r.file_descriptor().native_handle() };
+            self.waitset
+                .attached_file_descriptors
+                .borrow_mut()
+                .retain(|attached_fd| *attached_fd != fd);
+        }
+
         self.waitset.detach();
     }
 }
@@ -457,6 +478,8 @@ impl WaitSetBuilder {
                 deadline_to_attachment: RefCell::new(HashMap::new()),
                 attachment_counter: IoxAtomicUsize::new(0),
                 keep_running: IoxAtomicBool::new(true),
+                #[cfg(feature = "async")]
+                attached_file_descriptors: RefCell::new(vec![]),
             }),
             Err(ReactorCreateError::UnknownError(e)) => {
                 fail!(from self, with WaitSetCreateError::InternalError,
@@ -484,6 +507,8 @@ pub struct WaitSet<Service: crate::service::Service> {
     deadline_to_attachment: RefCell<HashMap<DeadlineQueueIndex, i32>>,
     attachment_counter: IoxAtomicUsize,
     keep_running: IoxAtomicBool,
+    #[cfg(feature = "async")]
+    attached_file_descriptors: RefCell<Vec<i32>>,
 }
 
 impl<Service: crate::service::Service> WaitSet<Service> {
@@ -583,6 +608,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
     ) -> Result<WaitSetGuard<'waitset, 'attachment, Service>, WaitSetAttachmentError> {
         let reactor_guard = self.attach_to_reactor(attachment)?;
         self.attach()?;
+        #[cfg(feature = "async")]
+        self.attached_file_descriptors
+            .borrow_mut()
+            .push(unsafe { reactor_guard.file_descriptor().native_handle() });
 
         Ok(WaitSetGuard {
             waitset: self,
@@ -613,6 +642,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
             .borrow_mut()
             .insert(deadline_idx, reactor_idx);
         self.attach()?;
+        #[cfg(feature = "async")]
+        self.attached_file_descriptors
+            .borrow_mut()
+            .push(reactor_idx);
 
         Ok(WaitSetGuard {
             waitset: self,
@@ -675,8 +708,23 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         &self,
         mut fn_call: F,
     ) -> Result<(), WaitSetRunError> {
-        let msg = "Unable to call WaitSet::try_wait_and_process()";
+        self.process(
+            &mut fn_call,
+            true,
+            "Unable to call WaitSet::try_wait_and_process()",
+        )?;
+        Ok(())
+    }
 
+    // Waits until an attachment was triggered or a deadline was hit when `block` is true,
+    // otherwise it only handles what is already present. Returns the number of triggered
+    // attachments.
+    fn process<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        fn_call: &mut F,
+        block: bool,
+        msg: &str,
+    ) -> Result<usize, WaitSetRunError> {
         if SignalHandler::termination_requested() {
             fail!(from self, with WaitSetRunError::TerminationRequest,
                 "{msg} since a termination request was received.");
@@ -701,7 +749,10 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         // Collect all triggered file descriptors. We need to collect them first, then reset
         // the deadline and then call the callback, otherwise a long callback may destroy the
         // deadline contract.
-        let reactor_wait_result = if self.deadline_queue.is_empty() {
+        let reactor_wait_result = if !block {
+            self.reactor
+                .timed_wait(collect_triggered_fds, Duration::ZERO)
+        } else if self.deadline_queue.is_empty() {
             self.reactor.blocking_wait(collect_triggered_fds)
         } else {
             self.reactor.timed_wait(collect_triggered_fds, next_timeout)
@@ -709,12 +760,12 @@ impl<Service: crate::service::Service> WaitSet<Service> {
 
         match reactor_wait_result {
             Ok(0) => {
-                self.handle_deadlines(&mut fn_call, msg)?;
-                Ok(())
+                self.handle_deadlines(fn_call, msg)?;
+                Ok(0)
             }
-            Ok(_) => {
-                self.handle_all_attachments(&triggered_file_descriptors, &mut fn_call, msg)?;
-                Ok(())
+            Ok(n) => {
+                self.handle_all_attachments(&triggered_file_descriptors, fn_call, msg)?;
+                Ok(n)
             }
             Err(ReactorWaitError::Interrupt) => {
                 fail!(from self, with WaitSetRunError::Interrupt,
@@ -786,3 +837,84 @@ impl<Service: crate::service::Service> WaitSet<Service> {
         }
     }
 }
+
+#[cfg(feature = "async")]
+impl<Service: crate::service::Service + 'static> WaitSet<Service>
+where
+    <Service::Event as iceoryx2_cal::event::Event>::Listener: SynchronousMultiplexing,
+    <Service::Event as iceoryx2_cal::event::Event>::Notifier: Send + Sync,
+{
+    /// The asynchronous counterpart of [`WaitSet::wait_and_process()`]. Instead of blocking the
+    /// thread, the returned future is woken up by the reactor of the
+    /// [`Service`](crate::service::Service) as soon as an attachment is triggered or the next
+    /// deadline or interval is hit. It does not depend on a specific async runtime.
+    /// The future is ready when the user has requested to stop with [`WaitSet::stop()`] or
+    /// an interrupt- (`SIGINT`) or a termination-signal (`SIGTERM`) was detected. Signals are
+    /// detected the next time the future is woken up.
+    pub fn wait_and_process_async<'a, F: FnMut(WaitSetAttachmentId<Service>) + 'a>(
+        &'a self,
+        mut fn_call: F,
+    ) -> impl Future<Output = Result<WaitSetRunResult, WaitSetRunError>> + 'a {
+        let mut registration = None;
+        std::future::poll_fn(move |cx| {
+            self.poll_wait_and_process(&mut fn_call, &mut registration, cx)
+        })
+    }
+
+    fn poll_wait_and_process<F: FnMut(WaitSetAttachmentId<Service>)>(
+        &self,
+        fn_call: &mut F,
+        registration: &mut Option<Registration<Service>>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Result<WaitSetRunResult, WaitSetRunError>> {
+        let msg = "Unable to run in WaitSet::wait_and_process_async()";
+        if !self.keep_running.load(Ordering::Relaxed) {
+            return Poll::Ready(Ok(WaitSetRunResult::StopRequest));
+        }
+
+        let number_of_triggered_attachments = match self.process(fn_call, false, msg) {
+            Ok(n) => n,
+            Err(WaitSetRunError::TerminationRequest) => {
+                return Poll::Ready(Ok(WaitSetRunResult::TerminationRequest))
+            }
+            Err(WaitSetRunError::Interrupt) => return Poll::Ready(Ok(WaitSetRunResult::Interrupt)),
+            Err(e) => {
+                warn!(from self, "{msg} since ({:?}) has occurred.", e);
+                return Poll::Ready(Err(e));
+            }
+        };
+
+        if !self.keep_running.load(Ordering::Relaxed) {
+            return Poll::Ready(Ok(WaitSetRunResult::StopRequest));
+        }
+
+        // more attachments may be triggered already, the future yields to the executor and
+        // handles them when it is polled again
+        if number_of_triggered_attachments != 0 {
+            cx.waker().wake_by_ref();
+            return Poll::Pending;
+        }
+
+        let deadline = if self.deadline_queue.is_empty() {
+            None
+        } else {
+            match self.deadline_queue.duration_until_next_deadline() {
+                Ok(timeout) => Some(Instant::now() + timeout),
+                Err(e) => {
+                    warn!(from self, "{msg} since the next timeout could not be acquired ({:?}).", e);
+                    return Poll::Ready(Err(WaitSetRunError::InternalError));
+                }
+            }
+        };
+
+        registration
+            .get_or_insert_with(Registration::new)
+            .wake_when_ready(
+                self.attached_file_descriptors.borrow().clone(),
+                deadline,
+                cx.waker(),
+            );
+
+        Poll::Pending
+    }
+}
@@ -0,0 +1,216 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 123)
+//!     .create()?;
+//!
+//! let writer = service.writer_builder().create()?;
+//!
+//! let entry = writer.entry::<i32>(&0)?;
+//! entry.update_with_copy(456);
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use iceoryx2_bb_lock_free::{mpmc::container::ContainerHandle, spmc::unrestricted_atomic::*};
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::service::{self, dynamic_config::blackboard::WriterDetails, ServiceState};
+
+use super::port_identifiers::UniqueWriterId;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
+
+/// Failures that can occur when a new [`Writer`] is created with the
+/// [`crate::service::port_factory::writer::PortFactoryWriter`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum WriterCreateError {
+    /// A blackboard supports exactly one [`Writer`]. When it already exists no other
+    /// [`Writer`] can be created for the [`Service`](crate::service::Service).
+    ExceedsMaxSupportedWriters,
+}
+
+impl std::fmt::Display for WriterCreateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "WriterCreateError::{:?}", self)
+    }
+}
+
+impl std::error::Error for WriterCreateError {}
+
+/// Failures that can occur when an [`EntryHandleMut`] is acquired with [`Writer::entry()`].
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum EntryHandleMutError {
+    /// The blackboard does not contain an entry with the provided key and value type.
+    EntryDoesNotExist,
+    /// An [`EntryHandleMut`] to the entry exists already. Every entry can have at most one
+    /// [`EntryHandleMut`] at a time.
+    HandleAlreadyExists,
+}
+
+impl std::fmt::Display for EntryHandleMutError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::write!(f, "EntryHandleMutError::{:?}", self)
+    }
+}
+
+impl std::error::Error for EntryHandleMutError {}
+
+/// Writing endpoint of a blackboard based communication.
+#[derive(Debug)]
+pub struct Writer<
+    Service: service::Service,
+    KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+> {
+    service_state: Arc<ServiceState<Service>>,
+    mgmt: Arc<Service::BlackboardMgmt>,
+    dynamic_writer_handle: Option<ContainerHandle>,
+    writer_id: UniqueWriterId,
+    _key: PhantomData<KeyType>,
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+    > Drop for Writer<Service, KeyType>
+{
+    fn drop(&mut self) {
+        if let Some(handle) = self.dynamic_writer_handle {
+            self.service_state
+                .dynamic_storage
+                .get()
+                .blackboard()
+                .release_writer_handle(handle)
+        }
+    }
+}
+
+impl<
+        Service: service::Service,
+        KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static,
+    > Writer<Service, KeyType>
+{
+    pub(crate) fn new(
+        service: &Service,
+        mgmt: &Arc<Service::BlackboardMgmt>,
+    ) -> Result<Self, WriterCreateError> {
+        let msg = "Unable to create Writer port";
+        let origin = "Writer::new()";
+        let writer_id = UniqueWriterId::new();
+
+        let mut new_self = Self {
+            service_state: service.__internal_state().clone(),
+            mgmt: mgmt.clone(),
+            dynamic_writer_handle: None,
+            writer_id,
+            _key: PhantomData,
+        };
+
+        std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+
+        // !MUST! be the last task otherwise a writer is added to the dynamic config without
+        // being fully initialized
+        let dynamic_writer_handle = match new_self
+            .service_state
+            .dynamic_storage
+            .get()
+            .blackboard()
+            .add_writer_id(WriterDetails {
+                writer_id,
+                node_id: *service.__internal_state().shared_node.id(),
+            }) {
+            Some(handle) => handle,
+            None => {
+                fail!(from origin, with WriterCreateError::ExceedsMaxSupportedWriters,
+                    "{} since a blackboard supports only one writer and it already exists.",
+                    msg);
+            }
+        };
+        new_self.dynamic_writer_handle = Some(dynamic_writer_handle);
+
+        Ok(new_self)
+    }
+
+    /// Returns the [`UniqueWriterId`] of the [`Writer`]
+    pub fn id(&self) -> UniqueWriterId {
+        self.writer_id
+    }
+
+    /// Acquires an [`EntryHandleMut`] to the entry with the provided key. If the blackboard
+    /// does not contain an entry with the key or the value type of the entry differs from
+    /// `ValueType` it returns [`EntryHandleMutError::EntryDoesNotExist`]. Every entry can have
+    /// at most one [`EntryHandleMut`] at a time.
+    pub fn entry<ValueType: Copy + ZeroCopySend>(
+        &self,
+        key: &KeyType,
+    ) -> Result<EntryHandleMut<'_, ValueType>, EntryHandleMutError> {
+        let msg = "Unable to acquire mutable entry handle";
+        let mgmt = self.mgmt.get();
+
+        // SAFETY: the service was opened with the same key type, verified in the service
+        //         builder
+        let atomic = match unsafe { mgmt.find_entry(key) } {
+            // SAFETY: the value type was verified and the atomic was constructed in the
+            //         blackboard management segment when the service was created
+            Some(entry) if entry.has_value_type::<ValueType>() => unsafe {
+                &*(mgmt.ptr_from_offset(entry.value_offset) as *const UnrestrictedAtomic<ValueType>)
+            },
+            _ => {
+                fail!(from self, with EntryHandleMutError::EntryDoesNotExist,
+                    "{} since no entry with the key {:?} and the value type {} exists.",
+                    msg, key, core::any::type_name::<ValueType>());
+            }
+        };
+
+        match atomic.acquire_producer() {
+            Some(producer) => Ok(EntryHandleMut { producer }),
+            None => {
+                fail!(from self, with EntryHandleMutError::HandleAlreadyExists,
+                    "{} since a mutable entry handle for the key {:?} already exists.", msg, key);
+            }
+        }
+    }
+}
+
+/// A write handle to a single entry of the blackboard. Acquired with [`Writer::entry()`].
+pub struct EntryHandleMut<'writer, ValueType: Copy + ZeroCopySend> {
+    producer: Producer<'writer, ValueType>,
+}
+
+impl<ValueType: Copy + ZeroCopySend> Debug for EntryHandleMut<'_, ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "EntryHandleMut<{}> {{ }}",
+            core::any::type_name::<ValueType>()
+        )
+    }
+}
+
+impl<ValueType: Copy + ZeroCopySend> EntryHandleMut<'_, ValueType> {
+    /// Updates the value of the entry. All [`crate::port::reader::Reader`]s will read the new
+    /// value from now on.
+    pub fn update_with_copy(&self, value: ValueType) {
+        self.producer.store(value);
+    }
+}
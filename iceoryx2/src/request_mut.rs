@@ -0,0 +1,152 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .request_response::<u64, u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let client = service.client_builder().create()?;
+//!
+//! let mut request = client.loan()?;
+//! *request.payload_mut() = 1234;
+//!
+//! println!("client port id: {:?}", request.header().client_id());
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    pending_response::PendingResponse,
+    port::client::{ClientBackend, ClientSendError},
+    service::header::request_response::RequestHeader,
+};
+
+/// Acquired by a [`crate::port::client::Client`] via
+///  * [`crate::port::client::Client::loan()`]
+///  * [`crate::request_mut_uninit::RequestMutUninit::write_payload()`]
+///
+/// It stores the payload that will be sent to all connected
+/// [`crate::port::server::Server`]s. If the [`RequestMut`] is not sent it will release the
+/// loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the
+/// [`crate::port::client::Client`] and the [`crate::port::client::Client`] is not thread-safe!
+pub struct RequestMut<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    pub(crate) client_backend: Arc<ClientBackend<Service>>,
+    pub(crate) header: *mut RequestHeader,
+    pub(crate) payload: *mut RequestPayload,
+    pub(crate) offset_to_chunk: PointerOffset,
+    pub(crate) _response_payload: PhantomData<ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Debug
+    for RequestMut<Service, RequestPayload, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "RequestMut<{}, {}, {}> {{ header: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<RequestPayload>(),
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.header(),
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug> Drop
+    for RequestMut<Service, RequestPayload, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.client_backend
+            .return_loaned_request(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMut<Service, RequestPayload, ResponsePayload>
+{
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        unsafe { &*self.header }
+    }
+
+    /// Returns a reference to the payload of the request.
+    pub fn payload(&self) -> &RequestPayload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a mutable reference to the payload of the request.
+    pub fn payload_mut(&mut self) -> &mut RequestPayload {
+        unsafe { &mut *self.payload }
+    }
+
+    /// Sends the request to all connected [`crate::port::server::Server`]s and returns a
+    /// [`PendingResponse`] that receives the corresponding responses. On failure it returns a
+    /// [`ClientSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan()?;
+    /// *request.payload_mut() = 4;
+    ///
+    /// let pending_response = request.send()?;
+    /// println!("sent request with id {}", pending_response.request_id());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(
+        self,
+    ) -> Result<PendingResponse<Service, RequestPayload, ResponsePayload>, ClientSendError> {
+        let request_id = self.header().request_id();
+        let number_of_server_connections = self
+            .client_backend
+            .send_request(self.offset_to_chunk, request_id)?;
+
+        Ok(PendingResponse::new(
+            &self.client_backend,
+            request_id,
+            number_of_server_connections,
+        ))
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .request_response::<u64, u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let client = service.client_builder().create()?;
+//!
+//! let request = client.loan_uninit()?;
+//! // write 1234 into the request
+//! let mut request = request.write_payload(1234);
+//! // override contents with 456 because its fun
+//! *request.payload_mut() = 456;
+//!
+//! println!("client port id: {:?}", request.header().client_id());
+//! let pending_response = request.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::client::ClientBackend, request_mut::RequestMut,
+    service::header::request_response::RequestHeader,
+};
+
+/// Acquired by a [`crate::port::client::Client`] via
+/// [`crate::port::client::Client::loan_uninit()`]. It stores the uninitialized payload of a
+/// request that has to be initialized before it can be sent. If the [`RequestMutUninit`] is
+/// not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent requests in the
+/// [`crate::port::client::Client`] and the [`crate::port::client::Client`] is not thread-safe!
+#[derive(Debug)]
+pub struct RequestMutUninit<
+    Service: crate::service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    request: RequestMut<Service, MaybeUninit<RequestPayload>, ResponsePayload>,
+}
+
+impl<Service: crate::service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    RequestMutUninit<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        client_backend: &Arc<ClientBackend<Service>>,
+        header: *mut RequestHeader,
+        payload: *mut MaybeUninit<RequestPayload>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            request: RequestMut {
+                client_backend: client_backend.clone(),
+                header,
+                payload,
+                offset_to_chunk,
+                _response_payload: PhantomData,
+            },
+        }
+    }
+
+    /// Returns a reference to the [`RequestHeader`] of the request.
+    pub fn header(&self) -> &RequestHeader {
+        self.request.header()
+    }
+
+    /// Returns a reference to the uninitialized payload of the request.
+    pub fn payload(&self) -> &MaybeUninit<RequestPayload> {
+        self.request.payload()
+    }
+
+    /// Returns a mutable reference to the uninitialized payload of the request.
+    pub fn payload_mut(&mut self) -> &mut MaybeUninit<RequestPayload> {
+        self.request.payload_mut()
+    }
+
+    /// Writes the payload to the request and labels the [`RequestMutUninit`] as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let request = client.loan_uninit()?;
+    /// let request = request.write_payload(1234);
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(
+        mut self,
+        value: RequestPayload,
+    ) -> RequestMut<Service, RequestPayload, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`MaybeUninit<RequestPayload>`] container and labels the
+    /// [`RequestMutUninit`] as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`MaybeUninit<RequestPayload>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined
+    /// behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .request_response::<u64, u64>()
+    /// #     .open_or_create()?;
+    /// # let client = service.client_builder().create()?;
+    ///
+    /// let mut request = client.loan_uninit()?;
+    /// request.payload_mut().write(1234);
+    /// let request = unsafe { request.assume_init() };
+    ///
+    /// let pending_response = request.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> RequestMut<Service, RequestPayload, ResponsePayload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self.request)
+    }
+}
@@ -0,0 +1,108 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let client = service.client_builder().create()?;
+//!
+//! let pending_response = client.send_copy(123)?;
+//! while let Some(response) = pending_response.receive()? {
+//!     println!("received: {:?}", *response);
+//!     println!("header server id {:?}", response.header().server_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref};
+
+use crate::{
+    port::{client::ReceivedResponse, port_identifiers::UniqueServerId},
+    service::header::request_response::ResponseHeader,
+};
+
+/// It stores the payload and is acquired by the [`crate::port::client::Client`] whenever it
+/// receives a response from a [`crate::port::server::Server`] via
+/// [`PendingResponse::receive()`](crate::pending_response::PendingResponse::receive()).
+pub struct Response<Service: crate::service::Service, ResponsePayload: Debug> {
+    details: ReceivedResponse<Service>,
+    header: *const ResponseHeader,
+    payload: *const ResponsePayload,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Debug
+    for Response<Service, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Response<{}, {}> {{ details: {:?} }}",
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.details
+        )
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Deref
+    for Response<Service, ResponsePayload>
+{
+    type Target = ResponsePayload;
+    fn deref(&self) -> &Self::Target {
+        self.payload()
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Drop
+    for Response<Service, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.details.release();
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Response<Service, ResponsePayload> {
+    pub(crate) fn new(
+        details: ReceivedResponse<Service>,
+        header: *const ResponseHeader,
+        payload: *const ResponsePayload,
+    ) -> Self {
+        Self {
+            details,
+            header,
+            payload,
+        }
+    }
+
+    /// Returns a reference to the payload of the [`Response`]
+    pub fn payload(&self) -> &ResponsePayload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the [`Response`].
+    pub fn header(&self) -> &ResponseHeader {
+        unsafe { &*self.header }
+    }
+
+    /// Returns the [`UniqueServerId`] of the [`crate::port::server::Server`]
+    pub fn origin(&self) -> UniqueServerId {
+        self.header().server_id()
+    }
+}
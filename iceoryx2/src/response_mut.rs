@@ -0,0 +1,116 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let mut response = active_request.loan()?;
+//!     *response.payload_mut() = 1234;
+//!
+//!     println!("server port id: {:?}", response.header().server_id());
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::{
+        details::client_connections::ClientConnection,
+        server::{ServerBackend, ServerSendError},
+    },
+    service::header::request_response::ResponseHeader,
+};
+
+/// Acquired by an [`crate::active_request::ActiveRequest`] via
+///  * [`crate::active_request::ActiveRequest::loan()`]
+///  * [`crate::response_mut_uninit::ResponseMutUninit::write_payload()`]
+///
+/// It stores the payload that will be sent to the [`crate::port::client::Client`] that sent
+/// the corresponding request. If the [`ResponseMut`] is not sent it will release the loaned
+/// memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the
+/// [`crate::port::server::Server`] and the [`crate::port::server::Server`] is not thread-safe!
+pub struct ResponseMut<Service: crate::service::Service, ResponsePayload: Debug> {
+    pub(crate) server_backend: Arc<ServerBackend<Service>>,
+    pub(crate) connection: Arc<ClientConnection<Service>>,
+    pub(crate) header: *mut ResponseHeader,
+    pub(crate) payload: *mut ResponsePayload,
+    pub(crate) offset_to_chunk: PointerOffset,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Debug
+    for ResponseMut<Service, ResponsePayload>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ResponseMut<{}, {}> {{ header: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<ResponsePayload>(),
+            core::any::type_name::<Service>(),
+            self.header(),
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug> Drop
+    for ResponseMut<Service, ResponsePayload>
+{
+    fn drop(&mut self) {
+        self.server_backend
+            .return_loaned_response(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug>
+    ResponseMut<Service, ResponsePayload>
+{
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        unsafe { &*self.header }
+    }
+
+    /// Returns a reference to the payload of the response.
+    pub fn payload(&self) -> &ResponsePayload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a mutable reference to the payload of the response.
+    pub fn payload_mut(&mut self) -> &mut ResponsePayload {
+        unsafe { &mut *self.payload }
+    }
+
+    /// Sends the response to the [`crate::port::client::Client`] that sent the corresponding
+    /// request. On failure it returns a [`ServerSendError`] describing the failure.
+    pub fn send(self) -> Result<(), ServerSendError> {
+        self.server_backend
+            .send_response(&self.connection, self.offset_to_chunk)
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .request_response::<u64, u64>()
+//! #   .open_or_create()?;
+//! # let server = service.server_builder().create()?;
+//!
+//! while let Some(active_request) = server.receive()? {
+//!     let response = active_request.loan_uninit()?;
+//!     // write 1234 into the response
+//!     let mut response = response.write_payload(1234);
+//!     // override contents with 456 because its fun
+//!     *response.payload_mut() = 456;
+//!
+//!     response.send()?;
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::{details::client_connections::ClientConnection, server::ServerBackend},
+    response_mut::ResponseMut,
+    service::header::request_response::ResponseHeader,
+};
+
+/// Acquired by an [`crate::active_request::ActiveRequest`] via
+/// [`crate::active_request::ActiveRequest::loan_uninit()`]. It stores the uninitialized
+/// payload of a response that has to be initialized before it can be sent. If the
+/// [`ResponseMutUninit`] is not sent it will release the loaned memory when going out of
+/// scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent responses in the
+/// [`crate::port::server::Server`] and the [`crate::port::server::Server`] is not thread-safe!
+#[derive(Debug)]
+pub struct ResponseMutUninit<Service: crate::service::Service, ResponsePayload: Debug> {
+    response: ResponseMut<Service, MaybeUninit<ResponsePayload>>,
+}
+
+impl<Service: crate::service::Service, ResponsePayload: Debug>
+    ResponseMutUninit<Service, ResponsePayload>
+{
+    pub(crate) fn new(
+        server_backend: &Arc<ServerBackend<Service>>,
+        connection: &Arc<ClientConnection<Service>>,
+        header: *mut ResponseHeader,
+        payload: *mut MaybeUninit<ResponsePayload>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            response: ResponseMut {
+                server_backend: server_backend.clone(),
+                connection: connection.clone(),
+                header,
+                payload,
+                offset_to_chunk,
+            },
+        }
+    }
+
+    /// Returns a reference to the [`ResponseHeader`] of the response.
+    pub fn header(&self) -> &ResponseHeader {
+        self.response.header()
+    }
+
+    /// Returns a reference to the uninitialized payload of the response.
+    pub fn payload(&self) -> &MaybeUninit<ResponsePayload> {
+        self.response.payload()
+    }
+
+    /// Returns a mutable reference to the uninitialized payload of the response.
+    pub fn payload_mut(&mut self) -> &mut MaybeUninit<ResponsePayload> {
+        self.response.payload_mut()
+    }
+
+    /// Writes the payload to the response and labels the [`ResponseMutUninit`] as initialized
+    pub fn write_payload(
+        mut self,
+        value: ResponsePayload,
+    ) -> ResponseMut<Service, ResponsePayload> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`MaybeUninit<ResponsePayload>`] container and labels the
+    /// [`ResponseMutUninit`] as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`MaybeUninit<ResponsePayload>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined
+    /// behavior.
+    pub unsafe fn assume_init(self) -> ResponseMut<Service, ResponsePayload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self.response)
+    }
+}
@@ -64,7 +64,7 @@
 //! ```
 
 use crate::{
-    port::publisher::{DataSegment, PublisherSendError},
+    port::publisher::{DataSegment, DeliveryReport, PublisherSendError},
     raw_sample::RawSampleMut,
     service::header::publish_subscribe::Header,
 };
@@ -263,8 +263,9 @@ impl<
     /// [`crate::port::publisher::Publisher::loan()`] [`SampleMut`] to all connected
     /// [`crate::port::subscriber::Subscriber`]s of the service.
     ///
-    /// On success the number of [`crate::port::subscriber::Subscriber`]s that received
-    /// the data is returned, otherwise a [`PublisherSendError`] describing the failure.
+    /// On success a [`DeliveryReport`] with the number of [`crate::port::subscriber::Subscriber`]s
+    /// that received the data and the number of overflows the delivery caused is returned,
+    /// otherwise a [`PublisherSendError`] describing the failure.
     ///
     /// # Example
     ///
@@ -282,12 +283,18 @@ impl<
     /// let mut sample = publisher.loan()?;
     /// *sample.payload_mut() = 4567;
     ///
-    /// sample.send()?;
+    /// let report = sample.send()?;
+    /// println!(
+    ///     "delivered to {} subscribers, {} overflowed",
+    ///     report.number_of_recipients(),
+    ///     report.number_of_overflows()
+    /// );
     ///
     /// # Ok(())
     /// # }
     /// ```
-    pub fn send(self) -> Result<usize, PublisherSendError> {
-        self.data_segment.send_sample(self.offset_to_chunk.value())
+    pub fn send(mut self) -> Result<DeliveryReport, PublisherSendError> {
+        self.data_segment
+            .send_sample(self.offset_to_chunk, self.ptr.as_header_mut())
     }
 }
@@ -89,9 +89,60 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Dynamically sized containers
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2_bb_container::shm_heap::{ShmPayload, ShmSend};
+//! use iceoryx2_bb_container::shm_string::ShmString;
+//! use iceoryx2_bb_container::shm_vec::ShmVec;
+//!
+//! #[derive(Debug)]
+//! #[repr(C)]
+//! struct Measurement {
+//!     sensor: ShmString,
+//!     readings: ShmVec<f64>,
+//! }
+//!
+//! // the containers can only be sent together with their heap as part of a ShmPayload
+//! unsafe impl ShmSend for Measurement {}
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! const HEAP_SIZE: usize = 4096;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<ShmPayload<Measurement, HEAP_SIZE>>()
+//!     .create()?;
+//! #
+//! # let publisher = service.publisher_builder().create()?;
+//!
+//! let sample = publisher.loan_uninit()?;
+//! // the sample owns the heap from which the containers acquire their memory
+//! let mut sample = sample.write_shm_payload(Measurement {
+//!     sensor: ShmString::new(),
+//!     readings: ShmVec::new(),
+//! });
+//!
+//! let (measurement, heap) = sample.payload_mut().split_mut();
+//! // the containers stay in the sample together with the heap
+//! unsafe {
+//!     measurement.sensor.push_str(heap, "front-left").expect("heap has enough memory");
+//!     for i in 0..64 {
+//!         measurement.readings.push(heap, i as f64 * 0.5).expect("heap has enough memory");
+//!     }
+//! }
+//!
+//! sample.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
 
+use iceoryx2_bb_container::shm_heap::ShmPayload;
 use iceoryx2_cal::shm_allocator::PointerOffset;
 
 use crate::{
@@ -337,6 +388,53 @@ impl<Service: crate::service::Service, Payload: Debug, UserHeader>
     }
 }
 
+impl<Service: crate::service::Service, Payload: Debug, UserHeader, const HEAP_SIZE: usize>
+    SampleMutUninit<Service, MaybeUninit<ShmPayload<Payload, HEAP_SIZE>>, UserHeader>
+{
+    /// Writes the payload together with an empty [`iceoryx2_bb_container::shm_heap::ShmHeap`]
+    /// to the sample and labels the sample as initialized. The heap is initialized in place
+    /// inside the sample, therefore the sample owns the memory of all dynamically sized
+    /// containers of the payload, like [`iceoryx2_bb_container::shm_vec::ShmVec`], and
+    /// releases it when the sample is released.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2_bb_container::shm_heap::ShmPayload;
+    /// use iceoryx2_bb_container::shm_vec::ShmVec;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// const HEAP_SIZE: usize = 1024;
+    /// let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<ShmPayload<ShmVec<u64>, HEAP_SIZE>>()
+    ///     .open_or_create()?;
+    /// #
+    /// # let publisher = service.publisher_builder().create()?;
+    ///
+    /// let sample = publisher.loan_uninit()?;
+    /// let mut sample = sample.write_shm_payload(ShmVec::new());
+    ///
+    /// let (readings, heap) = sample.payload_mut().split_mut();
+    /// // the vector stays in the sample together with the heap
+    /// unsafe { readings.extend_from_slice(heap, &[1, 2, 3]).expect("heap has enough memory") };
+    ///
+    /// sample.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_shm_payload(
+        mut self,
+        value: Payload,
+    ) -> SampleMut<Service, ShmPayload<Payload, HEAP_SIZE>, UserHeader> {
+        unsafe { ShmPayload::placement_new(self.payload_mut().as_mut_ptr(), value) };
+        // SAFETY: this is safe since the payload and the heap were initialized on the line above
+        unsafe { self.assume_init() }
+    }
+}
+
 impl<Service: crate::service::Service, Payload: Debug, UserHeader>
     SampleMutUninit<Service, [MaybeUninit<Payload>], UserHeader>
 {
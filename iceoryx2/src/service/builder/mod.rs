@@ -14,18 +14,32 @@
 //!
 //! See [`crate::service`]
 
+/// Builder for [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+pub mod blackboard;
+
 /// Builder for [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
 pub mod event;
 
+/// Builder for [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+pub mod pipeline;
+
 /// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
 pub mod publish_subscribe;
 
+/// Builder for [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// services that serialize their payload
+pub mod publish_subscribe_serialized;
+
+/// Builder for [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+pub mod request_response;
+
 use crate::node::SharedNode;
 use crate::service;
 use crate::service::dynamic_config::DynamicConfig;
 use crate::service::dynamic_config::RegisterNodeResult;
 use crate::service::static_config::*;
 use iceoryx2_bb_elementary::enum_gen;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::fail;
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
@@ -112,7 +126,7 @@ impl<S: Service> Builder<S> {
 
     /// Create a new builder to create a
     /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`].
-    pub fn publish_subscribe<PayloadType: Debug + ?Sized>(
+    pub fn publish_subscribe<PayloadType: Debug + ZeroCopySend + ?Sized>(
         self,
     ) -> publish_subscribe::Builder<PayloadType, (), S> {
         BuilderWithServiceType::new(
@@ -125,6 +139,77 @@ impl<S: Service> Builder<S> {
         .publish_subscribe()
     }
 
+    /// Create a new builder to create a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) [`Service`]
+    /// that serializes its payload. Suitable for payload types that cannot be shared via
+    /// shared memory, like types that own heap memory.
+    pub fn publish_subscribe_serialized<
+        Payload: Debug + serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        self,
+    ) -> publish_subscribe_serialized::Builder<Payload, publish_subscribe_serialized::Postcard, S>
+    {
+        publish_subscribe_serialized::Builder::new(self.publish_subscribe::<[u8]>())
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) [`Service`].
+    pub fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+        self,
+    ) -> request_response::Builder<RequestPayload, ResponsePayload, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_request_response::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .request_response()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) [`Service`].
+    pub fn pipeline<Payload: Debug>(self) -> pipeline::Builder<Payload, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_pipeline::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .pipeline()
+    }
+
+    /// Create a new builder to create a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard_creator<KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static>(
+        self,
+    ) -> blackboard::Creator<KeyType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .blackboard_creator::<KeyType>()
+    }
+
+    /// Create a new builder to open a
+    /// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) [`Service`].
+    pub fn blackboard_opener<KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static>(
+        self,
+    ) -> blackboard::Opener<KeyType, S> {
+        BuilderWithServiceType::new(
+            StaticConfig::new_blackboard::<S::ServiceNameHasher>(
+                &self.name,
+                self.shared_node.config(),
+            ),
+            self.shared_node,
+        )
+        .blackboard_opener::<KeyType>()
+    }
+
     /// Create a new builder to create a
     /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) [`Service`].
     pub fn event(self) -> event::Builder<S> {
@@ -159,10 +244,32 @@ impl<ServiceType: service::Service> BuilderWithServiceType<ServiceType> {
         publish_subscribe::Builder::new(self)
     }
 
+    fn request_response<RequestPayload: Debug, ResponsePayload: Debug>(
+        self,
+    ) -> request_response::Builder<RequestPayload, ResponsePayload, ServiceType> {
+        request_response::Builder::new(self)
+    }
+
+    fn pipeline<Payload: Debug>(self) -> pipeline::Builder<Payload, ServiceType> {
+        pipeline::Builder::new(self)
+    }
+
     fn event(self) -> event::Builder<ServiceType> {
         event::Builder::new(self)
     }
 
+    fn blackboard_creator<KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static>(
+        self,
+    ) -> blackboard::Creator<KeyType, ServiceType> {
+        blackboard::Creator::new(self)
+    }
+
+    fn blackboard_opener<KeyType: Send + Sync + Eq + Copy + Debug + ZeroCopySend + 'static>(
+        self,
+    ) -> blackboard::Opener<KeyType, ServiceType> {
+        blackboard::Opener::new(self)
+    }
+
     fn is_service_available(
         &self,
         msg: &str,
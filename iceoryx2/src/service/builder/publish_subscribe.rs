@@ -15,7 +15,9 @@
 //! See [`crate::service`]
 //!
 use std::marker::PhantomData;
+use std::time::Duration;
 
+use crate::port::event_id::EventId;
 use crate::service;
 use crate::service::dynamic_config::publish_subscribe::DynamicConfigSettings;
 use crate::service::header::publish_subscribe::Header;
@@ -23,6 +25,7 @@ use crate::service::port_factory::publish_subscribe;
 use crate::service::static_config::messaging_pattern::MessagingPattern;
 use crate::service::*;
 use iceoryx2_bb_elementary::alignment::Alignment;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use iceoryx2_bb_log::{fail, fatal_panic, warn};
 use iceoryx2_cal::dynamic_storage::DynamicStorageCreateError;
 use iceoryx2_cal::serialize::Serialize;
@@ -40,6 +43,9 @@ use super::{OpenDynamicStorageFailure, ServiceState};
 #[doc(hidden)]
 pub struct CustomHeaderMarker {}
 
+// the marker is only a placeholder for a user header whose layout is defined by the user
+unsafe impl ZeroCopySend for CustomHeaderMarker {}
+
 /// Errors that can occur when an existing [`MessagingPattern::PublishSubscribe`] [`Service`] shall be opened.
 #[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
 pub enum PublishSubscribeOpenError {
@@ -67,6 +73,12 @@ pub enum PublishSubscribeOpenError {
     DoesNotSupportRequestedAmountOfNodes,
     /// The [`Service`] required overflow behavior is not compatible.
     IncompatibleOverflowBehavior,
+    /// The [`Service`] has a different publish deadline or deadline missed event than requested.
+    IncompatibleDeadline,
+    /// The [`Service`] has a different liveliness lease or liveliness lost event than requested.
+    IncompatibleLivelinessLease,
+    /// The [`Service`] transfers its payload with a different serializer than requested.
+    IncompatibleSerializer,
     /// The process has not enough permissions to open the [`Service`]
     InsufficientPermissions,
     /// Some underlying resources of the [`Service`] are either missing, corrupted or unaccessible.
@@ -222,6 +234,8 @@ pub struct Builder<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: serv
     verify_publisher_history_size: bool,
     verify_enable_safe_overflow: bool,
     verify_max_nodes: bool,
+    verify_publish_deadline: bool,
+    verify_liveliness_lease: bool,
     _data: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -239,6 +253,8 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
             verify_subscriber_max_borrowed_samples: false,
             verify_enable_safe_overflow: false,
             verify_max_nodes: false,
+            verify_publish_deadline: false,
+            verify_liveliness_lease: false,
             override_alignment: None,
             override_payload_type: None,
             override_user_header_type: None,
@@ -296,7 +312,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
     }
 
     /// Sets the user header type of the [`Service`].
-    pub fn user_header<M: Debug>(self) -> Builder<Payload, M, ServiceType> {
+    pub fn user_header<M: Debug + ZeroCopySend>(self) -> Builder<Payload, M, ServiceType> {
         unsafe { core::mem::transmute::<Self, Builder<Payload, M, ServiceType>>(self) }
     }
 
@@ -371,6 +387,56 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         self
     }
 
+    /// If the [`Service`] is created it defines the maximum time that is allowed to pass
+    /// between two [`crate::sample::Sample`]s sent by a [`crate::port::publisher::Publisher`].
+    /// A [`crate::port::subscriber::Subscriber`] can detect the [`crate::port::publisher::Publisher`]s
+    /// that missed the deadline with
+    /// [`Subscriber::missed_deadlines()`](crate::port::subscriber::Subscriber::missed_deadlines()).
+    /// If an existing [`Service`] is opened it requires the service to have the same deadline.
+    pub fn publish_deadline(mut self, value: Duration) -> Self {
+        self.config_details_mut().publish_deadline = Some(value);
+        self.verify_publish_deadline = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`EventId`] that is emitted on the
+    /// [`MessagingPattern::Event`] based [`Service`] with the same name whenever a
+    /// [`crate::port::subscriber::Subscriber`] or another [`crate::port::publisher::Publisher`]
+    /// detects that a [`crate::port::publisher::Publisher`] missed the publish deadline. Every
+    /// [`crate::port::publisher::Publisher`] checks the other ones whenever it sends. The event
+    /// based [`Service`] is created together with the service and exists as long as it exists.
+    /// If an existing [`Service`] is opened it requires the service to have the same event.
+    pub fn deadline_missed_event(mut self, value: EventId) -> Self {
+        self.config_details_mut().deadline_missed_event = Some(value.as_value());
+        self.verify_publish_deadline = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the maximum time that is allowed to pass until
+    /// a [`crate::port::publisher::Publisher`] has to show that it is still alive, either by
+    /// sending a [`crate::sample::Sample`] or with
+    /// [`Publisher::assert_liveliness()`](crate::port::publisher::Publisher::assert_liveliness()).
+    /// If an existing [`Service`] is opened it requires the service to have the same lease.
+    pub fn liveliness_lease(mut self, value: Duration) -> Self {
+        self.config_details_mut().liveliness_lease = Some(value);
+        self.verify_liveliness_lease = true;
+        self
+    }
+
+    /// If the [`Service`] is created it defines the [`EventId`] that is emitted on the
+    /// [`MessagingPattern::Event`] based [`Service`] with the same name whenever a
+    /// [`crate::port::subscriber::Subscriber`] or another [`crate::port::publisher::Publisher`]
+    /// detects that the liveliness lease of a [`crate::port::publisher::Publisher`] has
+    /// expired. Every [`crate::port::publisher::Publisher`] checks the other ones whenever it
+    /// sends or asserts its liveliness. The event based [`Service`] is created together with
+    /// the service and exists as long as it exists.
+    /// If an existing [`Service`] is opened it requires the service to have the same event.
+    pub fn liveliness_lost_event(mut self, value: EventId) -> Self {
+        self.config_details_mut().liveliness_lost_event = Some(value.as_value());
+        self.verify_liveliness_lease = true;
+        self
+    }
+
     /// Validates configuration and overrides the invalid setting with meaningful values.
     fn adjust_attributes_to_meaningful_values(&mut self) {
         let origin = format!("{:?}", self);
@@ -405,6 +471,18 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                 "Setting the maximum amount of nodes to 0 is not supported. Adjust it to 1, the smallest supported value.");
             settings.max_nodes = 1;
         }
+
+        if settings.publish_deadline == Some(Duration::ZERO) {
+            warn!(from origin,
+                "Setting the publish deadline to 0 is not supported. The service is created without a publish deadline.");
+            settings.publish_deadline = None;
+        }
+
+        if settings.liveliness_lease == Some(Duration::ZERO) {
+            warn!(from origin,
+                "Setting the liveliness lease to 0 is not supported. The service is created without a liveliness lease.");
+            settings.liveliness_lease = None;
+        }
     }
 
     fn verify_service_attributes(
@@ -481,6 +559,34 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                                 msg);
         }
 
+        if self.verify_publish_deadline
+            && (existing_settings.publish_deadline != required_settings.publish_deadline
+                || existing_settings.deadline_missed_event
+                    != required_settings.deadline_missed_event)
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleDeadline,
+                                "{} since the service has the publish deadline {:?} with the event {:?} but the deadline {:?} with the event {:?} was requested.",
+                                msg, existing_settings.publish_deadline, existing_settings.deadline_missed_event,
+                                required_settings.publish_deadline, required_settings.deadline_missed_event);
+        }
+
+        if self.verify_liveliness_lease
+            && (existing_settings.liveliness_lease != required_settings.liveliness_lease
+                || existing_settings.liveliness_lost_event
+                    != required_settings.liveliness_lost_event)
+        {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleLivelinessLease,
+                                "{} since the service has the liveliness lease {:?} with the event {:?} but the lease {:?} with the event {:?} was requested.",
+                                msg, existing_settings.liveliness_lease, existing_settings.liveliness_lost_event,
+                                required_settings.liveliness_lease, required_settings.liveliness_lost_event);
+        }
+
+        if existing_settings.serializer != required_settings.serializer {
+            fail!(from self, with PublishSubscribeOpenError::IncompatibleSerializer,
+                                "{} since the service uses the serializer {:?} but the serializer {:?} was requested.",
+                                msg, existing_settings.serializer, required_settings.serializer);
+        }
+
         if self.verify_max_nodes && existing_settings.max_nodes < required_settings.max_nodes {
             fail!(from self, with PublishSubscribeOpenError::DoesNotSupportRequestedAmountOfNodes,
                                 "{} since the service supports only {} nodes but {} are required.",
@@ -490,6 +596,28 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
         Ok(existing_settings.clone())
     }
 
+    // the quality of service events are emitted on the event service with the same name, it is
+    // required only when the service defines at least one of them
+    fn qos_event_builder(
+        &self,
+        settings: &static_config::publish_subscribe::StaticConfig,
+    ) -> Option<super::event::Builder<ServiceType>> {
+        let event_id_max_value = settings
+            .deadline_missed_event
+            .max(settings.liveliness_lost_event)?;
+
+        Some(
+            crate::service::builder::Builder::new(
+                self.base.service_config.name(),
+                self.base.shared_node.clone(),
+            )
+            .event()
+            .max_nodes(settings.max_nodes)
+            .max_notifiers(settings.max_subscribers)
+            .event_id_max_value(event_id_max_value),
+        )
+    }
+
     fn create_impl(
         &mut self,
         attributes: &AttributeSpecifier,
@@ -566,6 +694,15 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                     }
                 };
 
+                // the event service must exist before the service becomes visible, so that
+                // every subscriber can open it
+                let qos_event_service = match self.qos_event_builder(self.config_details()) {
+                    Some(builder) => Some(fail!(from self, when builder.open_or_create(),
+                            with PublishSubscribeCreateError::InternalFailure,
+                            "{} since the event service for the quality of service events could not be created.", msg)),
+                    None => None,
+                };
+
                 self.base.service_config.attributes = attributes.0.clone();
                 let service_config = fail!(from self,
                             when ServiceType::ConfigSerializer::serialize(&self.base.service_config),
@@ -589,6 +726,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         dynamic_config,
                         unlocked_static_details,
                     )),
+                    qos_event_service,
                 ))
             }
             Some(_) => {
@@ -651,6 +789,13 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                         }
                     };
 
+                    let qos_event_service = match self.qos_event_builder(&pub_sub_static_config) {
+                        Some(builder) => Some(fail!(from self, when builder.open(),
+                            with PublishSubscribeOpenError::ServiceInCorruptedState,
+                            "{} since the event service for the quality of service events could not be opened.", msg)),
+                        None => None,
+                    };
+
                     self.base.service_config.messaging_pattern =
                         MessagingPattern::PublishSubscribe(pub_sub_static_config.clone());
 
@@ -665,6 +810,7 @@ impl<Payload: Debug + ?Sized, UserHeader: Debug, ServiceType: service::Service>
                             dynamic_config,
                             static_storage,
                         )),
+                        qos_event_service,
                     ));
                 }
             }
@@ -722,6 +868,12 @@ impl<UserHeader: Debug, ServiceType: service::Service> Builder<[u8], UserHeader,
         self.override_payload_type = Some(value.clone());
         self
     }
+
+    #[doc(hidden)]
+    pub fn __internal_set_serializer_name(mut self, value: &str) -> Self {
+        self.config_details_mut().serializer = Some(value.to_string());
+        self
+    }
 }
 
 impl<Payload: Debug + ?Sized, ServiceType: service::Service>
@@ -734,12 +886,17 @@ impl<Payload: Debug + ?Sized, ServiceType: service::Service>
     }
 }
 
-impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
-    Builder<Payload, UserHeader, ServiceType>
+impl<
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Builder<Payload, UserHeader, ServiceType>
 {
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
-            MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::FixedSize);
+            MessageTypeDetails::from_zero_copy_send::<Header, UserHeader, Payload>(
+                TypeVariant::FixedSize,
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
@@ -826,12 +983,17 @@ impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
     }
 }
 
-impl<Payload: Debug, UserHeader: Debug, ServiceType: service::Service>
-    Builder<[Payload], UserHeader, ServiceType>
+impl<
+        Payload: Debug + ZeroCopySend,
+        UserHeader: Debug + ZeroCopySend,
+        ServiceType: service::Service,
+    > Builder<[Payload], UserHeader, ServiceType>
 {
     fn prepare_config_details(&mut self) {
         self.config_details_mut().message_type_details =
-            MessageTypeDetails::from::<Header, UserHeader, Payload>(TypeVariant::Dynamic);
+            MessageTypeDetails::from_zero_copy_send::<Header, UserHeader, Payload>(
+                TypeVariant::Dynamic,
+            );
 
         if let Some(details) = &self.override_payload_type {
             self.config_details_mut().message_type_details.payload = details.clone();
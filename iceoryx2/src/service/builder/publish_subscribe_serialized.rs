@@ -0,0 +1,303 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::builder::publish_subscribe_serialized::Bincode;
+//!
+//! #[derive(Debug, serde::Serialize, serde::Deserialize)]
+//! struct Message {
+//!     text: String,
+//!     values: Vec<u64>,
+//! }
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<Message>()
+//!     // the default serializer is Postcard
+//!     .serializer::<Bincode>()
+//!     .max_publishers(2)
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder().create()?;
+//! let subscriber = service.subscriber_builder().create()?;
+//!
+//! publisher.send(&Message { text: "hello".to_string(), values: vec![1, 2, 3] })?;
+//!
+//! if let Some(message) = subscriber.receive()? {
+//!     println!("received: {:?}", message);
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+
+use crate::port::event_id::EventId;
+use crate::service;
+use crate::service::builder::publish_subscribe;
+use crate::service::port_factory::publish_subscribe_serialized;
+use crate::service::*;
+
+use self::attribute::{AttributeSpecifier, AttributeVerifier};
+use self::message_type_details::{TypeDetail, TypeVariant};
+
+use super::publish_subscribe::{
+    PublishSubscribeCreateError, PublishSubscribeOpenError, PublishSubscribeOpenOrCreateError,
+};
+
+pub use iceoryx2_cal::serialize::{
+    bincode::Bincode, cdr::Cdr, postcard::Postcard, toml::Toml, Serialize,
+};
+
+/// Builder to create new [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based [`Service`]s that transfer payloads which are not suitable for shared memory, like
+/// types that own heap memory. The payload is serialized with the [`Serialize`] implementation,
+/// copied into a loaned byte slice and deserialized on reception.
+///
+/// The serializer is stored in the static configuration of the [`Service`], therefore only
+/// participants that use the same serializer can open it.
+///
+/// # Example
+///
+/// See [`crate::service::builder::publish_subscribe_serialized`]
+pub struct Builder<
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+    ServiceType: service::Service,
+> {
+    builder: publish_subscribe::Builder<[u8], (), ServiceType>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+        ServiceType: service::Service,
+    > Debug for Builder<Payload, Serializer, ServiceType>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Builder<{}, {}, {}> {{ builder: {:?} }}",
+            core::any::type_name::<Payload>(),
+            Serializer::NAME,
+            core::any::type_name::<ServiceType>(),
+            self.builder
+        )
+    }
+}
+
+impl<
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+        ServiceType: service::Service,
+    > Builder<Payload, Serializer, ServiceType>
+{
+    pub(crate) fn new(builder: publish_subscribe::Builder<[u8], (), ServiceType>) -> Self {
+        Self {
+            builder,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    fn map(
+        self,
+        f: impl FnOnce(
+            publish_subscribe::Builder<[u8], (), ServiceType>,
+        ) -> publish_subscribe::Builder<[u8], (), ServiceType>,
+    ) -> Self {
+        Self::new(f(self.builder))
+    }
+
+    /// Sets the [`Serialize`] implementation that transfers the payload. If an existing
+    /// [`Service`] is opened it requires the service to use the same serializer.
+    pub fn serializer<S: Serialize>(self) -> Builder<Payload, S, ServiceType> {
+        Builder::new(self.builder)
+    }
+
+    /// If the [`Service`] is created, defines the overflow behavior of the service. If an existing
+    /// [`Service`] is opened it requires the service to have the defined overflow behavior.
+    pub fn enable_safe_overflow(self, value: bool) -> Self {
+        self.map(|b| b.enable_safe_overflow(value))
+    }
+
+    /// If the [`Service`] is created it defines how many samples a
+    /// [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber) can
+    /// borrow at most in parallel. If an existing [`Service`] is opened it defines the minimum
+    /// required.
+    pub fn subscriber_max_borrowed_samples(self, value: usize) -> Self {
+        self.map(|b| b.subscriber_max_borrowed_samples(value))
+    }
+
+    /// If the [`Service`] is created it defines the maximum history size a
+    /// [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber) can
+    /// request on connection. If an existing [`Service`] is opened it defines the minimum
+    /// required.
+    pub fn history_size(self, value: usize) -> Self {
+        self.map(|b| b.history_size(value))
+    }
+
+    /// If the [`Service`] is created it defines how many samples a
+    /// [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber) can
+    /// store in its internal buffer. If an existing [`Service`] is opened it defines the minimum
+    /// required.
+    pub fn subscriber_max_buffer_size(self, value: usize) -> Self {
+        self.map(|b| b.subscriber_max_buffer_size(value))
+    }
+
+    /// If the [`Service`] is created it defines how many
+    /// [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber)s shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many must be at
+    /// least supported.
+    pub fn max_subscribers(self, value: usize) -> Self {
+        self.map(|b| b.max_subscribers(value))
+    }
+
+    /// If the [`Service`] is created it defines how many
+    /// [`SerializedPublisher`](crate::port::serialized_publisher::SerializedPublisher)s shall
+    /// be supported at most. If an existing [`Service`] is opened it defines how many must be at
+    /// least supported.
+    pub fn max_publishers(self, value: usize) -> Self {
+        self.map(|b| b.max_publishers(value))
+    }
+
+    /// If the [`Service`] is created it defines how many [`Node`](crate::node::Node)s shall
+    /// be able to open it in parallel. If an existing [`Service`] is opened it defines how many
+    /// [`Node`](crate::node::Node)s must be at least supported.
+    pub fn max_nodes(self, value: usize) -> Self {
+        self.map(|b| b.max_nodes(value))
+    }
+
+    /// See [`publish_subscribe::Builder::publish_deadline()`].
+    pub fn publish_deadline(self, value: Duration) -> Self {
+        self.map(|b| b.publish_deadline(value))
+    }
+
+    /// See [`publish_subscribe::Builder::deadline_missed_event()`].
+    pub fn deadline_missed_event(self, value: EventId) -> Self {
+        self.map(|b| b.deadline_missed_event(value))
+    }
+
+    /// See [`publish_subscribe::Builder::liveliness_lease()`].
+    pub fn liveliness_lease(self, value: Duration) -> Self {
+        self.map(|b| b.liveliness_lease(value))
+    }
+
+    /// See [`publish_subscribe::Builder::liveliness_lost_event()`].
+    pub fn liveliness_lost_event(self, value: EventId) -> Self {
+        self.map(|b| b.liveliness_lost_event(value))
+    }
+
+    fn prepare_builder(self) -> publish_subscribe::Builder<[u8], (), ServiceType> {
+        // the payload is transferred as byte slice, the type name identifies the actual
+        // type since its memory layout is irrelevant after serialization
+        let payload_type = TypeDetail {
+            variant: TypeVariant::Dynamic,
+            type_name: core::any::type_name::<Payload>().to_string(),
+            size: core::mem::size_of::<u8>(),
+            alignment: core::mem::align_of::<u8>(),
+            layout_hash: None,
+        };
+
+        unsafe {
+            self.builder
+                .__internal_set_payload_type_details(&payload_type)
+                .__internal_set_serializer_name(Serializer::NAME)
+        }
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created.
+    pub fn open_or_create(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        self.open_or_create_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// If the [`Service`] exists, it will be opened otherwise a new [`Service`] will be
+    /// created. It defines a set of attributes. If the [`Service`] already exists all attribute
+    /// requirements must be satisfied otherwise the open process will fail. If the [`Service`]
+    /// does not exist the required attributes will be defined in the [`Service`].
+    pub fn open_or_create_with_attributes(
+        self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenOrCreateError,
+    > {
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            self.prepare_builder()
+                .open_or_create_with_attributes(attributes)?,
+        ))
+    }
+
+    /// Opens an existing [`Service`].
+    pub fn open(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenError,
+    > {
+        self.open_with_attributes(&AttributeVerifier::new())
+    }
+
+    /// Opens an existing [`Service`] with attribute requirements. If the defined attribute
+    /// requirements are not satisfied the open process will fail.
+    pub fn open_with_attributes(
+        self,
+        attributes: &AttributeVerifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeOpenError,
+    > {
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            self.prepare_builder().open_with_attributes(attributes)?,
+        ))
+    }
+
+    /// Creates a new [`Service`].
+    pub fn create(
+        self,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeCreateError,
+    > {
+        self.create_with_attributes(&AttributeSpecifier::new())
+    }
+
+    /// Creates a new [`Service`] with a set of attributes.
+    pub fn create_with_attributes(
+        self,
+        attributes: &AttributeSpecifier,
+    ) -> Result<
+        publish_subscribe_serialized::PortFactory<ServiceType, Payload, Serializer>,
+        PublishSubscribeCreateError,
+    > {
+        Ok(publish_subscribe_serialized::PortFactory::new(
+            self.prepare_builder().create_with_attributes(attributes)?,
+        ))
+    }
+}
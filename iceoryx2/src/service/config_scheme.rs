@@ -66,6 +66,15 @@ pub(crate) fn data_segment_config<Service: crate::service::Service>(
         .path_hint(global_config.global.root_path())
 }
 
+pub(crate) fn blackboard_mgmt_config<Service: crate::service::Service>(
+    global_config: &config::Config,
+) -> <Service::BlackboardMgmt as NamedConceptMgmt>::Configuration {
+    <<Service::BlackboardMgmt as NamedConceptMgmt>::Configuration>::default()
+        .prefix(&global_config.global.prefix)
+        .suffix(&global_config.global.service.blackboard_mgmt_suffix)
+        .path_hint(global_config.global.root_path())
+}
+
 pub(crate) fn node_monitoring_config<Service: crate::service::Service>(
     global_config: &config::Config,
 ) -> <Service::Monitoring as NamedConceptMgmt>::Configuration {
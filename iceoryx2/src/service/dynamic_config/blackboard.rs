@@ -0,0 +1,245 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Examples
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let blackboard = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .blackboard_creator::<u64>()
+//!     .add::<i32>(0, 0)
+//!     .create()?;
+//!
+//! println!("number of active readers:     {:?}", blackboard.dynamic_config().number_of_readers());
+//! println!("number of active writers:     {:?}", blackboard.dynamic_config().number_of_writers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_container::byte_string::FixedSizeByteString;
+use iceoryx2_bb_container::vec::RelocatableVec;
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::{
+    node::NodeId,
+    port::port_identifiers::{UniquePortId, UniqueReaderId, UniqueWriterId},
+};
+
+use super::PortCleanupAction;
+
+/// The maximum supported length of the type name of a blackboard value. Longer type names
+/// are truncated.
+pub(crate) const MAX_VALUE_TYPE_NAME_LENGTH: usize = 256;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_readers: usize,
+    pub number_of_writers: usize,
+}
+
+/// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Blackboard`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) readers: Container<ReaderDetails>,
+    pub(crate) writers: Container<WriterDetails>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ReaderDetails {
+    pub(crate) reader_id: UniqueReaderId,
+    pub(crate) node_id: NodeId,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WriterDetails {
+    pub(crate) writer_id: UniqueWriterId,
+    pub(crate) node_id: NodeId,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            readers: unsafe { Container::new_uninit(config.number_of_readers) },
+            writers: unsafe { Container::new_uninit(config.number_of_writers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.readers.init(allocator),
+            "This should never happen! Unable to initialize reader port id container.");
+        fatal_panic!(from "blackboard::DynamicConfig::init",
+            when self.writers.init(allocator),
+            "This should never happen! Unable to initialize writer port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ReaderDetails>::memory_size(config.number_of_readers)
+            + Container::<WriterDetails>::memory_size(config.number_of_writers)
+    }
+
+    /// Returns the how many [`crate::port::reader::Reader`] ports are currently connected.
+    pub fn number_of_readers(&self) -> usize {
+        self.readers.len()
+    }
+
+    /// Returns the how many [`crate::port::writer::Writer`] ports are currently connected.
+    pub fn number_of_writers(&self) -> usize {
+        self.writers.len()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_reader_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.readers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_writer_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.writers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.readers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_reader| {
+                if registered_reader.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Reader(registered_reader.reader_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_reader_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.writers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_writer| {
+                if registered_writer.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Writer(registered_writer.writer_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_writer_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    pub(crate) fn add_reader_id(&self, id: ReaderDetails) -> Option<ContainerHandle> {
+        unsafe { self.readers.add(id).ok() }
+    }
+
+    pub(crate) fn release_reader_handle(&self, handle: ContainerHandle) {
+        unsafe { self.readers.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_writer_id(&self, id: WriterDetails) -> Option<ContainerHandle> {
+        unsafe { self.writers.add(id).ok() }
+    }
+
+    pub(crate) fn release_writer_handle(&self, handle: ContainerHandle) {
+        unsafe { self.writers.remove(handle, ReleaseMode::Default) };
+    }
+}
+
+/// Describes a single entry of the blackboard. The key and the value are stored in the
+/// supplementary memory of the [`Mgmt`] and are addressed with an offset relative to the
+/// start of the [`Mgmt`] so that every process can access them independent of the address
+/// the memory is mapped to.
+#[derive(Debug, Clone, Copy)]
+#[repr(C)]
+pub(crate) struct EntryDetails {
+    pub(crate) key_offset: u64,
+    pub(crate) value_offset: u64,
+    pub(crate) value_size: usize,
+    pub(crate) value_alignment: usize,
+    pub(crate) value_type_name: FixedSizeByteString<MAX_VALUE_TYPE_NAME_LENGTH>,
+}
+
+impl EntryDetails {
+    pub(crate) fn has_value_type<ValueType>(&self) -> bool {
+        self.value_size == core::mem::size_of::<ValueType>()
+            && self.value_alignment == core::mem::align_of::<ValueType>()
+            && self.value_type_name
+                == FixedSizeByteString::<MAX_VALUE_TYPE_NAME_LENGTH>::from_bytes_truncated(
+                    core::any::type_name::<ValueType>().as_bytes(),
+                )
+    }
+}
+
+/// The shared memory management segment of a
+/// [`crate::service::messaging_pattern::MessagingPattern::Blackboard`] based service. It
+/// contains the details of all entries whereby the keys and the values itself are stored
+/// right behind it in the supplementary memory.
+#[doc(hidden)]
+#[derive(Debug)]
+#[repr(C)]
+pub struct Mgmt {
+    pub(crate) entries: RelocatableVec<EntryDetails>,
+}
+
+unsafe impl Send for Mgmt {}
+unsafe impl Sync for Mgmt {}
+
+impl Mgmt {
+    pub(crate) fn new_uninit(number_of_entries: usize) -> Self {
+        Self {
+            entries: unsafe { RelocatableVec::new_uninit(number_of_entries) },
+        }
+    }
+
+    pub(crate) fn memory_size(number_of_entries: usize) -> usize {
+        RelocatableVec::<EntryDetails>::const_memory_size(number_of_entries)
+    }
+
+    pub(crate) fn base_address(&self) -> usize {
+        (self as *const Self) as usize
+    }
+
+    /// Returns a pointer to the memory position that is `offset` bytes behind the start of
+    /// the [`Mgmt`].
+    pub(crate) fn ptr_from_offset(&self, offset: u64) -> *const u8 {
+        (self.base_address() + offset as usize) as *const u8
+    }
+
+    /// Returns the [`EntryDetails`] of the entry that has the provided key.
+    ///
+    /// # Safety
+    ///
+    ///  * KeyType must be the key type the [`Mgmt`] was created with
+    pub(crate) unsafe fn find_entry<KeyType: Eq>(&self, key: &KeyType) -> Option<&EntryDetails> {
+        self.entries
+            .iter()
+            .find(|e| *(self.ptr_from_offset(e.key_offset) as *const KeyType) == *key)
+    }
+}
@@ -20,6 +20,21 @@ pub mod event;
 /// based service.
 pub mod publish_subscribe;
 
+/// The dynamic service configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service.
+pub mod request_response;
+
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+/// based service.
+pub mod blackboard;
+
+/// The dynamic service configuration of an
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service.
+pub mod pipeline;
+
 use iceoryx2_bb_container::queue::RelocatableContainer;
 use iceoryx2_bb_elementary::CallbackProgression;
 use iceoryx2_bb_lock_free::mpmc::{
@@ -58,6 +73,9 @@ pub(crate) enum RemoveDeadNodeResult {
 pub(crate) enum MessagingPattern {
     PublishSubscribe(publish_subscribe::DynamicConfig),
     Event(event::DynamicConfig),
+    RequestResponse(request_response::DynamicConfig),
+    Blackboard(blackboard::DynamicConfig),
+    Pipeline(pipeline::DynamicConfig),
 }
 
 #[doc(hidden)]
@@ -92,12 +110,15 @@ impl DynamicConfig {
         Container::<NodeId>::memory_size(max_number_of_nodes)
     }
 
-    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
         fatal_panic!(from self, when self.nodes.init(allocator),
             "This should never happen! Unable to initialize NodeId container.");
-        match &self.messaging_pattern {
-            MessagingPattern::PublishSubscribe(ref v) => v.init(allocator),
+        match &mut self.messaging_pattern {
+            MessagingPattern::PublishSubscribe(ref mut v) => v.init(allocator),
             MessagingPattern::Event(ref v) => v.init(allocator),
+            MessagingPattern::RequestResponse(ref v) => v.init(allocator),
+            MessagingPattern::Blackboard(ref v) => v.init(allocator),
+            MessagingPattern::Pipeline(ref v) => v.init(allocator),
         }
     }
 
@@ -113,6 +134,15 @@ impl DynamicConfig {
                 v.remove_dead_node_id(node_id, port_cleanup_callback)
             }
             MessagingPattern::Event(ref v) => v.remove_dead_node_id(node_id, port_cleanup_callback),
+            MessagingPattern::RequestResponse(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Blackboard(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
+            MessagingPattern::Pipeline(ref v) => {
+                v.remove_dead_node_id(node_id, port_cleanup_callback)
+            }
         };
 
         let mut ret_val = Err(RemoveDeadNodeResult::NodeNotRegistered);
@@ -184,4 +214,35 @@ impl DynamicConfig {
             }
         }
     }
+
+    pub(crate) fn request_response(&self) -> &request_response::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::RequestResponse(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access request_response::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn blackboard(&self) -> &blackboard::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Blackboard(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access blackboard::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn pipeline(&self) -> &pipeline::DynamicConfig {
+        match &self.messaging_pattern {
+            MessagingPattern::Pipeline(ref v) => v,
+            m => {
+                fatal_panic!(from self, "This should never happen! Try to access pipeline::DynamicConfig when the messaging pattern is actually {:?}.", m);
+            }
+        }
+    }
+
+    pub(crate) fn is_blackboard(&self) -> bool {
+        matches!(self.messaging_pattern, MessagingPattern::Blackboard(_))
+    }
 }
@@ -23,17 +23,27 @@
 //!
 //! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
 //! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//! println!("statistics:                       {:?}", pubsub.dynamic_config().statistics());
 //! # Ok(())
 //! # }
 //! ```
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+
+use iceoryx2_bb_container::vec::RelocatableVec;
 use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
 use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
 use iceoryx2_bb_log::fatal_panic;
 use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+use iceoryx2_bb_posix::clock::{ClockType, Time};
+use iceoryx2_pal_concurrency_sync::iox_atomic::{IoxAtomicBool, IoxAtomicU64, IoxAtomicU8};
 
 use crate::{
     node::NodeId,
-    port::port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+    port::{
+        port_identifiers::{UniquePortId, UniquePublisherId, UniqueSubscriberId},
+        subscriber::SampleFilter,
+    },
 };
 
 use super::PortCleanupAction;
@@ -50,6 +60,7 @@ pub(crate) struct PublisherDetails {
     pub(crate) node_id: NodeId,
     pub(crate) number_of_samples: usize,
     pub(crate) max_slice_len: usize,
+    pub(crate) max_number_of_segments: u8,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -57,6 +68,338 @@ pub(crate) struct SubscriberDetails {
     pub(crate) subscriber_id: UniqueSubscriberId,
     pub(crate) node_id: NodeId,
     pub(crate) buffer_size: usize,
+    pub(crate) filter: Option<SampleFilter>,
+}
+
+/// Timestamp of the last activities of a [`crate::port::publisher::Publisher`] in nanoseconds
+/// of the system wide monotonic clock. It is stored in the slot of the publisher container
+/// that belongs to the [`crate::port::publisher::Publisher`]. A timestamp of 0 means that
+/// the slot is currently not in use.
+///
+/// It also stores the timestamps of the last activities for which a violation was already
+/// reported, so that every violation is reported only once even when multiple
+/// [`crate::port::subscriber::Subscriber`]s detect it.
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct PublisherActivity {
+    last_delivery: IoxAtomicU64,
+    last_liveliness_assertion: IoxAtomicU64,
+    reported_missed_deadline: IoxAtomicU64,
+    reported_expired_lease: IoxAtomicU64,
+}
+
+impl PublisherActivity {
+    fn new() -> Self {
+        Self {
+            last_delivery: IoxAtomicU64::new(0),
+            last_liveliness_assertion: IoxAtomicU64::new(0),
+            reported_missed_deadline: IoxAtomicU64::new(0),
+            reported_expired_lease: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn reset(&self, timestamp: u64) {
+        self.last_delivery.store(timestamp, Ordering::Relaxed);
+        self.last_liveliness_assertion
+            .store(timestamp, Ordering::Relaxed);
+        self.reported_missed_deadline.store(0, Ordering::Relaxed);
+        self.reported_expired_lease.store(0, Ordering::Relaxed);
+    }
+
+    /// Marks the missed deadline that follows the delivery with the provided timestamp as
+    /// reported. Returns true when the caller is the first one who reports it.
+    pub(crate) fn claim_missed_deadline_report(&self, timestamp: u64) -> bool {
+        Self::claim_report(&self.reported_missed_deadline, timestamp)
+    }
+
+    /// Marks the expired lease that follows the liveliness assertion with the provided
+    /// timestamp as reported. Returns true when the caller is the first one who reports it.
+    pub(crate) fn claim_expired_lease_report(&self, timestamp: u64) -> bool {
+        Self::claim_report(&self.reported_expired_lease, timestamp)
+    }
+
+    fn claim_report(reported: &IoxAtomicU64, timestamp: u64) -> bool {
+        let mut current = reported.load(Ordering::Relaxed);
+        // the activity timestamps are monotonic, an older timestamp was read before the
+        // publisher became active again and must not be reported anymore
+        while current < timestamp {
+            match reported.compare_exchange_weak(
+                current,
+                timestamp,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return true,
+                Err(v) => current = v,
+            }
+        }
+
+        false
+    }
+
+    /// Returns the time that has passed since the last delivered sample. If the slot is not
+    /// in use it returns [`None`].
+    pub(crate) fn time_since_last_delivery(&self) -> Option<(u64, Duration)> {
+        Self::time_since(&self.last_delivery)
+    }
+
+    /// Returns the time that has passed since the publisher has shown that it is alive. If
+    /// the slot is not in use it returns [`None`].
+    pub(crate) fn time_since_last_liveliness_assertion(&self) -> Option<(u64, Duration)> {
+        Self::time_since(&self.last_liveliness_assertion)
+    }
+
+    pub(crate) fn record_delivery(&self) {
+        let now = monotonic_timestamp();
+        self.last_delivery.store(now, Ordering::Relaxed);
+        self.last_liveliness_assertion.store(now, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_liveliness_assertion(&self) {
+        self.last_liveliness_assertion
+            .store(monotonic_timestamp(), Ordering::Relaxed);
+    }
+
+    fn time_since(timestamp: &IoxAtomicU64) -> Option<(u64, Duration)> {
+        match timestamp.load(Ordering::Relaxed) {
+            0 => None,
+            t => Some((
+                t,
+                Duration::from_nanos(monotonic_timestamp().saturating_sub(t)),
+            )),
+        }
+    }
+}
+
+/// Counters of a [`crate::port::publisher::Publisher`] that are stored in the slot of the
+/// publisher container that belongs to the [`crate::port::publisher::Publisher`].
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct PublisherCounters {
+    sent_samples: IoxAtomicU64,
+    overflowed_samples: IoxAtomicU64,
+    loan_failures: IoxAtomicU64,
+    blocked_send_time: IoxAtomicU64,
+}
+
+impl PublisherCounters {
+    fn new() -> Self {
+        Self {
+            sent_samples: IoxAtomicU64::new(0),
+            overflowed_samples: IoxAtomicU64::new(0),
+            loan_failures: IoxAtomicU64::new(0),
+            blocked_send_time: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn reset(&self) {
+        self.sent_samples.store(0, Ordering::Relaxed);
+        self.overflowed_samples.store(0, Ordering::Relaxed);
+        self.loan_failures.store(0, Ordering::Relaxed);
+        self.blocked_send_time.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_sent_sample(&self) {
+        self.sent_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_overflowed_sample(&self) {
+        self.overflowed_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_loan_failure(&self) {
+        self.loan_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_blocked_send_time(&self, value: Duration) {
+        self.blocked_send_time
+            .fetch_add(value.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+/// Counters of a [`crate::port::subscriber::Subscriber`] that are stored in the slot of the
+/// subscriber container that belongs to the [`crate::port::subscriber::Subscriber`]. The
+/// delivered and overflowed samples are recorded by the
+/// [`crate::port::publisher::Publisher`]s.
+#[derive(Debug)]
+#[repr(C)]
+pub(crate) struct SubscriberCounters {
+    delivered_samples: IoxAtomicU64,
+    received_samples: IoxAtomicU64,
+    overflowed_samples: IoxAtomicU64,
+}
+
+impl SubscriberCounters {
+    fn new() -> Self {
+        Self {
+            delivered_samples: IoxAtomicU64::new(0),
+            received_samples: IoxAtomicU64::new(0),
+            overflowed_samples: IoxAtomicU64::new(0),
+        }
+    }
+
+    fn reset(&self) {
+        self.delivered_samples.store(0, Ordering::Relaxed);
+        self.received_samples.store(0, Ordering::Relaxed);
+        self.overflowed_samples.store(0, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_delivered_sample(&self) {
+        self.delivered_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_received_sample(&self) {
+        self.received_samples.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_overflowed_sample(&self) {
+        self.overflowed_samples.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+/// Snapshot of the counters of a [`crate::port::publisher::Publisher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublisherStatistics {
+    publisher_id: UniquePublisherId,
+    node_id: NodeId,
+    sent_samples: u64,
+    overflowed_samples: u64,
+    loan_failures: u64,
+    blocked_send_time: Duration,
+}
+
+impl PublisherStatistics {
+    /// Returns the [`UniquePublisherId`] of the [`crate::port::publisher::Publisher`].
+    pub fn publisher_id(&self) -> UniquePublisherId {
+        self.publisher_id
+    }
+
+    /// Returns the [`NodeId`] of the [`crate::node::Node`] that owns the
+    /// [`crate::port::publisher::Publisher`].
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the number of samples that were sent.
+    pub fn sent_samples(&self) -> u64 {
+        self.sent_samples
+    }
+
+    /// Returns the number of samples that were overridden in the full buffer of a
+    /// [`crate::port::subscriber::Subscriber`] due to a safe overflow.
+    pub fn overflowed_samples(&self) -> u64 {
+        self.overflowed_samples
+    }
+
+    /// Returns the number of loans that failed since the maximum number of loaned samples
+    /// was exceeded or the data segment was out of memory.
+    pub fn loan_failures(&self) -> u64 {
+        self.loan_failures
+    }
+
+    /// Returns the total time the [`crate::port::publisher::Publisher`] was blocked in a send
+    /// call since the buffer of a [`crate::port::subscriber::Subscriber`] was full.
+    pub fn blocked_send_time(&self) -> Duration {
+        self.blocked_send_time
+    }
+}
+
+/// Snapshot of the counters of a [`crate::port::subscriber::Subscriber`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubscriberStatistics {
+    subscriber_id: UniqueSubscriberId,
+    node_id: NodeId,
+    buffer_size: usize,
+    delivered_samples: u64,
+    received_samples: u64,
+    overflowed_samples: u64,
+}
+
+impl SubscriberStatistics {
+    /// Returns the [`UniqueSubscriberId`] of the [`crate::port::subscriber::Subscriber`].
+    pub fn subscriber_id(&self) -> UniqueSubscriberId {
+        self.subscriber_id
+    }
+
+    /// Returns the [`NodeId`] of the [`crate::node::Node`] that owns the
+    /// [`crate::port::subscriber::Subscriber`].
+    pub fn node_id(&self) -> &NodeId {
+        &self.node_id
+    }
+
+    /// Returns the size of the buffer of the [`crate::port::subscriber::Subscriber`].
+    pub fn buffer_size(&self) -> usize {
+        self.buffer_size
+    }
+
+    /// Returns the number of samples that were delivered into the buffer.
+    pub fn delivered_samples(&self) -> u64 {
+        self.delivered_samples
+    }
+
+    /// Returns the number of samples that were taken out of the buffer.
+    pub fn received_samples(&self) -> u64 {
+        self.received_samples
+    }
+
+    /// Returns the number of samples that were overridden in the full buffer due to a safe
+    /// overflow.
+    pub fn overflowed_samples(&self) -> u64 {
+        self.overflowed_samples
+    }
+
+    /// Returns the number of samples that are currently stored in the buffer and wait to be
+    /// received.
+    pub fn pending_samples(&self) -> u64 {
+        self.delivered_samples
+            .saturating_sub(self.received_samples + self.overflowed_samples)
+    }
+}
+
+/// Snapshot of the [`PublisherStatistics`] and [`SubscriberStatistics`] of all ports of a
+/// service. Acquired with [`DynamicConfig::statistics()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Statistics {
+    publishers: Vec<PublisherStatistics>,
+    subscribers: Vec<SubscriberStatistics>,
+}
+
+impl Statistics {
+    /// Returns the [`PublisherStatistics`] of all [`crate::port::publisher::Publisher`]s.
+    pub fn publishers(&self) -> &[PublisherStatistics] {
+        &self.publishers
+    }
+
+    /// Returns the [`SubscriberStatistics`] of all [`crate::port::subscriber::Subscriber`]s.
+    pub fn subscribers(&self) -> &[SubscriberStatistics] {
+        &self.subscribers
+    }
+
+    /// Returns the total number of samples that were sent by all
+    /// [`crate::port::publisher::Publisher`]s.
+    pub fn sent_samples(&self) -> u64 {
+        self.publishers.iter().map(|p| p.sent_samples).sum()
+    }
+
+    /// Returns the total number of samples that were received by all
+    /// [`crate::port::subscriber::Subscriber`]s.
+    pub fn received_samples(&self) -> u64 {
+        self.subscribers.iter().map(|s| s.received_samples).sum()
+    }
+
+    /// Returns the total number of samples that were overridden in the buffers of all
+    /// [`crate::port::subscriber::Subscriber`]s due to a safe overflow.
+    pub fn overflowed_samples(&self) -> u64 {
+        self.subscribers.iter().map(|s| s.overflowed_samples).sum()
+    }
+}
+
+fn monotonic_timestamp() -> u64 {
+    let now = fatal_panic!(from "publish_subscribe::DynamicConfig",
+        when Time::now_with_clock(ClockType::Monotonic),
+        "This should never happen! Unable to acquire the current time of the monotonic clock.");
+    // 0 is reserved for unused slots
+    (now.as_duration().as_nanos() as u64).max(1)
 }
 
 /// The dynamic configuration of an [`crate::service::messaging_pattern::MessagingPattern::Event`]
@@ -65,6 +408,11 @@ pub(crate) struct SubscriberDetails {
 pub struct DynamicConfig {
     pub(crate) subscribers: Container<SubscriberDetails>,
     pub(crate) publishers: Container<PublisherDetails>,
+    publisher_activities: RelocatableVec<PublisherActivity>,
+    publisher_counters: RelocatableVec<PublisherCounters>,
+    publisher_segments: RelocatableVec<IoxAtomicU8>,
+    subscriber_counters: RelocatableVec<SubscriberCounters>,
+    subscriber_notifications: RelocatableVec<IoxAtomicBool>,
 }
 
 impl DynamicConfig {
@@ -72,21 +420,60 @@ impl DynamicConfig {
         Self {
             subscribers: unsafe { Container::new_uninit(config.number_of_subscribers) },
             publishers: unsafe { Container::new_uninit(config.number_of_publishers) },
+            publisher_activities: unsafe {
+                RelocatableVec::new_uninit(config.number_of_publishers)
+            },
+            publisher_counters: unsafe { RelocatableVec::new_uninit(config.number_of_publishers) },
+            publisher_segments: unsafe { RelocatableVec::new_uninit(config.number_of_publishers) },
+            subscriber_counters: unsafe {
+                RelocatableVec::new_uninit(config.number_of_subscribers)
+            },
+            subscriber_notifications: unsafe {
+                RelocatableVec::new_uninit(config.number_of_subscribers)
+            },
         }
     }
 
-    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+    pub(crate) unsafe fn init(&mut self, allocator: &BumpAllocator) {
         fatal_panic!(from self,
             when self.subscribers.init(allocator),
             "This should never happen! Unable to initialize subscriber port id container.");
         fatal_panic!(from self,
             when self.publishers.init(allocator),
             "This should never happen! Unable to initialize publisher port id container.");
+        fatal_panic!(from self,
+            when self.publisher_activities.init(allocator),
+            "This should never happen! Unable to initialize publisher activity vector.");
+        while self.publisher_activities.push(PublisherActivity::new()) {}
+        fatal_panic!(from self,
+            when self.publisher_counters.init(allocator),
+            "This should never happen! Unable to initialize publisher counter vector.");
+        while self.publisher_counters.push(PublisherCounters::new()) {}
+        fatal_panic!(from self,
+            when self.publisher_segments.init(allocator),
+            "This should never happen! Unable to initialize publisher segment vector.");
+        while self.publisher_segments.push(IoxAtomicU8::new(0)) {}
+        fatal_panic!(from self,
+            when self.subscriber_counters.init(allocator),
+            "This should never happen! Unable to initialize subscriber counter vector.");
+        while self.subscriber_counters.push(SubscriberCounters::new()) {}
+        fatal_panic!(from self,
+            when self.subscriber_notifications.init(allocator),
+            "This should never happen! Unable to initialize subscriber notification vector.");
+        while self
+            .subscriber_notifications
+            .push(IoxAtomicBool::new(false))
+        {}
     }
 
     pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
         Container::<SubscriberDetails>::memory_size(config.number_of_subscribers)
             + Container::<PublisherDetails>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<PublisherActivity>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<PublisherCounters>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<IoxAtomicU8>::memory_size(config.number_of_publishers)
+            + RelocatableVec::<SubscriberCounters>::memory_size(config.number_of_subscribers)
+            + RelocatableVec::<IoxAtomicBool>::memory_size(config.number_of_subscribers)
     }
 
     pub(crate) unsafe fn remove_dead_node_id<
@@ -133,6 +520,41 @@ impl DynamicConfig {
         self.subscribers.len()
     }
 
+    /// Returns a snapshot of the [`Statistics`] of all ports that are currently connected.
+    pub fn statistics(&self) -> Statistics {
+        let mut statistics = Statistics::default();
+
+        unsafe { self.publishers.get_state() }.for_each(|handle, details| {
+            let counters = self.publisher_counters(handle);
+            statistics.publishers.push(PublisherStatistics {
+                publisher_id: details.publisher_id,
+                node_id: details.node_id,
+                sent_samples: counters.sent_samples.load(Ordering::Relaxed),
+                overflowed_samples: counters.overflowed_samples.load(Ordering::Relaxed),
+                loan_failures: counters.loan_failures.load(Ordering::Relaxed),
+                blocked_send_time: Duration::from_nanos(
+                    counters.blocked_send_time.load(Ordering::Relaxed),
+                ),
+            });
+            CallbackProgression::Continue
+        });
+
+        unsafe { self.subscribers.get_state() }.for_each(|handle, details| {
+            let counters = self.subscriber_counters(handle.index() as usize);
+            statistics.subscribers.push(SubscriberStatistics {
+                subscriber_id: details.subscriber_id,
+                node_id: details.node_id,
+                buffer_size: details.buffer_size,
+                delivered_samples: counters.delivered_samples.load(Ordering::Relaxed),
+                received_samples: counters.received_samples.load(Ordering::Relaxed),
+                overflowed_samples: counters.overflowed_samples.load(Ordering::Relaxed),
+            });
+            CallbackProgression::Continue
+        });
+
+        statistics
+    }
+
     #[doc(hidden)]
     pub fn __internal_subscriber_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
         let state = unsafe { self.subscribers.get_state() };
@@ -154,7 +576,10 @@ impl DynamicConfig {
     }
 
     pub(crate) fn add_subscriber_id(&self, details: SubscriberDetails) -> Option<ContainerHandle> {
-        unsafe { self.subscribers.add(details).ok() }
+        let handle = unsafe { self.subscribers.add(details).ok()? };
+        self.subscriber_counters(handle.index() as usize).reset();
+        self.subscriber_notifications[handle.index() as usize].store(false, Ordering::SeqCst);
+        Some(handle)
     }
 
     pub(crate) fn release_subscriber_handle(&self, handle: ContainerHandle) {
@@ -162,10 +587,72 @@ impl DynamicConfig {
     }
 
     pub(crate) fn add_publisher_id(&self, details: PublisherDetails) -> Option<ContainerHandle> {
-        unsafe { self.publishers.add(details).ok() }
+        let handle = unsafe { self.publishers.add(details).ok()? };
+        self.publisher_activity(handle).reset(monotonic_timestamp());
+        self.publisher_counters(handle).reset();
+        // the initial data segment is always created before the publisher is registered
+        self.set_number_of_created_segments(handle, 1);
+        Some(handle)
     }
 
     pub(crate) fn release_publisher_handle(&self, handle: ContainerHandle) {
+        self.publisher_activity(handle).reset(0);
         unsafe { self.publishers.remove(handle, ReleaseMode::Default) };
     }
+
+    /// Returns the [`PublisherActivity`] of the publisher that owns the container handle.
+    pub(crate) fn publisher_activity(&self, handle: ContainerHandle) -> &PublisherActivity {
+        &self.publisher_activities[handle.index() as usize]
+    }
+
+    /// Stores how many data segments the publisher that owns the container handle has
+    /// created so far. The segment ids are assigned in ascending order starting with 0 and
+    /// the ids of released segments are reused, therefore the value never decreases.
+    pub(crate) fn set_number_of_created_segments(&self, handle: ContainerHandle, value: u8) {
+        self.publisher_segments[handle.index() as usize].store(value, Ordering::Relaxed);
+    }
+
+    /// Returns the highest number of data segments the publisher with the provided id has used
+    /// or [`None`] if the publisher is not registered.
+    pub(crate) fn number_of_created_segments(
+        &self,
+        publisher_id: &UniquePublisherId,
+    ) -> Option<u8> {
+        let mut number_of_segments = None;
+        unsafe { self.publishers.get_state() }.for_each(|handle, details| {
+            if details.publisher_id == *publisher_id {
+                number_of_segments =
+                    Some(self.publisher_segments[handle.index() as usize].load(Ordering::Relaxed));
+                CallbackProgression::Stop
+            } else {
+                CallbackProgression::Continue
+            }
+        });
+        number_of_segments
+    }
+
+    /// Returns the [`PublisherCounters`] of the publisher that owns the container handle.
+    pub(crate) fn publisher_counters(&self, handle: ContainerHandle) -> &PublisherCounters {
+        &self.publisher_counters[handle.index() as usize]
+    }
+
+    /// Returns the [`SubscriberCounters`] of the subscriber that owns the container slot with
+    /// the provided index. The index of a subscriber slot corresponds to the index of the
+    /// subscriber connection of a [`crate::port::publisher::Publisher`].
+    pub(crate) fn subscriber_counters(&self, index: usize) -> &SubscriberCounters {
+        &self.subscriber_counters[index]
+    }
+
+    /// Requests that the [`crate::port::publisher::Publisher`]s notify the subscriber that
+    /// owns the container handle after every delivered sample.
+    #[cfg(feature = "async")]
+    pub(crate) fn request_sample_notifications(&self, handle: ContainerHandle) {
+        self.subscriber_notifications[handle.index() as usize].store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true when the subscriber that owns the container slot with the provided index
+    /// requested to be notified after every delivered sample.
+    pub(crate) fn wants_sample_notifications(&self, index: usize) -> bool {
+        self.subscriber_notifications[index].load(Ordering::SeqCst)
+    }
 }
@@ -0,0 +1,169 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("number of active clients:         {:?}", req_res.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", req_res.dynamic_config().number_of_servers());
+//! # Ok(())
+//! # }
+//! ```
+use iceoryx2_bb_elementary::relocatable_container::RelocatableContainer;
+use iceoryx2_bb_lock_free::mpmc::{container::*, unique_index_set::ReleaseMode};
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_memory::bump_allocator::BumpAllocator;
+
+use crate::{
+    node::NodeId,
+    port::port_identifiers::{UniqueClientId, UniquePortId, UniqueServerId},
+};
+
+use super::PortCleanupAction;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DynamicConfigSettings {
+    pub number_of_clients: usize,
+    pub number_of_servers: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ClientDetails {
+    pub(crate) client_id: UniqueClientId,
+    pub(crate) node_id: NodeId,
+    pub(crate) number_of_requests: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ServerDetails {
+    pub(crate) server_id: UniqueServerId,
+    pub(crate) node_id: NodeId,
+    pub(crate) number_of_responses: usize,
+}
+
+/// The dynamic configuration of an
+/// [`crate::service::messaging_pattern::MessagingPattern::RequestResponse`]
+/// based service. Contains dynamic parameters like the connected endpoints etc..
+#[derive(Debug)]
+pub struct DynamicConfig {
+    pub(crate) clients: Container<ClientDetails>,
+    pub(crate) servers: Container<ServerDetails>,
+}
+
+impl DynamicConfig {
+    pub(crate) fn new(config: &DynamicConfigSettings) -> Self {
+        Self {
+            clients: unsafe { Container::new_uninit(config.number_of_clients) },
+            servers: unsafe { Container::new_uninit(config.number_of_servers) },
+        }
+    }
+
+    pub(crate) unsafe fn init(&self, allocator: &BumpAllocator) {
+        fatal_panic!(from self,
+            when self.clients.init(allocator),
+            "This should never happen! Unable to initialize client port id container.");
+        fatal_panic!(from self,
+            when self.servers.init(allocator),
+            "This should never happen! Unable to initialize server port id container.");
+    }
+
+    pub(crate) fn memory_size(config: &DynamicConfigSettings) -> usize {
+        Container::<ClientDetails>::memory_size(config.number_of_clients)
+            + Container::<ServerDetails>::memory_size(config.number_of_servers)
+    }
+
+    pub(crate) unsafe fn remove_dead_node_id<
+        PortCleanup: FnMut(UniquePortId) -> PortCleanupAction,
+    >(
+        &self,
+        node_id: &NodeId,
+        mut port_cleanup_callback: PortCleanup,
+    ) {
+        self.clients
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_client| {
+                if registered_client.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Client(registered_client.client_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_client_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+
+        self.servers
+            .get_state()
+            .for_each(|handle: ContainerHandle, registered_server| {
+                if registered_server.node_id == *node_id
+                    && port_cleanup_callback(UniquePortId::Server(registered_server.server_id))
+                        == PortCleanupAction::RemovePort
+                {
+                    self.release_server_handle(handle);
+                }
+                CallbackProgression::Continue
+            });
+    }
+
+    /// Returns how many [`crate::port::client::Client`] ports are currently connected.
+    pub fn number_of_clients(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns how many [`crate::port::server::Server`] ports are currently connected.
+    pub fn number_of_servers(&self) -> usize {
+        self.servers.len()
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_client_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.clients.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    #[doc(hidden)]
+    pub fn __internal_server_owners<F: FnMut(&NodeId)>(&self, mut callback: F) {
+        let state = unsafe { self.servers.get_state() };
+
+        state.for_each(|_, details| {
+            callback(&details.node_id);
+            CallbackProgression::Continue
+        });
+    }
+
+    pub(crate) fn add_client_id(&self, details: ClientDetails) -> Option<ContainerHandle> {
+        unsafe { self.clients.add(details).ok() }
+    }
+
+    pub(crate) fn release_client_handle(&self, handle: ContainerHandle) {
+        unsafe { self.clients.remove(handle, ReleaseMode::Default) };
+    }
+
+    pub(crate) fn add_server_id(&self, details: ServerDetails) -> Option<ContainerHandle> {
+        unsafe { self.servers.add(details).ok() }
+    }
+
+    pub(crate) fn release_server_handle(&self, handle: ContainerHandle) {
+        unsafe { self.servers.remove(handle, ReleaseMode::Default) };
+    }
+}
@@ -32,6 +32,8 @@
 //! ```
 use std::alloc::Layout;
 
+use iceoryx2_bb_posix::clock::Time;
+
 use crate::port::port_identifiers::UniquePublisherId;
 
 /// Sample header used by
@@ -41,6 +43,8 @@ use crate::port::port_identifiers::UniquePublisherId;
 pub struct Header {
     publisher_port_id: UniquePublisherId,
     payload_type_layout: Layout,
+    sequence_number: u64,
+    timestamp: Time,
 }
 
 impl Header {
@@ -48,9 +52,16 @@ impl Header {
         Self {
             publisher_port_id,
             payload_type_layout,
+            sequence_number: 0,
+            timestamp: Time::default(),
         }
     }
 
+    pub(crate) fn set_send_details(&mut self, sequence_number: u64, timestamp: Time) {
+        self.sequence_number = sequence_number;
+        self.timestamp = timestamp;
+    }
+
     /// Returns the [`UniquePublisherId`] of the source [`crate::port::publisher::Publisher`].
     pub fn publisher_id(&self) -> UniquePublisherId {
         self.publisher_port_id
@@ -60,4 +71,18 @@ impl Header {
     pub fn payload_type_layout(&self) -> Layout {
         self.payload_type_layout
     }
+
+    /// Returns the sequence number of the sample. Every
+    /// [`crate::port::publisher::Publisher`] numbers its sent samples consecutively, starting
+    /// with `0`, so that a gap between two received samples of the same publisher indicates
+    /// lost samples.
+    pub fn sequence_number(&self) -> u64 {
+        self.sequence_number
+    }
+
+    /// Returns the [`Time`] at which the sample was sent, acquired from the
+    /// [`ClockType::Monotonic`](iceoryx2_bb_posix::clock::ClockType::Monotonic) clock.
+    pub fn timestamp(&self) -> Time {
+        self.timestamp
+    }
 }
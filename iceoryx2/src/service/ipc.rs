@@ -34,7 +34,7 @@
 
 use std::sync::Arc;
 
-use crate::service::dynamic_config::DynamicConfig;
+use crate::service::dynamic_config::{blackboard, DynamicConfig};
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::*;
 
@@ -50,11 +50,16 @@ impl crate::service::Service for Service {
     type StaticStorage = static_storage::file::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type BlackboardMgmt = dynamic_storage::posix_shared_memory::Storage<blackboard::Mgmt>;
     type ServiceNameHasher = hash::sha1::Sha1;
+    type ShmAllocator = PoolAllocator;
     type SharedMemory = shared_memory::posix::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::posix_shared_memory::Connection;
     type Event = event::unix_datagram_socket::EventImpl;
     type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
     type Reactor = reactor::posix_select::Reactor;
 }
 
@@ -0,0 +1,88 @@
+// Copyright (c) 2023 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::ipc_buddy;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc_buddy::Service>()?;
+//!
+//! // use `ipc_buddy` as communication variant for slices of varying length
+//! let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .open_or_create()?;
+//!
+//! let publisher = service.publisher_builder()
+//!     .max_slice_len(4096)
+//!     .create()?;
+//!
+//! // occupies only a small block of the data segment
+//! let sample = publisher.loan_slice(16)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! See [`Service`](crate::service) for more detailed examples.
+
+use std::sync::Arc;
+
+use crate::service::dynamic_config::{blackboard, DynamicConfig};
+use iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator;
+use iceoryx2_cal::*;
+
+use super::ServiceState;
+
+/// Defines a zero copy inter-process communication setup based on posix mechanisms like
+/// [`ipc::Service`](crate::service::ipc::Service), but the payload memory is managed by a
+/// [`BuddyAllocator`]. A sample occupies only the memory its actual size requires, which
+/// saves memory for slice payloads of varying length.
+///
+/// The communication variants must not be mixed, ports of an
+/// [`ipc::Service`](crate::service::ipc::Service) cannot connect to ports of an
+/// [`ipc_buddy::Service`](crate::service::ipc_buddy::Service).
+#[derive(Debug)]
+pub struct Service {
+    state: Arc<ServiceState<Self>>,
+}
+
+impl crate::service::Service for Service {
+    type StaticStorage = static_storage::file::Storage;
+    type ConfigSerializer = serialize::toml::Toml;
+    type DynamicStorage = dynamic_storage::posix_shared_memory::Storage<DynamicConfig>;
+    type BlackboardMgmt = dynamic_storage::posix_shared_memory::Storage<blackboard::Mgmt>;
+    type ServiceNameHasher = hash::sha1::Sha1;
+    type ShmAllocator = BuddyAllocator;
+    type SharedMemory = shared_memory::posix::Memory<BuddyAllocator>;
+    type Connection = zero_copy_connection::posix_shared_memory::Connection;
+    type Event = event::unix_datagram_socket::EventImpl;
+    type Monitoring = monitoring::file_lock::FileLockMonitoring;
+    #[cfg(target_os = "linux")]
+    type Reactor = reactor::epoll::Reactor;
+    #[cfg(not(target_os = "linux"))]
+    type Reactor = reactor::posix_select::Reactor;
+}
+
+impl crate::service::internal::ServiceInternal<Service> for Service {
+    fn __internal_from_state(state: ServiceState<Self>) -> Self {
+        Self {
+            state: Arc::new(state),
+        }
+    }
+
+    fn __internal_state(&self) -> &Arc<ServiceState<Self>> {
+        &self.state
+    }
+}
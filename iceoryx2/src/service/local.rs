@@ -34,7 +34,7 @@
 
 use std::sync::Arc;
 
-use crate::service::dynamic_config::DynamicConfig;
+use crate::service::dynamic_config::{blackboard, DynamicConfig};
 use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
 use iceoryx2_cal::*;
 
@@ -50,7 +50,9 @@ impl crate::service::Service for Service {
     type StaticStorage = static_storage::process_local::Storage;
     type ConfigSerializer = serialize::toml::Toml;
     type DynamicStorage = dynamic_storage::process_local::Storage<DynamicConfig>;
+    type BlackboardMgmt = dynamic_storage::process_local::Storage<blackboard::Mgmt>;
     type ServiceNameHasher = hash::sha1::Sha1;
+    type ShmAllocator = PoolAllocator;
     type SharedMemory = shared_memory::process_local::Memory<PoolAllocator>;
     type Connection = zero_copy_connection::process_local::Connection;
     //type Event = event::process_local::EventImpl;
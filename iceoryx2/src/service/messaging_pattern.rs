@@ -31,6 +31,27 @@
 //! [`Listener`](crate::port::listener::Listener)s.
 //!
 //! **Note:** This does **not** send or receive POSIX signals nor is it based on them.
+//!
+//! ### Request-Response
+//!
+//! Bi-directional communication where `n` [`Client`](crate::port::client::Client)s send
+//! requests to `m` [`Server`](crate::port::server::Server)s. Every request is answered with
+//! responses that are delivered only to the [`Client`](crate::port::client::Client) that sent
+//! the request.
+//!
+//! ### Blackboard
+//!
+//! A key-value store in shared memory where a single [`Writer`](crate::port::writer::Writer)
+//! updates the values and `m` [`Reader`](crate::port::reader::Reader)s read the latest value
+//! of an entry lock-free. The key type is fixed for the whole service while every entry can
+//! have its own value type.
+//!
+//! ### Pipeline
+//!
+//! Work-queue communication where `n` [`Producer`](crate::port::producer::Producer)s
+//! distribute work items to `m` [`Consumer`](crate::port::consumer::Consumer)s. Every work
+//! item is delivered to exactly one [`Consumer`](crate::port::consumer::Consumer), which
+//! spreads the load across a pool of workers.
 
 /// Identifies the kind of messaging pattern the [`Service`](crate::service::Service) will use.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
@@ -46,4 +67,18 @@ pub enum MessagingPattern {
     /// ability to sleep until a signal/event arrives.
     /// Building block to realize push-notifications.
     Event,
+
+    /// Bidirectional communication pattern where the [`Client`](crate::port::client::Client)
+    /// sends a request to the [`Server`](crate::port::server::Server) and receives the
+    /// corresponding responses.
+    RequestResponse,
+
+    /// Key-value store where the [`Writer`](crate::port::writer::Writer) updates the values
+    /// and the [`Reader`](crate::port::reader::Reader)s always read the latest value of an entry.
+    Blackboard,
+
+    /// Unidirectional communication pattern where the
+    /// [`Producer`](crate::port::producer::Producer) delivers every work item to exactly one
+    /// [`Consumer`](crate::port::consumer::Consumer).
+    Pipeline,
 }
@@ -161,6 +161,10 @@ pub mod local;
 /// A configuration when communicating between different processes using posix mechanisms.
 pub mod ipc;
 
+/// A configuration when communicating between different processes using posix mechanisms
+/// where the payload memory is managed by a buddy allocator.
+pub mod ipc_buddy;
+
 pub(crate) mod config_scheme;
 pub(crate) mod naming_scheme;
 
@@ -171,7 +175,7 @@ use std::time::Duration;
 use crate::config;
 use crate::node::{NodeId, NodeListFailure, NodeState, SharedNode};
 use crate::service::config_scheme::dynamic_config_storage_config;
-use crate::service::dynamic_config::DynamicConfig;
+use crate::service::dynamic_config::{blackboard, DynamicConfig};
 use crate::service::static_config::*;
 use config_scheme::service_tag_config;
 use iceoryx2_bb_container::semantic_string::SemanticString;
@@ -188,7 +192,7 @@ use iceoryx2_cal::named_concept::*;
 use iceoryx2_cal::reactor::Reactor;
 use iceoryx2_cal::serialize::Serialize;
 use iceoryx2_cal::shared_memory::SharedMemory;
-use iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator;
+use iceoryx2_cal::shm_allocator::SampleAllocator;
 use iceoryx2_cal::static_storage::*;
 use iceoryx2_cal::zero_copy_connection::ZeroCopyConnection;
 use service_id::ServiceId;
@@ -261,6 +265,11 @@ impl std::error::Error for ServiceListError {}
 pub struct ServiceDynamicDetails<S: Service> {
     /// A list of all [`Node`](crate::node::Node)s that a registered at the [`Service`]
     pub nodes: Vec<NodeState<S>>,
+    /// The [`Statistics`](dynamic_config::publish_subscribe::Statistics) of all ports when the
+    /// [`Service`] is a
+    /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+    /// based service, otherwise [`None`].
+    pub statistics: Option<dynamic_config::publish_subscribe::Statistics>,
 }
 
 /// Represents all the [`Service`] information that one can acquire with [`Service::list()`].
@@ -318,6 +327,9 @@ impl<S: Service> Drop for ServiceState<S> {
                             self.static_config.name(), id);
                 }
                 DeregisterNodeState::NoMoreOwners => {
+                    if self.dynamic_storage.get().is_blackboard() {
+                        remove_blackboard_mgmt::<S>(id, self.shared_node.config());
+                    }
                     self.static_storage.acquire_ownership();
                     self.dynamic_storage.acquire_ownership();
                     trace!(from origin, "close and remove service: {} ({:?})",
@@ -335,12 +347,16 @@ pub(crate) mod internal {
     use crate::{
         node::NodeId,
         port::{
+            client::{remove_client_from_all_connections, remove_data_segment_of_client},
+            consumer::remove_consumer_from_all_connections,
             listener::remove_connection_of_listener,
             port_identifiers::UniquePortId,
+            producer::{remove_data_segment_of_producer, remove_producer_from_all_connections},
             publisher::{
                 remove_data_segment_of_publisher, remove_publisher_from_all_connections,
                 remove_subscriber_from_all_connections,
             },
+            server::{remove_data_segment_of_server, remove_server_from_all_connections},
         },
     };
 
@@ -385,8 +401,14 @@ pub(crate) mod internal {
                             return PortCleanupAction::SkipPort;
                         }
 
-                        if let Err(e) = unsafe { remove_data_segment_of_publisher::<S>(id, config) }
-                        {
+                        let number_of_segments = dynamic_config
+                            .get()
+                            .publish_subscribe()
+                            .number_of_created_segments(id)
+                            .unwrap_or(1);
+                        if let Err(e) = unsafe {
+                            remove_data_segment_of_publisher::<S>(id, number_of_segments, config)
+                        } {
                             debug!(from origin, "Failed to remove the publishers ({:?}) data segment ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
@@ -400,12 +422,65 @@ pub(crate) mod internal {
                         }
                     }
                     UniquePortId::Notifier(_) => (),
+                    UniquePortId::Reader(_) => (),
+                    UniquePortId::Writer(_) => (),
                     UniquePortId::Listener(ref id) => {
                         if let Err(e) = unsafe { remove_connection_of_listener::<S>(id, config) } {
                             debug!(from origin, "Failed to remove the listeners ({:?}) connection ({:?}).", id, e);
                             return PortCleanupAction::SkipPort;
                         }
                     }
+                    UniquePortId::Client(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_client_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the client ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_client::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the clients ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Server(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_server_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the server ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_server::<S>(id, config) } {
+                            debug!(from origin, "Failed to remove the servers ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Producer(ref id) => {
+                        if let Err(e) =
+                            unsafe { remove_producer_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the producer ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+
+                        if let Err(e) = unsafe { remove_data_segment_of_producer::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the producers ({:?}) data segment ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
+                    UniquePortId::Consumer(ref id) => {
+                        // the work items the consumer still held are reclaimed by the
+                        // producers as soon as they detect that the consumer is gone and
+                        // are then redelivered to the remaining consumers
+                        if let Err(e) =
+                            unsafe { remove_consumer_from_all_connections::<S>(id, config) }
+                        {
+                            debug!(from origin, "Failed to remove the consumer ({:?}) from all of its connections ({:?}).", id, e);
+                            return PortCleanupAction::SkipPort;
+                        }
+                    }
                 };
 
                 debug!(from origin, "Remove port {:?} from service.", port_id);
@@ -433,6 +508,9 @@ pub(crate) mod internal {
                 } {
                     Ok(_) => {
                         debug!(from origin, "Remove unused service.");
+                        if dynamic_config.get().is_blackboard() {
+                            remove_blackboard_mgmt::<S>(service_id, config);
+                        }
                         dynamic_config.acquire_ownership()
                     }
                     Err(e) => {
@@ -467,8 +545,20 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     /// contains for instance all endpoints and other dynamic details.
     type DynamicStorage: DynamicStorage<DynamicConfig>;
 
+    /// Defines the construct used to store the management data of a
+    /// [`MessagingPattern::Blackboard`] based [`Service`], like the key-value entries.
+    type BlackboardMgmt: DynamicStorage<blackboard::Mgmt>;
+
+    /// The allocator that manages the payload memory. [`PoolAllocator`] hands out chunks of
+    /// the maximum sample size, [`BuddyAllocator`] adjusts the chunk size to the actual size
+    /// of the sample which saves memory for slice payloads of varying length.
+    ///
+    /// [`PoolAllocator`]: iceoryx2_cal::shm_allocator::pool_allocator::PoolAllocator
+    /// [`BuddyAllocator`]: iceoryx2_cal::shm_allocator::buddy_allocator::BuddyAllocator
+    type ShmAllocator: SampleAllocator;
+
     /// The memory used to store the payload.
-    type SharedMemory: SharedMemory<PoolAllocator>;
+    type SharedMemory: SharedMemory<Self::ShmAllocator>;
 
     /// The connection used to exchange pointers to the payload
     type Connection: ZeroCopyConnection;
@@ -581,6 +671,26 @@ pub trait Service: Debug + Sized + internal::ServiceInternal<Self> {
     }
 }
 
+fn remove_blackboard_mgmt<S: Service>(service_id: &ServiceId, config: &config::Config) {
+    let origin = "remove_blackboard_mgmt()";
+    match unsafe {
+        <S::BlackboardMgmt as NamedConceptMgmt>::remove_cfg(
+            &service_id.0.into(),
+            &config_scheme::blackboard_mgmt_config::<S>(config),
+        )
+    } {
+        Ok(true) => (),
+        Ok(false) => {
+            debug!(from origin, "The blackboard management segment of the service {:?} was already removed.",
+                service_id);
+        }
+        Err(e) => {
+            warn!(from origin, "Unable to remove the blackboard management segment of the service {:?} ({:?}).",
+                service_id, e);
+        }
+    }
+}
+
 fn details<S: Service>(
     config: &config::Config,
     uuid: &FileName,
@@ -644,7 +754,13 @@ fn details<S: Service>(
             };
             CallbackProgression::Continue
         });
-        Some(ServiceDynamicDetails { nodes })
+        let statistics = match service_config.messaging_pattern() {
+            static_config::messaging_pattern::MessagingPattern::PublishSubscribe(_) => {
+                Some(d.get().publish_subscribe().statistics())
+            }
+            _ => None,
+        };
+        Some(ServiceDynamicDetails { nodes, statistics })
     } else {
         None
     };
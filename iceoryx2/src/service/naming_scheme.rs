@@ -10,9 +10,13 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
-use crate::port::port_identifiers::{UniqueListenerId, UniquePublisherId, UniqueSubscriberId};
+use crate::port::port_identifiers::{
+    UniqueClientId, UniqueConsumerId, UniqueListenerId, UniqueProducerId, UniquePublisherId,
+    UniqueServerId, UniqueSubscriberId,
+};
 use iceoryx2_bb_container::semantic_string::SemanticString;
 use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
 use iceoryx2_bb_system_types::file_name::FileName;
 
 pub(crate) fn event_concept_name(listener_id: &UniqueListenerId) -> FileName {
@@ -52,11 +56,84 @@ pub(crate) fn extract_subscriber_id_from_connection(connection: &FileName) -> Un
 }
 
 pub(crate) fn data_segment_name(publisher_id: &UniquePublisherId) -> FileName {
-    let msg = "The system does not support the required file name length for the publishers data segment.";
-    let origin = "data_segment_name()";
+    port_data_segment_name(&publisher_id.0, "data_segment_name()")
+}
+
+pub(crate) fn resized_data_segment_name(
+    publisher_id: &UniquePublisherId,
+    segment_id: u8,
+) -> FileName {
+    let mut file = data_segment_name(publisher_id);
+    if segment_id != 0 {
+        let msg = "The system does not support the required file name length for the publishers resized data segment.";
+        let origin = "resized_data_segment_name()";
+        fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
+        fatal_panic!(from origin, when file.push_bytes(segment_id.to_string().as_bytes()), "{}", msg);
+    }
+    file
+}
+
+pub(crate) fn client_data_segment_name(client_id: &UniqueClientId) -> FileName {
+    port_data_segment_name(&client_id.0, "client_data_segment_name()")
+}
+
+pub(crate) fn server_data_segment_name(server_id: &UniqueServerId) -> FileName {
+    port_data_segment_name(&server_id.0, "server_data_segment_name()")
+}
 
-    let mut file = fatal_panic!(from origin, when FileName::new(publisher_id.0.pid().to_string().as_bytes()), "{}", msg);
+pub(crate) fn producer_data_segment_name(producer_id: &UniqueProducerId) -> FileName {
+    port_data_segment_name(&producer_id.0, "producer_data_segment_name()")
+}
+
+fn port_data_segment_name(port_id: &UniqueSystemId, origin: &str) -> FileName {
+    let msg =
+        "The system does not support the required file name length for the ports data segment.";
+
+    let mut file = fatal_panic!(from origin, when FileName::new(port_id.pid().to_string().as_bytes()), "{}", msg);
     fatal_panic!(from origin, when file.push(b'_'), "{}", msg);
-    fatal_panic!(from origin, when file.push_bytes(publisher_id.0.value().to_string().as_bytes()), "{}", msg);
+    fatal_panic!(from origin, when file.push_bytes(port_id.value().to_string().as_bytes()), "{}", msg);
     file
 }
+
+pub(crate) fn request_connection_name(
+    client_id: UniqueClientId,
+    server_id: UniqueServerId,
+) -> FileName {
+    let mut file = FileName::new(client_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(server_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+pub(crate) fn response_connection_name(
+    server_id: UniqueServerId,
+    client_id: UniqueClientId,
+) -> FileName {
+    let mut file = FileName::new(server_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(client_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+pub(crate) fn pipeline_connection_name(
+    producer_id: UniqueProducerId,
+    consumer_id: UniqueConsumerId,
+) -> FileName {
+    let mut file = FileName::new(producer_id.0.value().to_string().as_bytes()).unwrap();
+    file.push(b'_').unwrap();
+    file.push_bytes(consumer_id.0.value().to_string().as_bytes())
+        .unwrap();
+    file
+}
+
+/// Returns true when the port id is either the sender or the receiver of the connection.
+pub(crate) fn is_connection_of_port(connection: &FileName, port_id: &UniqueSystemId) -> bool {
+    let name = core::str::from_utf8(connection.as_bytes()).unwrap();
+    let port_id = port_id.value().to_string();
+    match name.find('_') {
+        Some(pos) => name[..pos] == port_id || name[pos + 1..] == port_id,
+        None => false,
+    }
+}
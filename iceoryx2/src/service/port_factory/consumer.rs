@@ -0,0 +1,82 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let consumer = pipeline.consumer_builder()
+//!                     .buffer_size(1)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::consumer::{Consumer, ConsumerCreateError},
+    service,
+};
+
+use super::pipeline::PortFactory;
+
+#[derive(Debug)]
+pub(crate) struct LocalConsumerConfig {
+    pub(crate) buffer_size: Option<usize>,
+}
+
+/// Factory to create a new [`Consumer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryConsumer<'factory, Service: service::Service, Payload: Debug> {
+    config: LocalConsumerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, Payload>,
+}
+
+impl<'factory, Service: service::Service, Payload: Debug>
+    PortFactoryConsumer<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        Self {
+            config: LocalConsumerConfig { buffer_size: None },
+            factory,
+        }
+    }
+
+    /// Defines the required buffer size of the [`Consumer`]. Smallest possible value is `1`.
+    /// A smaller buffer distributes the work more evenly between the [`Consumer`]s.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.config.buffer_size = Some(value.max(1));
+        self
+    }
+
+    /// Creates a new [`Consumer`] or returns a [`ConsumerCreateError`] on failure.
+    pub fn create(self) -> Result<Consumer<Service, Payload>, ConsumerCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Consumer::new(&self.factory.service, self.factory.service.__internal_state().static_config.pipeline(), self.config),
+                "Failed to create new Consumer port."),
+        )
+    }
+}
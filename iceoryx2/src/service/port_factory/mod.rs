@@ -19,6 +19,17 @@ use super::dynamic_config::DynamicConfig;
 use super::service_id::ServiceId;
 use super::{attribute::AttributeSet, service_name::ServiceName};
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard) based
+/// communication and to acquire static and dynamic service information
+pub mod blackboard;
+
+/// Factory to create a [`Client`](crate::port::client::Client)
+pub mod client;
+
+/// Factory to create a [`Consumer`](crate::port::consumer::Consumer)
+pub mod consumer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event) based
 /// communication and to acquire static and dynamic service information
@@ -30,17 +41,50 @@ pub mod listener;
 /// Factory to create a [`Notifier`](crate::port::notifier::Notifier)
 pub mod notifier;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication and to acquire static and dynamic service information
+pub mod pipeline;
+
+/// Factory to create a [`Producer`](crate::port::producer::Producer)
+pub mod producer;
+
 /// Factory to create the endpoints of
 /// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
 /// communication and to acquire static and dynamic service information
 pub mod publish_subscribe;
 
+/// Factory to create the endpoints of
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe) based
+/// communication with serialized payloads and to acquire static and dynamic service information
+pub mod publish_subscribe_serialized;
+
 /// Factory to create a [`Publisher`](crate::port::publisher::Publisher)
 pub mod publisher;
 
+/// Factory to create a [`Reader`](crate::port::reader::Reader)
+pub mod reader;
+
+/// Factory to create the endpoints of
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication and to acquire static and dynamic service information
+pub mod request_response;
+
+/// Factory to create a [`SerializedPublisher`](crate::port::serialized_publisher::SerializedPublisher)
+pub mod serialized_publisher;
+
+/// Factory to create a [`SerializedSubscriber`](crate::port::serialized_subscriber::SerializedSubscriber)
+pub mod serialized_subscriber;
+
+/// Factory to create a [`Server`](crate::port::server::Server)
+pub mod server;
+
 /// Factory to create a [`Subscriber`](crate::port::subscriber::Subscriber)
 pub mod subscriber;
 
+/// Factory to create a [`Writer`](crate::port::writer::Writer)
+pub mod writer;
+
 /// The trait that contains the interface of all port factories for any kind of
 /// [`crate::service::messaging_pattern::MessagingPattern`].
 pub trait PortFactory {
@@ -0,0 +1,168 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! let producer = pipeline.producer_builder()
+//!                     .max_loaned_work_items(6)
+//!                     .delivery_strategy(DeliveryStrategy::FirstFree)
+//!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+use serde::{de::Visitor, Deserialize, Serialize};
+
+use crate::{
+    port::producer::{Producer, ProducerCreateError},
+    service,
+};
+
+use super::{pipeline::PortFactory, publisher::UnableToDeliverStrategy};
+
+/// Defines how the [`Producer`] selects the [`crate::port::consumer::Consumer`] that
+/// receives the next [`crate::work_item_mut::WorkItemMut`]. Every work item is delivered to
+/// exactly one [`crate::port::consumer::Consumer`].
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub enum DeliveryStrategy {
+    /// The [`crate::port::consumer::Consumer`]s are served one after another. When the buffer of
+    /// the next [`crate::port::consumer::Consumer`] is full, the following one is tried.
+    RoundRobin,
+    /// The work item is delivered to the first [`crate::port::consumer::Consumer`] that has
+    /// space left in its buffer.
+    FirstFree,
+}
+
+impl Serialize for DeliveryStrategy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&std::format!("{:?}", self))
+    }
+}
+
+struct DeliveryStrategyVisitor;
+
+impl<'de> Visitor<'de> for DeliveryStrategyVisitor {
+    type Value = DeliveryStrategy;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("a string containing either 'RoundRobin' or 'FirstFree'")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match v {
+            "RoundRobin" => Ok(DeliveryStrategy::RoundRobin),
+            "FirstFree" => Ok(DeliveryStrategy::FirstFree),
+            v => Err(E::custom(format!(
+                "Invalid DeliveryStrategy provided: \"{:?}\".",
+                v
+            ))),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for DeliveryStrategy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(DeliveryStrategyVisitor)
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct LocalProducerConfig {
+    pub(crate) max_loaned_work_items: usize,
+    pub(crate) delivery_strategy: DeliveryStrategy,
+    pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
+}
+
+/// Factory to create a new [`Producer`] port/endpoint for
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryProducer<'factory, Service: service::Service, Payload: Debug> {
+    config: LocalProducerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, Payload>,
+}
+
+impl<'factory, Service: service::Service, Payload: Debug>
+    PortFactoryProducer<'factory, Service, Payload>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload>) -> Self {
+        let defaults = &factory
+            .service
+            .__internal_state()
+            .shared_node
+            .config()
+            .defaults
+            .pipeline;
+
+        Self {
+            config: LocalProducerConfig {
+                max_loaned_work_items: defaults.producer_max_loaned_work_items,
+                delivery_strategy: defaults.delivery_strategy,
+                unable_to_deliver_strategy: defaults.unable_to_deliver_strategy,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::work_item_mut::WorkItemMut`] the [`Producer`] can loan with
+    /// [`Producer::loan()`] or [`Producer::loan_uninit()`] in parallel.
+    pub fn max_loaned_work_items(mut self, value: usize) -> Self {
+        self.config.max_loaned_work_items = value.max(1);
+        self
+    }
+
+    /// Defines how the [`Producer`] selects the [`crate::port::consumer::Consumer`] that
+    /// receives the next work item.
+    pub fn delivery_strategy(mut self, value: DeliveryStrategy) -> Self {
+        self.config.delivery_strategy = value;
+        self
+    }
+
+    /// Defines how the [`Producer`] shall behave when no [`crate::port::consumer::Consumer`]
+    /// has space left in its buffer.
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.config.unable_to_deliver_strategy = value;
+        self
+    }
+
+    /// Creates a new [`Producer`] or returns a [`ProducerCreateError`] on failure.
+    pub fn create(self) -> Result<Producer<Service, Payload>, ProducerCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Producer::new(&self.factory.service, self.factory.service.__internal_state().static_config.pipeline(), self.config),
+                "Failed to create new Producer port."),
+        )
+    }
+}
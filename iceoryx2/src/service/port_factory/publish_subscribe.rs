@@ -51,6 +51,7 @@ use crate::service::service_id::ServiceId;
 use crate::service::service_name::ServiceName;
 use crate::service::{self, dynamic_config, static_config};
 
+use super::event;
 use super::nodes;
 use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 
@@ -62,6 +63,9 @@ use super::{publisher::PortFactoryPublisher, subscriber::PortFactorySubscriber};
 #[derive(Debug)]
 pub struct PortFactory<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug> {
     pub(crate) service: Service,
+    // keeps the event service of the quality of service events alive as long as the
+    // publish subscribe service exists
+    _qos_event_service: Option<event::PortFactory<Service>>,
     _payload: PhantomData<Payload>,
     _user_header: PhantomData<UserHeader>,
 }
@@ -124,14 +128,44 @@ impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
 impl<Service: service::Service, Payload: Debug + ?Sized, UserHeader: Debug>
     PortFactory<Service, Payload, UserHeader>
 {
-    pub(crate) fn new(service: Service) -> Self {
+    pub(crate) fn new(
+        service: Service,
+        qos_event_service: Option<event::PortFactory<Service>>,
+    ) -> Self {
         Self {
             service,
+            _qos_event_service: qos_event_service,
             _payload: PhantomData,
             _user_header: PhantomData,
         }
     }
 
+    /// Returns a snapshot of the
+    /// [`Statistics`](dynamic_config::publish_subscribe::Statistics) of all ports of the
+    /// service, like the number of sent, received and overflowed samples.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let statistics = pubsub.statistics();
+    /// println!("sent samples: {}", statistics.sent_samples());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn statistics(&self) -> dynamic_config::publish_subscribe::Statistics {
+        use crate::service::port_factory::PortFactory;
+        self.dynamic_config().statistics()
+    }
+
     /// Returns a [`PortFactorySubscriber`] to create a new
     /// [`crate::port::subscriber::Subscriber`] port.
     ///
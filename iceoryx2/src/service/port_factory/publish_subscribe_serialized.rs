@@ -0,0 +1,210 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<String>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", pubsub.name());
+//! println!("service id:                       {:?}", pubsub.service_id());
+//! println!("serializer:                       {:?}", pubsub.static_config().serializer());
+//! println!("number of active publishers:      {:?}", pubsub.dynamic_config().number_of_publishers());
+//! println!("number of active subscribers:     {:?}", pubsub.dynamic_config().number_of_subscribers());
+//!
+//! let publisher = pubsub.publisher_builder().create()?;
+//! let subscriber = pubsub.subscriber_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::serialize::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::node::NodeListFailure;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::publish_subscribe;
+use super::serialized_publisher::PortFactorySerializedPublisher;
+use super::serialized_subscriber::PortFactorySerializedSubscriber;
+
+/// The factory for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based services that serialize their payload. It can acquire dynamic and static service
+/// informations and create
+/// [`crate::port::serialized_publisher::SerializedPublisher`]
+/// or [`crate::port::serialized_subscriber::SerializedSubscriber`] ports.
+pub struct PortFactory<
+    Service: service::Service,
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+> {
+    pub(crate) factory: publish_subscribe::PortFactory<Service, [u8], ()>,
+    _payload: PhantomData<Payload>,
+    _serializer: PhantomData<Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for PortFactory<Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactory<{}, {}, {}> {{ factory: {:?} }}",
+            core::any::type_name::<Service>(),
+            core::any::type_name::<Payload>(),
+            Serializer::NAME,
+            self.factory
+        )
+    }
+}
+
+unsafe impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Send for PortFactory<Service, Payload, Serializer>
+{
+}
+unsafe impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Sync for PortFactory<Service, Payload, Serializer>
+{
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > crate::service::port_factory::PortFactory for PortFactory<Service, Payload, Serializer>
+{
+    type Service = Service;
+    type StaticConfig = static_config::publish_subscribe::StaticConfig;
+    type DynamicConfig = dynamic_config::publish_subscribe::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.factory.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.factory.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.factory.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::publish_subscribe::StaticConfig {
+        self.factory.static_config()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::publish_subscribe::DynamicConfig {
+        self.factory.dynamic_config()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        self.factory.nodes(callback)
+    }
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > PortFactory<Service, Payload, Serializer>
+{
+    pub(crate) fn new(factory: publish_subscribe::PortFactory<Service, [u8], ()>) -> Self {
+        Self {
+            factory,
+            _payload: PhantomData,
+            _serializer: PhantomData,
+        }
+    }
+
+    /// See [`publish_subscribe::PortFactory::statistics()`].
+    pub fn statistics(&self) -> dynamic_config::publish_subscribe::Statistics {
+        self.factory.statistics()
+    }
+
+    /// Returns a [`PortFactorySerializedSubscriber`] to create a new
+    /// [`crate::port::serialized_subscriber::SerializedSubscriber`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe_serialized::<Vec<String>>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = pubsub.subscriber_builder().create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn subscriber_builder(
+        &self,
+    ) -> PortFactorySerializedSubscriber<Service, Payload, Serializer> {
+        PortFactorySerializedSubscriber::new(self)
+    }
+
+    /// Returns a [`PortFactorySerializedPublisher`] to create a new
+    /// [`crate::port::serialized_publisher::SerializedPublisher`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe_serialized::<Vec<String>>()
+    ///     .open_or_create()?;
+    ///
+    /// let publisher = pubsub.publisher_builder()
+    ///                     .initial_max_slice_len(256)
+    ///                     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn publisher_builder(
+        &self,
+    ) -> PortFactorySerializedPublisher<Service, Payload, Serializer> {
+        PortFactorySerializedPublisher::new(self)
+    }
+}
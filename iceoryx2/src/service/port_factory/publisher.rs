@@ -53,6 +53,29 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ## Slice API with growing data segment
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe::<[u8]>()
+//!     .open_or_create()?;
+//!
+//! let publisher = pubsub.publisher_builder()
+//!                     .max_slice_len(128)
+//!                     // a larger slice is acquired by mapping an additional data segment
+//!                     .allocation_strategy(AllocationStrategy::PowerOfTwo)
+//!                     .create()?;
+//!
+//! let sample = publisher.loan_slice(1000)?;
+//!
+//! # Ok(())
+//! # }
+//! ```
 
 use std::fmt::Debug;
 
@@ -65,7 +88,7 @@ use crate::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
         publisher::Publisher,
         publisher::PublisherCreateError,
-        DegrationAction, DegrationCallback,
+        DegrationAction, DegrationCallback, OverflowCallback,
     },
     service,
 };
@@ -125,12 +148,30 @@ impl<'de> Deserialize<'de> for UnableToDeliverStrategy {
     }
 }
 
+/// Defines how the data segment of a [`Publisher`] grows when a slice is loaned that exceeds
+/// the current maximum slice length.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy)]
+pub enum AllocationStrategy {
+    /// The new maximum slice length is the next power of two that is greater or equal
+    /// to the requested slice length.
+    PowerOfTwo,
+    /// The new maximum slice length is exactly the requested slice length.
+    BestFit,
+    /// The data segment never grows. Loaning a slice that exceeds the maximum slice length
+    /// fails.
+    #[default]
+    Static,
+}
+
 #[derive(Debug)]
 pub(crate) struct LocalPublisherConfig {
     pub(crate) max_loaned_samples: usize,
     pub(crate) unable_to_deliver_strategy: UnableToDeliverStrategy,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) overflow_callback: Option<OverflowCallback<'static>>,
     pub(crate) max_slice_len: usize,
+    pub(crate) allocation_strategy: AllocationStrategy,
+    pub(crate) max_number_of_segments: u8,
 }
 
 /// Factory to create a new [`Publisher`] port/endpoint for
@@ -154,7 +195,17 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         Self {
             config: LocalPublisherConfig {
                 degration_callback: None,
+                overflow_callback: None,
                 max_slice_len: 1,
+                allocation_strategy: AllocationStrategy::Static,
+                max_number_of_segments: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .publish_subscribe
+                    .publisher_max_number_of_segments,
                 max_loaned_samples: factory
                     .service
                     .__internal_state()
@@ -212,6 +263,25 @@ impl<'factory, Service: service::Service, Payload: Debug + ?Sized, UserHeader: D
         self
     }
 
+    /// Sets the [`OverflowCallback`] of the [`Publisher`]. Whenever the [`Publisher`] overrides
+    /// the oldest sample in the full buffer of a [`crate::port::subscriber::Subscriber`] since
+    /// the service has enabled the safe overflow, this callback is called with the total number
+    /// of samples the [`crate::port::subscriber::Subscriber`] has missed so far.
+    pub fn set_overflow_callback<
+        F: Fn(service::static_config::StaticConfig, UniquePublisherId, UniqueSubscriberId, u64)
+            + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        match callback {
+            Some(c) => self.config.overflow_callback = Some(OverflowCallback::new(c)),
+            None => self.config.overflow_callback = None,
+        }
+
+        self
+    }
+
     /// Creates a new [`Publisher`] or returns a [`PublisherCreateError`] on failure.
     pub fn create(self) -> Result<Publisher<Service, Payload, UserHeader>, PublisherCreateError> {
         let origin = format!("{:?}", self);
@@ -231,4 +301,25 @@ impl<'factory, Service: service::Service, Payload: Debug, UserHeader: Debug>
         self.config.max_slice_len = value;
         self
     }
+
+    /// Defines the [`AllocationStrategy`] that is pursued when the [`Publisher`] loans a slice
+    /// that exceeds the current maximum slice length. With any other strategy than
+    /// [`AllocationStrategy::Static`] the [`Publisher`] maps an additional data segment that
+    /// is large enough and the value set with
+    /// [`PortFactoryPublisher::max_slice_len()`] becomes the initial slice length.
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.config.allocation_strategy = value;
+        self
+    }
+
+    /// Defines how many data segments the [`Publisher`] can use at the same time when it grows
+    /// with an [`AllocationStrategy`] other than [`AllocationStrategy::Static`]. A data segment
+    /// that was replaced by a larger one is released as soon as all of its samples were
+    /// returned and its slot can be reused by the next growth. Every
+    /// [`crate::port::subscriber::Subscriber`] connection reserves resources for the samples
+    /// of all possible data segments, therefore the value should be kept small.
+    pub fn max_number_of_segments(mut self, value: u8) -> Self {
+        self.config.max_number_of_segments = value;
+        self
+    }
 }
@@ -0,0 +1,183 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("name:                             {:?}", req_res.name());
+//! println!("service id:                       {:?}", req_res.service_id());
+//! println!("request type details:             {:?}", req_res.static_config().request_message_type_details());
+//! println!("response type details:            {:?}", req_res.static_config().response_message_type_details());
+//! println!("max clients:                      {:?}", req_res.static_config().max_clients());
+//! println!("max servers:                      {:?}", req_res.static_config().max_servers());
+//! println!("max active requests:              {:?}", req_res.static_config().max_active_requests());
+//! println!("number of active clients:         {:?}", req_res.dynamic_config().number_of_clients());
+//! println!("number of active servers:         {:?}", req_res.dynamic_config().number_of_servers());
+//!
+//! let client = req_res.client_builder().create()?;
+//! let server = req_res.server_builder().create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use iceoryx2_bb_elementary::CallbackProgression;
+use iceoryx2_cal::dynamic_storage::DynamicStorage;
+
+use crate::node::NodeListFailure;
+use crate::service::attribute::AttributeSet;
+use crate::service::service_id::ServiceId;
+use crate::service::service_name::ServiceName;
+use crate::service::{self, dynamic_config, static_config};
+
+use super::nodes;
+use super::{client::PortFactoryClient, server::PortFactoryServer};
+
+/// The factory for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse).
+/// It can acquire dynamic and static service informations and create
+/// [`crate::port::client::Client`]
+/// or [`crate::port::server::Server`] ports.
+#[derive(Debug)]
+pub struct PortFactory<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> {
+    pub(crate) service: Service,
+    _request_payload: PhantomData<RequestPayload>,
+    _response_payload: PhantomData<ResponsePayload>,
+}
+
+unsafe impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Send
+    for PortFactory<Service, RequestPayload, ResponsePayload>
+{
+}
+unsafe impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug> Sync
+    for PortFactory<Service, RequestPayload, ResponsePayload>
+{
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    crate::service::port_factory::PortFactory
+    for PortFactory<Service, RequestPayload, ResponsePayload>
+{
+    type Service = Service;
+    type StaticConfig = static_config::request_response::StaticConfig;
+    type DynamicConfig = dynamic_config::request_response::DynamicConfig;
+
+    fn name(&self) -> &ServiceName {
+        self.service.__internal_state().static_config.name()
+    }
+
+    fn service_id(&self) -> &ServiceId {
+        self.service.__internal_state().static_config.service_id()
+    }
+
+    fn attributes(&self) -> &AttributeSet {
+        self.service.__internal_state().static_config.attributes()
+    }
+
+    fn static_config(&self) -> &static_config::request_response::StaticConfig {
+        self.service
+            .__internal_state()
+            .static_config
+            .request_response()
+    }
+
+    fn dynamic_config(&self) -> &dynamic_config::request_response::DynamicConfig {
+        self.service
+            .__internal_state()
+            .dynamic_storage
+            .get()
+            .request_response()
+    }
+
+    fn nodes<F: FnMut(crate::node::NodeState<Service>) -> CallbackProgression>(
+        &self,
+        callback: F,
+    ) -> Result<(), NodeListFailure> {
+        nodes(
+            self.service.__internal_state().dynamic_storage.get(),
+            self.service.__internal_state().shared_node.config(),
+            callback,
+        )
+    }
+}
+
+impl<Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    PortFactory<Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(service: Service) -> Self {
+        Self {
+            service,
+            _request_payload: PhantomData,
+            _response_payload: PhantomData,
+        }
+    }
+
+    /// Returns a [`PortFactoryClient`] to create a new
+    /// [`crate::port::client::Client`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let client = req_res.client_builder()
+    ///                     .max_loaned_requests(3)
+    ///                     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn client_builder(&self) -> PortFactoryClient<Service, RequestPayload, ResponsePayload> {
+        PortFactoryClient::new(self)
+    }
+
+    /// Returns a [`PortFactoryServer`] to create a new
+    /// [`crate::port::server::Server`] port.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .request_response::<u64, u64>()
+    ///     .open_or_create()?;
+    ///
+    /// let server = req_res.server_builder()
+    ///                     .max_loaned_responses(3)
+    ///                     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn server_builder(&self) -> PortFactoryServer<Service, RequestPayload, ResponsePayload> {
+        PortFactoryServer::new(self)
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! use iceoryx2::service::port_factory::publisher::{AllocationStrategy, UnableToDeliverStrategy};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<String>()
+//!     .open_or_create()?;
+//!
+//! let publisher = pubsub.publisher_builder()
+//!                     .initial_max_slice_len(1024)
+//!                     .allocation_strategy(AllocationStrategy::BestFit)
+//!                     .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::publisher::PublisherCreateError;
+use crate::port::serialized_publisher::SerializedPublisher;
+use crate::port::DegrationAction;
+use crate::service;
+
+use super::publish_subscribe_serialized::PortFactory;
+use super::publisher::{AllocationStrategy, PortFactoryPublisher, UnableToDeliverStrategy};
+
+/// Factory to create a new [`SerializedPublisher`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based communication with serialized payloads.
+///
+/// Since the size of a serialized payload is only known at runtime, the underlying data
+/// segment grows with [`AllocationStrategy::PowerOfTwo`] by default.
+pub struct PortFactorySerializedPublisher<
+    'factory,
+    Service: service::Service,
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+> {
+    builder: PortFactoryPublisher<'factory, Service, [u8], ()>,
+    factory: &'factory PortFactory<Service, Payload, Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for PortFactorySerializedPublisher<'_, Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerializedPublisher {{ builder: {:?}, factory: {:?} }}",
+            self.builder, self.factory
+        )
+    }
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > PortFactorySerializedPublisher<'factory, Service, Payload, Serializer>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload, Serializer>) -> Self {
+        Self {
+            builder: factory
+                .factory
+                .publisher_builder()
+                .allocation_strategy(AllocationStrategy::PowerOfTwo),
+            factory,
+        }
+    }
+
+    /// Defines how many samples the [`SerializedPublisher`] can loan in parallel. Every
+    /// [`SerializedPublisher::send()`] loans one sample that is returned after delivery.
+    pub fn max_loaned_samples(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_loaned_samples(value);
+        self
+    }
+
+    /// Sets the [`UnableToDeliverStrategy`].
+    pub fn unable_to_deliver_strategy(mut self, value: UnableToDeliverStrategy) -> Self {
+        self.builder = self.builder.unable_to_deliver_strategy(value);
+        self
+    }
+
+    /// Sets the initial number of bytes that a serialized payload can occupy. Larger payloads
+    /// grow the underlying data segment depending on the [`AllocationStrategy`].
+    pub fn initial_max_slice_len(mut self, value: usize) -> Self {
+        self.builder = self.builder.max_slice_len(value);
+        self
+    }
+
+    /// Defines the [`AllocationStrategy`] that is pursued when a serialized payload exceeds
+    /// the current maximum slice length. With [`AllocationStrategy::Static`]
+    /// [`SerializedPublisher::send()`] fails for payloads that exceed
+    /// [`PortFactorySerializedPublisher::initial_max_slice_len()`].
+    pub fn allocation_strategy(mut self, value: AllocationStrategy) -> Self {
+        self.builder = self.builder.allocation_strategy(value);
+        self
+    }
+
+    /// Defines how many data segments the [`SerializedPublisher`] can use at the same time to
+    /// fit larger serialized payloads. Released data segments are reused.
+    pub fn max_number_of_segments(mut self, value: u8) -> Self {
+        self.builder = self.builder.max_number_of_segments(value);
+        self
+    }
+
+    /// Sets the [`DegrationCallback`](crate::port::DegrationCallback) of the
+    /// [`SerializedPublisher`]. Whenever a connection to a
+    /// [`crate::port::serialized_subscriber::SerializedSubscriber`] is corrupted or it seems to
+    /// be dead, this callback is called and depending on the returned [`DegrationAction`]
+    /// measures will be taken.
+    pub fn set_degration_callback<
+        F: Fn(
+                service::static_config::StaticConfig,
+                UniquePublisherId,
+                UniqueSubscriberId,
+            ) -> DegrationAction
+            + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        self.builder = self.builder.set_degration_callback(callback);
+        self
+    }
+
+    /// Sets the [`OverflowCallback`](crate::port::OverflowCallback) of the
+    /// [`SerializedPublisher`]. It is called whenever the [`SerializedPublisher`] overrides the
+    /// oldest payload in the full buffer of a
+    /// [`crate::port::serialized_subscriber::SerializedSubscriber`].
+    pub fn set_overflow_callback<
+        F: Fn(service::static_config::StaticConfig, UniquePublisherId, UniqueSubscriberId, u64)
+            + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        self.builder = self.builder.set_overflow_callback(callback);
+        self
+    }
+
+    /// Creates a new [`SerializedPublisher`] or returns a [`PublisherCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<SerializedPublisher<Service, Payload, Serializer>, PublisherCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(SerializedPublisher::new(
+            fail!(from origin, when self.builder.create(),
+                "Failed to create new SerializedPublisher port."),
+        ))
+    }
+}
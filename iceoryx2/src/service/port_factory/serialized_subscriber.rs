@@ -0,0 +1,126 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .publish_subscribe_serialized::<String>()
+//!     .open_or_create()?;
+//!
+//! let subscriber = pubsub.subscriber_builder()
+//!                     .buffer_size(2)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+use iceoryx2_cal::serialize::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::port::port_identifiers::{UniquePublisherId, UniqueSubscriberId};
+use crate::port::serialized_subscriber::SerializedSubscriber;
+use crate::port::subscriber::SubscriberCreateError;
+use crate::port::DegrationAction;
+use crate::service;
+
+use super::publish_subscribe_serialized::PortFactory;
+use super::subscriber::PortFactorySubscriber;
+
+/// Factory to create a new [`SerializedSubscriber`] port/endpoint for
+/// [`MessagingPattern::PublishSubscribe`](crate::service::messaging_pattern::MessagingPattern::PublishSubscribe)
+/// based communication with serialized payloads.
+pub struct PortFactorySerializedSubscriber<
+    'factory,
+    Service: service::Service,
+    Payload: Debug + serde::Serialize + DeserializeOwned,
+    Serializer: Serialize,
+> {
+    builder: PortFactorySubscriber<'factory, Service, [u8], ()>,
+    factory: &'factory PortFactory<Service, Payload, Serializer>,
+}
+
+impl<
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > Debug for PortFactorySerializedSubscriber<'_, Service, Payload, Serializer>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "PortFactorySerializedSubscriber {{ builder: {:?}, factory: {:?} }}",
+            self.builder, self.factory
+        )
+    }
+}
+
+impl<
+        'factory,
+        Service: service::Service,
+        Payload: Debug + serde::Serialize + DeserializeOwned,
+        Serializer: Serialize,
+    > PortFactorySerializedSubscriber<'factory, Service, Payload, Serializer>
+{
+    pub(crate) fn new(factory: &'factory PortFactory<Service, Payload, Serializer>) -> Self {
+        Self {
+            builder: factory.factory.subscriber_builder(),
+            factory,
+        }
+    }
+
+    /// Defines the required buffer size of the [`SerializedSubscriber`]. Smallest possible
+    /// value is `1`.
+    pub fn buffer_size(mut self, value: usize) -> Self {
+        self.builder = self.builder.buffer_size(value);
+        self
+    }
+
+    /// Sets the [`DegrationCallback`](crate::port::DegrationCallback) of the
+    /// [`SerializedSubscriber`]. Whenever a connection to a
+    /// [`crate::port::serialized_publisher::SerializedPublisher`] is corrupted or it seems to
+    /// be dead, this callback is called and depending on the returned [`DegrationAction`]
+    /// measures will be taken.
+    pub fn set_degration_callback<
+        F: Fn(
+                service::static_config::StaticConfig,
+                UniquePublisherId,
+                UniqueSubscriberId,
+            ) -> DegrationAction
+            + 'static,
+    >(
+        mut self,
+        callback: Option<F>,
+    ) -> Self {
+        self.builder = self.builder.set_degration_callback(callback);
+        self
+    }
+
+    /// Creates a new [`SerializedSubscriber`] or returns a [`SubscriberCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<SerializedSubscriber<Service, Payload, Serializer>, SubscriberCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(SerializedSubscriber::new(
+            fail!(from origin, when self.builder.create(),
+                "Failed to create new SerializedSubscriber port."),
+        ))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! let server = req_res.server_builder()
+//!                     .max_loaned_responses(6)
+//!                     .create()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt::Debug;
+
+use iceoryx2_bb_log::fail;
+
+use crate::{
+    port::server::{Server, ServerCreateError},
+    service,
+};
+
+use super::request_response::PortFactory;
+
+#[derive(Debug)]
+pub(crate) struct LocalServerConfig {
+    pub(crate) max_loaned_responses: usize,
+}
+
+/// Factory to create a new [`Server`] port/endpoint for
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse) based
+/// communication.
+#[derive(Debug)]
+pub struct PortFactoryServer<
+    'factory,
+    Service: service::Service,
+    RequestPayload: Debug,
+    ResponsePayload: Debug,
+> {
+    config: LocalServerConfig,
+    pub(crate) factory: &'factory PortFactory<Service, RequestPayload, ResponsePayload>,
+}
+
+impl<'factory, Service: service::Service, RequestPayload: Debug, ResponsePayload: Debug>
+    PortFactoryServer<'factory, Service, RequestPayload, ResponsePayload>
+{
+    pub(crate) fn new(
+        factory: &'factory PortFactory<Service, RequestPayload, ResponsePayload>,
+    ) -> Self {
+        Self {
+            config: LocalServerConfig {
+                max_loaned_responses: factory
+                    .service
+                    .__internal_state()
+                    .shared_node
+                    .config()
+                    .defaults
+                    .request_response
+                    .server_max_loaned_responses,
+            },
+            factory,
+        }
+    }
+
+    /// Defines how many [`crate::response_mut::ResponseMut`] the [`Server`] can loan with
+    /// [`crate::active_request::ActiveRequest::loan()`] or
+    /// [`crate::active_request::ActiveRequest::loan_uninit()`] in parallel.
+    pub fn max_loaned_responses(mut self, value: usize) -> Self {
+        self.config.max_loaned_responses = value.max(1);
+        self
+    }
+
+    /// Creates a new [`Server`] or returns a [`ServerCreateError`] on failure.
+    pub fn create(
+        self,
+    ) -> Result<Server<Service, RequestPayload, ResponsePayload>, ServerCreateError> {
+        let origin = format!("{:?}", self);
+        Ok(
+            fail!(from origin, when Server::new(&self.factory.service, self.factory.service.__internal_state().static_config.request_response(), self.config),
+                "Failed to create new Server port."),
+        )
+    }
+}
@@ -36,7 +36,7 @@ use iceoryx2_bb_log::fail;
 use crate::{
     port::{
         port_identifiers::{UniquePublisherId, UniqueSubscriberId},
-        subscriber::{Subscriber, SubscriberCreateError},
+        subscriber::{SampleFilter, Subscriber, SubscriberCreateError},
         DegrationAction, DegrationCallback,
     },
     service,
@@ -48,6 +48,7 @@ use super::publish_subscribe::PortFactory;
 pub(crate) struct SubscriberConfig {
     pub(crate) buffer_size: Option<usize>,
     pub(crate) degration_callback: Option<DegrationCallback<'static>>,
+    pub(crate) filter: Option<SampleFilter>,
 }
 
 /// Factory to create a new [`Subscriber`] port/endpoint for
@@ -72,6 +73,7 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
             config: SubscriberConfig {
                 buffer_size: None,
                 degration_callback: None,
+                filter: None,
             },
             factory,
         }
@@ -105,6 +107,46 @@ impl<'factory, Service: service::Service, PayloadType: Debug + ?Sized, UserHeade
         self
     }
 
+    /// Sets the [`SampleFilter`] of the [`Subscriber`]. Every
+    /// [`crate::port::publisher::Publisher`] applies the filter to the user header of a sample
+    /// before it is delivered, so that rejected samples neither occupy the buffer of the
+    /// [`Subscriber`] nor block the [`crate::port::publisher::Publisher`]. If the filter
+    /// exceeds the user header, [`PortFactorySubscriber::create()`] fails with
+    /// [`SubscriberCreateError::SampleFilterExceedsUserHeader`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// use iceoryx2::port::subscriber::SampleFilter;
+    ///
+    /// #[derive(Debug, ZeroCopySend)]
+    /// #[repr(C)]
+    /// struct Topic {
+    ///     id: u64,
+    /// }
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// let pubsub = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    ///     .publish_subscribe::<u64>()
+    ///     .user_header::<Topic>()
+    ///     .open_or_create()?;
+    ///
+    /// let subscriber = pubsub
+    ///     .subscriber_builder()
+    ///     // accept only samples whose `Topic::id` at offset 0 is 42
+    ///     .filter(SampleFilter::equals(0, 42u64))
+    ///     .create()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn filter(mut self, filter: SampleFilter) -> Self {
+        self.config.filter = Some(filter);
+        self
+    }
+
     /// Creates a new [`Subscriber`] or returns a [`SubscriberCreateError`] on failure.
     pub fn create(
         self,
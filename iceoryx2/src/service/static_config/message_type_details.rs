@@ -13,6 +13,7 @@
 use std::alloc::Layout;
 
 use iceoryx2_bb_elementary::math::align;
+use iceoryx2_bb_elementary::zero_copy_send::ZeroCopySend;
 use serde::{Deserialize, Serialize};
 
 /// Defines if the type is a slice with a runtime-size ([`TypeVariant::Dynamic`])
@@ -46,13 +47,35 @@ pub enum TypeVariant {
 pub struct TypeDetail {
     /// The [`TypeVariant`] of the type
     pub variant: TypeVariant,
-    /// Contains the output of [`core::any::type_name()`].
+    /// Contains the name that identifies the type, either the output of
+    /// [`ZeroCopySend::type_name()`] or of [`core::any::type_name()`].
     pub type_name: String,
     /// The size of the underlying type calculated by [`core::mem::size_of`].
     pub size: usize,
     /// The ABI-required minimum alignment of the underlying type calculated by [`core::mem::align_of`].
     /// It may be set by users with a larger alignment, e.g. the memory provided by allocator used by SIMD.
     pub alignment: usize,
+    /// The structural layout hash of the type provided by [`ZeroCopySend::layout_hash()`].
+    #[serde(default, with = "layout_hash_serde")]
+    pub layout_hash: Option<u64>,
+}
+
+// the static config is stored as toml which supports only signed 64-bit integers
+mod layout_hash_serde {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub(super) fn serialize<S: Serializer>(
+        value: &Option<u64>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        value.map(|v| v as i64).serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<u64>, D::Error> {
+        Ok(Option::<i64>::deserialize(deserializer)?.map(|v| v as u64))
+    }
 }
 
 impl TypeDetail {
@@ -63,6 +86,34 @@ impl TypeDetail {
             type_name: core::any::type_name::<T>().to_string(),
             size: core::mem::size_of::<T>(),
             alignment: core::mem::align_of::<T>(),
+            layout_hash: None,
+        }
+    }
+
+    /// Creates the [`TypeDetail`] of a [`ZeroCopySend`] type. It uses the
+    /// [`ZeroCopySend::type_name()`] and [`ZeroCopySend::layout_hash()`] of the type so that
+    /// it can be identified independent of its module path.
+    pub fn new<T: ZeroCopySend>(variant: TypeVariant) -> Self {
+        Self {
+            variant,
+            type_name: T::type_name().to_string(),
+            size: core::mem::size_of::<T>(),
+            alignment: core::mem::align_of::<T>(),
+            layout_hash: T::layout_hash(),
+        }
+    }
+
+    /// Returns true when both types are identical. The [`TypeDetail::type_name`]s must be
+    /// equal and when both types provide a [`TypeDetail::layout_hash`] the hashes must be
+    /// equal as well.
+    pub(crate) fn has_same_identity(&self, rhs: &Self) -> bool {
+        if self.type_name != rhs.type_name {
+            return false;
+        }
+
+        match (self.layout_hash, rhs.layout_hash) {
+            (Some(lhs_hash), Some(rhs_hash)) => lhs_hash == rhs_hash,
+            _ => true,
         }
     }
 }
@@ -88,6 +139,16 @@ impl MessageTypeDetails {
         }
     }
 
+    pub(crate) fn from_zero_copy_send<Header, UserHeader: ZeroCopySend, Payload: ZeroCopySend>(
+        payload_variant: TypeVariant,
+    ) -> Self {
+        Self {
+            header: TypeDetail::__internal_new::<Header>(TypeVariant::FixedSize),
+            user_header: TypeDetail::new::<UserHeader>(TypeVariant::FixedSize),
+            payload: TypeDetail::new::<Payload>(payload_variant),
+        }
+    }
+
     pub(crate) fn payload_ptr_from_header(&self, header: *const u8) -> *const u8 {
         let user_header = self.user_header_ptr_from_header(header) as usize;
         let payload_start = align(user_header + self.user_header.size, self.payload.alignment);
@@ -127,11 +188,11 @@ impl MessageTypeDetails {
 
     pub(crate) fn is_compatible_to(&self, rhs: &Self) -> bool {
         self.header == rhs.header
-            && self.user_header.type_name == rhs.user_header.type_name
+            && self.user_header.has_same_identity(&rhs.user_header)
             && self.user_header.variant == rhs.user_header.variant
             && self.user_header.size == rhs.user_header.size
             && self.user_header.alignment <= rhs.user_header.alignment
-            && self.payload.type_name == rhs.payload.type_name
+            && self.payload.has_same_identity(&rhs.payload)
             && self.payload.variant == rhs.payload.variant
             && self.payload.size == rhs.payload.size
             && self.payload.alignment <= rhs.payload.alignment
@@ -164,18 +225,21 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4, // i32 uses 4 bytes, so its aliment is always 4 no matter x32 or x64.
+                layout_hash: None,
             },
             user_header: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail{
                 variant: TypeVariant::FixedSize,
                 type_name: "iceoryx2::service::static_config::message_type_details::tests::test_from::MyPayload".to_string(),
                 size: 16,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -187,18 +251,21 @@ mod tests {
                 type_name: "i32".to_string(),
                 size: 4,
                 alignment: 4,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "bool".to_string(),
                 size: 1,
                 alignment: 1,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::Dynamic,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
         };
         assert_that!(sut, eq expected);
@@ -375,18 +442,21 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
         };
         // smaller to bigger is allowed.
@@ -410,18 +480,21 @@ mod tests {
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: ALIGNMENT,
+                layout_hash: None,
             },
             user_header: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
             payload: TypeDetail {
                 variant: TypeVariant::FixedSize,
                 type_name: "i64".to_string(),
                 size: 8,
                 alignment: 2 * ALIGNMENT,
+                layout_hash: None,
             },
         };
         // bigger to smaller is invalid.
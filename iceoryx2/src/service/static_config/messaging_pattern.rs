@@ -13,8 +13,11 @@
 //! Stores the [`Service`](crate::service::Service) messaging pattern specific static configuration.
 use std::fmt::Display;
 
+use crate::service::static_config::blackboard;
 use crate::service::static_config::event;
+use crate::service::static_config::pipeline;
 use crate::service::static_config::publish_subscribe;
+use crate::service::static_config::request_response;
 use serde::{Deserialize, Serialize};
 
 /// Contains the static config of the corresponding
@@ -30,6 +33,18 @@ pub enum MessagingPattern {
     /// Stores the static config of the
     /// [`service::MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
     Event(event::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+    RequestResponse(request_response::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Blackboard`](crate::service::messaging_pattern::MessagingPattern::Blackboard)
+    Blackboard(blackboard::StaticConfig),
+
+    /// Stores the static config of the
+    /// [`service::MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+    Pipeline(pipeline::StaticConfig),
 }
 
 impl Display for MessagingPattern {
@@ -37,6 +52,9 @@ impl Display for MessagingPattern {
         match self {
             MessagingPattern::Event(_) => write!(f, "Event"),
             MessagingPattern::PublishSubscribe(_) => write!(f, "PublishSubscribe"),
+            MessagingPattern::RequestResponse(_) => write!(f, "RequestResponse"),
+            MessagingPattern::Blackboard(_) => write!(f, "Blackboard"),
+            MessagingPattern::Pipeline(_) => write!(f, "Pipeline"),
         }
     }
 }
@@ -69,8 +87,11 @@ mod tests {
 
     use super::*;
     use crate::service::config;
+    use crate::service::static_config::blackboard;
     use crate::service::static_config::event;
+    use crate::service::static_config::pipeline;
     use crate::service::static_config::publish_subscribe;
+    use crate::service::static_config::request_response;
 
     #[test]
     fn test_is_same_pattern() {
@@ -83,6 +104,9 @@ mod tests {
         let mut new_defaults = config::Defaults {
             publish_subscribe: cfg.defaults.publish_subscribe.clone(),
             event: cfg.defaults.event.clone(),
+            request_response: cfg.defaults.request_response.clone(),
+            blackboard: cfg.defaults.blackboard.clone(),
+            pipeline: cfg.defaults.pipeline.clone(),
         };
         new_defaults.event.event_id_max_value -= 1;
         new_defaults.publish_subscribe.max_nodes -= 1;
@@ -107,8 +131,30 @@ mod tests {
         assert_that!(e1.is_same_pattern(&e3), eq true);
         assert_that!(e2.is_same_pattern(&e3), eq true);
 
+        let r1 = MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg));
+        let r2 = MessagingPattern::RequestResponse(request_response::StaticConfig::new(&cfg2));
+        assert_that!(r1.is_same_pattern(&r2), eq true);
+        assert_that!(r2.is_same_pattern(&r1), eq true);
+
+        let b1 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg));
+        let b2 = MessagingPattern::Blackboard(blackboard::StaticConfig::new(&cfg2));
+        assert_that!(b1.is_same_pattern(&b2), eq true);
+        assert_that!(b2.is_same_pattern(&b1), eq true);
+
+        let l1 = MessagingPattern::Pipeline(pipeline::StaticConfig::new(&cfg));
+        let l2 = MessagingPattern::Pipeline(pipeline::StaticConfig::new(&cfg2));
+        assert_that!(l1.is_same_pattern(&l2), eq true);
+        assert_that!(l2.is_same_pattern(&l1), eq true);
+
         assert_that!(p1.is_same_pattern(&e1), eq false);
         assert_that!(p3.is_same_pattern(&e3), eq false);
+        assert_that!(p1.is_same_pattern(&r1), eq false);
+        assert_that!(e1.is_same_pattern(&r1), eq false);
+        assert_that!(p1.is_same_pattern(&b1), eq false);
+        assert_that!(e1.is_same_pattern(&b1), eq false);
+        assert_that!(r1.is_same_pattern(&b1), eq false);
+        assert_that!(p1.is_same_pattern(&l1), eq false);
+        assert_that!(b1.is_same_pattern(&l1), eq false);
     }
 
     #[test]
@@ -0,0 +1,110 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let pipeline = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .pipeline::<u64>()
+//!     .open_or_create()?;
+//!
+//! println!("type details:                     {:?}", pipeline.static_config().message_type_details());
+//! println!("max producers:                    {:?}", pipeline.static_config().max_producers());
+//! println!("max consumers:                    {:?}", pipeline.static_config().max_consumers());
+//! println!("max nodes:                        {:?}", pipeline.static_config().max_nodes());
+//! println!("consumer max buffer size:         {:?}", pipeline.static_config().consumer_max_buffer_size());
+//! println!("consumer max borrowed work items: {:?}", pipeline.static_config().consumer_max_borrowed_work_items());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::message_type_details::MessageTypeDetails;
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`MessagingPattern::Pipeline`](crate::service::messaging_pattern::MessagingPattern::Pipeline)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_producers: usize,
+    pub(crate) max_consumers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) consumer_max_buffer_size: usize,
+    pub(crate) consumer_max_borrowed_work_items: usize,
+    pub(crate) message_type_details: MessageTypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_producers: config.defaults.pipeline.max_producers,
+            max_consumers: config.defaults.pipeline.max_consumers,
+            max_nodes: config.defaults.pipeline.max_nodes,
+            consumer_max_buffer_size: config.defaults.pipeline.consumer_max_buffer_size,
+            consumer_max_borrowed_work_items: config
+                .defaults
+                .pipeline
+                .consumer_max_borrowed_work_items,
+            message_type_details: MessageTypeDetails::default(),
+        }
+    }
+
+    pub(crate) fn required_amount_of_work_items_per_data_segment(
+        &self,
+        producer_max_loaned_work_items: usize,
+    ) -> usize {
+        // every work item is delivered to exactly one consumer, therefore every consumer can
+        // hold at most its buffer plus its borrowed work items
+        self.max_consumers * (self.consumer_max_buffer_size + self.consumer_max_borrowed_work_items)
+            + producer_max_loaned_work_items
+    }
+
+    /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
+    /// [`Service`](crate::service::Service) in parallel.
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::producer::Producer`] ports
+    pub fn max_producers(&self) -> usize {
+        self.max_producers
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::consumer::Consumer`] ports
+    pub fn max_consumers(&self) -> usize {
+        self.max_consumers
+    }
+
+    /// Returns the maximum supported buffer size for [`crate::work_item::WorkItem`]s of a
+    /// [`crate::port::consumer::Consumer`] port.
+    pub fn consumer_max_buffer_size(&self) -> usize {
+        self.consumer_max_buffer_size
+    }
+
+    /// Returns how many [`crate::work_item::WorkItem`]s a [`crate::port::consumer::Consumer`]
+    /// port can borrow in parallel at most.
+    pub fn consumer_max_borrowed_work_items(&self) -> usize {
+        self.consumer_max_borrowed_work_items
+    }
+
+    /// Returns the type details of the work items of the [`crate::service::Service`].
+    pub fn message_type_details(&self) -> &MessageTypeDetails {
+        &self.message_type_details
+    }
+}
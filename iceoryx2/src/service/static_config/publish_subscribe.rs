@@ -28,13 +28,19 @@
 //! println!("history size:                     {:?}", pubsub.static_config().history_size());
 //! println!("subscriber max borrowed samples:  {:?}", pubsub.static_config().subscriber_max_borrowed_samples());
 //! println!("safe overflow:                    {:?}", pubsub.static_config().has_safe_overflow());
+//! println!("publish deadline:                 {:?}", pubsub.static_config().publish_deadline());
+//! println!("liveliness lease:                 {:?}", pubsub.static_config().liveliness_lease());
+//! println!("serializer:                       {:?}", pubsub.static_config().serializer());
 //!
 //! # Ok(())
 //! # }
 //! ```
 
+use std::time::Duration;
+
 use super::message_type_details::MessageTypeDetails;
 use crate::config;
+use crate::port::event_id::EventId;
 use serde::{Deserialize, Serialize};
 
 /// The static configuration of an
@@ -50,6 +56,11 @@ pub struct StaticConfig {
     pub(crate) subscriber_max_buffer_size: usize,
     pub(crate) subscriber_max_borrowed_samples: usize,
     pub(crate) enable_safe_overflow: bool,
+    pub(crate) publish_deadline: Option<Duration>,
+    pub(crate) deadline_missed_event: Option<usize>,
+    pub(crate) liveliness_lease: Option<Duration>,
+    pub(crate) liveliness_lost_event: Option<usize>,
+    pub(crate) serializer: Option<String>,
     pub(crate) message_type_details: MessageTypeDetails,
 }
 
@@ -69,6 +80,11 @@ impl StaticConfig {
                 .publish_subscribe
                 .subscriber_max_borrowed_samples,
             enable_safe_overflow: config.defaults.publish_subscribe.enable_safe_overflow,
+            publish_deadline: None,
+            deadline_missed_event: None,
+            liveliness_lease: None,
+            liveliness_lost_event: None,
+            serializer: None,
             message_type_details: MessageTypeDetails::default(),
         }
     }
@@ -113,6 +129,45 @@ impl StaticConfig {
         self.enable_safe_overflow
     }
 
+    /// Returns the maximum time that is allowed to pass between two
+    /// [`crate::sample::Sample`]s sent by a [`crate::port::publisher::Publisher`]. If no
+    /// deadline is defined it returns [`None`].
+    pub fn publish_deadline(&self) -> Option<Duration> {
+        self.publish_deadline
+    }
+
+    /// Returns the [`EventId`] that is emitted on the
+    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+    /// based service with the same name when a [`crate::port::subscriber::Subscriber`] detects
+    /// a missed publish deadline.
+    pub fn deadline_missed_event(&self) -> Option<EventId> {
+        self.deadline_missed_event.map(EventId::new)
+    }
+
+    /// Returns the maximum time that is allowed to pass until a
+    /// [`crate::port::publisher::Publisher`] has to show that it is still alive, either by
+    /// sending a [`crate::sample::Sample`] or by asserting its liveliness explicitly. If no
+    /// lease is defined it returns [`None`].
+    pub fn liveliness_lease(&self) -> Option<Duration> {
+        self.liveliness_lease
+    }
+
+    /// Returns the [`EventId`] that is emitted on the
+    /// [`MessagingPattern::Event`](crate::service::messaging_pattern::MessagingPattern::Event)
+    /// based service with the same name when a [`crate::port::subscriber::Subscriber`] detects
+    /// an expired liveliness lease.
+    pub fn liveliness_lost_event(&self) -> Option<EventId> {
+        self.liveliness_lost_event.map(EventId::new)
+    }
+
+    /// Returns the name of the [`Serialize`](iceoryx2_cal::serialize::Serialize) implementation
+    /// that transfers the payload when the [`crate::service::Service`] was created with
+    /// [`Builder::publish_subscribe_serialized()`](crate::service::builder::Builder::publish_subscribe_serialized()),
+    /// otherwise [`None`].
+    pub fn serializer(&self) -> Option<&str> {
+        self.serializer.as_deref()
+    }
+
     /// Returns the type details of the [`crate::service::Service`].
     pub fn message_type_details(&self) -> &MessageTypeDetails {
         &self.message_type_details
@@ -0,0 +1,137 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! let req_res = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//!     .request_response::<u64, u64>()
+//!     .open_or_create()?;
+//!
+//! println!("request type details:             {:?}", req_res.static_config().request_message_type_details());
+//! println!("response type details:            {:?}", req_res.static_config().response_message_type_details());
+//! println!("max clients:                      {:?}", req_res.static_config().max_clients());
+//! println!("max servers:                      {:?}", req_res.static_config().max_servers());
+//! println!("max active requests:              {:?}", req_res.static_config().max_active_requests());
+//! println!("client response buffer size:      {:?}", req_res.static_config().client_max_response_buffer_size());
+//! println!("client max borrowed responses:    {:?}", req_res.static_config().client_max_borrowed_responses());
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use super::message_type_details::MessageTypeDetails;
+use crate::config;
+use serde::{Deserialize, Serialize};
+
+/// The static configuration of an
+/// [`MessagingPattern::RequestResponse`](crate::service::messaging_pattern::MessagingPattern::RequestResponse)
+/// based service. Contains all parameters that do not change during the lifetime of a
+/// [`Service`](crate::service::Service).
+#[derive(Debug, Clone, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct StaticConfig {
+    pub(crate) max_clients: usize,
+    pub(crate) max_servers: usize,
+    pub(crate) max_nodes: usize,
+    pub(crate) max_active_requests: usize,
+    pub(crate) client_max_response_buffer_size: usize,
+    pub(crate) client_max_borrowed_responses: usize,
+    pub(crate) request_message_type_details: MessageTypeDetails,
+    pub(crate) response_message_type_details: MessageTypeDetails,
+}
+
+impl StaticConfig {
+    pub(crate) fn new(config: &config::Config) -> Self {
+        Self {
+            max_clients: config.defaults.request_response.max_clients,
+            max_servers: config.defaults.request_response.max_servers,
+            max_nodes: config.defaults.request_response.max_nodes,
+            max_active_requests: config.defaults.request_response.max_active_requests,
+            client_max_response_buffer_size: config
+                .defaults
+                .request_response
+                .client_max_response_buffer_size,
+            client_max_borrowed_responses: config
+                .defaults
+                .request_response
+                .client_max_borrowed_responses,
+            request_message_type_details: MessageTypeDetails::default(),
+            response_message_type_details: MessageTypeDetails::default(),
+        }
+    }
+
+    pub(crate) fn required_amount_of_requests_per_data_segment(
+        &self,
+        client_max_loaned_requests: usize,
+    ) -> usize {
+        // every server can hold up to max_active_requests in its buffer and the same amount
+        // as borrowed requests
+        self.max_servers * 2 * self.max_active_requests + client_max_loaned_requests
+    }
+
+    pub(crate) fn required_amount_of_responses_per_data_segment(
+        &self,
+        server_max_loaned_responses: usize,
+    ) -> usize {
+        self.max_clients
+            * (self.client_max_response_buffer_size + self.client_max_borrowed_responses)
+            + server_max_loaned_responses
+    }
+
+    /// Returns the maximum supported amount of [`Node`](crate::node::Node)s that can open the
+    /// [`Service`](crate::service::Service) in parallel.
+    pub fn max_nodes(&self) -> usize {
+        self.max_nodes
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::client::Client`] ports
+    pub fn max_clients(&self) -> usize {
+        self.max_clients
+    }
+
+    /// Returns the maximum supported amount of [`crate::port::server::Server`] ports
+    pub fn max_servers(&self) -> usize {
+        self.max_servers
+    }
+
+    /// Returns how many requests a [`crate::port::client::Client`] can have in flight at the
+    /// same time.
+    pub fn max_active_requests(&self) -> usize {
+        self.max_active_requests
+    }
+
+    /// Returns the maximum supported buffer size for [`crate::response::Response`]s of a
+    /// [`crate::port::client::Client`] port.
+    pub fn client_max_response_buffer_size(&self) -> usize {
+        self.client_max_response_buffer_size
+    }
+
+    /// Returns how many [`crate::response::Response`]s a [`crate::port::client::Client`] port
+    /// can borrow in parallel at most.
+    pub fn client_max_borrowed_responses(&self) -> usize {
+        self.client_max_borrowed_responses
+    }
+
+    /// Returns the type details of the requests of the [`crate::service::Service`].
+    pub fn request_message_type_details(&self) -> &MessageTypeDetails {
+        &self.request_message_type_details
+    }
+
+    /// Returns the type details of the responses of the [`crate::service::Service`].
+    pub fn response_message_type_details(&self) -> &MessageTypeDetails {
+        &self.response_message_type_details
+    }
+}
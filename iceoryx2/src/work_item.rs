@@ -0,0 +1,117 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #   .pipeline::<u64>()
+//! #   .open_or_create()?;
+//! # let consumer = service.consumer_builder().create()?;
+//!
+//! while let Some(work_item) = consumer.receive()? {
+//!     println!("received: {:?}", *work_item);
+//!     println!("header producer id {:?}", work_item.header().producer_id());
+//! }
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, ops::Deref, sync::Arc};
+
+use iceoryx2_bb_log::fatal_panic;
+use iceoryx2_cal::{
+    shm_allocator::PointerOffset,
+    zero_copy_connection::{ZeroCopyReceiver, ZeroCopyReleaseError},
+};
+
+use crate::{
+    port::{details::producer_connections::ProducerConnection, port_identifiers::UniqueProducerId},
+    service::header::pipeline::Header,
+};
+
+/// It stores the payload and is acquired by the [`crate::port::consumer::Consumer`] whenever
+/// it receives a work item from a [`crate::port::producer::Producer`] via
+/// [`crate::port::consumer::Consumer::receive()`]. When it goes out of scope, the work item is
+/// released and returned to the [`crate::port::producer::Producer`].
+pub struct WorkItem<Service: crate::service::Service, Payload: Debug> {
+    connection: Arc<ProducerConnection<Service>>,
+    offset: PointerOffset,
+    header: *const Header,
+    payload: *const Payload,
+}
+
+impl<Service: crate::service::Service, Payload: Debug> Debug for WorkItem<Service, Payload> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WorkItem<{}, {}> {{ header: {:?}, offset: {:?} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Service>(),
+            self.header(),
+            self.offset
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug> Deref for WorkItem<Service, Payload> {
+    type Target = Payload;
+    fn deref(&self) -> &Self::Target {
+        self.payload()
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug> Drop for WorkItem<Service, Payload> {
+    fn drop(&mut self) {
+        match self.connection.receiver.release(self.offset) {
+            Ok(()) => (),
+            Err(ZeroCopyReleaseError::RetrieveBufferFull) => {
+                fatal_panic!(from self, "This should never happen! The producers retrieve channel is full and the work item cannot be returned.");
+            }
+        }
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug> WorkItem<Service, Payload> {
+    pub(crate) fn new(
+        connection: &Arc<ProducerConnection<Service>>,
+        offset: PointerOffset,
+        header: *const Header,
+        payload: *const Payload,
+    ) -> Self {
+        Self {
+            connection: connection.clone(),
+            offset,
+            header,
+            payload,
+        }
+    }
+
+    /// Returns a reference to the payload of the [`WorkItem`]
+    pub fn payload(&self) -> &Payload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a reference to the [`Header`] of the [`WorkItem`].
+    pub fn header(&self) -> &Header {
+        unsafe { &*self.header }
+    }
+
+    /// Returns the [`UniqueProducerId`] of the [`crate::port::producer::Producer`]
+    pub fn origin(&self) -> UniqueProducerId {
+        self.header().producer_id()
+    }
+}
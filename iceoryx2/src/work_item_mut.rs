@@ -0,0 +1,129 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .pipeline::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let producer = service.producer_builder().create()?;
+//!
+//! let mut work_item = producer.loan()?;
+//! *work_item.payload_mut() = 1234;
+//!
+//! println!("producer port id: {:?}", work_item.header().producer_id());
+//! work_item.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::producer::{ProducerBackend, ProducerSendError},
+    service::header::pipeline::Header,
+};
+
+/// Acquired by a [`crate::port::producer::Producer`] via
+///  * [`crate::port::producer::Producer::loan()`]
+///  * [`crate::work_item_mut_uninit::WorkItemMutUninit::write_payload()`]
+///
+/// It stores the payload that will be delivered to exactly one
+/// [`crate::port::consumer::Consumer`]. If the [`WorkItemMut`] is not sent it will release the
+/// loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent work items in the
+/// [`crate::port::producer::Producer`] and the [`crate::port::producer::Producer`] is not
+/// thread-safe!
+pub struct WorkItemMut<Service: crate::service::Service, Payload: Debug> {
+    pub(crate) producer_backend: Arc<ProducerBackend<Service>>,
+    pub(crate) header: *mut Header,
+    pub(crate) payload: *mut Payload,
+    pub(crate) offset_to_chunk: PointerOffset,
+}
+
+impl<Service: crate::service::Service, Payload: Debug> Debug for WorkItemMut<Service, Payload> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "WorkItemMut<{}, {}> {{ header: {:?}, offset_to_chunk: {:?} }}",
+            core::any::type_name::<Payload>(),
+            core::any::type_name::<Service>(),
+            self.header(),
+            self.offset_to_chunk
+        )
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug> Drop for WorkItemMut<Service, Payload> {
+    fn drop(&mut self) {
+        self.producer_backend
+            .return_loaned_work_item(self.offset_to_chunk);
+    }
+}
+
+impl<Service: crate::service::Service, Payload: Debug> WorkItemMut<Service, Payload> {
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        unsafe { &*self.header }
+    }
+
+    /// Returns a reference to the payload of the work item.
+    pub fn payload(&self) -> &Payload {
+        unsafe { &*self.payload }
+    }
+
+    /// Returns a mutable reference to the payload of the work item.
+    pub fn payload_mut(&mut self) -> &mut Payload {
+        unsafe { &mut *self.payload }
+    }
+
+    /// Delivers the work item to exactly one connected [`crate::port::consumer::Consumer`]. On
+    /// success it returns the number of [`crate::port::consumer::Consumer`]s that received the
+    /// work item, which is `0` when no [`crate::port::consumer::Consumer`] could accept it,
+    /// otherwise a [`ProducerSendError`] describing the failure.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let mut work_item = producer.loan()?;
+    /// *work_item.payload_mut() = 4;
+    ///
+    /// let number_of_recipients = work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn send(self) -> Result<usize, ProducerSendError> {
+        self.producer_backend.send_work_item(self.offset_to_chunk)
+    }
+}
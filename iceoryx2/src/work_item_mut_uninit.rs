@@ -0,0 +1,156 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! # Example
+//!
+//! ```
+//! use iceoryx2::prelude::*;
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! # let node = NodeBuilder::new().create::<ipc::Service>()?;
+//! #
+//! # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+//! #     .pipeline::<u64>()
+//! #     .open_or_create()?;
+//! #
+//! # let producer = service.producer_builder().create()?;
+//!
+//! let work_item = producer.loan_uninit()?;
+//! // write 1234 into the work item
+//! let mut work_item = work_item.write_payload(1234);
+//! // override contents with 456 because its fun
+//! *work_item.payload_mut() = 456;
+//!
+//! println!("producer port id: {:?}", work_item.header().producer_id());
+//! work_item.send()?;
+//!
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+
+use iceoryx2_cal::shm_allocator::PointerOffset;
+
+use crate::{
+    port::producer::ProducerBackend, service::header::pipeline::Header, work_item_mut::WorkItemMut,
+};
+
+/// Acquired by a [`crate::port::producer::Producer`] via
+/// [`crate::port::producer::Producer::loan_uninit()`]. It stores the uninitialized payload of a
+/// work item that has to be initialized before it can be sent. If the [`WorkItemMutUninit`] is
+/// not sent it will release the loaned memory when going out of scope.
+///
+/// # Notes
+///
+/// Does not implement [`Send`] since it releases unsent work items in the
+/// [`crate::port::producer::Producer`] and the [`crate::port::producer::Producer`] is not
+/// thread-safe!
+#[derive(Debug)]
+pub struct WorkItemMutUninit<Service: crate::service::Service, Payload: Debug> {
+    work_item: WorkItemMut<Service, MaybeUninit<Payload>>,
+}
+
+impl<Service: crate::service::Service, Payload: Debug> WorkItemMutUninit<Service, Payload> {
+    pub(crate) fn new(
+        producer_backend: &Arc<ProducerBackend<Service>>,
+        header: *mut Header,
+        payload: *mut MaybeUninit<Payload>,
+        offset_to_chunk: PointerOffset,
+    ) -> Self {
+        Self {
+            work_item: WorkItemMut {
+                producer_backend: producer_backend.clone(),
+                header,
+                payload,
+                offset_to_chunk,
+            },
+        }
+    }
+
+    /// Returns a reference to the [`Header`] of the work item.
+    pub fn header(&self) -> &Header {
+        self.work_item.header()
+    }
+
+    /// Returns a reference to the uninitialized payload of the work item.
+    pub fn payload(&self) -> &MaybeUninit<Payload> {
+        self.work_item.payload()
+    }
+
+    /// Returns a mutable reference to the uninitialized payload of the work item.
+    pub fn payload_mut(&mut self) -> &mut MaybeUninit<Payload> {
+        self.work_item.payload_mut()
+    }
+
+    /// Writes the payload to the work item and labels the [`WorkItemMutUninit`] as initialized
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let work_item = producer.loan_uninit()?;
+    /// let work_item = work_item.write_payload(1234);
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn write_payload(mut self, value: Payload) -> WorkItemMut<Service, Payload> {
+        self.payload_mut().write(value);
+        // SAFETY: this is safe since the payload was initialized on the line above
+        unsafe { self.assume_init() }
+    }
+
+    /// Extracts the value of the [`MaybeUninit<Payload>`] container and labels the
+    /// [`WorkItemMutUninit`] as initialized
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that [`MaybeUninit<Payload>`] really is initialized.
+    /// Calling this when the content is not fully initialized causes immediate undefined
+    /// behavior.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use iceoryx2::prelude::*;
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let node = NodeBuilder::new().create::<ipc::Service>()?;
+    /// #
+    /// # let service = node.service_builder(&"My/Funk/ServiceName".try_into()?)
+    /// #     .pipeline::<u64>()
+    /// #     .open_or_create()?;
+    /// # let producer = service.producer_builder().create()?;
+    ///
+    /// let mut work_item = producer.loan_uninit()?;
+    /// work_item.payload_mut().write(1234);
+    /// let work_item = unsafe { work_item.assume_init() };
+    ///
+    /// work_item.send()?;
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub unsafe fn assume_init(self) -> WorkItemMut<Service, Payload> {
+        // the transmute is not nice but safe since MaybeUninit is #[repr(transparent)] to the inner type
+        std::mem::transmute(self.work_item)
+    }
+}
@@ -0,0 +1,382 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "async")]
+
+#[generic_tests::define]
+mod async_api {
+    use std::future::Future;
+    use std::pin::{pin, Pin};
+    use std::sync::atomic::Ordering;
+    use std::sync::{Arc, Barrier};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::Thread;
+    use std::time::{Duration, Instant};
+
+    use futures_core::Stream;
+    use iceoryx2::port::event_id::EventId;
+    use iceoryx2::port::waitset::{WaitSetBuilder, WaitSetRunResult};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+    use iceoryx2_pal_concurrency_sync::iox_atomic::IoxAtomicUsize;
+
+    const TIMEOUT: Duration = Duration::from_millis(50);
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "async_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    struct CountingWaker(Arc<IoxAtomicUsize>);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    // a minimal executor that only relies on the std waker to demonstrate that the API
+    // is independent of a specific async runtime
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+        let mut cx = Context::from_waker(&waker);
+
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(value) => return value,
+                Poll::Pending => std::thread::park(),
+            }
+        }
+    }
+
+    fn next<S: Stream + Unpin>(stream: &mut S) -> Option<S::Item> {
+        block_on(std::future::poll_fn(|cx| {
+            Pin::new(&mut *stream).poll_next(cx)
+        }))
+    }
+
+    #[test]
+    fn wait_one_async_returns_present_event_immediately<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        notifier
+            .notify_with_custom_event_id(EventId::new(5))
+            .unwrap();
+
+        assert_that!(block_on(listener.wait_one_async()), eq Ok(EventId::new(5)));
+    }
+
+    #[test]
+    fn wait_one_async_is_woken_up_by_notification<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let notifier = service.notifier_builder().create().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                notifier
+                    .notify_with_custom_event_id(EventId::new(7))
+                    .unwrap();
+            });
+
+            barrier.wait();
+            assert_that!(block_on(listener.wait_one_async()), eq Ok(EventId::new(7)));
+        });
+    }
+
+    #[test]
+    fn listener_stream_yields_all_events<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_EVENTS: usize = 4;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let mut stream = listener.stream();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let notifier = service.notifier_builder().create().unwrap();
+                for i in 0..NUMBER_OF_EVENTS {
+                    std::thread::sleep(TIMEOUT / 5);
+                    notifier
+                        .notify_with_custom_event_id(EventId::new(i))
+                        .unwrap();
+                }
+            });
+
+            let mut received_ids = vec![];
+            for _ in 0..NUMBER_OF_EVENTS {
+                received_ids.push(next(&mut stream).unwrap().unwrap());
+            }
+            received_ids.sort();
+
+            assert_that!(
+                received_ids,
+                eq(0..NUMBER_OF_EVENTS)
+                    .map(EventId::new)
+                    .collect::<Vec<_>>()
+            );
+        });
+    }
+
+    #[test]
+    fn dropped_pending_wait_does_not_affect_other_waits<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let notifier = service.notifier_builder().create().unwrap();
+
+        {
+            let listener = service.listener_builder().create().unwrap();
+            let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            let mut future = pin!(listener.wait_one_async());
+            assert_that!(future.as_mut().poll(&mut cx), eq Poll::Pending);
+        }
+
+        let listener = service.listener_builder().create().unwrap();
+        notifier
+            .notify_with_custom_event_id(EventId::new(3))
+            .unwrap();
+        assert_that!(block_on(listener.wait_one_async()), eq Ok(EventId::new(3)));
+    }
+
+    #[test]
+    fn receive_async_returns_sample_that_is_sent_later<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let barrier = Barrier::new(2);
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let publisher = service.publisher_builder().create().unwrap();
+                barrier.wait();
+                std::thread::sleep(TIMEOUT);
+                publisher.send_copy(8127).unwrap();
+            });
+
+            barrier.wait();
+            let sample = block_on(subscriber.receive_async()).unwrap();
+            assert_that!(*sample, eq 8127);
+        });
+    }
+
+    #[test]
+    fn receive_async_without_notification_stays_pending<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let _publisher = service.publisher_builder().create().unwrap();
+
+        let counter = Arc::new(IoxAtomicUsize::new(0));
+        let waker = Waker::from(Arc::new(CountingWaker(counter.clone())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(subscriber.receive_async());
+        assert_that!(future.as_mut().poll(&mut cx).is_pending(), eq true);
+
+        std::thread::sleep(TIMEOUT);
+        assert_that!(counter.load(Ordering::Relaxed), eq 0);
+    }
+
+    #[test]
+    fn receive_async_of_slice_returns_sample<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()
+            .unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(2)
+            .create()
+            .unwrap();
+
+        let sample = publisher.loan_slice_uninit(2).unwrap();
+        sample.write_from_fn(|i| i as u64 + 1).send().unwrap();
+
+        let sample = block_on(subscriber.receive_async()).unwrap();
+        assert_that!(*sample, eq [1, 2]);
+    }
+
+    #[test]
+    fn waitset_async_processes_notifications_until_stopped<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_EVENTS: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+        let listener = service.listener_builder().create().unwrap();
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_notification(&listener).unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let notifier = service.notifier_builder().create().unwrap();
+                for i in 0..NUMBER_OF_EVENTS {
+                    std::thread::sleep(TIMEOUT / 5);
+                    notifier
+                        .notify_with_custom_event_id(EventId::new(i))
+                        .unwrap();
+                }
+            });
+
+            let mut received_ids = vec![];
+            let result = block_on(waitset.wait_and_process_async(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    listener.try_wait_all(|id| received_ids.push(id)).unwrap();
+                }
+
+                if received_ids.len() == NUMBER_OF_EVENTS {
+                    waitset.stop();
+                }
+            }));
+
+            assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+            assert_that!(
+                received_ids,
+                eq(0..NUMBER_OF_EVENTS)
+                    .map(EventId::new)
+                    .collect::<Vec<_>>()
+            );
+        });
+    }
+
+    #[test]
+    fn waitset_async_is_woken_up_by_interval<Sut: Service + 'static>()
+    where
+        <Sut::Event as Event>::Listener: SynchronousMultiplexing,
+        <Sut::Event as Event>::Notifier: Send + Sync,
+    {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_TICKS: usize = 3;
+        let waitset = WaitSetBuilder::new().create::<Sut>().unwrap();
+        let guard = waitset.attach_interval(TIMEOUT / 5).unwrap();
+
+        let start = Instant::now();
+        let mut ticks = 0;
+        let result = block_on(waitset.wait_and_process_async(|attachment_id| {
+            if attachment_id.has_event_from(&guard) {
+                ticks += 1;
+            }
+
+            if ticks == NUMBER_OF_TICKS {
+                waitset.stop();
+            }
+        }));
+
+        assert_that!(result, eq Ok(WaitSetRunResult::StopRequest));
+        assert_that!(start.elapsed(), ge TIMEOUT / 5 * NUMBER_OF_TICKS as u32);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
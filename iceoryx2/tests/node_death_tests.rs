@@ -13,16 +13,20 @@
 #[generic_tests::define]
 mod node_death_tests {
     use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::Duration;
 
     use iceoryx2::config::Config;
+    use iceoryx2::node::heartbeat::HeartbeatState;
     use iceoryx2::node::testing::__internal_node_staged_death;
-    use iceoryx2::node::{CleanupState, NodeState};
+    use iceoryx2::node::{CleanupState, NodeState, NodeView};
+    use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
     use iceoryx2::service::Service;
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::watchdog::Watchdog;
     use iceoryx2_bb_testing::{assert_that, test_fail};
+    use iceoryx2_cal::named_concept::{NamedConceptConfiguration, NamedConceptMgmt};
 
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
@@ -43,18 +47,22 @@ mod node_death_tests {
             NodeName::new(&(prefix.to_string() + &i.to_string())).unwrap()
         }
 
-        fn create_test_node(config: &Config) -> TestDetails<Self::Service> {
+        fn generate_fake_node_id() -> UniqueSystemId {
             static COUNTER: AtomicU32 = AtomicU32::new(0);
-            let node_name = Self::generate_node_name(0, "toby or no toby");
             let fake_node_id = ((u32::MAX - COUNTER.fetch_add(1, Ordering::Relaxed)) as u128) << 96;
-            let fake_node_id =
-                unsafe { core::mem::transmute::<u128, UniqueSystemId>(fake_node_id) };
+            unsafe { core::mem::transmute::<u128, UniqueSystemId>(fake_node_id) }
+        }
+
+        fn create_test_node(config: &Config) -> TestDetails<Self::Service> {
+            let node_name = Self::generate_node_name(0, "toby or no toby");
 
             let node = unsafe {
                 NodeBuilder::new()
                     .name(&node_name)
                     .config(config)
-                    .__internal_create_with_custom_node_id::<Self::Service>(fake_node_id)
+                    .__internal_create_with_custom_node_id::<Self::Service>(
+                        Self::generate_fake_node_id(),
+                    )
                     .unwrap()
             };
 
@@ -279,6 +287,197 @@ mod node_death_tests {
         }
     }
 
+    #[test]
+    fn work_items_of_dead_consumer_are_redelivered<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_WORK_ITEMS: u64 = 4;
+
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_name();
+
+        let mut bad_node = S::create_test_node(&config).node;
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+
+        let bad_service = bad_node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .open_or_create()
+            .unwrap();
+        let bad_consumer = bad_service
+            .consumer_builder()
+            .buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .create()
+            .unwrap();
+
+        let good_service = good_node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .open_or_create()
+            .unwrap();
+        let producer = good_service
+            .producer_builder()
+            .delivery_strategy(DeliveryStrategy::FirstFree)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_WORK_ITEMS {
+            assert_that!(producer.send_copy(n), eq Ok(1));
+        }
+
+        let good_consumer = good_service
+            .consumer_builder()
+            .buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .create()
+            .unwrap();
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_consumer);
+        core::mem::forget(bad_service);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(Config::global_config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(good_service.dynamic_config().number_of_consumers(), eq 1);
+
+        assert_that!(producer.update_connections(), is_ok);
+        assert_that!(producer.number_of_undelivered_work_items(), eq 0);
+
+        let mut received = vec![];
+        while let Some(work_item) = good_consumer.receive().unwrap() {
+            received.push(*work_item);
+        }
+        received.sort();
+        assert_that!(received, eq(0..NUMBER_OF_WORK_ITEMS).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn samples_of_dead_subscriber_are_returned_to_publisher<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const NUMBER_OF_RESTARTS: usize = 8;
+        const BUFFER_SIZE: usize = 2;
+        const MAX_BORROWED_SAMPLES: usize = 2;
+        const MAX_LOANED_SAMPLES: usize = 3;
+
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+        let service_name = generate_name();
+
+        let good_node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let good_service = good_node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_subscribers(1)
+            .subscriber_max_buffer_size(BUFFER_SIZE)
+            .subscriber_max_borrowed_samples(MAX_BORROWED_SAMPLES)
+            .history_size(0)
+            .open_or_create()
+            .unwrap();
+        let publisher = good_service
+            .publisher_builder()
+            .max_loaned_samples(MAX_LOANED_SAMPLES)
+            .create()
+            .unwrap();
+
+        for _ in 0..NUMBER_OF_RESTARTS {
+            let mut bad_node = S::create_test_node(&config).node;
+            let bad_service = bad_node
+                .service_builder(&service_name)
+                .publish_subscribe::<u64>()
+                .open()
+                .unwrap();
+            let bad_subscriber = bad_service.subscriber_builder().create().unwrap();
+
+            // the subscriber dies with a full buffer while it borrows the maximum
+            // number of samples
+            for n in 0..BUFFER_SIZE {
+                assert_that!(publisher.send_copy(n as u64).map(|r| r.number_of_recipients()), eq Ok(1));
+            }
+            for _ in 0..MAX_BORROWED_SAMPLES {
+                core::mem::forget(bad_subscriber.receive().unwrap().unwrap());
+            }
+            for n in 0..BUFFER_SIZE {
+                assert_that!(publisher.send_copy(n as u64).map(|r| r.number_of_recipients()), eq Ok(1));
+            }
+
+            S::staged_death(&mut bad_node);
+            core::mem::forget(bad_subscriber);
+            core::mem::forget(bad_service);
+
+            assert_that!(Node::<S::Service>::cleanup_dead_nodes(Config::global_config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+            assert_that!(good_service.dynamic_config().number_of_subscribers(), eq 0);
+
+            let mut samples = vec![];
+            for _ in 0..MAX_LOANED_SAMPLES {
+                let sample = publisher.loan_uninit();
+                assert_that!(sample, is_ok);
+                samples.push(sample);
+            }
+        }
+
+        let subscriber = good_service.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1234).map(|r| r.number_of_recipients()), eq Ok(1));
+        assert_that!(*subscriber.receive().unwrap().unwrap(), eq 1234);
+    }
+
+    #[test]
+    fn all_data_segments_of_dead_growing_publisher_are_removed<S: Test>() {
+        const NUMBER_OF_SEGMENTS: usize = 3;
+        let service_name = generate_name();
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let data_segment_config =
+            <<S::Service as Service>::SharedMemory as NamedConceptMgmt>::Configuration::default()
+                .prefix(&config.global.prefix)
+                .suffix(&config.global.service.publisher_data_segment_suffix)
+                .path_hint(config.global.root_path());
+        let number_of_data_segments = |publisher_id: &str| {
+            <<S::Service as Service>::SharedMemory as NamedConceptMgmt>::list_cfg(
+                &data_segment_config,
+            )
+            .unwrap()
+            .iter()
+            .filter(|name| name.to_string().contains(publisher_id))
+            .count()
+        };
+
+        let mut sut = S::create_test_node(&config).node;
+        let service = sut
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .open_or_create()
+            .unwrap();
+        let publisher = service
+            .publisher_builder()
+            .max_loaned_samples(NUMBER_OF_SEGMENTS)
+            .max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .max_number_of_segments(NUMBER_OF_SEGMENTS as u8)
+            .create()
+            .unwrap();
+        let publisher_id = publisher.id().value().to_string();
+
+        // every loan grows the data segment while the previous segments stay in use
+        for slice_len in 1..=NUMBER_OF_SEGMENTS {
+            core::mem::forget(publisher.loan_slice(slice_len).unwrap());
+        }
+        assert_that!(number_of_data_segments(&publisher_id), eq NUMBER_OF_SEGMENTS);
+
+        S::staged_death(&mut sut);
+        core::mem::forget(publisher);
+        core::mem::forget(service);
+
+        assert_that!(Node::<S::Service>::cleanup_dead_nodes(Config::global_config()), eq CleanupState { cleanups: 1, failed_cleanups: 0});
+        assert_that!(number_of_data_segments(&publisher_id), eq 0);
+    }
+
     #[test]
     fn event_service_is_removed_when_last_node_dies<S: Test>() {
         let service_name = generate_name();
@@ -429,6 +628,52 @@ mod node_death_tests {
         assert_that!(number_of_nodes(), eq 0);
     }
 
+    #[test]
+    fn heartbeat_monitor_detects_dead_node<S: Test>() {
+        let _watchdog = Watchdog::new();
+        const TIMEOUT: Duration = Duration::from_millis(50);
+        let mut config = Config::global_config().clone();
+        config.global.node.cleanup_dead_nodes_on_creation = false;
+
+        let mut bad_node = unsafe {
+            NodeBuilder::new()
+                .config(&config)
+                .heartbeat_interval(TIMEOUT / 5)
+                .__internal_create_with_custom_node_id::<S::Service>(S::generate_fake_node_id())
+                .unwrap()
+        };
+        let bad_node_id = *bad_node.id();
+
+        let node = NodeBuilder::new()
+            .config(&config)
+            .create::<S::Service>()
+            .unwrap();
+        let sut = node
+            .heartbeat_monitor_builder(&bad_node_id)
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+        assert_that!(sut.state(), eq HeartbeatState::Alive);
+
+        S::staged_death(&mut bad_node);
+        core::mem::forget(bad_node);
+        std::thread::sleep(TIMEOUT * 2);
+
+        assert_that!(sut.state(), eq HeartbeatState::Dead);
+
+        Node::<S::Service>::list(&config, |node_state| {
+            if let NodeState::Dead(state) = node_state {
+                if *state.id() == bad_node_id {
+                    assert_that!(state.remove_stale_resources(), eq Ok(true));
+                }
+            }
+            CallbackProgression::Continue
+        })
+        .unwrap();
+
+        assert_that!(sut.state(), eq HeartbeatState::Removed);
+    }
+
     #[instantiate_tests(<ZeroCopy>)]
     mod ipc {}
 }
@@ -0,0 +1,190 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod node_heartbeat {
+    use std::time::Duration;
+
+    use iceoryx2::node::heartbeat::{HeartbeatMonitorCreateError, HeartbeatState};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::file_descriptor_set::SynchronousMultiplexing;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_bb_testing::watchdog::Watchdog;
+    use iceoryx2_cal::event::Event;
+
+    const HEARTBEAT_INTERVAL: Duration = Duration::from_millis(10);
+    const TIMEOUT: Duration = Duration::from_millis(100);
+
+    fn create_node<S: Service>(heartbeat_interval: Option<Duration>) -> Node<S> {
+        let builder = NodeBuilder::new();
+        match heartbeat_interval {
+            Some(interval) => builder.heartbeat_interval(interval).create::<S>().unwrap(),
+            None => builder.create::<S>().unwrap(),
+        }
+    }
+
+    #[test]
+    fn monitor_cannot_be_created_for_node_without_heartbeats<S: Service>() {
+        let monitored_node = create_node::<S>(None);
+        let node = create_node::<S>(None);
+
+        let sut = node.heartbeat_monitor_builder(monitored_node.id()).create();
+
+        assert_that!(sut.err(), eq Some(HeartbeatMonitorCreateError::HeartbeatsNotEnabled));
+    }
+
+    #[test]
+    fn node_that_sends_heartbeats_is_alive<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(HEARTBEAT_INTERVAL));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+
+        assert_that!(*sut.node_id(), eq * monitored_node.id());
+        assert_that!(sut.timeout(), eq TIMEOUT);
+
+        for _ in 0..5 {
+            assert_that!(monitored_node.wait(TIMEOUT / 2), is_ok);
+            assert_that!(sut.state(), eq HeartbeatState::Alive);
+        }
+    }
+
+    #[test]
+    fn node_with_zero_heartbeat_interval_waits_and_sends_heartbeats<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(Duration::ZERO));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+
+        assert_that!(monitored_node.wait(HEARTBEAT_INTERVAL), is_ok);
+        assert_that!(sut.state(), eq HeartbeatState::Alive);
+    }
+
+    #[test]
+    fn node_that_stops_sending_heartbeats_becomes_unresponsive<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(HEARTBEAT_INTERVAL));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+        assert_that!(sut.state(), eq HeartbeatState::Alive);
+
+        std::thread::sleep(TIMEOUT * 2);
+        assert_that!(sut.state(), eq HeartbeatState::Unresponsive);
+
+        monitored_node.send_heartbeat();
+        assert_that!(sut.state(), eq HeartbeatState::Alive);
+    }
+
+    #[test]
+    fn node_that_was_shut_down_is_removed<S: Service>() {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(HEARTBEAT_INTERVAL));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+
+        drop(monitored_node);
+        std::thread::sleep(TIMEOUT * 2);
+
+        assert_that!(sut.state(), eq HeartbeatState::Removed);
+    }
+
+    #[test]
+    fn monitor_wakes_up_waitset_on_heartbeat<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(HEARTBEAT_INTERVAL));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = waitset.attach_deadline(&sut, sut.timeout()).unwrap();
+
+        let mut states = vec![];
+        monitored_node.send_heartbeat();
+        waitset
+            .try_wait_and_process(|attachment_id| {
+                if attachment_id.has_event_from(&guard) {
+                    states.push(sut.state());
+                }
+            })
+            .unwrap();
+
+        assert_that!(states, eq vec![HeartbeatState::Alive]);
+    }
+
+    #[test]
+    fn monitor_reports_missed_deadline_for_unresponsive_node<S: Service>()
+    where
+        <S::Event as Event>::Listener: SynchronousMultiplexing,
+    {
+        let _watchdog = Watchdog::new();
+        let monitored_node = create_node::<S>(Some(HEARTBEAT_INTERVAL));
+        let node = create_node::<S>(None);
+
+        let sut = node
+            .heartbeat_monitor_builder(monitored_node.id())
+            .timeout(TIMEOUT)
+            .create()
+            .unwrap();
+        // consume the initial heartbeat
+        assert_that!(sut.state(), eq HeartbeatState::Alive);
+
+        let waitset = WaitSetBuilder::new().create::<S>().unwrap();
+        let guard = waitset.attach_deadline(&sut, sut.timeout()).unwrap();
+
+        let mut states = vec![];
+        while states.is_empty() {
+            waitset
+                .try_wait_and_process(|attachment_id| {
+                    if attachment_id.has_missed_deadline(&guard) {
+                        states.push(sut.state());
+                    }
+                })
+                .unwrap();
+        }
+
+        assert_that!(states, eq vec![HeartbeatState::Unresponsive]);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -18,7 +18,7 @@ mod publisher {
 
     use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
     use iceoryx2::prelude::*;
-    use iceoryx2::service::port_factory::publisher::UnableToDeliverStrategy;
+    use iceoryx2::service::port_factory::publisher::{AllocationStrategy, UnableToDeliverStrategy};
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2::service::{service_name::ServiceName, Service};
     use iceoryx2_bb_posix::barrier::*;
@@ -39,7 +39,7 @@ mod publisher {
 
     const COMPLEX_TYPE_DEFAULT_VALUE: u64 = 872379237;
 
-    #[derive(Debug)]
+    #[derive(Debug, ZeroCopySend)]
     #[repr(C)]
     struct ComplexType {
         data: u64,
@@ -156,6 +156,167 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_with_static_allocation_strategy_does_not_grow<Sut: Service>() -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()?;
+
+        let sut = publisher.loan_slice(NUMBER_OF_ELEMENTS + 1);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+
+        Ok(())
+    }
+
+    fn publisher_grows_data_segment_and_delivers_slice<Sut: Service>(
+        strategy: AllocationStrategy,
+    ) -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(strategy)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for slice_len in [1, NUMBER_OF_ELEMENTS, 100, 1000, 50, 5000] {
+            let sample = publisher.loan_slice_uninit(slice_len)?;
+            assert_that!(sample.payload(), len slice_len);
+            let sample = sample.write_from_fn(|n| (n * slice_len) as u64);
+            sample.send()?;
+
+            let sample = subscriber.receive()?.unwrap();
+            assert_that!(sample.payload(), len slice_len);
+            for (n, element) in sample.payload().iter().enumerate() {
+                assert_that!(*element, eq(n * slice_len) as u64);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_with_power_of_two_allocation_strategy_grows<Sut: Service>() -> TestResult<()> {
+        publisher_grows_data_segment_and_delivers_slice::<Sut>(AllocationStrategy::PowerOfTwo)
+    }
+
+    #[test]
+    fn publisher_with_best_fit_allocation_strategy_grows<Sut: Service>() -> TestResult<()> {
+        publisher_grows_data_segment_and_delivers_slice::<Sut>(AllocationStrategy::BestFit)
+    }
+
+    #[test]
+    fn publisher_growth_is_limited_by_max_number_of_segments<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(1)
+            .max_loaned_samples(3)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .max_number_of_segments(3)
+            .create()?;
+
+        let _sample_1 = publisher.loan_slice(1)?;
+        let _sample_2 = publisher.loan_slice(2)?;
+        let _sample_3 = publisher.loan_slice(3)?;
+
+        let sut = publisher.loan_slice(4);
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PublisherLoanError::ExceedsMaxLoanSize);
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_reuses_released_data_segments_when_growing<Sut: Service>() -> TestResult<()> {
+        const MAX_SLICE_LEN: usize = 16;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(1)
+            .allocation_strategy(AllocationStrategy::BestFit)
+            .max_number_of_segments(2)
+            .create()?;
+        let subscriber = service.subscriber_builder().create()?;
+
+        for len in 2..=MAX_SLICE_LEN {
+            let sample = publisher.loan_slice_uninit(len)?;
+            let sample = sample.write_from_fn(|i| (len * 100 + i) as u64);
+            sample.send()?;
+
+            let received = subscriber.receive()?.unwrap();
+            assert_that!(received.payload(), len len);
+            for (i, value) in received.payload().iter().enumerate() {
+                let expected = (len * 100 + i) as u64;
+                assert_that!(*value, eq expected);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn publisher_keeps_samples_of_old_data_segment_valid_after_growing<Sut: Service>(
+    ) -> TestResult<()> {
+        const NUMBER_OF_ELEMENTS: usize = 8;
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(2)
+            .create()?;
+
+        let publisher = service
+            .publisher_builder()
+            .max_slice_len(NUMBER_OF_ELEMENTS)
+            .allocation_strategy(AllocationStrategy::PowerOfTwo)
+            .create()?;
+        let subscriber = service.subscriber_builder().buffer_size(2).create()?;
+
+        let small_sample = publisher.loan_slice(NUMBER_OF_ELEMENTS)?;
+        let large_sample = publisher.loan_slice(NUMBER_OF_ELEMENTS * 32)?;
+        small_sample.send()?;
+        large_sample.send()?;
+
+        let small_sample = subscriber.receive()?.unwrap();
+        let large_sample = subscriber.receive()?.unwrap();
+        assert_that!(small_sample.payload(), len NUMBER_OF_ELEMENTS);
+        assert_that!(large_sample.payload(), len NUMBER_OF_ELEMENTS * 32);
+
+        Ok(())
+    }
+
     #[test]
     fn publisher_loan_unit_and_send_sample_works<Sut: Service>() -> TestResult<()> {
         let service_name = generate_name()?;
@@ -404,9 +565,69 @@ mod publisher {
         Ok(())
     }
 
+    #[test]
+    fn publisher_reports_overflows_per_subscriber<Sut: Service>() -> TestResult<()> {
+        let service_name = generate_name()?;
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()?;
+
+        let overflows = std::sync::Arc::new(Mutex::new(vec![]));
+        let overflows_in_callback = overflows.clone();
+        let sut = service
+            .publisher_builder()
+            .set_overflow_callback(Some(move |_, _, subscriber_id, missed_samples| {
+                overflows_in_callback
+                    .lock()
+                    .unwrap()
+                    .push((subscriber_id, missed_samples));
+            }))
+            .create()?;
+        let subscriber_1 = service.subscriber_builder().buffer_size(2).create()?;
+        let subscriber_2 = service.subscriber_builder().buffer_size(2).create()?;
+
+        for value in 0..2 {
+            assert_that!(sut.send_copy(value)?.number_of_overflows(), eq 0);
+        }
+        assert_that!(overflows.lock().unwrap(), len 0);
+        assert_that!(sut.overflow_counts(), contains(subscriber_1.id(), 0));
+
+        assert_that!(*subscriber_2.receive()?.unwrap(), eq 0);
+        let report = sut.send_copy(2)?;
+        assert_that!(report.number_of_recipients(), eq 2);
+        assert_that!(report.number_of_overflows(), eq 1);
+        assert_that!(*overflows.lock().unwrap(), eq vec![(subscriber_1.id(), 1)]);
+
+        let report = sut.send_copy(3)?;
+        assert_that!(report.number_of_recipients(), eq 2);
+        assert_that!(report.number_of_overflows(), eq 2);
+
+        let counts = sut.overflow_counts();
+        assert_that!(counts, len 2);
+        assert_that!(counts, contains(subscriber_1.id(), 2));
+        assert_that!(counts, contains(subscriber_2.id(), 1));
+        assert_that!(*overflows.lock().unwrap(), eq vec![
+            (subscriber_1.id(), 1),
+            (subscriber_1.id(), 2),
+            (subscriber_2.id(), 1)
+        ]);
+
+        assert_that!(subscriber_1.missed_samples(), eq 2);
+        assert_that!(subscriber_2.missed_samples(), eq 1);
+
+        Ok(())
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
+    #[instantiate_tests(<iceoryx2::service::ipc_buddy::Service>)]
+    mod ipc_buddy {}
+
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
 }
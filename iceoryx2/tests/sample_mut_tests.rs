@@ -18,6 +18,9 @@ mod sample_mut {
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::port_factory::publish_subscribe::PortFactory;
     use iceoryx2::service::Service;
+    use iceoryx2_bb_container::shm_heap::{ShmPayload, ShmSend};
+    use iceoryx2_bb_container::shm_string::ShmString;
+    use iceoryx2_bb_container::shm_vec::ShmVec;
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
@@ -134,12 +137,56 @@ mod sample_mut {
         let sample = test_context.publisher.loan_uninit().unwrap();
         let sample = sample.write_payload(PAYLOAD);
 
-        assert_that!(sample.send(), eq Ok(1));
+        assert_that!(sample.send().map(|r| r.number_of_recipients()), eq Ok(1));
 
         let received_sample = test_context.subscriber.receive().unwrap().unwrap();
         assert_that!(*received_sample, eq PAYLOAD);
     }
 
+    #[test]
+    fn write_shm_payload_sends_dynamically_sized_containers<Sut: Service>() {
+        const HEAP_SIZE: usize = 4096;
+        const NUMBER_OF_READINGS: usize = 100;
+        type Payload = ShmPayload<Readings, HEAP_SIZE>;
+
+        #[derive(Debug)]
+        #[repr(C)]
+        struct Readings {
+            name: ShmString,
+            values: ShmVec<u64>,
+        }
+
+        unsafe impl ShmSend for Readings {}
+
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<Payload>()
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let subscriber = service.subscriber_builder().create().unwrap();
+
+        let sample = publisher.loan_uninit().unwrap();
+        let mut sample = sample.write_shm_payload(Readings {
+            name: ShmString::new(),
+            values: ShmVec::new(),
+        });
+        let (readings, heap) = sample.payload_mut().split_mut();
+        assert_that!(unsafe { readings.name.push_str(heap, "hypnotoad") }, is_ok);
+        for i in 0..NUMBER_OF_READINGS {
+            assert_that!(unsafe { readings.values.push(heap, i as u64 * 3) }, is_ok);
+        }
+
+        assert_that!(sample.send().map(|r| r.number_of_recipients()), eq Ok(1));
+
+        let received_sample = subscriber.receive().unwrap().unwrap();
+        let readings = &**received_sample.payload();
+        let expected_readings: Vec<u64> = (0..NUMBER_OF_READINGS).map(|i| i as u64 * 3).collect();
+        assert_that!(unsafe { readings.name.as_str() }, eq "hypnotoad");
+        assert_that!(unsafe { readings.values.as_slice() }, eq expected_readings.as_slice());
+    }
+
     #[test]
     fn sample_of_dropped_service_does_block_new_service_creation<Sut: Service>() {
         let test_context = TestContext::<Sut>::new();
@@ -70,22 +70,50 @@ mod sample {
     fn origin_is_tracked_correctly<Sut: Service>() {
         let test_context = TestContext::<Sut>::new();
 
-        assert_that!(test_context.publisher_1.send_copy(123), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(123).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample = test_context.subscriber.receive().unwrap().unwrap();
         assert_that!(sample.origin(), eq test_context.publisher_1.id());
 
-        assert_that!(test_context.publisher_2.send_copy(123), eq Ok(1));
+        assert_that!(test_context.publisher_2.send_copy(123).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample = test_context.subscriber.receive().unwrap().unwrap();
         assert_that!(sample.origin(), eq test_context.publisher_2.id());
     }
 
+    #[test]
+    fn sequence_numbers_are_consecutive_per_publisher<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        for expected_sequence_number in 0..2 {
+            assert_that!(test_context.publisher_1.send_copy(1).map(|r| r.number_of_recipients()), eq Ok(1));
+            let sample = test_context.subscriber.receive().unwrap().unwrap();
+            assert_that!(sample.header().sequence_number(), eq expected_sequence_number);
+        }
+
+        assert_that!(test_context.publisher_2.send_copy(2).map(|r| r.number_of_recipients()), eq Ok(1));
+        let sample = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.header().sequence_number(), eq 0);
+    }
+
+    #[test]
+    fn timestamps_are_monotonic<Sut: Service>() {
+        let test_context = TestContext::<Sut>::new();
+
+        assert_that!(test_context.publisher_1.send_copy(1).map(|r| r.number_of_recipients()), eq Ok(1));
+        let first = test_context.subscriber.receive().unwrap().unwrap();
+        assert_that!(test_context.publisher_1.send_copy(2).map(|r| r.number_of_recipients()), eq Ok(1));
+        let second = test_context.subscriber.receive().unwrap().unwrap();
+
+        assert_that!(first.header().timestamp().as_duration(), ne std::time::Duration::ZERO);
+        assert_that!(second.header().timestamp().as_duration(), ge first.header().timestamp().as_duration());
+    }
+
     #[test]
     fn sample_of_dropped_service_does_not_block_new_service_creation<Sut: Service>() {
         let test_context = TestContext::<Sut>::new();
 
         let service_name = test_context.service_name.clone();
 
-        assert_that!(test_context.publisher_1.send_copy(5), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(5).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample = test_context.subscriber.receive().unwrap();
         assert_that!(sample, is_some);
 
@@ -114,7 +142,7 @@ mod sample {
 
         const PAYLOAD: u64 = 8761238679123;
 
-        assert_that!(publisher_1.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(publisher_1.send_copy(PAYLOAD).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample = subscriber.receive().unwrap().unwrap();
 
         drop(subscriber);
@@ -131,7 +159,7 @@ mod sample {
 
         let publisher = test_context.publisher_1;
 
-        assert_that!(publisher.send_copy(PAYLOAD_1), eq Ok(1));
+        assert_that!(publisher.send_copy(PAYLOAD_1).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample = test_context.subscriber.receive().unwrap().unwrap();
 
         drop(publisher);
@@ -139,7 +167,7 @@ mod sample {
         const PAYLOAD_2: u64 = 123981235645;
 
         let publisher = test_context.service.publisher_builder().create().unwrap();
-        assert_that!(publisher.send_copy(PAYLOAD_2), eq Ok(1));
+        assert_that!(publisher.send_copy(PAYLOAD_2).map(|r| r.number_of_recipients()), eq Ok(1));
         assert_that!(*sample, eq PAYLOAD_1);
         let sample_2 = test_context.subscriber.receive().unwrap().unwrap();
         assert_that!(*sample_2, eq PAYLOAD_2);
@@ -152,7 +180,7 @@ mod sample {
 
         let subscriber = test_context.subscriber;
 
-        assert_that!(test_context.publisher_1.send_copy(PAYLOAD_1), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(PAYLOAD_1).map(|r| r.number_of_recipients()), eq Ok(1));
         let _sample = subscriber.receive().unwrap().unwrap();
 
         drop(subscriber);
@@ -160,7 +188,7 @@ mod sample {
         const PAYLOAD_2: u64 = 123666645;
 
         let subscriber = test_context.service.subscriber_builder().create().unwrap();
-        assert_that!(test_context.publisher_1.send_copy(PAYLOAD_2), eq Ok(1));
+        assert_that!(test_context.publisher_1.send_copy(PAYLOAD_2).map(|r| r.number_of_recipients()), eq Ok(1));
         let sample_1 = subscriber.receive().unwrap().unwrap();
         let sample_2 = subscriber.receive().unwrap().unwrap();
         assert_that!(*sample_1, eq PAYLOAD_1);
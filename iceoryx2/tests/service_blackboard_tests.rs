@@ -0,0 +1,541 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_blackboard {
+    use std::sync::Barrier;
+
+    use iceoryx2::port::reader::{EntryHandleError, ReaderCreateError};
+    use iceoryx2::port::writer::{EntryHandleMutError, WriterCreateError};
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, ZeroCopySend)]
+    #[repr(C)]
+    struct Position {
+        x: f64,
+        y: f64,
+        z: f64,
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_service_without_entries_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .create();
+
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardCreateError::NoEntriesProvided);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn opening_non_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn opening_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(*sut2.name(), eq service_name);
+    }
+
+    #[test]
+    fn opening_existing_service_with_different_key_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<i64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::IncompatibleKeys);
+    }
+
+    #[test]
+    fn opening_service_with_different_messaging_pattern_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .event()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn service_settings_are_applied<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_readers(4)
+            .max_nodes(5)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_readers(), eq 4);
+        assert_that!(sut.static_config().max_writers(), eq 1);
+        assert_that!(sut.static_config().max_nodes(), eq 5);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+
+        assert_that!(sut2.static_config().max_readers(), eq 4);
+        assert_that!(sut2.static_config().max_nodes(), eq 5);
+    }
+
+    #[test]
+    fn settings_with_zero_are_adjusted_to_smallest_possible_value<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_readers(0)
+            .max_nodes(0)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().max_readers(), eq 1);
+        assert_that!(sut.static_config().max_nodes(), eq 1);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_reader_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_readers(2)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_readers(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfReaders);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_readers(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_node_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_nodes(2)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .max_nodes(3)
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq BlackboardOpenError::DoesNotSupportRequestedAmountOfNodes);
+    }
+
+    #[test]
+    fn service_can_be_recreated_after_it_was_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+        drop(sut);
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u32>(0, 12)
+            .create();
+        assert_that!(sut, is_ok);
+
+        let reader = sut.unwrap().reader_builder().create().unwrap();
+        assert_that!(reader.entry::<u32>(&0).unwrap().get(), eq 12);
+    }
+
+    #[test]
+    fn reader_reads_initial_values<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 3)
+            .add::<i64>(1, -7)
+            .add::<Position>(
+                2,
+                Position {
+                    x: 1.0,
+                    y: 2.0,
+                    z: 3.0,
+                },
+            )
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.entry::<u8>(&0).unwrap().get(), eq 3);
+        assert_that!(reader.entry::<i64>(&1).unwrap().get(), eq - 7);
+        assert_that!(reader.entry::<Position>(&2).unwrap().get(), eq Position {
+            x: 1.0,
+            y: 2.0,
+            z: 3.0,
+        });
+    }
+
+    #[test]
+    fn adding_the_same_key_twice_replaces_the_entry<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u8>(0, 3)
+            .add::<u16>(0, 1234)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+
+        assert_that!(reader.entry::<u8>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry::<u16>(&0).unwrap().get(), eq 1234);
+    }
+
+    #[test]
+    fn entry_with_non_existing_key_or_wrong_value_type_does_not_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 3)
+            .create()
+            .unwrap();
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+
+        assert_that!(reader.entry::<u64>(&1).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(reader.entry::<i64>(&0).err(), eq Some(EntryHandleError::EntryDoesNotExist));
+        assert_that!(writer.entry::<u64>(&1).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+        assert_that!(writer.entry::<u32>(&0).err(), eq Some(EntryHandleMutError::EntryDoesNotExist));
+    }
+
+    #[test]
+    fn writer_updates_are_visible_to_all_readers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<i32>(1, 0)
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .blackboard_opener::<u64>()
+            .open()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+        let reader_1 = sut.reader_builder().create().unwrap();
+        let reader_2 = sut2.reader_builder().create().unwrap();
+
+        let entry_0 = writer.entry::<u64>(&0).unwrap();
+        let entry_1 = writer.entry::<i32>(&1).unwrap();
+
+        entry_0.update_with_copy(8912);
+        entry_1.update_with_copy(-19);
+
+        for reader in [&reader_1, &reader_2] {
+            assert_that!(reader.entry::<u64>(&0).unwrap().get(), eq 8912);
+            assert_that!(reader.entry::<i32>(&1).unwrap().get(), eq - 19);
+        }
+
+        let reader_entry = reader_2.entry::<u64>(&0).unwrap();
+        for i in 0..10 {
+            entry_0.update_with_copy(i);
+            assert_that!(reader_entry.get(), eq i);
+        }
+    }
+
+    #[test]
+    fn only_one_mutable_entry_handle_per_entry_can_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .add::<u64>(1, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create().unwrap();
+
+        let entry = writer.entry::<u64>(&0).unwrap();
+        assert_that!(writer.entry::<u64>(&0).err(), eq Some(EntryHandleMutError::HandleAlreadyExists));
+        assert_that!(writer.entry::<u64>(&1), is_ok);
+
+        drop(entry);
+        assert_that!(writer.entry::<u64>(&0), is_ok);
+    }
+
+    #[test]
+    fn only_one_writer_can_exist<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let writer = sut.writer_builder().create();
+        assert_that!(writer, is_ok);
+
+        let writer2 = sut.writer_builder().create();
+        assert_that!(writer2, is_err);
+        assert_that!(writer2.err().unwrap(), eq WriterCreateError::ExceedsMaxSupportedWriters);
+
+        drop(writer);
+        assert_that!(sut.writer_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn number_of_readers_is_limited<Sut: Service>() {
+        const MAX_READERS: usize = 3;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .max_readers(MAX_READERS)
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        let mut readers = vec![];
+        for _ in 0..MAX_READERS {
+            readers.push(sut.reader_builder().create().unwrap());
+        }
+
+        let reader = sut.reader_builder().create();
+        assert_that!(reader, is_err);
+        assert_that!(reader.err().unwrap(), eq ReaderCreateError::ExceedsMaxSupportedReaders);
+
+        readers.pop();
+        assert_that!(sut.reader_builder().create(), is_ok);
+    }
+
+    #[test]
+    fn dynamic_config_tracks_readers_and_writers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<u64>(0, 0)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+
+        let reader = sut.reader_builder().create().unwrap();
+        let writer = sut.writer_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 1);
+
+        drop(reader);
+        drop(writer);
+        assert_that!(sut.dynamic_config().number_of_readers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_writers(), eq 0);
+    }
+
+    #[test]
+    fn concurrent_reads_are_consistent<Sut: Service>() {
+        const NUMBER_OF_UPDATES: u64 = 10000;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let barrier = Barrier::new(2);
+
+        let sut = node
+            .service_builder(&service_name)
+            .blackboard_creator::<u64>()
+            .add::<[u64; 4]>(0, [0; 4])
+            .create()
+            .unwrap();
+
+        std::thread::scope(|s| {
+            s.spawn(|| {
+                let writer = sut.writer_builder().create().unwrap();
+                let entry = writer.entry::<[u64; 4]>(&0).unwrap();
+                barrier.wait();
+
+                for i in 1..=NUMBER_OF_UPDATES {
+                    entry.update_with_copy([i; 4]);
+                }
+            });
+
+            let reader = sut.reader_builder().create().unwrap();
+            let entry = reader.entry::<[u64; 4]>(&0).unwrap();
+            barrier.wait();
+
+            let mut last_value = 0;
+            while last_value < NUMBER_OF_UPDATES {
+                let value = entry.get();
+                assert_that!(value.iter().all(|v| *v == value[0]), eq true);
+                assert_that!(value[0], ge last_value);
+                last_value = value[0];
+            }
+        });
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
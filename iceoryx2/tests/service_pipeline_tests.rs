@@ -0,0 +1,424 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_pipeline {
+    use std::collections::HashSet;
+
+    use iceoryx2::port::consumer::ConsumerCreateError;
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::pipeline::{PipelineCreateError, PipelineOpenError};
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn opening_non_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node.service_builder(&service_name).pipeline::<u64>().open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq PipelineOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn opening_existing_service_with_wrong_payload_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node.service_builder(&service_name).pipeline::<i64>().open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::IncompatibleTypes);
+    }
+
+    #[test]
+    fn opening_existing_service_with_unsupported_properties_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(2)
+            .max_consumers(3)
+            .consumer_max_buffer_size(4)
+            .consumer_max_borrowed_work_items(5)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfProducers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_consumers(4)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedAmountOfConsumers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(5)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedMinBufferSize);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_borrowed_work_items(6)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq PipelineOpenError::DoesNotSupportRequestedMinBorrowedWorkItems);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .max_producers(1)
+            .max_consumers(2)
+            .consumer_max_buffer_size(3)
+            .consumer_max_borrowed_work_items(4)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn consumer_with_buffer_size_larger_than_supported_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let consumer = sut.consumer_builder().buffer_size(5).create();
+        assert_that!(consumer.err().unwrap(), eq ConsumerCreateError::BufferSizeExceedsMaxSupportedBufferSizeOfService);
+
+        let consumer = sut.consumer_builder().buffer_size(4).create();
+        assert_that!(consumer, is_ok);
+        assert_that!(consumer.unwrap().buffer_size(), eq 4);
+    }
+
+    #[test]
+    fn number_of_producers_and_consumers_is_tracked<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+        let consumer_1 = sut.consumer_builder().create().unwrap();
+        let consumer_2 = sut.consumer_builder().create().unwrap();
+
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 2);
+
+        drop(consumer_1);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 1);
+
+        drop(producer);
+        drop(consumer_2);
+        assert_that!(sut.dynamic_config().number_of_producers(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_consumers(), eq 0);
+    }
+
+    #[test]
+    fn send_without_consumers_discards_work_item<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+        assert_that!(producer.send_copy(123), eq Ok(0));
+    }
+
+    #[test]
+    fn every_work_item_is_delivered_to_exactly_one_consumer<Sut: Service>() {
+        const NUMBER_OF_WORK_ITEMS: u64 = 6;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(NUMBER_OF_WORK_ITEMS as usize)
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+        let consumers = [
+            sut.consumer_builder().create().unwrap(),
+            sut.consumer_builder().create().unwrap(),
+            sut.consumer_builder().create().unwrap(),
+        ];
+
+        for n in 0..NUMBER_OF_WORK_ITEMS {
+            assert_that!(producer.send_copy(n), eq Ok(1));
+        }
+
+        let mut received = HashSet::new();
+        for consumer in &consumers {
+            while let Some(work_item) = consumer.receive().unwrap() {
+                assert_that!(work_item.origin(), eq producer.id());
+                assert_that!(received.insert(*work_item), eq true);
+            }
+        }
+
+        assert_that!(received, len NUMBER_OF_WORK_ITEMS as usize);
+    }
+
+    #[test]
+    fn round_robin_distributes_work_items_evenly<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .delivery_strategy(DeliveryStrategy::RoundRobin)
+            .create()
+            .unwrap();
+        let consumer_1 = sut.consumer_builder().buffer_size(4).create().unwrap();
+        let consumer_2 = sut.consumer_builder().buffer_size(4).create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(producer.send_copy(n), eq Ok(1));
+        }
+
+        for consumer in [&consumer_1, &consumer_2] {
+            let mut counter = 0;
+            while consumer.receive().unwrap().is_some() {
+                counter += 1;
+            }
+            assert_that!(counter, eq 2);
+        }
+    }
+
+    #[test]
+    fn first_free_fills_first_consumer_before_the_next<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .delivery_strategy(DeliveryStrategy::FirstFree)
+            .create()
+            .unwrap();
+        let consumer_1 = sut.consumer_builder().buffer_size(2).create().unwrap();
+        let consumer_2 = sut.consumer_builder().buffer_size(2).create().unwrap();
+
+        for n in 0..3 {
+            assert_that!(producer.send_copy(n), eq Ok(1));
+        }
+
+        let mut counter = [0; 2];
+        for (i, consumer) in [&consumer_1, &consumer_2].iter().enumerate() {
+            while consumer.receive().unwrap().is_some() {
+                counter[i] += 1;
+            }
+        }
+
+        counter.sort();
+        assert_that!(counter, eq [1, 2]);
+    }
+
+    #[test]
+    fn discard_sample_strategy_discards_when_all_consumers_are_full<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(1)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::DiscardSample)
+            .create()
+            .unwrap();
+        let consumer = sut.consumer_builder().buffer_size(1).create().unwrap();
+
+        assert_that!(producer.send_copy(1), eq Ok(1));
+        assert_that!(producer.send_copy(2), eq Ok(0));
+
+        let work_item = consumer.receive().unwrap();
+        assert_that!(work_item, is_some);
+        assert_that!(*work_item.unwrap(), eq 1);
+        assert_that!(consumer.receive().unwrap(), is_none);
+
+        assert_that!(producer.send_copy(3), eq Ok(1));
+        assert_that!(*consumer.receive().unwrap().unwrap(), eq 3);
+    }
+
+    #[test]
+    fn work_items_of_removed_consumer_are_redelivered<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(4)
+            .create()
+            .unwrap();
+
+        let producer = sut
+            .producer_builder()
+            .delivery_strategy(DeliveryStrategy::RoundRobin)
+            .create()
+            .unwrap();
+        let consumer_1 = sut.consumer_builder().buffer_size(4).create().unwrap();
+        let consumer_2 = sut.consumer_builder().buffer_size(4).create().unwrap();
+
+        for n in 0..4 {
+            assert_that!(producer.send_copy(n), eq Ok(1));
+        }
+
+        let mut received = HashSet::new();
+        while let Some(work_item) = consumer_2.receive().unwrap() {
+            received.insert(*work_item);
+        }
+        assert_that!(received, len 2);
+
+        drop(consumer_1);
+        assert_that!(producer.update_connections(), is_ok);
+        assert_that!(producer.number_of_undelivered_work_items(), eq 0);
+
+        while let Some(work_item) = consumer_2.receive().unwrap() {
+            assert_that!(received.insert(*work_item), eq true);
+        }
+        assert_that!(received, len 4);
+    }
+
+    #[test]
+    fn work_items_are_kept_until_a_consumer_is_available<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .pipeline::<u64>()
+            .consumer_max_buffer_size(2)
+            .create()
+            .unwrap();
+
+        let producer = sut.producer_builder().create().unwrap();
+        let consumer = sut.consumer_builder().buffer_size(2).create().unwrap();
+
+        assert_that!(producer.send_copy(5), eq Ok(1));
+        assert_that!(producer.send_copy(7), eq Ok(1));
+        drop(consumer);
+
+        assert_that!(producer.update_connections(), is_ok);
+        assert_that!(producer.number_of_undelivered_work_items(), eq 2);
+
+        let consumer = sut.consumer_builder().buffer_size(2).create().unwrap();
+        assert_that!(producer.update_connections(), is_ok);
+        assert_that!(producer.number_of_undelivered_work_items(), eq 0);
+
+        let mut received = HashSet::new();
+        while let Some(work_item) = consumer.receive().unwrap() {
+            received.insert(*work_item);
+        }
+        assert_that!(received, eq HashSet::from([5, 7]));
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_buddy::Service>)]
+    mod ipc_buddy {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
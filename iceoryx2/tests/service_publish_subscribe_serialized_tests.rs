@@ -0,0 +1,265 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_publish_subscribe_serialized {
+    use iceoryx2::port::update_connections::UpdateConnections;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
+    use iceoryx2::service::builder::publish_subscribe_serialized::{Postcard, Serialize};
+    use iceoryx2::service::port_factory::publisher::AllocationStrategy;
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+    use iceoryx2_cal::serialize::{DeserializeError, SerializeError};
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Message {
+        text: String,
+        values: Vec<u64>,
+        flag: bool,
+    }
+
+    struct OtherSerializer {}
+
+    impl Serialize for OtherSerializer {
+        const NAME: &'static str = "other";
+
+        fn serialize<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, SerializeError> {
+            Postcard::serialize(value)
+        }
+
+        fn deserialize<T: serde::de::DeserializeOwned>(
+            bytes: &[u8],
+        ) -> Result<T, DeserializeError> {
+            Postcard::deserialize(bytes)
+        }
+    }
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    fn message(n: usize) -> Message {
+        Message {
+            text: "x".repeat(n),
+            values: (0..n as u64).collect(),
+            flag: n % 2 == 0,
+        }
+    }
+
+    #[test]
+    fn static_config_stores_serializer<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().serializer(), eq Some(S::NAME));
+
+        let sut = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().serializer(), eq None);
+    }
+
+    #[test]
+    fn open_succeeds_with_same_payload_and_serializer<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_with_different_serializer<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<OtherSerializer>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleSerializer));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<OtherSerializer>()
+            .open_or_create();
+        assert_that!(sut2, is_err);
+    }
+
+    #[test]
+    fn open_fails_with_different_payload_type<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<String>()
+            .serializer::<S>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn zero_copy_service_cannot_open_serialized_service<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u8]>()
+            .open();
+        assert_that!(sut2, is_err);
+    }
+
+    #[test]
+    fn send_and_receive_works<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .subscriber_max_buffer_size(5)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        assert_that!(subscriber.has_samples().unwrap(), eq false);
+
+        for n in 0..5 {
+            assert_that!(publisher.send(&message(n)).map(|r| r.number_of_recipients()), eq Ok(1));
+        }
+
+        for n in 0..5 {
+            let received = subscriber.receive().unwrap();
+            assert_that!(received, eq Some(message(n)));
+        }
+
+        assert_that!(subscriber.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn publisher_grows_for_large_payloads<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .create()
+            .unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.update_connections(), is_ok);
+
+        for n in [1, 100, 1000, 10000] {
+            assert_that!(publisher.send(&message(n)).map(|r| r.number_of_recipients()), eq Ok(1));
+            assert_that!(subscriber.receive().unwrap(), eq Some(message(n)));
+        }
+    }
+
+    #[test]
+    fn publisher_with_static_allocation_fails_for_large_payloads<Sut: Service, S: Serialize>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe_serialized::<Message>()
+            .serializer::<S>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .initial_max_slice_len(16)
+            .allocation_strategy(AllocationStrategy::Static)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send(&message(1000)), is_err);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2_cal::serialize::postcard::Postcard>)]
+    mod ipc_postcard {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2_cal::serialize::bincode::Bincode>)]
+    mod ipc_bincode {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2_cal::serialize::cdr::Cdr>)]
+    mod ipc_cdr {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service, iceoryx2_cal::serialize::toml::Toml>)]
+    mod ipc_toml {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service, iceoryx2_cal::serialize::postcard::Postcard>)]
+    mod local_postcard {}
+}
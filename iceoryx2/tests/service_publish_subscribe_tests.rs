@@ -10,17 +10,70 @@
 //
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+mod payload_a {
+    use iceoryx2::prelude::*;
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Payload")]
+    #[repr(C)]
+    pub struct Payload {
+        pub value: u64,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    pub struct StructuralPayload {
+        pub value: u64,
+        pub flag: bool,
+    }
+}
+
+mod payload_b {
+    use iceoryx2::prelude::*;
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Payload")]
+    #[repr(C)]
+    pub struct Payload {
+        pub other_value: u64,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[type_name("Payload")]
+    #[repr(C)]
+    pub struct IncompatiblePayload {
+        pub value: u32,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    pub struct StructuralPayload {
+        pub other_value: u64,
+        pub other_flag: bool,
+    }
+
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    pub struct ReorderedStructuralPayload {
+        pub flag: bool,
+        pub value: u64,
+    }
+}
+
 #[generic_tests::define]
 mod service_publish_subscribe {
     use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
     use std::sync::{Barrier, Mutex};
     use std::thread;
+    use std::time::Duration;
 
     use iceoryx2::config::Config;
+    use iceoryx2::port::port_identifiers::UniquePublisherId;
     use iceoryx2::port::publisher::{PublisherCreateError, PublisherLoanError};
-    use iceoryx2::port::subscriber::SubscriberCreateError;
+    use iceoryx2::port::subscriber::{Subscriber, SubscriberCreateError};
     use iceoryx2::port::update_connections::UpdateConnections;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::event::EventOpenError;
     use iceoryx2::service::builder::publish_subscribe::CustomHeaderMarker;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeCreateError;
     use iceoryx2::service::builder::publish_subscribe::PublishSubscribeOpenError;
@@ -35,7 +88,8 @@ mod service_publish_subscribe {
     use iceoryx2_bb_testing::assert_that;
     use iceoryx2_bb_testing::watchdog::Watchdog;
 
-    #[derive(Debug)]
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
     struct SomeUserHeader {
         value: [u64; 1024],
     }
@@ -48,6 +102,22 @@ mod service_publish_subscribe {
         .unwrap()
     }
 
+    fn missed_deadlines<Sut: Service>(
+        subscriber: &Subscriber<Sut, u64, ()>,
+    ) -> Vec<UniquePublisherId> {
+        let mut publishers = vec![];
+        subscriber.missed_deadlines(|id| publishers.push(id));
+        publishers
+    }
+
+    fn expired_liveliness_leases<Sut: Service>(
+        subscriber: &Subscriber<Sut, u64, ()>,
+    ) -> Vec<UniquePublisherId> {
+        let mut publishers = vec![];
+        subscriber.expired_liveliness_leases(|id| publishers.push(id));
+        publishers
+    }
+
     #[test]
     fn open_or_create_with_attributes_succeeds_when_service_does_exist<Sut: Service>() {
         let service_name = generate_name();
@@ -1083,12 +1153,12 @@ mod service_publish_subscribe {
                     subscribers.push(sut.subscriber_builder().create());
                 }
 
-                assert_that!(publisher.send_copy(1234), eq Ok(n));
-                assert_that!(publisher.send_copy(4567), eq Ok(n));
-                assert_that!(publisher.send_copy(789), eq Ok(n));
+                assert_that!(publisher.send_copy(1234).map(|r| r.number_of_recipients()), eq Ok(n));
+                assert_that!(publisher.send_copy(4567).map(|r| r.number_of_recipients()), eq Ok(n));
+                assert_that!(publisher.send_copy(789).map(|r| r.number_of_recipients()), eq Ok(n));
                 subscribers.clear();
-                assert_that!(publisher.send_copy(789), eq Ok(0));
-                assert_that!(publisher.send_copy(789), eq Ok(0));
+                assert_that!(publisher.send_copy(789).map(|r| r.number_of_recipients()), eq Ok(0));
+                assert_that!(publisher.send_copy(789).map(|r| r.number_of_recipients()), eq Ok(0));
             }
         }
     }
@@ -1120,7 +1190,7 @@ mod service_publish_subscribe {
                     subscribers.push(sut.subscriber_builder().create().unwrap());
                 }
 
-                assert_that!(publisher.send_copy(1234), eq Ok(n));
+                assert_that!(publisher.send_copy(1234).map(|r| r.number_of_recipients()), eq Ok(n));
                 for subscriber in &subscribers {
                     assert_that!(subscriber.receive().unwrap(), is_some);
                 }
@@ -1156,7 +1226,7 @@ mod service_publish_subscribe {
 
                 for publisher in publishers {
                     let payload: u64 = (n * k + 12) as _;
-                    assert_that!(publisher.send_copy(payload), eq Ok(1));
+                    assert_that!(publisher.send_copy(payload).map(|r| r.number_of_recipients()), eq Ok(1));
                     let sample = subscriber.receive().unwrap();
                     assert_that!(sample, is_some);
                     assert_that!(*sample.unwrap(), eq payload);
@@ -2151,7 +2221,7 @@ mod service_publish_subscribe {
 
         const PAYLOAD: u64 = 98129312938;
 
-        assert_that!(publisher.send_copy(PAYLOAD), eq Ok(1));
+        assert_that!(publisher.send_copy(PAYLOAD).map(|r| r.number_of_recipients()), eq Ok(1));
         assert_that!(*subscriber.receive().unwrap().unwrap(), eq PAYLOAD);
     }
     #[test]
@@ -2315,6 +2385,48 @@ mod service_publish_subscribe {
         }
     }
 
+    #[test]
+    fn slice_samples_of_varying_length_can_be_in_flight_in_parallel<Sut: Service>() {
+        const MAX_ELEMENTS: usize = 512;
+        const NUMBER_OF_SAMPLES: usize = 16;
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[u64]>()
+            .subscriber_max_buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_slice_len(MAX_ELEMENTS)
+            .create()
+            .unwrap();
+        let subscriber = sut
+            .subscriber_builder()
+            .buffer_size(NUMBER_OF_SAMPLES)
+            .create()
+            .unwrap();
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let len = if n % 2 == 0 { n } else { MAX_ELEMENTS - n };
+            let sample = publisher.loan_slice_uninit(len).unwrap();
+            let report = sample.write_from_fn(|i| (i + n) as u64).send().unwrap();
+            assert_that!(report.number_of_recipients(), eq 1);
+        }
+
+        for n in 0..NUMBER_OF_SAMPLES {
+            let len = if n % 2 == 0 { n } else { MAX_ELEMENTS - n };
+            let recv_sample = subscriber.receive().unwrap().unwrap();
+
+            assert_that!(recv_sample.payload(), len len);
+            for (i, element) in recv_sample.payload().iter().enumerate() {
+                assert_that!(*element, eq(i + n) as u64);
+            }
+        }
+    }
+
     #[test]
     fn slice_aligned_service_works<Sut: Service>() {
         const MAX_ELEMENTS: usize = 91;
@@ -2423,6 +2535,95 @@ mod service_publish_subscribe {
         assert_that!(sut2.err().unwrap(), eq PublishSubscribeOpenError::IncompatibleTypes);
     }
 
+    #[test]
+    fn open_succeeds_when_payload_types_have_same_type_name_and_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_a::Payload>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_b::Payload>()
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<[crate::payload_b::Payload]>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_fails_when_payload_types_have_same_type_name_but_different_layout<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_a::Payload>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_b::IncompatiblePayload>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn open_fails_when_payload_types_have_same_layout_but_different_type_name<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_a::StructuralPayload>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_b::StructuralPayload>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_b::ReorderedStructuralPayload>()
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleTypes));
+    }
+
+    #[test]
+    fn communication_between_payload_types_with_same_type_name_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_a::Payload>()
+            .create()
+            .unwrap();
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<crate::payload_b::Payload>()
+            .open()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut2.subscriber_builder().create().unwrap();
+
+        assert_that!(
+            publisher.send_copy(crate::payload_a::Payload { value: 8127 }),
+            is_ok
+        );
+        let sample = subscriber.receive().unwrap().unwrap();
+        assert_that!(sample.other_value, eq 8127);
+    }
+
     #[test]
     fn create_with_custom_payload_type_works<Sut: Service>() {
         let service_name = generate_name();
@@ -2715,9 +2916,493 @@ mod service_publish_subscribe {
         assert_that!(*sample, eq 456);
     }
 
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_publish_deadline_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(10))
+            .deadline_missed_event(EventId::new(3))
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(20))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleDeadline));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(10))
+            .deadline_missed_event(EventId::new(4))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleDeadline));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(10))
+            .deadline_missed_event(EventId::new(3))
+            .open();
+        assert_that!(sut2, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_liveliness_lease_requirement<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(Duration::from_millis(10))
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(Duration::from_millis(20))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleLivelinessLease));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(Duration::from_millis(10))
+            .liveliness_lost_event(EventId::new(1))
+            .open();
+        assert_that!(sut2.err(), eq Some(PublishSubscribeOpenError::IncompatibleLivelinessLease));
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(Duration::from_millis(10))
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn deadline_and_liveliness_lease_are_stored_in_static_config<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(12))
+            .deadline_missed_event(EventId::new(5))
+            .liveliness_lease(Duration::from_millis(34))
+            .liveliness_lost_event(EventId::new(6))
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .open()
+            .unwrap();
+
+        for static_config in [sut.static_config(), sut2.static_config()] {
+            assert_that!(static_config.publish_deadline(), eq Some(Duration::from_millis(12)));
+            assert_that!(static_config.deadline_missed_event(), eq Some(EventId::new(5)));
+            assert_that!(static_config.liveliness_lease(), eq Some(Duration::from_millis(34)));
+            assert_that!(static_config.liveliness_lost_event(), eq Some(EventId::new(6)));
+        }
+    }
+
+    #[test]
+    fn deadline_and_liveliness_lease_are_disabled_by_default<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::ZERO)
+            .create()
+            .unwrap();
+
+        assert_that!(sut.static_config().publish_deadline(), eq None);
+        assert_that!(sut.static_config().deadline_missed_event(), eq None);
+        assert_that!(sut.static_config().liveliness_lease(), eq None);
+        assert_that!(sut.static_config().liveliness_lost_event(), eq None);
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+
+        assert_that!(missed_deadlines(&subscriber), len 0);
+        assert_that!(expired_liveliness_leases(&subscriber), len 0);
+        drop(publisher);
+    }
+
+    #[test]
+    fn subscriber_detects_missed_publish_deadline<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(DEADLINE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(missed_deadlines(&subscriber), len 0);
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(missed_deadlines(&subscriber), eq vec![publisher.id()]);
+
+        publisher.assert_liveliness();
+        assert_that!(missed_deadlines(&subscriber), eq vec![publisher.id()]);
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(missed_deadlines(&subscriber), len 0);
+
+        drop(publisher);
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(missed_deadlines(&subscriber), len 0);
+    }
+
+    #[test]
+    fn subscriber_detects_expired_liveliness_lease<Sut: Service>() {
+        const LEASE: Duration = Duration::from_millis(50);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(LEASE)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(expired_liveliness_leases(&subscriber), len 0);
+
+        std::thread::sleep(LEASE * 2);
+        assert_that!(expired_liveliness_leases(&subscriber), eq vec![publisher.id()]);
+
+        publisher.assert_liveliness();
+        assert_that!(expired_liveliness_leases(&subscriber), len 0);
+
+        std::thread::sleep(LEASE * 2);
+        assert_that!(expired_liveliness_leases(&subscriber), eq vec![publisher.id()]);
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        assert_that!(expired_liveliness_leases(&subscriber), len 0);
+    }
+
+    #[test]
+    fn missed_publish_deadline_is_emitted_once_as_event<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        const DEADLINE_EVENT: EventId = EventId::new(7);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(DEADLINE)
+            .deadline_missed_event(DEADLINE_EVENT)
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(missed_deadlines(&subscriber), len 1);
+        assert_that!(missed_deadlines(&subscriber), len 1);
+
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(DEADLINE_EVENT));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+
+        assert_that!(publisher.send_copy(1), is_ok);
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(missed_deadlines(&subscriber), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(DEADLINE_EVENT));
+    }
+
+    #[test]
+    fn expired_liveliness_lease_is_emitted_as_event<Sut: Service>() {
+        const LEASE: Duration = Duration::from_millis(50);
+        const LIVELINESS_EVENT: EventId = EventId::new(9);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(LEASE)
+            .liveliness_lost_event(LIVELINESS_EVENT)
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+
+        std::thread::sleep(LEASE * 2);
+        assert_that!(expired_liveliness_leases(&subscriber), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(LIVELINESS_EVENT));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
+    #[test]
+    fn missed_publish_deadline_is_emitted_once_for_all_subscribers<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        const DEADLINE_EVENT: EventId = EventId::new(7);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(DEADLINE)
+            .deadline_missed_event(DEADLINE_EVENT)
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let subscriber_1 = sut.subscriber_builder().create().unwrap();
+        let subscriber_2 = sut.subscriber_builder().create().unwrap();
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(missed_deadlines(&subscriber_1), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(DEADLINE_EVENT));
+
+        assert_that!(missed_deadlines(&subscriber_2), len 1);
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
+    #[test]
+    fn sending_publisher_emits_missed_deadline_of_other_publisher<Sut: Service>() {
+        const DEADLINE: Duration = Duration::from_millis(50);
+        const DEADLINE_EVENT: EventId = EventId::new(7);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(DEADLINE)
+            .deadline_missed_event(DEADLINE_EVENT)
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let active_publisher = sut.publisher_builder().create().unwrap();
+        let _silent_publisher = sut.publisher_builder().create().unwrap();
+
+        std::thread::sleep(DEADLINE * 2);
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+
+        assert_that!(active_publisher.send_copy(1), is_ok);
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(DEADLINE_EVENT));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+
+        assert_that!(active_publisher.send_copy(2), is_ok);
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
+    #[test]
+    fn publisher_asserting_liveliness_emits_expired_lease_of_other_publisher<Sut: Service>() {
+        const LEASE: Duration = Duration::from_millis(50);
+        const LIVELINESS_EVENT: EventId = EventId::new(9);
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(LEASE)
+            .liveliness_lost_event(LIVELINESS_EVENT)
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open().unwrap();
+        let listener = event.listener_builder().create().unwrap();
+
+        let active_publisher = sut.publisher_builder().create().unwrap();
+        let _silent_publisher = sut.publisher_builder().create().unwrap();
+
+        std::thread::sleep(LEASE * 2);
+        active_publisher.assert_liveliness();
+        assert_that!(listener.try_wait_one().unwrap(), eq Some(LIVELINESS_EVENT));
+        assert_that!(listener.try_wait_one().unwrap(), eq None);
+    }
+
+    #[test]
+    fn event_service_for_quality_of_service_events_is_created_with_the_service<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .liveliness_lease(Duration::from_millis(10))
+            .liveliness_lost_event(EventId::new(12))
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open();
+        assert_that!(event, is_ok);
+        assert_that!(event.unwrap().static_config().event_id_max_value(), ge 12);
+
+        drop(sut);
+        let event = node.service_builder(&service_name).event().open();
+        assert_that!(event.err(), eq Some(EventOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn no_event_service_is_created_without_quality_of_service_events<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .publish_deadline(Duration::from_millis(10))
+            .create()
+            .unwrap();
+        let event = node.service_builder(&service_name).event().open();
+        assert_that!(event.err(), eq Some(EventOpenError::DoesNotExist));
+    }
+
+    #[test]
+    fn statistics_count_sent_received_and_overflowed_samples<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().buffer_size(2).create().unwrap();
+
+        for value in 0..5 {
+            assert_that!(publisher.send_copy(value).map(|r| r.number_of_recipients()), eq Ok(1));
+        }
+        assert_that!(subscriber.receive().unwrap(), is_some);
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.sent_samples(), eq 5);
+        assert_that!(statistics.received_samples(), eq 1);
+        assert_that!(statistics.overflowed_samples(), eq 3);
+
+        assert_that!(statistics.publishers(), len 1);
+        let publisher_statistics = &statistics.publishers()[0];
+        assert_that!(publisher_statistics.publisher_id(), eq publisher.id());
+        assert_that!(publisher_statistics.node_id(), eq node.id());
+        assert_that!(publisher_statistics.sent_samples(), eq 5);
+        assert_that!(publisher_statistics.overflowed_samples(), eq 3);
+
+        assert_that!(statistics.subscribers(), len 1);
+        let subscriber_statistics = &statistics.subscribers()[0];
+        assert_that!(subscriber_statistics.subscriber_id(), eq subscriber.id());
+        assert_that!(subscriber_statistics.buffer_size(), eq 2);
+        assert_that!(subscriber_statistics.delivered_samples(), eq 5);
+        assert_that!(subscriber_statistics.received_samples(), eq 1);
+        assert_that!(subscriber_statistics.overflowed_samples(), eq 3);
+        assert_that!(subscriber_statistics.pending_samples(), eq 1);
+    }
+
+    #[test]
+    fn statistics_count_loan_failures<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut
+            .publisher_builder()
+            .max_loaned_samples(1)
+            .create()
+            .unwrap();
+
+        let _sample = publisher.loan().unwrap();
+        assert_that!(publisher.loan(), is_err);
+        assert_that!(publisher.loan(), is_err);
+
+        assert_that!(sut.statistics().publishers()[0].loan_failures(), eq 2);
+    }
+
+    #[test]
+    fn statistics_of_new_ports_start_at_zero<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .max_publishers(1)
+            .max_subscribers(1)
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        let subscriber = sut.subscriber_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1).map(|r| r.number_of_recipients()), eq Ok(1));
+        assert_that!(subscriber.receive().unwrap(), is_some);
+        drop(publisher);
+        drop(subscriber);
+
+        let _publisher = sut.publisher_builder().create().unwrap();
+        let _subscriber = sut.subscriber_builder().create().unwrap();
+
+        let statistics = sut.statistics();
+        assert_that!(statistics.sent_samples(), eq 0);
+        assert_that!(statistics.received_samples(), eq 0);
+        assert_that!(statistics.subscribers()[0].delivered_samples(), eq 0);
+    }
+
+    #[test]
+    fn statistics_are_part_of_service_details<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let publisher = sut.publisher_builder().create().unwrap();
+        assert_that!(publisher.send_copy(1).map(|r| r.number_of_recipients()), eq Ok(0));
+
+        let details = Sut::details(
+            &service_name,
+            Config::global_config(),
+            MessagingPattern::PublishSubscribe,
+        )
+        .unwrap()
+        .unwrap();
+        let statistics = details.dynamic_details.unwrap().statistics.unwrap();
+        assert_that!(statistics.sent_samples(), eq 1);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
+    #[instantiate_tests(<iceoryx2::service::ipc_buddy::Service>)]
+    mod ipc_buddy {}
+
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
 }
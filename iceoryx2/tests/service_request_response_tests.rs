@@ -0,0 +1,571 @@
+// Copyright (c) 2024 Contributors to the Eclipse Foundation
+//
+// See the NOTICE file(s) distributed with this work for additional
+// information regarding copyright ownership.
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache Software License 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0, or the MIT license
+// which is available at https://opensource.org/licenses/MIT.
+//
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#[generic_tests::define]
+mod service_request_response {
+    use iceoryx2::port::client::{ClientCreateError, ClientLoanError, ClientSendError};
+    use iceoryx2::port::server::ServerCreateError;
+    use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
+    use iceoryx2::service::Service;
+    use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
+    use iceoryx2_bb_testing::assert_that;
+
+    fn generate_name() -> ServiceName {
+        ServiceName::new(&format!(
+            "service_tests_{}",
+            UniqueSystemId::new().unwrap().value()
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn creating_non_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+
+        assert_that!(sut, is_ok);
+        let sut = sut.unwrap();
+        assert_that!(*sut.name(), eq service_name);
+    }
+
+    #[test]
+    fn creating_same_service_twice_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut, is_ok);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseCreateError::AlreadyExists);
+    }
+
+    #[test]
+    fn opening_non_existing_service_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut, is_err);
+        assert_that!(sut.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn opening_existing_service_works<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_ok);
+        let sut2 = sut2.unwrap();
+        assert_that!(sut2.service_id(), eq sut.service_id());
+    }
+
+    #[test]
+    fn opening_existing_service_with_wrong_request_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<i64, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleRequestType);
+    }
+
+    #[test]
+    fn opening_existing_service_with_wrong_response_type_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, i64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::IncompatibleResponseType);
+    }
+
+    #[test]
+    fn opening_publish_subscribe_service_as_request_response_fails<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .publish_subscribe::<u64>()
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .open();
+        assert_that!(sut2, is_err);
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotExist);
+    }
+
+    #[test]
+    fn open_fails_when_service_does_not_satisfy_settings<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let _sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(2)
+            .max_servers(2)
+            .max_active_requests(2)
+            .client_max_response_buffer_size(2)
+            .client_max_borrowed_responses(2)
+            .create()
+            .unwrap();
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfClients);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_servers(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfServers);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedAmountOfActiveRequests);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .client_max_response_buffer_size(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinResponseBufferSize);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .client_max_borrowed_responses(3)
+            .open();
+        assert_that!(sut2.err().unwrap(), eq RequestResponseOpenError::DoesNotSupportRequestedMinBorrowedResponses);
+
+        let sut2 = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(1)
+            .max_servers(1)
+            .max_active_requests(1)
+            .client_max_response_buffer_size(1)
+            .client_max_borrowed_responses(1)
+            .open();
+        assert_that!(sut2, is_ok);
+    }
+
+    #[test]
+    fn settings_are_stored_in_static_config<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(4)
+            .max_servers(3)
+            .max_nodes(5)
+            .max_active_requests(6)
+            .client_max_response_buffer_size(7)
+            .client_max_borrowed_responses(8)
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_clients(), eq 4);
+        assert_that!(static_config.max_servers(), eq 3);
+        assert_that!(static_config.max_nodes(), eq 5);
+        assert_that!(static_config.max_active_requests(), eq 6);
+        assert_that!(static_config.client_max_response_buffer_size(), eq 7);
+        assert_that!(static_config.client_max_borrowed_responses(), eq 8);
+    }
+
+    #[test]
+    fn settings_are_adjusted_to_meaningful_values<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(0)
+            .max_servers(0)
+            .max_nodes(0)
+            .max_active_requests(0)
+            .client_max_response_buffer_size(0)
+            .client_max_borrowed_responses(0)
+            .create()
+            .unwrap();
+
+        let static_config = sut.static_config();
+        assert_that!(static_config.max_clients(), eq 1);
+        assert_that!(static_config.max_servers(), eq 1);
+        assert_that!(static_config.max_nodes(), eq 1);
+        assert_that!(static_config.max_active_requests(), eq 1);
+        assert_that!(static_config.client_max_response_buffer_size(), eq 1);
+        assert_that!(static_config.client_max_borrowed_responses(), eq 1);
+    }
+
+    #[test]
+    fn number_of_clients_and_servers_is_limited<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_clients(1)
+            .max_servers(1)
+            .create()
+            .unwrap();
+
+        let _client = sut.client_builder().create().unwrap();
+        let client2 = sut.client_builder().create();
+        assert_that!(client2.err().unwrap(), eq ClientCreateError::ExceedsMaxSupportedClients);
+
+        let server = sut.server_builder().create().unwrap();
+        let server2 = sut.server_builder().create();
+        assert_that!(server2.err().unwrap(), eq ServerCreateError::ExceedsMaxSupportedServers);
+
+        drop(server);
+        let server2 = sut.server_builder().create();
+        assert_that!(server2, is_ok);
+    }
+
+    #[test]
+    fn client_and_server_can_exchange_requests_and_responses<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1234).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 1);
+        assert_that!(server.has_requests(), eq Ok(true));
+
+        let active_request = server.receive().unwrap().unwrap();
+        assert_that!(*active_request, eq 1234);
+        assert_that!(active_request.origin(), eq client.id());
+        assert_that!(active_request.header().request_id(), eq pending_response.request_id());
+        assert_that!(active_request.send_copy(5678), is_ok);
+        assert_that!(active_request.send_copy(9012), is_ok);
+        drop(active_request);
+
+        assert_that!(server.has_requests(), eq Ok(false));
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 5678);
+        assert_that!(response.origin(), eq server.id());
+        assert_that!(response.header().request_id(), eq pending_response.request_id());
+        let response_2 = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response_2, eq 9012);
+        drop(response);
+        drop(response_2);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn responses_are_delivered_to_the_matching_pending_response<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(2)
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(1).unwrap();
+        let pending_response_2 = client.send_copy(2).unwrap();
+        assert_that!(pending_response_1.request_id(), ne pending_response_2.request_id());
+
+        while let Some(active_request) = server.receive().unwrap() {
+            active_request.send_copy(*active_request * 10).unwrap();
+        }
+
+        let response = pending_response_2.receive().unwrap().unwrap();
+        assert_that!(*response, eq 20);
+        drop(response);
+        assert_that!(pending_response_2.receive().unwrap(), is_none);
+
+        let response = pending_response_1.receive().unwrap().unwrap();
+        assert_that!(*response, eq 10);
+        drop(response);
+        assert_that!(pending_response_1.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn request_is_delivered_to_all_servers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server_1 = sut.server_builder().create().unwrap();
+        let server_2 = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(42).unwrap();
+        assert_that!(pending_response.number_of_server_connections(), eq 2);
+
+        for server in [&server_1, &server_2] {
+            let active_request = server.receive().unwrap().unwrap();
+            assert_that!(*active_request, eq 42);
+            active_request.send_copy(*active_request + 1).unwrap();
+        }
+
+        let mut origins = vec![];
+        while let Some(response) = pending_response.receive().unwrap() {
+            assert_that!(*response, eq 43);
+            origins.push(response.origin());
+        }
+        assert_that!(origins, len 2);
+        assert_that!(origins, contains server_1.id());
+        assert_that!(origins, contains server_2.id());
+    }
+
+    #[test]
+    fn number_of_active_requests_is_limited<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(1)
+            .create()
+            .unwrap();
+
+        let _server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        let result = client.send_copy(2);
+        assert_that!(result.err().unwrap(), eq ClientSendError::ExceedsMaxActiveRequests);
+
+        drop(pending_response);
+        assert_that!(client.send_copy(3), is_ok);
+    }
+
+    #[test]
+    fn requests_without_receiving_server_do_not_count_as_active<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(1)
+            .create()
+            .unwrap();
+
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response_1 = client.send_copy(1).unwrap();
+        assert_that!(pending_response_1.number_of_server_connections(), eq 0);
+        let pending_response_2 = client.send_copy(2);
+        assert_that!(pending_response_2, is_ok);
+    }
+
+    #[test]
+    fn server_receives_requests_from_all_clients_in_turn<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .max_active_requests(4)
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client_1 = sut.client_builder().create().unwrap();
+        let client_2 = sut.client_builder().create().unwrap();
+
+        let mut pending_responses = vec![];
+        for i in 0..2 {
+            pending_responses.push(client_1.send_copy(i).unwrap());
+            pending_responses.push(client_2.send_copy(i).unwrap());
+        }
+
+        let mut origins = vec![];
+        while let Some(active_request) = server.receive().unwrap() {
+            origins.push(active_request.origin());
+        }
+
+        assert_that!(origins, len 4);
+        assert_that!(origins[0], ne origins[1]);
+        assert_that!(origins[1], ne origins[2]);
+        assert_that!(origins[2], ne origins[3]);
+    }
+
+    #[test]
+    fn number_of_loaned_requests_is_limited<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let client = sut
+            .client_builder()
+            .max_loaned_requests(2)
+            .create()
+            .unwrap();
+
+        let request_1 = client.loan().unwrap();
+        let _request_2 = client.loan_uninit().unwrap();
+        let request_3 = client.loan();
+        assert_that!(request_3.err().unwrap(), eq ClientLoanError::ExceedsMaxLoanedRequests);
+
+        drop(request_1);
+        assert_that!(client.loan(), is_ok);
+    }
+
+    #[test]
+    fn response_is_not_delivered_after_pending_response_was_dropped<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        let server = sut.server_builder().create().unwrap();
+        let client = sut.client_builder().create().unwrap();
+
+        let pending_response = client.send_copy(1).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        drop(pending_response);
+
+        active_request.send_copy(2).unwrap();
+        drop(active_request);
+
+        let pending_response = client.send_copy(3).unwrap();
+        let active_request = server.receive().unwrap().unwrap();
+        active_request.send_copy(4).unwrap();
+
+        let response = pending_response.receive().unwrap().unwrap();
+        assert_that!(*response, eq 4);
+        drop(response);
+        assert_that!(pending_response.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn dynamic_config_tracks_number_of_clients_and_servers<Sut: Service>() {
+        let service_name = generate_name();
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+
+        let sut = node
+            .service_builder(&service_name)
+            .request_response::<u64, u64>()
+            .create()
+            .unwrap();
+
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 0);
+
+        let client = sut.client_builder().create().unwrap();
+        let server = sut.server_builder().create().unwrap();
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 1);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 1);
+
+        drop(client);
+        drop(server);
+        assert_that!(sut.dynamic_config().number_of_clients(), eq 0);
+        assert_that!(sut.dynamic_config().number_of_servers(), eq 0);
+    }
+
+    #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
+    mod ipc {}
+
+    #[instantiate_tests(<iceoryx2::service::ipc_buddy::Service>)]
+    mod ipc_buddy {}
+
+    #[instantiate_tests(<iceoryx2::service::local::Service>)]
+    mod local {}
+}
@@ -12,6 +12,7 @@
 
 #[cfg(test)]
 mod service_static_config_message_type_details {
+    use iceoryx2::prelude::ZeroCopySend;
     use iceoryx2::service::static_config::message_type_details::{TypeDetail, TypeVariant};
     use iceoryx2_bb_testing::assert_that;
     use std::mem::size_of;
@@ -31,6 +32,7 @@ mod service_static_config_message_type_details {
             type_name: "service_static_config_tests::service_static_config_message_type_details::test_internal_new::Tmp".to_string(),
             size: 0 ,
             alignment: 1,
+            layout_hash: None,
         };
         assert_that!(sut, eq expected);
 
@@ -40,6 +42,7 @@ mod service_static_config_message_type_details {
             type_name: "i64".to_string(),
             size: 8,
             alignment: ALIGNMENT,
+            layout_hash: None,
         };
 
         assert_that!(sut, eq expected);
@@ -51,8 +54,40 @@ mod service_static_config_message_type_details {
                 .to_string(),
             size: size_of::<TypeDetail>(),
             alignment: ALIGNMENT,
+            layout_hash: None,
         };
 
         assert_that!(sut, eq expected);
     }
+
+    #[test]
+    fn test_new_uses_zero_copy_send_type_identity() {
+        #[derive(ZeroCopySend)]
+        #[type_name("Tmp")]
+        #[repr(C)]
+        struct Tmp {
+            _value: u32,
+        }
+
+        let sut = TypeDetail::new::<Tmp>(TypeVariant::FixedSize);
+        let expected = TypeDetail {
+            variant: TypeVariant::FixedSize,
+            type_name: "Tmp".to_string(),
+            size: 4,
+            alignment: 4,
+            layout_hash: Tmp::layout_hash(),
+        };
+        assert_that!(sut, eq expected);
+        assert_that!(sut.layout_hash, is_some);
+
+        let sut = TypeDetail::new::<i64>(TypeVariant::Dynamic);
+        let expected = TypeDetail {
+            variant: TypeVariant::Dynamic,
+            type_name: "i64".to_string(),
+            size: 8,
+            alignment: ALIGNMENT,
+            layout_hash: None,
+        };
+        assert_that!(sut, eq expected);
+    }
 }
@@ -19,12 +19,16 @@ mod service {
 
     use iceoryx2::node::NodeView;
     use iceoryx2::prelude::*;
+    use iceoryx2::service::builder::blackboard::{BlackboardCreateError, BlackboardOpenError};
     use iceoryx2::service::builder::event::{EventCreateError, EventOpenError};
     use iceoryx2::service::builder::publish_subscribe::{
         PublishSubscribeCreateError, PublishSubscribeOpenError,
     };
+    use iceoryx2::service::builder::request_response::{
+        RequestResponseCreateError, RequestResponseOpenError,
+    };
     use iceoryx2::service::messaging_pattern::MessagingPattern;
-    use iceoryx2::service::port_factory::{event, publish_subscribe};
+    use iceoryx2::service::port_factory::{blackboard, event, publish_subscribe, request_response};
     use iceoryx2::service::{ServiceDetailsError, ServiceListError};
     use iceoryx2_bb_log::{set_log_level, LogLevel};
     use iceoryx2_bb_posix::system_configuration::SystemInfo;
@@ -79,6 +83,20 @@ mod service {
     unsafe impl<Sut: Service> Send for EventTests<Sut> {}
     unsafe impl<Sut: Service> Sync for EventTests<Sut> {}
 
+    struct RequestResponseTests<Sut: Service> {
+        _data: PhantomData<Sut>,
+    }
+
+    unsafe impl<Sut: Service> Send for RequestResponseTests<Sut> {}
+    unsafe impl<Sut: Service> Sync for RequestResponseTests<Sut> {}
+
+    struct BlackboardTests<Sut: Service> {
+        _data: PhantomData<Sut>,
+    }
+
+    unsafe impl<Sut: Service> Send for BlackboardTests<Sut> {}
+    unsafe impl<Sut: Service> Sync for BlackboardTests<Sut> {}
+
     impl<Sut: Service> SutFactory<Sut> for PubSubTests<Sut> {
         type Factory = publish_subscribe::PortFactory<Sut, u64, ()>;
         type CreateError = PublishSubscribeCreateError;
@@ -209,6 +227,137 @@ mod service {
         }
     }
 
+    impl<Sut: Service> SutFactory<Sut> for RequestResponseTests<Sut> {
+        type Factory = request_response::PortFactory<Sut, u64, u64>;
+        type CreateError = RequestResponseCreateError;
+        type OpenError = RequestResponseOpenError;
+
+        fn new() -> Self {
+            Self { _data: PhantomData }
+        }
+
+        fn open(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &AttributeVerifier,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name)
+                .request_response::<u64, u64>()
+                .open_with_attributes(attributes)
+        }
+
+        fn create(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &AttributeSpecifier,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            let number_of_nodes = (SystemInfo::NumberOfCpuCores.value()).clamp(128, 1024);
+            node.service_builder(service_name)
+                .request_response::<u64, u64>()
+                .max_nodes(number_of_nodes)
+                .create_with_attributes(attributes)
+        }
+
+        fn assert_attribute_error(error: Self::OpenError) {
+            assert_that!(error, eq RequestResponseOpenError::IncompatibleAttributes);
+        }
+
+        fn assert_create_error(error: Self::CreateError) {
+            assert_that!(
+                error,
+                any_of([
+                    RequestResponseCreateError::AlreadyExists,
+                    RequestResponseCreateError::IsBeingCreatedByAnotherInstance,
+                    RequestResponseCreateError::HangsInCreation
+                ])
+            );
+        }
+        fn assert_open_error(error: Self::OpenError) {
+            assert_that!(
+                error,
+                any_of([
+                    RequestResponseOpenError::DoesNotExist,
+                    RequestResponseOpenError::InsufficientPermissions,
+                    RequestResponseOpenError::IsMarkedForDestruction,
+                    RequestResponseOpenError::ServiceInCorruptedState,
+                    RequestResponseOpenError::HangsInCreation
+                ])
+            );
+        }
+
+        fn messaging_pattern() -> MessagingPattern {
+            MessagingPattern::RequestResponse
+        }
+    }
+
+    impl<Sut: Service> SutFactory<Sut> for BlackboardTests<Sut> {
+        type Factory = blackboard::PortFactory<Sut, u64>;
+        type CreateError = BlackboardCreateError;
+        type OpenError = BlackboardOpenError;
+
+        fn new() -> Self {
+            Self { _data: PhantomData }
+        }
+
+        fn open(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &AttributeVerifier,
+        ) -> Result<Self::Factory, Self::OpenError> {
+            node.service_builder(service_name)
+                .blackboard_opener::<u64>()
+                .open_with_attributes(attributes)
+        }
+
+        fn create(
+            &self,
+            node: &Node<Sut>,
+            service_name: &ServiceName,
+            attributes: &AttributeSpecifier,
+        ) -> Result<Self::Factory, Self::CreateError> {
+            let number_of_nodes = (SystemInfo::NumberOfCpuCores.value()).clamp(128, 1024);
+            node.service_builder(service_name)
+                .blackboard_creator::<u64>()
+                .max_nodes(number_of_nodes)
+                .add::<u64>(0, 0)
+                .create_with_attributes(attributes)
+        }
+
+        fn assert_attribute_error(error: Self::OpenError) {
+            assert_that!(error, eq BlackboardOpenError::IncompatibleAttributes);
+        }
+
+        fn assert_create_error(error: Self::CreateError) {
+            assert_that!(
+                error,
+                any_of([
+                    BlackboardCreateError::AlreadyExists,
+                    BlackboardCreateError::IsBeingCreatedByAnotherInstance,
+                    BlackboardCreateError::HangsInCreation
+                ])
+            );
+        }
+        fn assert_open_error(error: Self::OpenError) {
+            assert_that!(
+                error,
+                any_of([
+                    BlackboardOpenError::DoesNotExist,
+                    BlackboardOpenError::InsufficientPermissions,
+                    BlackboardOpenError::IsMarkedForDestruction,
+                    BlackboardOpenError::ServiceInCorruptedState,
+                    BlackboardOpenError::HangsInCreation
+                ])
+            );
+        }
+
+        fn messaging_pattern() -> MessagingPattern {
+            MessagingPattern::Blackboard
+        }
+    }
+
     #[test]
     fn same_name_with_different_messaging_pattern_is_allowed<
         Sut: Service,
@@ -945,6 +1094,12 @@ mod service {
 
         #[instantiate_tests(<Service, crate::service::PubSubTests::<Service>>)]
         mod publish_subscribe {}
+
+        #[instantiate_tests(<Service, crate::service::RequestResponseTests::<Service>>)]
+        mod request_response {}
+
+        #[instantiate_tests(<Service, crate::service::BlackboardTests::<Service>>)]
+        mod blackboard {}
     }
 
     mod local {
@@ -955,5 +1110,11 @@ mod service {
 
         #[instantiate_tests(<Service, crate::service::PubSubTests::<Service>>)]
         mod publish_subscribe {}
+
+        #[instantiate_tests(<Service, crate::service::RequestResponseTests::<Service>>)]
+        mod request_response {}
+
+        #[instantiate_tests(<Service, crate::service::BlackboardTests::<Service>>)]
+        mod blackboard {}
     }
 }
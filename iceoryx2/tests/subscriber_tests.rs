@@ -16,12 +16,26 @@ mod subscriber {
 
     use iceoryx2::{
         node::NodeBuilder,
-        port::subscriber::{SubscriberCreateError, SubscriberReceiveError},
-        service::{service_name::ServiceName, Service},
+        port::subscriber::{SampleFilter, SubscriberCreateError, SubscriberReceiveError},
+        port::update_connections::UpdateConnections,
+        prelude::ZeroCopySend,
+        service::{
+            port_factory::{publish_subscribe::PortFactory, publisher::UnableToDeliverStrategy},
+            service_name::ServiceName,
+            Service,
+        },
     };
     use iceoryx2_bb_posix::unique_system_id::UniqueSystemId;
     use iceoryx2_bb_testing::assert_that;
 
+    #[derive(Debug, ZeroCopySend)]
+    #[repr(C)]
+    struct Topic {
+        id: u64,
+    }
+
+    const TOPIC_ID_OFFSET: usize = 0;
+
     fn generate_name() -> ServiceName {
         ServiceName::new(&format!(
             "listener_tests_{}",
@@ -67,9 +81,241 @@ mod subscriber {
         }
     }
 
+    fn create_topic_service<Sut: Service>(
+        node: &iceoryx2::node::Node<Sut>,
+    ) -> PortFactory<Sut, u64, Topic> {
+        node.service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<Topic>()
+            .subscriber_max_buffer_size(2)
+            .subscriber_max_borrowed_samples(1)
+            .enable_safe_overflow(false)
+            .create()
+            .unwrap()
+    }
+
+    fn send_with_topic<Sut: Service>(
+        publisher: &iceoryx2::port::publisher::Publisher<Sut, u64, Topic>,
+        id: u64,
+    ) {
+        let mut sample = publisher.loan_uninit().unwrap();
+        sample.user_header_mut().id = id;
+        sample.write_payload(id).send().unwrap();
+    }
+
+    #[test]
+    fn filter_delivers_only_accepted_samples<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = create_topic_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::masked_equals(TOPIC_ID_OFFSET, 0u64, 1u64))
+            .create()
+            .unwrap();
+
+        for id in 0..10 {
+            send_with_topic(&publisher, id);
+            if id % 2 == 0 {
+                let sample = sut.receive().unwrap().unwrap();
+                assert_that!(*sample.payload(), eq id);
+                assert_that!(sample.user_header().id, eq id);
+            }
+        }
+
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn filtered_samples_are_not_delivered_by_publisher<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = create_topic_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let _sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(TOPIC_ID_OFFSET, 1u64))
+            .create()
+            .unwrap();
+        let _other_subscriber = service.subscriber_builder().create().unwrap();
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        sample.user_header_mut().id = 0;
+        assert_that!(sample.write_payload(0).send().map(|r| r.number_of_recipients()), eq Ok(1));
+
+        let mut sample = publisher.loan_uninit().unwrap();
+        sample.user_header_mut().id = 1;
+        assert_that!(sample.write_payload(1).send().map(|r| r.number_of_recipients()), eq Ok(2));
+    }
+
+    #[test]
+    fn filtered_samples_do_not_block_publisher<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = create_topic_service(&node);
+        let publisher = service
+            .publisher_builder()
+            .unable_to_deliver_strategy(UnableToDeliverStrategy::Block)
+            .create()
+            .unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(TOPIC_ID_OFFSET, 1u64))
+            .create()
+            .unwrap();
+
+        for _ in 0..10 {
+            send_with_topic(&publisher, 0);
+        }
+        assert_that!(sut.has_samples().unwrap(), eq false);
+
+        send_with_topic(&publisher, 1);
+        assert_that!(sut.has_samples().unwrap(), eq true);
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 1);
+    }
+
+    #[test]
+    fn filter_is_applied_to_history<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<Topic>()
+            .history_size(4)
+            .subscriber_max_buffer_size(4)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+
+        for id in 0..4 {
+            send_with_topic(&publisher, id);
+        }
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(TOPIC_ID_OFFSET, 2u64))
+            .create()
+            .unwrap();
+        publisher.update_connections().unwrap();
+
+        let sample = sut.receive().unwrap().unwrap();
+        assert_that!(*sample.payload(), eq 2);
+        drop(sample);
+        assert_that!(sut.receive().unwrap(), is_none);
+    }
+
+    #[test]
+    fn filter_exceeding_user_header_fails<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = create_topic_service(&node);
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(TOPIC_ID_OFFSET + 1, 1u64))
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::SampleFilterExceedsUserHeader));
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(usize::MAX, 1u64))
+            .create();
+        assert_that!(sut.err(), eq Some(SubscriberCreateError::SampleFilterExceedsUserHeader));
+
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::equals(TOPIC_ID_OFFSET + 4, 1u32))
+            .create();
+        assert_that!(sut, is_ok);
+    }
+
+    #[test]
+    fn overridden_samples_are_counted_as_lost<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(2)
+            .create()
+            .unwrap();
+
+        assert_that!(publisher.send_copy(0).map(|r| r.number_of_recipients()), eq Ok(1));
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 0);
+
+        for value in 1..6 {
+            assert_that!(publisher.send_copy(value).map(|r| r.number_of_recipients()), eq Ok(1));
+        }
+
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 4);
+        assert_that!(sut.number_of_lost_samples(), eq 3);
+        assert_that!(*sut.receive().unwrap().unwrap(), eq 5);
+        assert_that!(sut.number_of_lost_samples(), eq 3);
+    }
+
+    #[test]
+    fn filtered_samples_are_not_counted_as_lost<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = create_topic_service(&node);
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .filter(SampleFilter::masked_equals(TOPIC_ID_OFFSET, 0u64, 1u64))
+            .create()
+            .unwrap();
+
+        for id in 0..10 {
+            send_with_topic(&publisher, id);
+            while sut.receive().unwrap().is_some() {}
+        }
+
+        assert_that!(sut.number_of_lost_samples(), eq 0);
+        // the last rejected sample is accounted with the next delivery
+        assert_that!(sut.number_of_skipped_samples(), eq 4);
+    }
+
+    #[test]
+    fn filter_does_not_hide_overridden_samples<Sut: Service>() {
+        let node = NodeBuilder::new().create::<Sut>().unwrap();
+        let service = node
+            .service_builder(&generate_name())
+            .publish_subscribe::<u64>()
+            .user_header::<Topic>()
+            .subscriber_max_buffer_size(2)
+            .enable_safe_overflow(true)
+            .create()
+            .unwrap();
+        let publisher = service.publisher_builder().create().unwrap();
+        let sut = service
+            .subscriber_builder()
+            .buffer_size(2)
+            .filter(SampleFilter::masked_equals(TOPIC_ID_OFFSET, 0u64, 1u64))
+            .create()
+            .unwrap();
+
+        send_with_topic(&publisher, 0);
+        assert_that!(sut.receive().unwrap(), is_some);
+
+        // delivers 2, 4, 6 and 8, where 2 and 4 are overridden in the buffer
+        for id in 1..10 {
+            send_with_topic(&publisher, id);
+        }
+        while sut.receive().unwrap().is_some() {}
+
+        assert_that!(sut.number_of_lost_samples(), eq 2);
+        assert_that!(sut.number_of_skipped_samples(), eq 4);
+    }
+
     #[instantiate_tests(<iceoryx2::service::ipc::Service>)]
     mod ipc {}
 
+    #[instantiate_tests(<iceoryx2::service::ipc_buddy::Service>)]
+    mod ipc_buddy {}
+
     #[instantiate_tests(<iceoryx2::service::local::Service>)]
     mod local {}
 }